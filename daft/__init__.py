@@ -66,7 +66,7 @@ from daft.convert import (
 from daft.daft import ImageFormat, ImageMode, ResourceRequest
 from daft.dataframe import DataFrame
 from daft.datatype import DataType, TimeUnit
-from daft.expressions import Expression, col, lit
+from daft.expressions import Expression, col, dense_rank, element, lit, rank, row_number, struct
 from daft.io import (
     DataCatalogTable,
     DataCatalogType,
@@ -107,6 +107,11 @@ __all__ = [
     "ImageMode",
     "ImageFormat",
     "lit",
+    "element",
+    "struct",
+    "row_number",
+    "rank",
+    "dense_rank",
     "Series",
     "TimeUnit",
     "register_viz_hook",
@@ -87,6 +87,9 @@ class JoinType(Enum):
     Inner: int
     Left: int
     Right: int
+    Outer: int
+    Semi: int
+    Anti: int
 
     @staticmethod
     def from_join_type_str(join_type: str) -> JoinType:
@@ -913,11 +916,41 @@ class PyExpr:
     def count(self, mode: CountMode) -> PyExpr: ...
     def sum(self) -> PyExpr: ...
     def mean(self) -> PyExpr: ...
+    def stddev(self, ddof: int) -> PyExpr: ...
+    def variance(self, ddof: int) -> PyExpr: ...
+    def covariance(self, other: PyExpr, ddof: int) -> PyExpr: ...
+    def correlation(self, other: PyExpr) -> PyExpr: ...
     def min(self) -> PyExpr: ...
     def max(self) -> PyExpr: ...
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
+    def bool_and(self) -> PyExpr: ...
+    def bool_or(self) -> PyExpr: ...
+    def bit_and(self) -> PyExpr: ...
+    def bit_or(self) -> PyExpr: ...
+    def bit_xor(self) -> PyExpr: ...
+    def approx_count_distinct(self) -> PyExpr: ...
+    def approx_percentiles(self, percentiles: list[float], force_list_output: bool) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
+    def lag(
+        self,
+        offset: int,
+        default: PyExpr,
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> PyExpr: ...
+    def lead(
+        self,
+        offset: int,
+        default: PyExpr,
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> PyExpr: ...
+    def cum_sum(self, partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
+    def cum_min(self, partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
+    def cum_max(self, partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
     def explode(self) -> PyExpr: ...
     def __abs__(self) -> PyExpr: ...
     def __add__(self, other: PyExpr) -> PyExpr: ...
@@ -940,6 +973,7 @@ class PyExpr:
     def not_null(self) -> PyExpr: ...
     def fill_null(self, fill_value: PyExpr) -> PyExpr: ...
     def is_in(self, other: PyExpr) -> PyExpr: ...
+    def hash(self, hash_function: str, seed: int | None) -> PyExpr: ...
     def name(self) -> str: ...
     def to_field(self, schema: PySchema) -> PyField: ...
     def to_sql(self) -> str: ...
@@ -953,7 +987,17 @@ class PyExpr:
     def dt_month(self) -> PyExpr: ...
     def dt_year(self) -> PyExpr: ...
     def dt_day_of_week(self) -> PyExpr: ...
+    def dt_minute(self) -> PyExpr: ...
+    def dt_second(self) -> PyExpr: ...
+    def dt_millisecond(self) -> PyExpr: ...
+    def dt_time(self) -> PyExpr: ...
+    def dt_day_of_year(self) -> PyExpr: ...
+    def dt_week_of_year(self) -> PyExpr: ...
+    def dt_quarter(self) -> PyExpr: ...
     def dt_truncate(self, interval: str, relative_to: PyExpr) -> PyExpr: ...
+    def dt_convert_time_zone(self, timezone: str) -> PyExpr: ...
+    def dt_replace_time_zone(self, timezone: str | None, ambiguous: str) -> PyExpr: ...
+    def dt_strftime(self, format: str) -> PyExpr: ...
     def utf8_endswith(self, pattern: PyExpr) -> PyExpr: ...
     def utf8_startswith(self, pattern: PyExpr) -> PyExpr: ...
     def utf8_contains(self, pattern: PyExpr) -> PyExpr: ...
@@ -972,6 +1016,20 @@ class PyExpr:
     def utf8_left(self, nchars: PyExpr) -> PyExpr: ...
     def utf8_right(self, nchars: PyExpr) -> PyExpr: ...
     def utf8_find(self, substr: PyExpr) -> PyExpr: ...
+    def utf8_tokenize(self, pattern: str) -> PyExpr: ...
+    def utf8_ngrams(self, n: int, chars: bool) -> PyExpr: ...
+    def utf8_word_count(self) -> PyExpr: ...
+    def utf8_lpad(self, length: PyExpr, pad: PyExpr) -> PyExpr: ...
+    def utf8_rpad(self, length: PyExpr, pad: PyExpr) -> PyExpr: ...
+    def utf8_repeat(self, n: PyExpr) -> PyExpr: ...
+    def utf8_substr(self, start: PyExpr, length: PyExpr) -> PyExpr: ...
+    def utf8_normalize(self, form: str) -> PyExpr: ...
+    def utf8_count_matches(self, pattern: PyExpr, regex: bool) -> PyExpr: ...
+    def utf8_like(self, pattern: PyExpr) -> PyExpr: ...
+    def utf8_ilike(self, pattern: PyExpr) -> PyExpr: ...
+    def utf8_strip_chars(self, chars: PyExpr, left: bool, right: bool) -> PyExpr: ...
+    def utf8_to_date(self, format: str, raise_error_on_failure: bool) -> PyExpr: ...
+    def utf8_to_datetime(self, format: str, timezone: str | None, raise_error_on_failure: bool) -> PyExpr: ...
     def image_decode(self, raise_error_on_failure: bool) -> PyExpr: ...
     def image_encode(self, image_format: ImageFormat) -> PyExpr: ...
     def image_resize(self, w: int, h: int) -> PyExpr: ...
@@ -979,11 +1037,26 @@ class PyExpr:
     def list_join(self, delimiter: PyExpr) -> PyExpr: ...
     def list_count(self, mode: CountMode) -> PyExpr: ...
     def list_get(self, idx: PyExpr, default: PyExpr) -> PyExpr: ...
+    def list_to_map(self, values: PyExpr) -> PyExpr: ...
     def list_sum(self) -> PyExpr: ...
     def list_mean(self) -> PyExpr: ...
     def list_min(self) -> PyExpr: ...
     def list_max(self) -> PyExpr: ...
+    def list_contains(self, item: PyExpr) -> PyExpr: ...
+    def list_slice(self, start: PyExpr, end: PyExpr) -> PyExpr: ...
+    def list_sort(self, descending: bool) -> PyExpr: ...
+    def list_distinct(self) -> PyExpr: ...
+    def list_flatten(self) -> PyExpr: ...
+    def list_value_counts(self) -> PyExpr: ...
+    def list_zip(self, others: list[PyExpr]) -> PyExpr: ...
+    def list_eval(self, element_expr: PyExpr) -> PyExpr: ...
+    def list_filter(self, predicate: PyExpr) -> PyExpr: ...
     def struct_get(self, name: str) -> PyExpr: ...
+    def struct_rename_fields(self, names: list[str]) -> PyExpr: ...
+    def struct_with_field(self, name: str, value: PyExpr) -> PyExpr: ...
+    def map_get(self, key: PyExpr) -> PyExpr: ...
+    def map_keys(self) -> PyExpr: ...
+    def map_values(self) -> PyExpr: ...
     def url_download(
         self, max_connections: int, raise_error_on_failure: bool, multi_thread: bool, config: IOConfig
     ) -> PyExpr: ...
@@ -993,6 +1066,8 @@ class PyExpr:
     def partitioning_years(self) -> PyExpr: ...
     def partitioning_iceberg_bucket(self, n: int) -> PyExpr: ...
     def partitioning_iceberg_truncate(self, w: int) -> PyExpr: ...
+    def minhash(self, num_hashes: int, ngram_size: int, seed: int) -> PyExpr: ...
+    def lsh_bands(self, num_bands: int) -> PyExpr: ...
     def json_query(self, query: str) -> PyExpr: ...
 
     ###
@@ -1002,8 +1077,13 @@ class PyExpr:
     def _input_mapping(self) -> builtins.str | None: ...
 
 def eq(expr1: PyExpr, expr2: PyExpr) -> bool: ...
+def row_number(partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
+def rank(partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
+def dense_rank(partition_by: list[PyExpr], order_by: list[PyExpr], descending: list[bool]) -> PyExpr: ...
+def struct_pack(inputs: list[PyExpr]) -> PyExpr: ...
 def col(name: str) -> PyExpr: ...
 def lit(item: Any) -> PyExpr: ...
+def element() -> PyExpr: ...
 def date_lit(item: int) -> PyExpr: ...
 def time_lit(item: int, tu: PyTimeUnit) -> PyExpr: ...
 def timestamp_lit(item: int, tu: PyTimeUnit, tz: str | None) -> PyExpr: ...
@@ -1039,6 +1119,7 @@ class PySeries:
     def sort(self, descending: bool) -> PySeries: ...
     def argsort(self, descending: bool) -> PySeries: ...
     def hash(self, seed: PySeries | None = None) -> PySeries: ...
+    def hash_with_function(self, hash_function: str, seed: int | None) -> PySeries: ...
     def __invert__(self) -> PySeries: ...
     def count(self, mode: CountMode) -> PySeries: ...
     def sum(self) -> PySeries: ...
@@ -1089,6 +1170,20 @@ class PySeries:
     def utf8_left(self, nchars: PySeries) -> PySeries: ...
     def utf8_right(self, nchars: PySeries) -> PySeries: ...
     def utf8_find(self, substr: PySeries) -> PySeries: ...
+    def utf8_tokenize(self, pattern: str) -> PySeries: ...
+    def utf8_ngrams(self, n: int, chars: bool) -> PySeries: ...
+    def utf8_word_count(self) -> PySeries: ...
+    def utf8_lpad(self, length: PySeries, pad: PySeries) -> PySeries: ...
+    def utf8_rpad(self, length: PySeries, pad: PySeries) -> PySeries: ...
+    def utf8_repeat(self, n: PySeries) -> PySeries: ...
+    def utf8_substr(self, start: PySeries, length: PySeries) -> PySeries: ...
+    def utf8_normalize(self, form: str) -> PySeries: ...
+    def utf8_count_matches(self, pattern: PySeries, regex: bool) -> PySeries: ...
+    def utf8_like(self, pattern: PySeries) -> PySeries: ...
+    def utf8_ilike(self, pattern: PySeries) -> PySeries: ...
+    def utf8_strip_chars(self, chars: PySeries, left: bool, right: bool) -> PySeries: ...
+    def utf8_to_date(self, format: str, raise_error_on_failure: bool) -> PySeries: ...
+    def utf8_to_datetime(self, format: str, timezone: str | None, raise_error_on_failure: bool) -> PySeries: ...
     def is_nan(self) -> PySeries: ...
     def dt_date(self) -> PySeries: ...
     def dt_day(self) -> PySeries: ...
@@ -1096,7 +1191,17 @@ class PySeries:
     def dt_month(self) -> PySeries: ...
     def dt_year(self) -> PySeries: ...
     def dt_day_of_week(self) -> PySeries: ...
+    def dt_minute(self) -> PySeries: ...
+    def dt_second(self) -> PySeries: ...
+    def dt_millisecond(self) -> PySeries: ...
+    def dt_time(self) -> PySeries: ...
+    def dt_day_of_year(self) -> PySeries: ...
+    def dt_week_of_year(self) -> PySeries: ...
+    def dt_quarter(self) -> PySeries: ...
     def dt_truncate(self, interval: str, relative_to: PySeries) -> PySeries: ...
+    def dt_convert_time_zone(self, timezone: str) -> PySeries: ...
+    def dt_replace_time_zone(self, timezone: str | None, ambiguous: str) -> PySeries: ...
+    def dt_strftime(self, format: str) -> PySeries: ...
     def partitioning_days(self) -> PySeries: ...
     def partitioning_hours(self) -> PySeries: ...
     def partitioning_months(self) -> PySeries: ...
@@ -1105,6 +1210,17 @@ class PySeries:
     def partitioning_iceberg_truncate(self, w: int) -> PySeries: ...
     def list_count(self, mode: CountMode) -> PySeries: ...
     def list_get(self, idx: PySeries, default: PySeries) -> PySeries: ...
+    def list_to_map(self, values: PySeries) -> PySeries: ...
+    def list_contains(self, item: PySeries) -> PySeries: ...
+    def list_slice(self, start: PySeries, end: PySeries) -> PySeries: ...
+    def list_sort(self, descending: bool) -> PySeries: ...
+    def list_distinct(self) -> PySeries: ...
+    def list_flatten(self) -> PySeries: ...
+    def list_value_counts(self) -> PySeries: ...
+    def list_zip(self, others: list[PySeries]) -> PySeries: ...
+    def map_get(self, key: PySeries) -> PySeries: ...
+    def map_keys(self) -> PySeries: ...
+    def map_values(self) -> PySeries: ...
     def image_decode(self, raise_error_on_failure: bool) -> PySeries: ...
     def image_encode(self, image_format: ImageFormat) -> PySeries: ...
     def image_resize(self, w: int, h: int) -> PySeries: ...
@@ -1113,6 +1229,8 @@ class PySeries:
     def not_null(self) -> PySeries: ...
     def fill_null(self, fill_value: PySeries) -> PySeries: ...
     def murmur3_32(self) -> PySeries: ...
+    def minhash(self, num_hashes: int, ngram_size: int, seed: int) -> PySeries: ...
+    def lsh_bands(self, num_bands: int) -> PySeries: ...
     def to_str_values(self) -> PySeries: ...
     def _debug_bincode_serialize(self) -> bytes: ...
     @staticmethod
@@ -1127,10 +1245,13 @@ class PyTable:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyTable: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyTable: ...
-    def hash_join(self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyTable: ...
+    def hash_join(
+        self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType
+    ) -> PyTable: ...
     def sort_merge_join(
-        self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
+        self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType, is_sorted: bool
     ) -> PyTable: ...
+    def nested_loop_join(self, right: PyTable, predicate: PyExpr | None, how: JoinType) -> PyTable: ...
     def explode(self, to_explode: list[PyExpr]) -> PyTable: ...
     def head(self, num: int) -> PyTable: ...
     def sample_by_fraction(self, fraction: float, with_replacement: bool, seed: int | None) -> PyTable: ...
@@ -1186,9 +1307,26 @@ class PyMicroPartition:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyMicroPartition: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
-    def hash_join(self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyMicroPartition: ...
+    def window(
+        self,
+        window_exprs: list[PyExpr],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> PyMicroPartition: ...
+    def hash_join(
+        self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], how: JoinType
+    ) -> PyMicroPartition: ...
     def sort_merge_join(
-        self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
+        self,
+        right: PyMicroPartition,
+        left_on: list[PyExpr],
+        right_on: list[PyExpr],
+        how: JoinType,
+        is_sorted: bool,
+    ) -> PyMicroPartition: ...
+    def nested_loop_join(
+        self, right: PyMicroPartition, predicate: PyExpr | None, how: JoinType
     ) -> PyMicroPartition: ...
     def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
     def head(self, num: int) -> PyMicroPartition: ...
@@ -1251,6 +1389,16 @@ class PyMicroPartition:
         io_config: IOConfig | None = None,
         multithreaded_io: bool | None = None,
     ): ...
+    def write_parquet(
+        self,
+        uri: str,
+        compression: str | None,
+        row_group_size: int,
+        inflation_factor: float,
+        io_config: IOConfig | None = None,
+    ) -> int: ...
+    def write_csv(self, uri: str, io_config: IOConfig | None = None) -> None: ...
+    def write_json(self, uri: str, io_config: IOConfig | None = None) -> None: ...
 
 class PhysicalPlanScheduler:
     """
@@ -1279,6 +1427,7 @@ class LogicalPlanBuilder:
     def select(self, to_select: list[PyExpr]) -> LogicalPlanBuilder: ...
     def with_columns(self, columns: list[PyExpr], resource_request: ResourceRequest) -> LogicalPlanBuilder: ...
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder: ...
+    def unnest(self, to_unnest: list[str]) -> LogicalPlanBuilder: ...
     def filter(self, predicate: PyExpr) -> LogicalPlanBuilder: ...
     def limit(self, limit: int, eager: bool) -> LogicalPlanBuilder: ...
     def explode(self, to_explode: list[PyExpr]) -> LogicalPlanBuilder: ...
@@ -1294,6 +1443,7 @@ class LogicalPlanBuilder:
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
     def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def window(self, window_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
     def join(
         self,
         right: LogicalPlanBuilder,
@@ -1301,6 +1451,7 @@ class LogicalPlanBuilder:
         right_on: list[PyExpr],
         join_type: JoinType,
         strategy: JoinStrategy | None = None,
+        predicate: PyExpr | None = None,
     ) -> LogicalPlanBuilder: ...
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
     def add_monotonically_increasing_id(self, column_name: str | None) -> LogicalPlanBuilder: ...
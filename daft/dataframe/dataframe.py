@@ -431,6 +431,51 @@ class DataFrame:
         result_df._preview = write_df._preview
         return result_df
 
+    @DataframePublicAPI
+    def write_json(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as newline-delimited JSON files, returning a new DataFrame with paths to the files that were written
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+        Args:
+            root_dir (str): root file path to write JSON files to.
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Json,
+            io_config=io_config,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        # Populate and return a new disconnected DataFrame
+        result_df = DataFrame(write_df._builder)
+        result_df._result_cache = write_df._result_cache
+        result_df._preview = write_df._preview
+        return result_df
+
     @DataframePublicAPI
     def write_iceberg(self, table: "IcebergTable", mode: str = "append") -> "DataFrame":
         """Writes the DataFrame to an Iceberg Table, returning a new DataFrame with the operations that occurred.
@@ -707,6 +752,26 @@ class DataFrame:
         builder = self._builder.exclude(list(names))
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def unnest(self, *names: str) -> "DataFrame":
+        """Expands struct columns into one column per struct field, in place of the struct column
+
+        The new columns are named after the struct fields, so they must not clash with the names of
+        other columns. Fields that are not used by later operations are not computed.
+
+        Example:
+            >>> df = daft.from_pydict({"s": [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}], "z": [1.0, 2.0]})
+            >>> df.unnest("s")  # columns: x, y, z
+
+        Args:
+            *names (str): names of the struct columns to unnest
+
+        Returns:
+            DataFrame: DataFrame with the struct columns expanded.
+        """
+        builder = self._builder.unnest(list(names))
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def where(self, predicate: Expression) -> "DataFrame":
         """Filters rows via a predicate expression, similar to SQL ``WHERE``.
@@ -792,6 +857,33 @@ class DataFrame:
         builder = self._builder.with_columns(new_columns, resource_request)
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def with_window_columns(self, columns: Dict[str, Expression]) -> "DataFrame":
+        """Adds columns computed by window expressions, such as ``row_number()`` or ``col("x").lag(1)``,
+        which are evaluated over partitions of rows rather than row by row
+
+        All of the window expressions must share the same ``partition_by``, ``order_by`` and ``desc``.
+        Every window partition is gathered into a single partition of the DataFrame, and the result is
+        sorted by the partition and order keys within each DataFrame partition.
+
+        Example:
+            >>> df = daft.from_pydict({"group": ["a", "a", "b"], "x": [3, 1, 2]})
+            >>> df = df.with_window_columns({
+                    "rank": daft.rank(partition_by="group", order_by="x"),
+                    "prev_x": col("x").lag(1, partition_by="group", order_by="x"),
+                })
+
+        Args:
+            columns (Dict[str, Expression]): Dictionary of new columns in the format { name: window expression }
+
+        Returns:
+            DataFrame: DataFrame with new columns.
+        """
+        new_columns = [col.alias(name) for name, col in columns.items()]
+
+        builder = self._builder.window(new_columns)
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def sort(
         self,
@@ -919,6 +1011,7 @@ class DataFrame:
         right_on: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
         how: str = "inner",
         strategy: Optional[str] = None,
+        predicate: Optional[Expression] = None,
     ) -> "DataFrame":
         """Column-wise join of the current DataFrame with an ``other`` DataFrame, similar to a SQL ``JOIN``
 
@@ -931,19 +1024,28 @@ class DataFrame:
             on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on [use if the keys on the left and right side match.]. Defaults to None.
             left_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on left DataFrame.. Defaults to None.
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
-            how (str, optional): what type of join to performing, currently only `inner` is supported. Defaults to "inner".
+            how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "semi", and "anti" are supported.
+                Semi and anti joins only keep the left DataFrame's columns. Defaults to "inner".
             strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
                 chooses the join strategy automatically during query optimization. The default is None.
+            predicate (Optional[Expression]): A boolean expression over the joined rows that matching rows must also satisfy,
+                such as ``col("ts") >= col("start")``. Columns of ``other`` whose names conflict with this DataFrame are
+                referred to with a "right." prefix. If no join keys are given, the join is executed as a nested-loop join,
+                and inequalities between the two sides are evaluated with a sort-based range join. On keyed joins,
+                predicates are only supported for inner joins. Defaults to None.
 
         Raises:
             ValueError: if `on` is passed in and `left_on` or `right_on` is not None.
-            ValueError: if `on` is None but both `left_on` and `right_on` are not defined.
+            ValueError: if `on` is None but both `left_on` and `right_on` are not defined, and no `predicate` is given.
 
         Returns:
             DataFrame: Joined DataFrame.
         """
         if on is None:
-            if left_on is None or right_on is None:
+            if left_on is None and right_on is None and predicate is not None:
+                left_on = []
+                right_on = []
+            elif left_on is None or right_on is None:
                 raise ValueError("If `on` is None then both `left_on` and `right_on` must not be None")
         else:
             if left_on is not None or right_on is not None:
@@ -951,8 +1053,6 @@ class DataFrame:
             left_on = on
             right_on = on
         join_type = JoinType.from_join_type_str(how)
-        if join_type != JoinType.Inner:
-            raise ValueError(f"Only inner joins are currently supported, but got: {how}")
         join_strategy = JoinStrategy.from_join_strategy_str(strategy) if strategy is not None else None
 
         left_exprs = self.__column_input_to_expression(tuple(left_on) if isinstance(left_on, list) else (left_on,))
@@ -963,9 +1063,26 @@ class DataFrame:
             right_on=right_exprs,
             how=join_type,
             strategy=join_strategy,
+            predicate=predicate,
         )
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def cross_join(self, other: "DataFrame") -> "DataFrame":
+        """Cartesian product of the current DataFrame with an ``other`` DataFrame, similar to a SQL ``CROSS JOIN``
+
+        Every row of the current DataFrame is paired with every row of ``other``. Columns of ``other`` whose names
+        conflict with this DataFrame are prefixed with "right.".
+
+        Args:
+            other (DataFrame): the right DataFrame to join with.
+
+        Returns:
+            DataFrame: Joined DataFrame with ``len(self) * len(other)`` rows.
+        """
+        builder = self._builder.join(other._builder, left_on=[], right_on=[])
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def concat(self, other: "DataFrame") -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation. The resulting DataFrame
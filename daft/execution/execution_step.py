@@ -630,6 +630,30 @@ class Aggregate(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class Window(SingleOutputInstruction):
+    window_exprs: list[Expression]
+    partition_by: ExpressionsProjection
+    order_by: ExpressionsProjection
+    descending: list[bool]
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._window(inputs)
+
+    def _window(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        return [input.window(self.window_exprs, self.partition_by, self.order_by, self.descending)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class HashJoin(SingleOutputInstruction):
     left_on: ExpressionsProjection
@@ -670,6 +694,38 @@ class HashJoin(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class NestedLoopJoin(SingleOutputInstruction):
+    predicate: Expression | None
+    how: JoinType
+    is_swapped: bool
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._nested_loop_join(inputs)
+
+    def _nested_loop_join(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        # All inputs except for the last are the left side of the join, in order to support left-broadcasted joins.
+        *lefts, right = inputs
+        if len(lefts) > 1:
+            left = MicroPartition.concat(lefts)
+        else:
+            left = lefts[0]
+        if self.is_swapped:
+            # Swap left/right back.
+            left, right = right, left
+        result = left.nested_loop_join(right, predicate=self.predicate, how=self.how)
+        return [result]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        # Can't derive anything.
+        return [
+            PartialPartitionMetadata(
+                num_rows=None,
+                size_bytes=None,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class MergeJoin(SingleOutputInstruction):
     left_on: ExpressionsProjection
@@ -693,6 +749,10 @@ class MergeJoin(SingleOutputInstruction):
 
     def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
         [left_meta, right_meta] = input_metadatas
+        if self.how != JoinType.Inner:
+            # Non-inner joins can emit unmatched rows, so neither emptiness nor the output boundaries can be inferred
+            # from the input boundaries.
+            return [PartialPartitionMetadata(num_rows=None, size_bytes=None)]
         # If the boundaries of the left and right partitions don't intersect, then the merge-join will result in an empty partition.
         if left_meta.boundaries is None or right_meta.boundaries is None:
             is_nonempty = True
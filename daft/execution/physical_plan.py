@@ -32,7 +32,7 @@ from daft.execution.execution_step import (
     ReduceInstruction,
     SingleOutputPartitionTask,
 )
-from daft.expressions import ExpressionsProjection
+from daft.expressions import Expression, ExpressionsProjection
 from daft.logical.schema import Schema
 from daft.runners.partitioning import (
     MaterializedResult,
@@ -174,6 +174,24 @@ def hash_join(
     how: JoinType,
 ) -> InProgressPhysicalPlan[PartitionT]:
     """Hash-based pairwise join the partitions from `left_child_plan` and `right_child_plan` together."""
+    yield from _pairwise_join(
+        left_plan,
+        right_plan,
+        execution_step.HashJoin(
+            left_on=left_on,
+            right_on=right_on,
+            how=how,
+            is_swapped=False,
+        ),
+    )
+
+
+def _pairwise_join(
+    left_plan: InProgressPhysicalPlan[PartitionT],
+    right_plan: InProgressPhysicalPlan[PartitionT],
+    join_instruction: Instruction,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Join the i-th partition from `left_plan` with the i-th partition from `right_plan` using `join_instruction`."""
 
     # Materialize the steps from the left and right sources to get partitions.
     # As the materializations complete, emit new steps to join each left and right partition.
@@ -208,14 +226,7 @@ def hash_join(
                 inputs=[next_left.partition(), next_right.partition()],
                 partial_metadatas=[next_left.partition_metadata(), next_right.partition_metadata()],
                 resource_request=ResourceRequest(memory_bytes=size_bytes),
-            ).add_instruction(
-                instruction=execution_step.HashJoin(
-                    left_on=left_on,
-                    right_on=right_on,
-                    how=how,
-                    is_swapped=False,
-                )
-            )
+            ).add_instruction(instruction=join_instruction)
             yield join_step
 
         # Exhausted all ready inputs; execute a single child step to get more join inputs.
@@ -255,10 +266,7 @@ def hash_join(
 def _create_broadcast_join_step(
     broadcaster_parts: deque[SingleOutputPartitionTask[PartitionT]],
     receiver_part: SingleOutputPartitionTask[PartitionT],
-    left_on: ExpressionsProjection,
-    right_on: ExpressionsProjection,
-    how: JoinType,
-    is_swapped: bool,
+    instruction: execution_step.SingleOutputInstruction,
 ) -> PartitionTaskBuilder[PartitionT]:
     # Calculate memory request for task.
     broadcaster_size_bytes_ = 0
@@ -298,25 +306,55 @@ def _create_broadcast_join_step(
         inputs=broadcaster_partitions + [receiver_part.partition()],
         partial_metadatas=list(broadcaster_partition_metadatas + [receiver_part.partition_metadata()]),
         resource_request=ResourceRequest(memory_bytes=size_bytes),
-    ).add_instruction(
-        instruction=execution_step.HashJoin(
+    ).add_instruction(instruction=instruction)
+
+
+def broadcast_join(
+    broadcaster_plan: InProgressPhysicalPlan[PartitionT],
+    receiver_plan: InProgressPhysicalPlan[PartitionT],
+    left_on: ExpressionsProjection,
+    right_on: ExpressionsProjection,
+    how: JoinType,
+    is_swapped: bool,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Broadcast join all partitions from the broadcaster child plan to each partition in the receiver child plan."""
+    return _broadcast(
+        broadcaster_plan,
+        receiver_plan,
+        execution_step.HashJoin(
             left_on=left_on,
             right_on=right_on,
             how=how,
             is_swapped=is_swapped,
-        )
+        ),
     )
 
 
-def broadcast_join(
+def nested_loop_join(
     broadcaster_plan: InProgressPhysicalPlan[PartitionT],
     receiver_plan: InProgressPhysicalPlan[PartitionT],
-    left_on: ExpressionsProjection,
-    right_on: ExpressionsProjection,
+    predicate: Expression | None,
     how: JoinType,
     is_swapped: bool,
 ) -> InProgressPhysicalPlan[PartitionT]:
-    """Broadcast join all partitions from the broadcaster child plan to each partition in the receiver child plan."""
+    """Nested-loop join all partitions from the broadcaster child plan with each partition in the receiver child plan."""
+    return _broadcast(
+        broadcaster_plan,
+        receiver_plan,
+        execution_step.NestedLoopJoin(
+            predicate=predicate,
+            how=how,
+            is_swapped=is_swapped,
+        ),
+    )
+
+
+def _broadcast(
+    broadcaster_plan: InProgressPhysicalPlan[PartitionT],
+    receiver_plan: InProgressPhysicalPlan[PartitionT],
+    instruction: execution_step.SingleOutputInstruction,
+) -> InProgressPhysicalPlan[PartitionT]:
+    """Run the instruction on all partitions from the broadcaster child plan together with each partition in the receiver child plan."""
 
     # Materialize the steps from the broadcaster and receiver sources to get partitions.
     # As the receiver-side materializations complete, emit new steps to join each broadcaster and receiver partition.
@@ -355,7 +393,7 @@ def broadcast_join(
         # Broadcast all broadcaster partitions to each new receiver partition that was materialized on this dispatch loop.
         while receiver_requests and receiver_requests[0].done():
             receiver_part = receiver_requests.popleft()
-            yield _create_broadcast_join_step(broadcaster_parts, receiver_part, left_on, right_on, how, is_swapped)
+            yield _create_broadcast_join_step(broadcaster_parts, receiver_part, instruction)
 
         # Execute single child step to pull in more input partitions.
         try:
@@ -979,7 +1017,22 @@ def sort_merge_join_aligned_boundaries(
     left_sorted_plan, right_sorted_plan = sorted_plans
 
     # Merge-join the two sorted sides of the join.
-    yield from merge_join_sorted(left_sorted_plan, right_sorted_plan, left_on, right_on, how, left_is_larger)
+    if how == JoinType.Inner:
+        yield from merge_join_sorted(left_sorted_plan, right_sorted_plan, left_on, right_on, how, left_is_larger)
+    else:
+        # Both sides were sorted with the same boundaries, so a key can only match within the same partition pair.
+        # Joining the partitions pairwise ensures that unmatched rows are emitted exactly once, which the windowed
+        # merge-join above doesn't guarantee for non-inner joins.
+        yield from _pairwise_join(
+            left_sorted_plan,
+            right_sorted_plan,
+            execution_step.MergeJoin(
+                left_on=left_on,
+                right_on=right_on,
+                how=how,
+                preserve_left_bounds=True,
+            ),
+        )
 
 
 def _to_per_partition_bounds(boundaries: MicroPartition, num_partitions: int) -> list[MicroPartition]:
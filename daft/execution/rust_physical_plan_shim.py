@@ -114,6 +114,27 @@ def local_aggregate(
     )
 
 
+def window(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    window_exprs: list[PyExpr],
+    partition_by: list[PyExpr],
+    order_by: list[PyExpr],
+    descending: list[bool],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    window_step = execution_step.Window(
+        window_exprs=[Expression._from_pyexpr(pyexpr) for pyexpr in window_exprs],
+        partition_by=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in partition_by]),
+        order_by=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in order_by]),
+        descending=descending,
+    )
+
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=window_step,
+        resource_request=ResourceRequest(),
+    )
+
+
 def sample(
     input: physical_plan.InProgressPhysicalPlan[PartitionT], fraction: float, with_replacement: bool, seed: int | None
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
@@ -243,6 +264,22 @@ def broadcast_join(
     )
 
 
+def nested_loop_join(
+    broadcaster: physical_plan.InProgressPhysicalPlan[PartitionT],
+    receiver: physical_plan.InProgressPhysicalPlan[PartitionT],
+    predicate: PyExpr | None,
+    join_type: JoinType,
+    is_swapped: bool,
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    return physical_plan.nested_loop_join(
+        broadcaster_plan=broadcaster,
+        receiver_plan=receiver,
+        predicate=Expression._from_pyexpr(predicate) if predicate is not None else None,
+        how=join_type,
+        is_swapped=is_swapped,
+    )
+
+
 def write_file(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     file_format: FileFormat,
@@ -1,5 +1,15 @@
 from __future__ import annotations
 
-from .expressions import Expression, ExpressionsProjection, col, lit
+from .expressions import Expression, ExpressionsProjection, col, dense_rank, element, lit, rank, row_number, struct
 
-__all__ = ["Expression", "ExpressionsProjection", "col", "lit"]
+__all__ = [
+    "Expression",
+    "ExpressionsProjection",
+    "col",
+    "dense_rank",
+    "element",
+    "lit",
+    "rank",
+    "row_number",
+    "struct",
+]
@@ -15,8 +15,13 @@ from daft.daft import PyExpr as _PyExpr
 from daft.daft import col as _col
 from daft.daft import date_lit as _date_lit
 from daft.daft import decimal_lit as _decimal_lit
+from daft.daft import dense_rank as _dense_rank
+from daft.daft import element as _element
 from daft.daft import lit as _lit
+from daft.daft import rank as _rank
+from daft.daft import row_number as _row_number
 from daft.daft import series_lit as _series_lit
+from daft.daft import struct_pack as _struct_pack
 from daft.daft import time_lit as _time_lit
 from daft.daft import timestamp_lit as _timestamp_lit
 from daft.daft import udf as _udf
@@ -112,6 +117,124 @@ def col(name: str) -> Expression:
     return Expression._from_pyexpr(_col(name))
 
 
+def element() -> Expression:
+    """Creates an Expression referring to each element of a list, for use within higher-order list
+    expressions such as ``.list.eval`` and ``.list.filter``
+
+    Example:
+        >>> col("x").list.eval(element() * 2)
+
+    Returns:
+        Expression: Expression representing an element of a list
+    """
+    return Expression._from_pyexpr(_element())
+
+
+def struct(*fields: Expression | str) -> Expression:
+    """Creates an Expression that packs the provided expressions into a struct, with one field per
+    expression named after that expression
+
+    Example:
+        >>> struct(col("x"), (col("y") + 1).alias("y_plus_one"))
+
+    Args:
+        *fields: expressions or column names to pack into the struct
+
+    Returns:
+        Expression: a struct expression, which is named after its first field
+    """
+    exprs = [col(f) if isinstance(f, builtins.str) else Expression._to_expression(f) for f in fields]
+    return Expression._from_pyexpr(_struct_pack([e._expr for e in exprs]))
+
+
+def _window_spec(
+    partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None,
+    order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None,
+    desc: bool | builtins.list[bool],
+) -> tuple[builtins.list[_PyExpr], builtins.list[_PyExpr], builtins.list[bool]]:
+    def to_pyexprs(
+        exprs: Expression | builtins.str | builtins.list[Expression | builtins.str] | None,
+    ) -> builtins.list[_PyExpr]:
+        if exprs is None:
+            return []
+        if not isinstance(exprs, builtins.list):
+            exprs = [exprs]
+        return [(col(e) if isinstance(e, builtins.str) else Expression._to_expression(e))._expr for e in exprs]
+
+    order_by_pyexprs = to_pyexprs(order_by)
+    if not isinstance(desc, builtins.list):
+        desc = [desc] * len(order_by_pyexprs)
+    return to_pyexprs(partition_by), order_by_pyexprs, desc
+
+
+def row_number(
+    partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    desc: bool | builtins.list[bool] = False,
+) -> Expression:
+    """Creates a window Expression numbering the rows of each window partition, starting from 1
+
+    Example:
+        >>> # partition = ["a", "a", "b"], x = [2, 1, 1] -> [2, 1, 1]
+        >>> df.with_window_columns({"rn": row_number(partition_by="partition", order_by="x")})
+
+    Args:
+        partition_by: columns to partition the rows by, or None to treat all rows as one partition
+        order_by: columns to order the rows of each partition by
+        desc: whether to order by each of the ``order_by`` columns in descending order
+
+    Returns:
+        Expression: a UInt64 window expression, to be evaluated with ``DataFrame.with_window_columns``
+    """
+    return Expression._from_pyexpr(_row_number(*_window_spec(partition_by, order_by, desc)))
+
+
+def rank(
+    partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    desc: bool | builtins.list[bool] = False,
+) -> Expression:
+    """Creates a window Expression ranking the rows of each window partition, where rows with equal
+    ``order_by`` values share a rank and leave a gap after them
+
+    Example:
+        >>> # x = [1, 1, 2] -> [1, 1, 3]
+        >>> df.with_window_columns({"rank": rank(order_by="x")})
+
+    Args:
+        partition_by: columns to partition the rows by, or None to treat all rows as one partition
+        order_by: columns to order the rows of each partition by
+        desc: whether to order by each of the ``order_by`` columns in descending order
+
+    Returns:
+        Expression: a UInt64 window expression, to be evaluated with ``DataFrame.with_window_columns``
+    """
+    return Expression._from_pyexpr(_rank(*_window_spec(partition_by, order_by, desc)))
+
+
+def dense_rank(
+    partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+    desc: bool | builtins.list[bool] = False,
+) -> Expression:
+    """Creates a window Expression ranking the rows of each window partition, where rows with equal
+    ``order_by`` values share a rank without leaving a gap after them
+
+    Example:
+        >>> # x = [1, 1, 2] -> [1, 1, 2]
+        >>> df.with_window_columns({"dense_rank": dense_rank(order_by="x")})
+
+    Args:
+        partition_by: columns to partition the rows by, or None to treat all rows as one partition
+        order_by: columns to order the rows of each partition by
+        desc: whether to order by each of the ``order_by`` columns in descending order
+
+    Returns:
+        Expression: a UInt64 window expression, to be evaluated with ``DataFrame.with_window_columns``
+    """
+    return Expression._from_pyexpr(_dense_rank(*_window_spec(partition_by, order_by, desc)))
+
+
 class Expression:
     _expr: _PyExpr = None  # type: ignore
 
@@ -148,6 +271,11 @@ class Expression:
         """Access methods that work on columns of structs"""
         return ExpressionStructNamespace.from_expression(self)
 
+    @accessor_namespace_property
+    def map(self) -> ExpressionMapNamespace:
+        """Access methods that work on columns of maps"""
+        return ExpressionMapNamespace.from_expression(self)
+
     @accessor_namespace_property
     def image(self) -> ExpressionImageNamespace:
         """Access methods that work on columns of images"""
@@ -439,6 +567,46 @@ class Expression:
         expr = self._expr.mean()
         return Expression._from_pyexpr(expr)
 
+    def stddev(self, ddof: int = 1) -> Expression:
+        """Calculates the standard deviation of the values in the expression
+
+        Args:
+            ddof: delta degrees of freedom, where the divisor is the number of non-null values minus ``ddof``.
+                Defaults to 1 for the sample standard deviation.
+        """
+        expr = self._expr.stddev(ddof)
+        return Expression._from_pyexpr(expr)
+
+    def variance(self, ddof: int = 1) -> Expression:
+        """Calculates the variance of the values in the expression
+
+        Args:
+            ddof: delta degrees of freedom, where the divisor is the number of non-null values minus ``ddof``.
+                Defaults to 1 for the sample variance.
+        """
+        expr = self._expr.variance(ddof)
+        return Expression._from_pyexpr(expr)
+
+    def covariance(self, other: Expression, ddof: int = 1) -> Expression:
+        """Calculates the covariance of the values in the expression and ``other``, over the rows where both are non-null
+
+        Args:
+            other: the expression to compute the covariance with
+            ddof: delta degrees of freedom, where the divisor is the number of non-null pairs minus ``ddof``.
+                Defaults to 1 for the sample covariance.
+        """
+        other = Expression._to_expression(other)
+        expr = self._expr.covariance(other._expr, ddof)
+        return Expression._from_pyexpr(expr)
+
+    def correlation(self, other: Expression) -> Expression:
+        """Calculates the Pearson correlation of the values in the expression and ``other``, over the rows where
+        both are non-null
+        """
+        other = Expression._to_expression(other)
+        expr = self._expr.correlation(other._expr)
+        return Expression._from_pyexpr(expr)
+
     def min(self) -> Expression:
         """Calculates the minimum value in the expression"""
         expr = self._expr.min()
@@ -458,6 +626,60 @@ class Expression:
         expr = self._expr.any_value(ignore_nulls)
         return Expression._from_pyexpr(expr)
 
+    def bool_and(self) -> Expression:
+        """Calculates whether all non-null values in the expression are true, or null if there are none"""
+        expr = self._expr.bool_and()
+        return Expression._from_pyexpr(expr)
+
+    def bool_or(self) -> Expression:
+        """Calculates whether any non-null value in the expression is true, or null if there are none"""
+        expr = self._expr.bool_or()
+        return Expression._from_pyexpr(expr)
+
+    def bit_and(self) -> Expression:
+        """Calculates the bitwise AND of the non-null integer values in the expression, or null if there are none"""
+        expr = self._expr.bit_and()
+        return Expression._from_pyexpr(expr)
+
+    def bit_or(self) -> Expression:
+        """Calculates the bitwise OR of the non-null integer values in the expression, or null if there are none"""
+        expr = self._expr.bit_or()
+        return Expression._from_pyexpr(expr)
+
+    def bit_xor(self) -> Expression:
+        """Calculates the bitwise XOR of the non-null integer values in the expression, or null if there are none"""
+        expr = self._expr.bit_xor()
+        return Expression._from_pyexpr(expr)
+
+    def approx_count_distinct(self) -> Expression:
+        """Estimates the number of distinct non-null values in the expression with a HyperLogLog sketch
+
+        The estimate has a relative standard error of about 0.8%, and is much cheaper to compute than an exact
+        distinct count since it doesn't require shuffling the values across partitions. Each sketch takes about 4
+        bytes per distinct value, up to 16KiB, so grouped aggregations over many small groups stay cheap.
+        """
+        expr = self._expr.approx_count_distinct()
+        return Expression._from_pyexpr(expr)
+
+    def approx_percentiles(self, percentiles: builtins.float | builtins.list[builtins.float]) -> Expression:
+        """Estimates the given percentiles of the non-null numeric values in the expression with a DDSketch
+
+        Example:
+            >>> df.agg([col("latency").approx_percentiles([0.5, 0.99]).alias("latency_p50_p99")])
+
+        Args:
+            percentiles: a percentile between 0.0 and 1.0, or a list of them
+
+        Returns:
+            Expression: a Float64 expression if ``percentiles`` is a single float, else a List[Float64] expression
+                with one value per percentile
+        """
+        if isinstance(percentiles, builtins.list):
+            expr = self._expr.approx_percentiles(percentiles, True)
+        else:
+            expr = self._expr.approx_percentiles([percentiles], False)
+        return Expression._from_pyexpr(expr)
+
     def agg_list(self) -> Expression:
         """Aggregates the values in the expression into a list"""
         expr = self._expr.agg_list()
@@ -468,6 +690,136 @@ class Expression:
         expr = self._expr.agg_concat()
         return Expression._from_pyexpr(expr)
 
+    def lag(
+        self,
+        offset: int = 1,
+        default: Any = None,
+        partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        desc: bool | builtins.list[bool] = False,
+    ) -> Expression:
+        """Creates a window Expression taking the value of this expression ``offset`` rows before the
+        current row in its window partition, or ``default`` if there is no such row
+
+        Example:
+            >>> # x = [1, 2, 3] -> [None, 1, 2]
+            >>> df.with_window_columns({"prev_x": col("x").lag(1, order_by="t")})
+
+        Args:
+            offset: number of rows to look back by
+            default: value to use for rows without a row ``offset`` rows before them, defaults to null
+            partition_by: columns to partition the rows by, or None to treat all rows as one partition
+            order_by: columns to order the rows of each partition by
+            desc: whether to order by each of the ``order_by`` columns in descending order
+
+        Returns:
+            Expression: a window expression, to be evaluated with ``DataFrame.with_window_columns``
+        """
+        default_expr = Expression._to_expression(default)
+        expr = self._expr.lag(offset, default_expr._expr, *_window_spec(partition_by, order_by, desc))
+        return Expression._from_pyexpr(expr)
+
+    def lead(
+        self,
+        offset: int = 1,
+        default: Any = None,
+        partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        desc: bool | builtins.list[bool] = False,
+    ) -> Expression:
+        """Creates a window Expression taking the value of this expression ``offset`` rows after the
+        current row in its window partition, or ``default`` if there is no such row
+
+        Example:
+            >>> # x = [1, 2, 3] -> [2, 3, None]
+            >>> df.with_window_columns({"next_x": col("x").lead(1, order_by="t")})
+
+        Args:
+            offset: number of rows to look ahead by
+            default: value to use for rows without a row ``offset`` rows after them, defaults to null
+            partition_by: columns to partition the rows by, or None to treat all rows as one partition
+            order_by: columns to order the rows of each partition by
+            desc: whether to order by each of the ``order_by`` columns in descending order
+
+        Returns:
+            Expression: a window expression, to be evaluated with ``DataFrame.with_window_columns``
+        """
+        default_expr = Expression._to_expression(default)
+        expr = self._expr.lead(offset, default_expr._expr, *_window_spec(partition_by, order_by, desc))
+        return Expression._from_pyexpr(expr)
+
+    def cum_sum(
+        self,
+        partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        desc: bool | builtins.list[bool] = False,
+    ) -> Expression:
+        """Creates a window Expression taking the running sum of this expression over each window
+        partition, skipping nulls
+
+        Example:
+            >>> # x = [1, None, 3] -> [1, 1, 4]
+            >>> df.with_window_columns({"total": col("x").cum_sum(order_by="t")})
+
+        Args:
+            partition_by: columns to partition the rows by, or None to treat all rows as one partition
+            order_by: columns to order the rows of each partition by
+            desc: whether to order by each of the ``order_by`` columns in descending order
+
+        Returns:
+            Expression: a window expression, to be evaluated with ``DataFrame.with_window_columns``
+        """
+        expr = self._expr.cum_sum(*_window_spec(partition_by, order_by, desc))
+        return Expression._from_pyexpr(expr)
+
+    def cum_min(
+        self,
+        partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        desc: bool | builtins.list[bool] = False,
+    ) -> Expression:
+        """Creates a window Expression taking the running minimum of this expression over each window
+        partition, skipping nulls
+
+        Example:
+            >>> # x = [2, None, 1] -> [2, 2, 1]
+            >>> df.with_window_columns({"lowest": col("x").cum_min(order_by="t")})
+
+        Args:
+            partition_by: columns to partition the rows by, or None to treat all rows as one partition
+            order_by: columns to order the rows of each partition by
+            desc: whether to order by each of the ``order_by`` columns in descending order
+
+        Returns:
+            Expression: a window expression, to be evaluated with ``DataFrame.with_window_columns``
+        """
+        expr = self._expr.cum_min(*_window_spec(partition_by, order_by, desc))
+        return Expression._from_pyexpr(expr)
+
+    def cum_max(
+        self,
+        partition_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        order_by: Expression | builtins.str | builtins.list[Expression | builtins.str] | None = None,
+        desc: bool | builtins.list[bool] = False,
+    ) -> Expression:
+        """Creates a window Expression taking the running maximum of this expression over each window
+        partition, skipping nulls
+
+        Example:
+            >>> # x = [1, None, 2] -> [1, 1, 2]
+            >>> df.with_window_columns({"highest": col("x").cum_max(order_by="t")})
+
+        Args:
+            partition_by: columns to partition the rows by, or None to treat all rows as one partition
+            order_by: columns to order the rows of each partition by
+            desc: whether to order by each of the ``order_by`` columns in descending order
+
+        Returns:
+            Expression: a window expression, to be evaluated with ``DataFrame.with_window_columns``
+        """
+        expr = self._expr.cum_max(*_window_spec(partition_by, order_by, desc))
+        return Expression._from_pyexpr(expr)
+
     def _explode(self) -> Expression:
         expr = self._expr.explode()
         return Expression._from_pyexpr(expr)
@@ -593,6 +945,69 @@ class Expression:
         expr = self._expr.is_in(other._expr)
         return Expression._from_pyexpr(expr)
 
+    def hash(self, seed: int | None = None, hash_function: builtins.str = "xxhash64") -> Expression:
+        """Hashes the values in the Expression with a well-known hash function, for stable keys across
+        runs and compatibility with other systems. Null values have null hashes.
+
+        Supported hash functions are:
+
+        * ``xxhash64``: 64-bit xxHash, returning UInt64
+        * ``murmur3_32``: 32-bit MurmurHash3 as used by Iceberg bucket partitioning, returning Int32
+        * ``sha1``, ``sha256`` and ``md5``: hex-encoded digests, returning strings
+
+        Example:
+            >>> col("x").hash(hash_function="sha256")
+
+        Args:
+            seed: Seed of the hash, only supported by ``xxhash64`` and ``murmur3_32``. Defaults to 0.
+            hash_function: Name of the hash function to use, defaults to ``xxhash64``.
+
+        Returns:
+            Expression: Expression with the hashes of the values
+        """
+        if seed is not None and (not isinstance(seed, int) or seed < 0):
+            raise ValueError(f"expected seed to be a non-negative integer, got {seed}")
+        expr = self._expr.hash(hash_function, seed)
+        return Expression._from_pyexpr(expr)
+
+    def minhash(self, num_hashes: int, ngram_size: int, seed: int = 1) -> Expression:
+        """Computes the MinHash signature of each string, for estimating the Jaccard similarity of
+        their word n-grams. Strings that are near-duplicates of each other agree on most values of
+        their signatures.
+
+        Example:
+            >>> col("text").minhash(num_hashes=64, ngram_size=3)
+
+        Args:
+            num_hashes: The number of hash permutations, which is the length of the signature
+            ngram_size: The number of whitespace-separated words in each shingle
+            seed: The seed of the hash permutations, defaults to 1
+
+        Returns:
+            Expression: a FixedSizeList[UInt32] expression of length ``num_hashes`` with the signatures
+        """
+        if num_hashes <= 0 or ngram_size <= 0:
+            raise ValueError(f"expected num_hashes and ngram_size to be positive, got {num_hashes} and {ngram_size}")
+        expr = self._expr.minhash(num_hashes, ngram_size, seed)
+        return Expression._from_pyexpr(expr)
+
+    def lsh_bands(self, num_bands: int) -> Expression:
+        """Splits FixedSizeList[UInt32] MinHash signatures into ``num_bands`` bands and hashes each band,
+        for locality-sensitive hashing. Two rows are candidate near-duplicates if they share a band hash,
+        so the result can be exploded and joined on to find candidate pairs.
+
+        Example:
+            >>> col("text").minhash(num_hashes=64, ngram_size=3).lsh_bands(16)
+
+        Args:
+            num_bands: The number of bands, which must divide the length of the signatures
+
+        Returns:
+            Expression: a FixedSizeList[UInt64] expression of length ``num_bands`` with the band hashes
+        """
+        expr = self._expr.lsh_bands(num_bands)
+        return Expression._from_pyexpr(expr)
+
     def name(self) -> builtins.str:
         return self._expr.name()
 
@@ -735,16 +1150,60 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         return Expression._from_pyexpr(self._expr.dt_day())
 
     def hour(self) -> Expression:
-        """Retrieves the day for a datetime column
+        """Retrieves the hour for a datetime or time column
 
         Example:
-            >>> col("x").dt.day()
+            >>> col("x").dt.hour()
 
         Returns:
-            Expression: a UInt32 expression with just the day extracted from a datetime column
+            Expression: a UInt32 expression with just the hour extracted from a datetime or time column
         """
         return Expression._from_pyexpr(self._expr.dt_hour())
 
+    def minute(self) -> Expression:
+        """Retrieves the minute for a datetime or time column
+
+        Example:
+            >>> col("x").dt.minute()
+
+        Returns:
+            Expression: a UInt32 expression with just the minute extracted from a datetime or time column
+        """
+        return Expression._from_pyexpr(self._expr.dt_minute())
+
+    def second(self) -> Expression:
+        """Retrieves the second for a datetime or time column
+
+        Example:
+            >>> col("x").dt.second()
+
+        Returns:
+            Expression: a UInt32 expression with just the second extracted from a datetime or time column
+        """
+        return Expression._from_pyexpr(self._expr.dt_second())
+
+    def millisecond(self) -> Expression:
+        """Retrieves the milliseconds past the second for a datetime or time column, from 0 to 999
+
+        Example:
+            >>> col("x").dt.millisecond()
+
+        Returns:
+            Expression: a UInt32 expression with just the millisecond extracted from a datetime or time column
+        """
+        return Expression._from_pyexpr(self._expr.dt_millisecond())
+
+    def time(self) -> Expression:
+        """Retrieves the time of day for a datetime column
+
+        Example:
+            >>> col("x").dt.time()
+
+        Returns:
+            Expression: a Time expression with just the time extracted from a datetime column
+        """
+        return Expression._from_pyexpr(self._expr.dt_time())
+
     def month(self) -> Expression:
         """Retrieves the month for a datetime column
 
@@ -778,6 +1237,41 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.dt_day_of_week())
 
+    def day_of_year(self) -> Expression:
+        """Retrieves the day of the year for a datetime column, starting at 1 for January 1st
+
+        Example:
+            >>> col("x").dt.day_of_year()
+
+        Returns:
+            Expression: a UInt32 expression with just the day_of_year extracted from a datetime column
+        """
+        return Expression._from_pyexpr(self._expr.dt_day_of_year())
+
+    def week_of_year(self) -> Expression:
+        """Retrieves the ISO 8601 week number for a datetime column, from 1 to 53
+
+        ISO weeks start on Monday, and the first week of a year is the one containing its first Thursday.
+
+        Example:
+            >>> col("x").dt.week_of_year()
+
+        Returns:
+            Expression: a UInt32 expression with just the week_of_year extracted from a datetime column
+        """
+        return Expression._from_pyexpr(self._expr.dt_week_of_year())
+
+    def quarter(self) -> Expression:
+        """Retrieves the quarter of the year for a datetime column, from 1 to 4
+
+        Example:
+            >>> col("x").dt.quarter()
+
+        Returns:
+            Expression: a UInt32 expression with just the quarter extracted from a datetime column
+        """
+        return Expression._from_pyexpr(self._expr.dt_quarter())
+
     def truncate(self, interval: str, relative_to: Expression | None = None) -> Expression:
         """Truncates the datetime column to the specified interval
 
@@ -815,6 +1309,61 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         relative_to = Expression._to_expression(relative_to)
         return Expression._from_pyexpr(self._expr.dt_truncate(interval, relative_to._expr))
 
+    def convert_time_zone(self, timezone: str) -> Expression:
+        """Converts a timezone-aware timestamp column to another timezone
+
+        The underlying instants are unchanged, only the timezone used to interpret them is replaced.
+
+        Example:
+            >>> col("x").dt.convert_time_zone("America/New_York")
+
+        Args:
+            timezone: The target timezone, either an IANA name such as "Europe/London" or a fixed offset such as "+05:30"
+
+        Returns:
+            Expression: a Timestamp expression in the target timezone
+        """
+        return Expression._from_pyexpr(self._expr.dt_convert_time_zone(timezone))
+
+    def replace_time_zone(
+        self,
+        timezone: str | None,
+        ambiguous: Literal["raise"] | Literal["earliest"] | Literal["latest"] | Literal["null"] = "raise",
+    ) -> Expression:
+        """Replaces the timezone of a timestamp column, keeping the wall-clock time the same
+
+        Wall-clock times that are ambiguous because of a daylight saving transition are resolved using ``ambiguous``.
+        Wall-clock times that do not exist in the target timezone become null if ``ambiguous="null"``, and raise otherwise.
+
+        Example:
+            >>> col("x").dt.replace_time_zone("America/New_York", ambiguous="earliest")
+
+        Args:
+            timezone: The timezone to localize to, or None to make the timestamps timezone-naive
+            ambiguous: How to resolve ambiguous times. One of "raise", "earliest", "latest" or "null". Defaults to "raise".
+
+        Returns:
+            Expression: a Timestamp expression in the given timezone
+        """
+        return Expression._from_pyexpr(self._expr.dt_replace_time_zone(timezone, ambiguous))
+
+    def strftime(self, format: str) -> Expression:
+        """Formats each date or timestamp as a string with the given format
+
+        The format uses chrono's strftime specifiers, see https://docs.rs/chrono/latest/chrono/format/strftime/index.html.
+        Timestamps are formatted using their wall-clock time in the column's timezone.
+
+        Example:
+            >>> col("x").dt.strftime("%Y/%m/%d")
+
+        Args:
+            format: The format to render each value with, e.g. "%Y-%m-%d %H:%M:%S"
+
+        Returns:
+            Expression: a String expression with each formatted value
+        """
+        return Expression._from_pyexpr(self._expr.dt_strftime(format))
+
 
 class ExpressionStringNamespace(ExpressionNamespace):
     def contains(self, substr: str | Expression) -> Expression:
@@ -1134,27 +1683,59 @@ class ExpressionStringNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.utf8_upper())
 
-    def lstrip(self) -> Expression:
-        """Strip whitespace from the left side of a UTF-8 string
+    def lstrip(self, chars: str | Expression | None = None) -> Expression:
+        """Strip whitespace, or the given characters, from the left side of a UTF-8 string
 
         Example:
             >>> col("x").str.lstrip()
+            >>> col("x").str.lstrip("_-")
+
+        Args:
+            chars: the characters to strip, or None to strip whitespace
 
         Returns:
-            Expression: a String expression which is `self` with leading whitespace stripped
+            Expression: a String expression which is `self` with leading whitespace or characters stripped
         """
-        return Expression._from_pyexpr(self._expr.utf8_lstrip())
+        if chars is None:
+            return Expression._from_pyexpr(self._expr.utf8_lstrip())
+        chars_expr = Expression._to_expression(chars)
+        return Expression._from_pyexpr(self._expr.utf8_strip_chars(chars_expr._expr, left=True, right=False))
 
-    def rstrip(self) -> Expression:
-        """Strip whitespace from the right side of a UTF-8 string
+    def rstrip(self, chars: str | Expression | None = None) -> Expression:
+        """Strip whitespace, or the given characters, from the right side of a UTF-8 string
 
         Example:
             >>> col("x").str.rstrip()
+            >>> col("x").str.rstrip("_-")
+
+        Args:
+            chars: the characters to strip, or None to strip whitespace
+
+        Returns:
+            Expression: a String expression which is `self` with trailing whitespace or characters stripped
+        """
+        if chars is None:
+            return Expression._from_pyexpr(self._expr.utf8_rstrip())
+        chars_expr = Expression._to_expression(chars)
+        return Expression._from_pyexpr(self._expr.utf8_strip_chars(chars_expr._expr, left=False, right=True))
+
+    def strip(self, chars: str | Expression | None = None) -> Expression:
+        """Strip whitespace, or the given characters, from both sides of a UTF-8 string
+
+        Example:
+            >>> col("x").str.strip()
+            >>> col("x").str.strip("_-")
+
+        Args:
+            chars: the characters to strip, or None to strip whitespace
 
         Returns:
-            Expression: a String expression which is `self` with trailing whitespace stripped
+            Expression: a String expression which is `self` with leading and trailing whitespace or characters stripped
         """
-        return Expression._from_pyexpr(self._expr.utf8_rstrip())
+        if chars is None:
+            return Expression._from_pyexpr(self._expr.utf8_lstrip().utf8_rstrip())
+        chars_expr = Expression._to_expression(chars)
+        return Expression._from_pyexpr(self._expr.utf8_strip_chars(chars_expr._expr, left=True, right=True))
 
     def reverse(self) -> Expression:
         """Reverse a UTF-8 string
@@ -1218,6 +1799,234 @@ class ExpressionStringNamespace(ExpressionNamespace):
         substr_expr = Expression._to_expression(substr)
         return Expression._from_pyexpr(self._expr.utf8_find(substr_expr._expr))
 
+    def tokenize(self, pattern: str = r"\s+") -> Expression:
+        r"""Splits each string into tokens separated by the given regex pattern, which is whitespace by default
+
+        .. NOTE::
+            Empty tokens, e.g. from leading or trailing separators, are dropped.
+
+        Example:
+            >>> col("x").str.tokenize()  # " hello  world " -> ["hello", "world"]
+            >>> col("x").str.tokenize(r"\W+")  # "a,b;c" -> ["a", "b", "c"]
+
+        Args:
+            pattern: regex pattern matching the separators between tokens
+
+        Returns:
+            Expression: a List[Utf8] expression with the tokens of each string
+        """
+        return Expression._from_pyexpr(self._expr.utf8_tokenize(pattern))
+
+    def ngrams(self, n: int, chars: bool = False) -> Expression:
+        """Gets the n-grams of each string, which are either n consecutive whitespace-separated words
+        joined by a single space, or n consecutive characters
+
+        .. NOTE::
+            Strings with fewer than ``n`` words or characters have no n-grams.
+
+        Example:
+            >>> col("x").str.ngrams(2)  # "the quick fox" -> ["the quick", "quick fox"]
+            >>> col("x").str.ngrams(3, chars=True)  # "abcd" -> ["abc", "bcd"]
+
+        Args:
+            n: the number of words or characters in each n-gram
+            chars: whether to get character n-grams rather than word n-grams
+
+        Returns:
+            Expression: a List[Utf8] expression with the n-grams of each string
+        """
+        if n <= 0:
+            raise ValueError(f"n must be positive, but received {n}")
+        return Expression._from_pyexpr(self._expr.utf8_ngrams(n, chars))
+
+    def word_count(self) -> Expression:
+        """Counts the whitespace-separated words in each string
+
+        Example:
+            >>> col("x").str.word_count()  # "the quick  fox" -> 3
+
+        Returns:
+            Expression: a UInt64 expression with the number of words in each string
+        """
+        return Expression._from_pyexpr(self._expr.utf8_word_count())
+
+    def lpad(self, length: int | Expression, pad: str | Expression = " ") -> Expression:
+        """Left-pads each string to the given length with repetitions of ``pad``
+
+        .. NOTE::
+            Strings longer than ``length`` are truncated to it.
+
+        Example:
+            >>> col("x").str.lpad(5, "0")  # "42" -> "00042"
+
+        Args:
+            length: the length in characters of the resulting strings
+            pad: the string to pad with, which is a space by default
+
+        Returns:
+            Expression: a String expression which is `self` padded to `length` characters
+        """
+        length_expr = Expression._to_expression(length)
+        pad_expr = Expression._to_expression(pad)
+        return Expression._from_pyexpr(self._expr.utf8_lpad(length_expr._expr, pad_expr._expr))
+
+    def rpad(self, length: int | Expression, pad: str | Expression = " ") -> Expression:
+        """Right-pads each string to the given length with repetitions of ``pad``
+
+        .. NOTE::
+            Strings longer than ``length`` are truncated to it.
+
+        Example:
+            >>> col("x").str.rpad(5, ".")  # "ab" -> "ab..."
+
+        Args:
+            length: the length in characters of the resulting strings
+            pad: the string to pad with, which is a space by default
+
+        Returns:
+            Expression: a String expression which is `self` padded to `length` characters
+        """
+        length_expr = Expression._to_expression(length)
+        pad_expr = Expression._to_expression(pad)
+        return Expression._from_pyexpr(self._expr.utf8_rpad(length_expr._expr, pad_expr._expr))
+
+    def repeat(self, n: int | Expression) -> Expression:
+        """Repeats each string n times
+
+        Example:
+            >>> col("x").str.repeat(3)  # "ab" -> "ababab"
+
+        Returns:
+            Expression: a String expression which is `self` repeated `n` times
+        """
+        n_expr = Expression._to_expression(n)
+        return Expression._from_pyexpr(self._expr.utf8_repeat(n_expr._expr))
+
+    def substr(self, start: int | Expression, length: int | Expression | None = None) -> Expression:
+        """Gets the substring of each string with ``length`` characters starting at ``start``
+
+        .. NOTE::
+            ``start`` is 0-based, and a negative ``start`` counts from the end of the string.
+
+        Example:
+            >>> col("x").str.substr(1, 3)  # "hello" -> "ell"
+
+        Args:
+            start: index of the first character of the substring
+            length: number of characters in the substring, or None to take all characters to the end of the string
+
+        Returns:
+            Expression: a String expression with the substring of each string
+        """
+        start_expr = Expression._to_expression(start)
+        length_expr = Expression._to_expression(length)
+        return Expression._from_pyexpr(self._expr.utf8_substr(start_expr._expr, length_expr._expr))
+
+    def normalize(self, form: Literal["NFC", "NFD", "NFKC", "NFKD"] = "NFC") -> Expression:
+        """Applies unicode normalization to each string
+
+        Example:
+            >>> col("x").str.normalize("NFKC")
+
+        Args:
+            form: the unicode normalization form, one of "NFC", "NFD", "NFKC" or "NFKD"
+
+        Returns:
+            Expression: a String expression which is `self` normalized
+        """
+        return Expression._from_pyexpr(self._expr.utf8_normalize(form))
+
+    def count_matches(self, pattern: str | Expression, regex: bool = False) -> Expression:
+        r"""Counts the non-overlapping occurrences of a pattern in each string
+
+        Example:
+            >>> col("x").str.count_matches("ab")  # "abcab" -> 2
+            >>> col("x").str.count_matches(r"\d+", regex=True)  # "a1b22" -> 2
+
+        Args:
+            pattern: the pattern to count
+            regex: whether the pattern is a regular expression
+
+        Returns:
+            Expression: a UInt64 expression with the number of occurrences in each string
+        """
+        pattern_expr = Expression._to_expression(pattern)
+        return Expression._from_pyexpr(self._expr.utf8_count_matches(pattern_expr._expr, regex))
+
+    def like(self, pattern: str | Expression) -> Expression:
+        r"""Checks whether each string matches a SQL LIKE pattern
+
+        ``%`` matches any sequence of characters, ``_`` matches any single character and ``\`` escapes them.
+
+        Example:
+            >>> col("x").str.like("foo%")
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string matches the pattern
+        """
+        pattern_expr = Expression._to_expression(pattern)
+        return Expression._from_pyexpr(self._expr.utf8_like(pattern_expr._expr))
+
+    def ilike(self, pattern: str | Expression) -> Expression:
+        """Checks whether each string matches a SQL LIKE pattern, ignoring case
+
+        Example:
+            >>> col("x").str.ilike("FOO%")
+
+        Returns:
+            Expression: a Boolean expression indicating whether each string matches the pattern
+        """
+        pattern_expr = Expression._to_expression(pattern)
+        return Expression._from_pyexpr(self._expr.utf8_ilike(pattern_expr._expr))
+
+    def to_date(self, format: str, on_error: Literal["raise"] | Literal["null"] = "raise") -> Expression:
+        """Parses each string as a date with the given format
+
+        The format uses chrono's strftime specifiers, see https://docs.rs/chrono/latest/chrono/format/strftime/index.html.
+
+        Example:
+            >>> col("x").str.to_date("%d/%m/%Y")
+
+        Args:
+            format: The format of the date strings, e.g. "%Y-%m-%d"
+            on_error: Whether to raise when a string doesn't match the format, or return a null for it
+
+        Returns:
+            Expression: a Date expression parsed from each string
+        """
+        if on_error not in ("raise", "null"):
+            raise NotImplementedError(f"Unimplemented on_error option: {on_error}.")
+        return Expression._from_pyexpr(self._expr.utf8_to_date(format, raise_error_on_failure=on_error == "raise"))
+
+    def to_datetime(
+        self,
+        format: str,
+        timezone: str | None = None,
+        on_error: Literal["raise"] | Literal["null"] = "raise",
+    ) -> Expression:
+        """Parses each string as a microsecond timestamp with the given format
+
+        The format uses chrono's strftime specifiers, see https://docs.rs/chrono/latest/chrono/format/strftime/index.html.
+        Strings without a UTC offset are read as wall-clock times in ``timezone``, while strings with an offset (``%z``)
+        are converted to that instant. If no timezone is given, the result is timezone-naive and offsets are normalized to UTC.
+
+        Example:
+            >>> col("x").str.to_datetime("%Y-%m-%d %H:%M:%S", timezone="Asia/Tokyo")
+
+        Args:
+            format: The format of the datetime strings, e.g. "%Y-%m-%d %H:%M:%S"
+            timezone: Optional timezone of the resulting timestamps
+            on_error: Whether to raise when a string doesn't match the format, or return a null for it
+
+        Returns:
+            Expression: a Timestamp expression parsed from each string
+        """
+        if on_error not in ("raise", "null"):
+            raise NotImplementedError(f"Unimplemented on_error option: {on_error}.")
+        return Expression._from_pyexpr(
+            self._expr.utf8_to_datetime(format, timezone, raise_error_on_failure=on_error == "raise")
+        )
+
 
 class ExpressionListNamespace(ExpressionNamespace):
     def join(self, delimiter: str | Expression) -> Expression:
@@ -1297,6 +2106,150 @@ class ExpressionListNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.list_max())
 
+    def to_map(self, values: Expression) -> Expression:
+        """Builds a map from each list of keys and the list of values at the same position in ``values``
+
+        A map is null if either of its lists is null. Otherwise the two lists must have the same length,
+        and the keys cannot be null.
+
+        Example:
+            >>> col("keys").list.to_map(col("values"))
+
+        Args:
+            values: a list expression with the values of each map
+
+        Returns:
+            Expression: a Map expression with entries of each key and its value
+        """
+        values_expr = Expression._to_expression(values)
+        return Expression._from_pyexpr(self._expr.list_to_map(values_expr._expr))
+
+    def contains(self, item: object) -> Expression:
+        """Checks whether each list contains an item. The result is null if the list or the item is null.
+
+        Example:
+            >>> col("tags").list.contains("daft")
+
+        Args:
+            item: the item to look for, which is either a literal or an expression with an item per list
+
+        Returns:
+            Expression: a Boolean expression
+        """
+        item_expr = Expression._to_expression(item)
+        return Expression._from_pyexpr(self._expr.list_contains(item_expr._expr))
+
+    def slice(self, start: int | Expression, end: int | Expression | None = None) -> Expression:
+        """Slices each list from ``start`` (inclusive) to ``end`` (exclusive)
+
+        Like Python slicing, negative bounds count from the end of the list and out of range bounds are clamped.
+        A null bound is unbounded.
+
+        Example:
+            >>> col("x").list.slice(1, -1)
+
+        Args:
+            start: index or indices to start each slice at
+            end: index or indices to end each slice at, or None to slice to the end of each list
+
+        Returns:
+            Expression: a List expression with the type of the list values
+        """
+        start_expr = Expression._to_expression(start)
+        end_expr = Expression._to_expression(end)
+        return Expression._from_pyexpr(self._expr.list_slice(start_expr._expr, end_expr._expr))
+
+    def sort(self, desc: bool = False) -> Expression:
+        """Sorts the elements of each list
+
+        Example:
+            >>> col("x").list.sort(desc=True)
+
+        Args:
+            desc: whether to sort in descending order
+
+        Returns:
+            Expression: an expression with the same type as the lists
+        """
+        return Expression._from_pyexpr(self._expr.list_sort(desc))
+
+    def distinct(self) -> Expression:
+        """Gets the distinct elements of each list, in order of first occurrence
+
+        Returns:
+            Expression: a List expression with the type of the list values
+        """
+        return Expression._from_pyexpr(self._expr.list_distinct())
+
+    def flatten(self) -> Expression:
+        """Flattens each list of lists into a list of the elements of its inner lists. Null inner lists are skipped.
+
+        Example:
+            >>> col("x").list.flatten()  # [[1, 2], [3]] -> [1, 2, 3]
+
+        Returns:
+            Expression: a List expression with the type of the inner list values
+        """
+        return Expression._from_pyexpr(self._expr.list_flatten())
+
+    def value_counts(self) -> Expression:
+        """Counts the occurrences of each distinct non-null element of each list
+
+        Example:
+            >>> col("tags").list.value_counts()  # ["a", "b", "a"] -> {"a": 2, "b": 1}
+
+        Returns:
+            Expression: a Map expression from the elements of each list to their UInt64 counts
+        """
+        return Expression._from_pyexpr(self._expr.list_value_counts())
+
+    def zip(self, *others: Expression) -> Expression:
+        """Zips each list with the lists of the other expressions in the same row into a list of structs
+
+        Each struct has one field per list, named after its expression. Zipped lists are as long as the shortest of
+        their lists, and are null if any of their lists is null.
+
+        Example:
+            >>> col("x").list.zip(col("y"))  # [1, 2], ["a", "b", "c"] -> [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}]
+
+        Args:
+            others: list expressions to zip with
+
+        Returns:
+            Expression: a List expression of Structs with one field per list
+        """
+        return Expression._from_pyexpr(self._expr.list_zip([other._expr for other in others]))
+
+    def eval(self, expr: Expression) -> Expression:
+        """Evaluates an expression on every element of each list, referring to the elements with ``element()``
+
+        Example:
+            >>> col("x").list.eval(element() * 2)  # [1, 2] -> [2, 4]
+
+        Args:
+            expr: expression to evaluate on each element, which can only refer to ``element()`` and literals
+
+        Returns:
+            Expression: a List expression with the type of ``expr``
+        """
+        expr = Expression._to_expression(expr)
+        return Expression._from_pyexpr(self._expr.list_eval(expr._expr))
+
+    def filter(self, predicate: Expression) -> Expression:
+        """Keeps the elements of each list for which ``predicate`` is true, referring to the elements with ``element()``
+
+        Example:
+            >>> col("x").list.filter(element() > 0)  # [-1, 2, None] -> [2]
+
+        Args:
+            predicate: boolean expression to evaluate on each element, where null counts as false
+
+        Returns:
+            Expression: a List expression with the type of the list values
+        """
+        predicate = Expression._to_expression(predicate)
+        return Expression._from_pyexpr(self._expr.list_filter(predicate._expr))
+
 
 class ExpressionStructNamespace(ExpressionNamespace):
     def get(self, name: str) -> Expression:
@@ -1310,6 +2263,69 @@ class ExpressionStructNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(self._expr.struct_get(name))
 
+    def rename_fields(self, names: list[str]) -> Expression:
+        """Renames the fields of a struct column, in order
+
+        Example:
+            >>> col("x").struct.rename_fields(["a", "b"])
+
+        Args:
+            names: the new names of the struct fields, one per field
+
+        Returns:
+            Expression: a struct expression with renamed fields
+        """
+        return Expression._from_pyexpr(self._expr.struct_rename_fields(names))
+
+    def with_field(self, name: str, value: object) -> Expression:
+        """Sets a field of a struct column, replacing the field if it exists and adding it otherwise
+
+        Example:
+            >>> col("x").struct.with_field("c", col("y") * 2)
+
+        Args:
+            name: the name of the field to set
+            value: the value of the field, which is either a literal or an expression
+
+        Returns:
+            Expression: a struct expression with the field set
+        """
+        value_expr = Expression._to_expression(value)
+        return Expression._from_pyexpr(self._expr.struct_with_field(name, value_expr._expr))
+
+
+class ExpressionMapNamespace(ExpressionNamespace):
+    def get(self, key: object) -> Expression:
+        """Retrieves the value for a key in each map
+
+        Example:
+            >>> col("x").map.get("a")
+
+        Args:
+            key: the key to look up, which is either a literal or an expression with a key per map
+
+        Returns:
+            Expression: an expression with the type of the map values, which is null if the key is not in the map
+        """
+        key_expr = Expression._to_expression(key)
+        return Expression._from_pyexpr(self._expr.map_get(key_expr._expr))
+
+    def keys(self) -> Expression:
+        """Retrieves the keys of each map
+
+        Returns:
+            Expression: a List expression with the keys of each map
+        """
+        return Expression._from_pyexpr(self._expr.map_keys())
+
+    def values(self) -> Expression:
+        """Retrieves the values of each map
+
+        Returns:
+            Expression: a List expression with the values of each map
+        """
+        return Expression._from_pyexpr(self._expr.map_values())
+
 
 class ExpressionsProjection(Iterable[Expression]):
     """A collection of Expressions that can be projected onto a Table to produce another Table
@@ -100,10 +100,19 @@ class LogicalPlanBuilder:
         builder = self._builder.with_columns(column_pyexprs, custom_resource_request)
         return LogicalPlanBuilder(builder)
 
+    def window(self, window_exprs: list[Expression]) -> LogicalPlanBuilder:
+        window_pyexprs = [expr._expr for expr in window_exprs]
+        builder = self._builder.window(window_pyexprs)
+        return LogicalPlanBuilder(builder)
+
     def exclude(self, to_exclude: list[str]) -> LogicalPlanBuilder:
         builder = self._builder.exclude(to_exclude)
         return LogicalPlanBuilder(builder)
 
+    def unnest(self, to_unnest: list[str]) -> LogicalPlanBuilder:
+        builder = self._builder.unnest(to_unnest)
+        return LogicalPlanBuilder(builder)
+
     def filter(self, predicate: Expression) -> LogicalPlanBuilder:
         builder = self._builder.filter(predicate._expr)
         return LogicalPlanBuilder(builder)
@@ -173,22 +182,17 @@ class LogicalPlanBuilder:
         right_on: list[Expression],
         how: JoinType = JoinType.Inner,
         strategy: JoinStrategy | None = None,
+        predicate: Expression | None = None,
     ) -> LogicalPlanBuilder:
-        if how == JoinType.Left:
-            raise NotImplementedError("Left join not implemented.")
-        elif how == JoinType.Right:
-            raise NotImplementedError("Right join not implemented.")
-        elif how == JoinType.Inner:
-            builder = self._builder.join(
-                right._builder,
-                [expr._expr for expr in left_on],
-                [expr._expr for expr in right_on],
-                how,
-                strategy,
-            )
-            return LogicalPlanBuilder(builder)
-        else:
-            raise NotImplementedError(f"{how} join not implemented.")
+        builder = self._builder.join(
+            right._builder,
+            [expr._expr for expr in left_on],
+            [expr._expr for expr in right_on],
+            how,
+            strategy,
+            predicate._expr if predicate is not None else None,
+        )
+        return LogicalPlanBuilder(builder)
 
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder:  # type: ignore[override]
         builder = self._builder.concat(other._builder)
@@ -206,8 +210,8 @@ class LogicalPlanBuilder:
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
     ) -> LogicalPlanBuilder:
-        if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
-            raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
+        if file_format not in (FileFormat.Csv, FileFormat.Json, FileFormat.Parquet):
+            raise ValueError(f"Writing is only supported for Parquet, CSV and JSON file formats, but got: {file_format}")
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
         builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
         return LogicalPlanBuilder(builder)
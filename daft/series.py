@@ -320,7 +320,12 @@ class Series:
 
         return Series._from_pyseries(self._series.sort(descending))
 
-    def hash(self, seed: Series | None = None) -> Series:
+    def hash(self, seed: Series | int | None = None, hash_function: str | None = None) -> Series:
+        if hash_function is not None:
+            if seed is not None and (not isinstance(seed, int) or seed < 0):
+                raise ValueError(f"expected `seed` to be a non-negative integer, got {seed}")
+            return Series._from_pyseries(self._series.hash_with_function(hash_function, seed))
+
         if not isinstance(seed, Series) and seed is not None:
             raise TypeError(f"expected `seed` to be Series, got {type(seed)}")
 
@@ -329,6 +334,20 @@ class Series:
     def murmur3_32(self) -> Series:
         return Series._from_pyseries(self._series.murmur3_32())
 
+    def minhash(self, num_hashes: int, ngram_size: int, seed: int = 1) -> Series:
+        if not isinstance(num_hashes, int) or not isinstance(ngram_size, int):
+            raise TypeError(
+                f"expected integer num_hashes and ngram_size, got {type(num_hashes)} and {type(ngram_size)}"
+            )
+        if num_hashes <= 0 or ngram_size <= 0:
+            raise ValueError(f"expected num_hashes and ngram_size to be positive, got {num_hashes} and {ngram_size}")
+        return Series._from_pyseries(self._series.minhash(num_hashes, ngram_size, seed))
+
+    def lsh_bands(self, num_bands: int) -> Series:
+        if not isinstance(num_bands, int):
+            raise TypeError(f"expected an integer num_bands, got {type(num_bands)}")
+        return Series._from_pyseries(self._series.lsh_bands(num_bands))
+
     def __repr__(self) -> str:
         return repr(self._series)
 
@@ -569,6 +588,10 @@ class Series:
     def list(self) -> SeriesListNamespace:
         return SeriesListNamespace.from_series(self)
 
+    @property
+    def map(self) -> SeriesMapNamespace:
+        return SeriesMapNamespace.from_series(self)
+
     @property
     def image(self) -> SeriesImageNamespace:
         return SeriesImageNamespace.from_series(self)
@@ -697,13 +720,29 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None
         return Series._from_pyseries(self._series.utf8_upper())
 
-    def lstrip(self) -> Series:
+    def lstrip(self, chars: Series | None = None) -> Series:
+        assert self._series is not None
+        if chars is None:
+            return Series._from_pyseries(self._series.utf8_lstrip())
+        if not isinstance(chars, Series):
+            raise ValueError(f"expected another Series but got {type(chars)}")
+        return Series._from_pyseries(self._series.utf8_strip_chars(chars._series, True, False))
+
+    def rstrip(self, chars: Series | None = None) -> Series:
         assert self._series is not None
-        return Series._from_pyseries(self._series.utf8_lstrip())
+        if chars is None:
+            return Series._from_pyseries(self._series.utf8_rstrip())
+        if not isinstance(chars, Series):
+            raise ValueError(f"expected another Series but got {type(chars)}")
+        return Series._from_pyseries(self._series.utf8_strip_chars(chars._series, False, True))
 
-    def rstrip(self) -> Series:
+    def strip(self, chars: Series | None = None) -> Series:
         assert self._series is not None
-        return Series._from_pyseries(self._series.utf8_rstrip())
+        if chars is None:
+            return Series._from_pyseries(self._series.utf8_lstrip().utf8_rstrip())
+        if not isinstance(chars, Series):
+            raise ValueError(f"expected another Series but got {type(chars)}")
+        return Series._from_pyseries(self._series.utf8_strip_chars(chars._series, True, True))
 
     def reverse(self) -> Series:
         assert self._series is not None
@@ -731,6 +770,83 @@ class SeriesStringNamespace(SeriesNamespace):
         assert self._series is not None and substr._series is not None
         return Series._from_pyseries(self._series.utf8_find(substr._series))
 
+    def tokenize(self, pattern: str = r"\s+") -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_tokenize(pattern))
+
+    def ngrams(self, n: int, chars: bool = False) -> Series:
+        if n <= 0:
+            raise ValueError(f"n must be positive, but received {n}")
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_ngrams(n, chars))
+
+    def word_count(self) -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_word_count())
+
+    def lpad(self, length: Series, pad: Series) -> Series:
+        if not isinstance(length, Series) or not isinstance(pad, Series):
+            raise ValueError(f"expected other Series but got {type(length)} and {type(pad)}")
+        assert self._series is not None and length._series is not None and pad._series is not None
+        return Series._from_pyseries(self._series.utf8_lpad(length._series, pad._series))
+
+    def rpad(self, length: Series, pad: Series) -> Series:
+        if not isinstance(length, Series) or not isinstance(pad, Series):
+            raise ValueError(f"expected other Series but got {type(length)} and {type(pad)}")
+        assert self._series is not None and length._series is not None and pad._series is not None
+        return Series._from_pyseries(self._series.utf8_rpad(length._series, pad._series))
+
+    def repeat(self, n: Series) -> Series:
+        if not isinstance(n, Series):
+            raise ValueError(f"expected another Series but got {type(n)}")
+        assert self._series is not None and n._series is not None
+        return Series._from_pyseries(self._series.utf8_repeat(n._series))
+
+    def substr(self, start: Series, length: Series | None = None) -> Series:
+        if length is None:
+            length = Series.from_pylist([None])
+        if not isinstance(start, Series) or not isinstance(length, Series):
+            raise ValueError(f"expected other Series but got {type(start)} and {type(length)}")
+        assert self._series is not None and start._series is not None and length._series is not None
+        return Series._from_pyseries(self._series.utf8_substr(start._series, length._series))
+
+    def normalize(self, form: Literal["NFC", "NFD", "NFKC", "NFKD"] = "NFC") -> Series:
+        assert self._series is not None
+        return Series._from_pyseries(self._series.utf8_normalize(form))
+
+    def count_matches(self, pattern: Series, regex: bool = False) -> Series:
+        if not isinstance(pattern, Series):
+            raise ValueError(f"expected another Series but got {type(pattern)}")
+        assert self._series is not None and pattern._series is not None
+        return Series._from_pyseries(self._series.utf8_count_matches(pattern._series, regex))
+
+    def like(self, pattern: Series) -> Series:
+        if not isinstance(pattern, Series):
+            raise ValueError(f"expected another Series but got {type(pattern)}")
+        assert self._series is not None and pattern._series is not None
+        return Series._from_pyseries(self._series.utf8_like(pattern._series))
+
+    def ilike(self, pattern: Series) -> Series:
+        if not isinstance(pattern, Series):
+            raise ValueError(f"expected another Series but got {type(pattern)}")
+        assert self._series is not None and pattern._series is not None
+        return Series._from_pyseries(self._series.utf8_ilike(pattern._series))
+
+    def to_date(self, format: str, on_error: Literal["raise"] | Literal["null"] = "raise") -> Series:
+        if on_error not in ("raise", "null"):
+            raise NotImplementedError(f"Unimplemented on_error option: {on_error}.")
+        return Series._from_pyseries(self._series.utf8_to_date(format, on_error == "raise"))
+
+    def to_datetime(
+        self,
+        format: str,
+        timezone: str | None = None,
+        on_error: Literal["raise"] | Literal["null"] = "raise",
+    ) -> Series:
+        if on_error not in ("raise", "null"):
+            raise NotImplementedError(f"Unimplemented on_error option: {on_error}.")
+        return Series._from_pyseries(self._series.utf8_to_datetime(format, timezone, on_error == "raise"))
+
 
 class SeriesDateNamespace(SeriesNamespace):
     def date(self) -> Series:
@@ -751,6 +867,27 @@ class SeriesDateNamespace(SeriesNamespace):
     def day_of_week(self) -> Series:
         return Series._from_pyseries(self._series.dt_day_of_week())
 
+    def minute(self) -> Series:
+        return Series._from_pyseries(self._series.dt_minute())
+
+    def second(self) -> Series:
+        return Series._from_pyseries(self._series.dt_second())
+
+    def millisecond(self) -> Series:
+        return Series._from_pyseries(self._series.dt_millisecond())
+
+    def time(self) -> Series:
+        return Series._from_pyseries(self._series.dt_time())
+
+    def day_of_year(self) -> Series:
+        return Series._from_pyseries(self._series.dt_day_of_year())
+
+    def week_of_year(self) -> Series:
+        return Series._from_pyseries(self._series.dt_week_of_year())
+
+    def quarter(self) -> Series:
+        return Series._from_pyseries(self._series.dt_quarter())
+
     def truncate(self, interval: str, relative_to: Series | None = None) -> Series:
         if relative_to is not None and not isinstance(relative_to, Series):
             raise ValueError(f"expected another Series but got {type(relative_to)}")
@@ -758,6 +895,19 @@ class SeriesDateNamespace(SeriesNamespace):
             relative_to = Series.from_arrow(pa.array([None]))
         return Series._from_pyseries(self._series.dt_truncate(interval, relative_to._series))
 
+    def convert_time_zone(self, timezone: str) -> Series:
+        return Series._from_pyseries(self._series.dt_convert_time_zone(timezone))
+
+    def replace_time_zone(
+        self,
+        timezone: str | None,
+        ambiguous: Literal["raise"] | Literal["earliest"] | Literal["latest"] | Literal["null"] = "raise",
+    ) -> Series:
+        return Series._from_pyseries(self._series.dt_replace_time_zone(timezone, ambiguous))
+
+    def strftime(self, format: str) -> Series:
+        return Series._from_pyseries(self._series.dt_strftime(format))
+
 
 class SeriesPartitioningNamespace(SeriesNamespace):
     def days(self) -> Series:
@@ -786,6 +936,41 @@ class SeriesListNamespace(SeriesNamespace):
     def get(self, idx: Series, default: Series) -> Series:
         return Series._from_pyseries(self._series.list_get(idx._series, default._series))
 
+    def to_map(self, values: Series) -> Series:
+        return Series._from_pyseries(self._series.list_to_map(values._series))
+
+    def contains(self, item: Series) -> Series:
+        return Series._from_pyseries(self._series.list_contains(item._series))
+
+    def slice(self, start: Series, end: Series) -> Series:
+        return Series._from_pyseries(self._series.list_slice(start._series, end._series))
+
+    def sort(self, desc: bool = False) -> Series:
+        return Series._from_pyseries(self._series.list_sort(desc))
+
+    def distinct(self) -> Series:
+        return Series._from_pyseries(self._series.list_distinct())
+
+    def flatten(self) -> Series:
+        return Series._from_pyseries(self._series.list_flatten())
+
+    def value_counts(self) -> Series:
+        return Series._from_pyseries(self._series.list_value_counts())
+
+    def zip(self, *others: Series) -> Series:
+        return Series._from_pyseries(self._series.list_zip([other._series for other in others]))
+
+
+class SeriesMapNamespace(SeriesNamespace):
+    def get(self, key: Series) -> Series:
+        return Series._from_pyseries(self._series.map_get(key._series))
+
+    def keys(self) -> Series:
+        return Series._from_pyseries(self._series.map_keys())
+
+    def values(self) -> Series:
+        return Series._from_pyseries(self._series.map_values())
+
 
 class SeriesImageNamespace(SeriesNamespace):
     def decode(self, on_error: Literal["raise"] | Literal["null"] = "raise") -> Series:
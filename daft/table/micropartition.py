@@ -222,6 +222,20 @@ class MicroPartition:
         group_by_pyexprs = [e._expr for e in group_by] if group_by is not None else []
         return MicroPartition._from_pymicropartition(self._micropartition.agg(to_agg_pyexprs, group_by_pyexprs))
 
+    def window(
+        self,
+        window_exprs: list[Expression],
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        descending: list[bool],
+    ) -> MicroPartition:
+        window_pyexprs = [e._expr for e in window_exprs]
+        partition_by_pyexprs = [e._expr for e in partition_by]
+        order_by_pyexprs = [e._expr for e in order_by]
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.window(window_pyexprs, partition_by_pyexprs, order_by_pyexprs, descending)
+        )
+
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
@@ -237,8 +251,6 @@ class MicroPartition:
         right_on: ExpressionsProjection,
         how: JoinType = JoinType.Inner,
     ) -> MicroPartition:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -251,7 +263,7 @@ class MicroPartition:
         right_exprs = [e._expr for e in right_on]
 
         return MicroPartition._from_pymicropartition(
-            self._micropartition.hash_join(right._micropartition, left_on=left_exprs, right_on=right_exprs)
+            self._micropartition.hash_join(right._micropartition, left_on=left_exprs, right_on=right_exprs, how=how)
         )
 
     def sort_merge_join(
@@ -262,8 +274,6 @@ class MicroPartition:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> MicroPartition:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -277,7 +287,22 @@ class MicroPartition:
 
         return MicroPartition._from_pymicropartition(
             self._micropartition.sort_merge_join(
-                right._micropartition, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted
+                right._micropartition, left_on=left_exprs, right_on=right_exprs, how=how, is_sorted=is_sorted
+            )
+        )
+
+    def nested_loop_join(
+        self,
+        right: MicroPartition,
+        predicate: Expression | None = None,
+        how: JoinType = JoinType.Inner,
+    ) -> MicroPartition:
+        if not isinstance(right, MicroPartition):
+            raise TypeError(f"Expected a MicroPartition for `right` in join but got {type(right)}")
+
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.nested_loop_join(
+                right._micropartition, predicate=predicate._expr if predicate is not None else None, how=how
             )
         )
 
@@ -458,3 +483,30 @@ class MicroPartition:
                 multithreaded_io=multithreaded_io,
             )
         )
+
+    def write_parquet(
+        self,
+        path: str,
+        row_group_size: int,
+        inflation_factor: float,
+        compression: str | None = None,
+        io_config: IOConfig | None = None,
+    ) -> int:
+        """Writes this MicroPartition into a single Parquet file at `path`, returning the size of the file in bytes.
+
+        Row groups are sized to about `row_group_size` bytes once written, estimated from their in-memory size
+        divided by `inflation_factor`.
+        """
+        return self._micropartition.write_parquet(path, compression, row_group_size, inflation_factor, io_config)
+
+    def write_csv(self, path: str, io_config: IOConfig | None = None) -> None:
+        """Writes this MicroPartition into a single CSV file at `path`, compressed with the codec inferred from its
+        extension, if any. Nulls are written as empty fields and empty strings as quoted empty fields.
+        """
+        self._micropartition.write_csv(path, io_config)
+
+    def write_json(self, path: str, io_config: IOConfig | None = None) -> None:
+        """Writes this MicroPartition into a single newline-delimited JSON file at `path`, compressed with the codec
+        inferred from its extension, if any.
+        """
+        self._micropartition.write_json(path, io_config)
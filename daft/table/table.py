@@ -321,8 +321,6 @@ class Table:
         right_on: ExpressionsProjection,
         how: JoinType = JoinType.Inner,
     ) -> Table:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -334,7 +332,9 @@ class Table:
         left_exprs = [e._expr for e in left_on]
         right_exprs = [e._expr for e in right_on]
 
-        return Table._from_pytable(self._table.hash_join(right._table, left_on=left_exprs, right_on=right_exprs))
+        return Table._from_pytable(
+            self._table.hash_join(right._table, left_on=left_exprs, right_on=right_exprs, how=how)
+        )
 
     def sort_merge_join(
         self,
@@ -344,8 +344,6 @@ class Table:
         how: JoinType = JoinType.Inner,
         is_sorted: bool = False,
     ) -> Table:
-        if how != JoinType.Inner:
-            raise NotImplementedError("TODO: [RUST] Implement Other Join types")
         if len(left_on) != len(right_on):
             raise ValueError(
                 f"Mismatch of number of join keys, left_on: {len(left_on)}, right_on: {len(right_on)}\nleft_on {left_on}\nright_on {right_on}"
@@ -358,7 +356,24 @@ class Table:
         right_exprs = [e._expr for e in right_on]
 
         return Table._from_pytable(
-            self._table.sort_merge_join(right._table, left_on=left_exprs, right_on=right_exprs, is_sorted=is_sorted)
+            self._table.sort_merge_join(
+                right._table, left_on=left_exprs, right_on=right_exprs, how=how, is_sorted=is_sorted
+            )
+        )
+
+    def nested_loop_join(
+        self,
+        right: Table,
+        predicate: Expression | None = None,
+        how: JoinType = JoinType.Inner,
+    ) -> Table:
+        if not isinstance(right, Table):
+            raise TypeError(f"Expected a Table for `right` in join but got {type(right)}")
+
+        return Table._from_pytable(
+            self._table.nested_loop_join(
+                right._table, predicate=predicate._expr if predicate is not None else None, how=how
+            )
         )
 
     def partition_by_hash(self, exprs: ExpressionsProjection, num_partitions: int) -> list[Table]:
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import contextlib
 import math
+import os
 import pathlib
 from collections.abc import Callable, Generator
 from typing import IO, TYPE_CHECKING, Any, Union
@@ -448,17 +449,28 @@ def write_tabular(
 
     TARGET_ROW_GROUP_SIZE = execution_config.parquet_target_row_group_size
 
+    native_uri = _native_write_uri(resolved_path, canonicalized_protocol)
     if file_format == FileFormat.Parquet:
         format = pads.ParquetFileFormat()
         inflation_factor = execution_config.parquet_inflation_factor
         target_file_size = execution_config.parquet_target_filesize
-        opts = format.make_write_options(compression=compression)
+        opts = format.make_write_options(compression=compression) if native_uri is None else None
+        extension = format.default_extname
     elif file_format == FileFormat.Csv:
         format = pads.CsvFileFormat()
         opts = None
         assert compression is None
         inflation_factor = execution_config.csv_inflation_factor
         target_file_size = execution_config.csv_target_filesize
+        extension = format.default_extname
+    elif file_format == FileFormat.Json:
+        if native_uri is None:
+            raise ValueError(f"Writing JSON is not supported for protocol {protocol}")
+        assert compression is None
+        # Newline-delimited JSON is sized like CSV, which it is about as verbose as.
+        inflation_factor = execution_config.csv_inflation_factor
+        target_file_size = execution_config.csv_target_filesize
+        extension = "json"
     else:
         raise ValueError(f"Unsupported file format {file_format}")
 
@@ -467,6 +479,31 @@ def write_tabular(
         if pf is not None and len(pf) > 0:
             full_path = f"{full_path}/{pf}"
 
+        if native_uri is not None:
+            full_uri = f"{native_uri}/{pf}" if pf is not None and len(pf) > 0 else native_uri
+            num_rows = len(tab)
+            target_num_files = max(math.ceil((tab.size_bytes() or 0) / target_file_size / inflation_factor), 1)
+            rows_per_file = max(math.ceil(num_rows / target_num_files), 1)
+            basename_prefix = str(uuid4())
+            for file_idx, start in enumerate(range(0, num_rows, rows_per_file)):
+                basename = f"{basename_prefix}-{file_idx}.{extension}"
+                file_table = tab.slice(start, min(start + rows_per_file, num_rows))
+                if file_format == FileFormat.Parquet:
+                    file_table.write_parquet(
+                        f"{full_uri}/{basename}",
+                        row_group_size=TARGET_ROW_GROUP_SIZE,
+                        inflation_factor=inflation_factor,
+                        compression=compression,
+                        io_config=io_config,
+                    )
+                elif file_format == FileFormat.Csv:
+                    file_table.write_csv(f"{full_uri}/{basename}", io_config=io_config)
+                else:
+                    file_table.write_json(f"{full_uri}/{basename}", io_config=io_config)
+                visited_paths.append(f"{full_path}/{basename}")
+                partition_idx.append(i)
+            continue
+
         arrow_table = tab.to_arrow()
 
         size_bytes = arrow_table.nbytes
@@ -509,6 +546,17 @@ def write_tabular(
     return MicroPartition.from_pydict(data_dict)
 
 
+def _native_write_uri(resolved_path: str, protocol: str) -> str | None:
+    """Returns the URI that Daft's native writers write `resolved_path` to, or None if they do not support `protocol`."""
+    if protocol == "file":
+        return os.path.abspath(resolved_path)
+    elif protocol in {"s3", "s3n"}:
+        return f"s3://{resolved_path}"
+    elif protocol in {"gs", "abfs"}:
+        return f"{protocol}://{resolved_path}"
+    return None
+
+
 def coerce_pyarrow_table_to_schema(pa_table: pa.Table, input_schema: pa.Schema) -> pa.Table:
     """Coerces a PyArrow table to the supplied schema
 
@@ -1,9 +1,15 @@
-use async_compression::tokio::bufread::{
-    BrotliDecoder, BzDecoder, DeflateDecoder, GzipDecoder, LzmaDecoder, XzDecoder, ZlibDecoder,
-    ZstdDecoder,
+use async_compression::tokio::{
+    bufread::{
+        BrotliDecoder, BzDecoder, DeflateDecoder, GzipDecoder, LzmaDecoder, XzDecoder, ZlibDecoder,
+        ZstdDecoder,
+    },
+    write::{
+        BrotliEncoder, BzEncoder, DeflateEncoder, GzipEncoder, LzmaEncoder, XzEncoder, ZlibEncoder,
+        ZstdEncoder,
+    },
 };
 use std::{path::PathBuf, pin::Pin};
-use tokio::io::{AsyncBufRead, AsyncRead};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite};
 use url::Url;
 
 #[derive(Debug)]
@@ -63,4 +69,22 @@ impl CompressionCodec {
             Zstd => Box::pin(ZstdDecoder::new(reader)),
         }
     }
+
+    /// Wraps `writer` into an encoder, which must be shut down to write the end of the stream.
+    pub fn to_encoder<'a, T: AsyncWrite + Send + 'a>(
+        &self,
+        writer: T,
+    ) -> Pin<Box<dyn AsyncWrite + Send + 'a>> {
+        use CompressionCodec::*;
+        match self {
+            Brotli => Box::pin(BrotliEncoder::new(writer)),
+            Bz => Box::pin(BzEncoder::new(writer)),
+            Deflate => Box::pin(DeflateEncoder::new(writer)),
+            Gzip => Box::pin(GzipEncoder::new(writer)),
+            Lzma => Box::pin(LzmaEncoder::new(writer)),
+            Xz => Box::pin(XzEncoder::new(writer)),
+            Zlib => Box::pin(ZlibEncoder::new(writer)),
+            Zstd => Box::pin(ZstdEncoder::new(writer)),
+        }
+    }
 }
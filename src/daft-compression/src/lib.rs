@@ -1,4 +1,4 @@
-//! Utilities for async decompression of data.
+//! Utilities for async compression and decompression of data.
 pub mod compression;
 
 pub use compression::CompressionCodec;
@@ -4,7 +4,10 @@ use arrow2::{array::PrimitiveArray, compute::arithmetics::basic};
 
 use crate::{
     array::DataArray,
-    datatypes::{DaftNumericType, Float64Array, Int64Array, Utf8Array},
+    datatypes::{
+        logical::{Decimal128Array, DurationArray},
+        DaftNumericType, DataType, Field, Float64Array, Int128Array, Int64Array, Utf8Array,
+    },
     kernels::utf8::add_utf8_arrays,
 };
 
@@ -199,3 +202,209 @@ where
         }
     }
 }
+
+/// Applies a fallible operation to each pair of values of two primitive arrays, broadcasting either
+/// side if it has length 1. Pairs with a null on either side are passed to the operation as `None`.
+fn try_broadcast_binary<L, R, O, F>(
+    lhs: &PrimitiveArray<L>,
+    rhs: &PrimitiveArray<R>,
+    operation: F,
+) -> DaftResult<Vec<Option<O>>>
+where
+    L: arrow2::types::NativeType,
+    R: arrow2::types::NativeType,
+    F: Fn(Option<&L>, Option<&R>) -> DaftResult<Option<O>>,
+{
+    match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(l, r)| operation(l, r))
+            .collect(),
+        // broadcast right path
+        (_, 1) => {
+            let r = rhs.get(0);
+            lhs.iter().map(|l| operation(l, r.as_ref())).collect()
+        }
+        (1, _) => {
+            let l = lhs.get(0);
+            rhs.iter().map(|r| operation(l.as_ref(), r)).collect()
+        }
+        (a, b) => Err(DaftError::ValueError(format!(
+            "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+        ))),
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding half away from zero.
+/// Returns `None` if the denominator is zero or the division overflows.
+fn div_round_half_away(numerator: i128, denominator: i128) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator % denominator;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        // |denominator| >= 2 here, so the quotient is far enough from the bounds to round.
+        Some(quotient + numerator.signum() * denominator.signum())
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Rescales a decimal value from `from_scale` to `to_scale` digits after the decimal point,
+/// rounding half away from zero if digits are dropped. Returns `None` on overflow.
+pub(crate) fn rescale_decimal(value: i128, from_scale: usize, to_scale: usize) -> Option<i128> {
+    if to_scale >= from_scale {
+        10i128
+            .checked_pow((to_scale - from_scale) as u32)?
+            .checked_mul(value)
+    } else {
+        div_round_half_away(value, 10i128.checked_pow((from_scale - to_scale) as u32)?)
+    }
+}
+
+fn decimal_scale(dtype: &DataType) -> usize {
+    match dtype {
+        DataType::Decimal128(_, scale) => *scale,
+        other => unreachable!("Expected a Decimal128 type, got {other}"),
+    }
+}
+
+/// Helper function to perform arithmetic operations on two Decimal128Arrays.
+/// The operation takes the unscaled values of both sides and returns the unscaled result at the
+/// scale of `dtype`, which is `Some(None)` for a null result and `None` if the result overflows.
+fn decimal_arithmetic_helper<F>(
+    lhs: &Decimal128Array,
+    rhs: &Decimal128Array,
+    dtype: DataType,
+    operation: F,
+) -> DaftResult<Decimal128Array>
+where
+    F: Fn(i128, i128) -> Option<Option<i128>>,
+{
+    let DataType::Decimal128(precision, _) = dtype else {
+        unreachable!("Expected a Decimal128 type, got {dtype}")
+    };
+    let max_abs = 10u128.checked_pow(precision as u32).unwrap_or(u128::MAX);
+    let apply = |l: Option<&i128>, r: Option<&i128>| -> DaftResult<Option<i128>> {
+        let (Some(l), Some(r)) = (l, r) else {
+            return Ok(None);
+        };
+        match operation(*l, *r) {
+            Some(Some(value)) if value.unsigned_abs() >= max_abs => None,
+            result => result,
+        }
+        .ok_or_else(|| {
+            DaftError::ComputeError(format!(
+                "Decimal overflow: result of operation on {} and {} does not fit in {dtype}",
+                lhs.data_type(),
+                rhs.data_type()
+            ))
+        })
+    };
+    let values = try_broadcast_binary(lhs.as_arrow(), rhs.as_arrow(), apply)?;
+    let physical = Int128Array::from((lhs.name(), Box::new(PrimitiveArray::from(values))));
+    Ok(Decimal128Array::new(
+        Field::new(lhs.name(), dtype),
+        physical,
+    ))
+}
+
+impl Add for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn add(self, rhs: Self) -> Self::Output {
+        let dtype = (self.data_type() + rhs.data_type())?;
+        let (l_scale, r_scale) = (
+            decimal_scale(self.data_type()),
+            decimal_scale(rhs.data_type()),
+        );
+        let scale = decimal_scale(&dtype);
+        decimal_arithmetic_helper(self, rhs, dtype, |l, r| {
+            let l = rescale_decimal(l, l_scale, scale)?;
+            let r = rescale_decimal(r, r_scale, scale)?;
+            l.checked_add(r).map(Some)
+        })
+    }
+}
+
+impl Sub for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let dtype = (self.data_type() - rhs.data_type())?;
+        let (l_scale, r_scale) = (
+            decimal_scale(self.data_type()),
+            decimal_scale(rhs.data_type()),
+        );
+        let scale = decimal_scale(&dtype);
+        decimal_arithmetic_helper(self, rhs, dtype, |l, r| {
+            let l = rescale_decimal(l, l_scale, scale)?;
+            let r = rescale_decimal(r, r_scale, scale)?;
+            l.checked_sub(r).map(Some)
+        })
+    }
+}
+
+impl Mul for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let dtype = (self.data_type() * rhs.data_type())?;
+        let product_scale = decimal_scale(self.data_type()) + decimal_scale(rhs.data_type());
+        let scale = decimal_scale(&dtype);
+        decimal_arithmetic_helper(self, rhs, dtype, |l, r| {
+            rescale_decimal(l.checked_mul(r)?, product_scale, scale).map(Some)
+        })
+    }
+}
+
+/// Division by zero gives null.
+impl Div for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn div(self, rhs: Self) -> Self::Output {
+        let dtype = (self.data_type() / rhs.data_type())?;
+        let (l_scale, r_scale) = (
+            decimal_scale(self.data_type()),
+            decimal_scale(rhs.data_type()),
+        );
+        // The quotient of the unscaled values has a scale of l_scale - r_scale, so the numerator
+        // is first rescaled to make the quotient have the output scale.
+        let numerator_scale = decimal_scale(&dtype) + r_scale;
+        decimal_arithmetic_helper(self, rhs, dtype, |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            div_round_half_away(rescale_decimal(l, l_scale, numerator_scale)?, r).map(Some)
+        })
+    }
+}
+
+/// Taking the remainder by zero gives null.
+impl Rem for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        let dtype = (self.data_type() % rhs.data_type())?;
+        let (l_scale, r_scale) = (
+            decimal_scale(self.data_type()),
+            decimal_scale(rhs.data_type()),
+        );
+        let scale = decimal_scale(&dtype);
+        decimal_arithmetic_helper(self, rhs, dtype, |l, r| {
+            if r == 0 {
+                return Some(None);
+            }
+            let l = rescale_decimal(l, l_scale, scale)?;
+            let r = rescale_decimal(r, r_scale, scale)?;
+            l.checked_rem(r).map(Some)
+        })
+    }
+}
+
+/// Dividing a duration by zero gives null.
+impl Div<&Int64Array> for &DurationArray {
+    type Output = DaftResult<DurationArray>;
+    fn div(self, rhs: &Int64Array) -> Self::Output {
+        let values = try_broadcast_binary(self.as_arrow(), rhs.as_arrow(), |l, r| match (l, r) {
+            (Some(l), Some(r)) => Ok(l.checked_div(*r)),
+            _ => Ok(None),
+        })?;
+        let physical = Int64Array::from((self.name(), Box::new(PrimitiveArray::from(values))));
+        Ok(DurationArray::new(self.field.clone(), physical))
+    }
+}
@@ -0,0 +1,88 @@
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use arrow2::array::PrimitiveArray;
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftBitwiseAggable, GroupIndices};
+use crate::datatypes::{DaftIntegerType, DataArray};
+
+/// Reduces the non-null values at the given indices with `op`, or returns None if there are none.
+fn reduce_native<N: arrow2::types::NativeType>(
+    arrow_array: &PrimitiveArray<N>,
+    indices: impl Iterator<Item = usize>,
+    op: impl Fn(N, N) -> N,
+) -> Option<N> {
+    indices
+        .filter(|idx| arrow_array.is_valid(*idx))
+        .map(|idx| arrow_array.value(idx))
+        .reduce(op)
+}
+
+fn bitwise_agg<T>(
+    data_array: &DataArray<T>,
+    op: impl Fn(T::Native, T::Native) -> T::Native,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftIntegerType,
+    T::Native: Ord,
+{
+    let arrow_array = data_array.as_arrow();
+    let result = reduce_native(arrow_array, 0..arrow_array.len(), op);
+    DataArray::new(
+        data_array.field.clone(),
+        Box::new(PrimitiveArray::from([result])),
+    )
+}
+
+fn grouped_bitwise_agg<T>(
+    data_array: &DataArray<T>,
+    op: impl Fn(T::Native, T::Native) -> T::Native + Copy,
+    groups: &GroupIndices,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftIntegerType,
+    T::Native: Ord,
+{
+    let arrow_array = data_array.as_arrow();
+    let reduced_per_group = groups
+        .iter()
+        .map(|g| reduce_native(arrow_array, g.iter().map(|i| *i as usize), op));
+    DataArray::new(
+        data_array.field.clone(),
+        Box::new(PrimitiveArray::from_trusted_len_iter(reduced_per_group)),
+    )
+}
+
+/// Nulls are ignored, and the result is null if there are no non-null values.
+impl<T> DaftBitwiseAggable for DataArray<T>
+where
+    T: DaftIntegerType,
+    T::Native:
+        Ord + BitAnd<Output = T::Native> + BitOr<Output = T::Native> + BitXor<Output = T::Native>,
+{
+    type Output = DaftResult<DataArray<T>>;
+
+    fn bit_and(&self) -> Self::Output {
+        bitwise_agg(self, |l, r| l & r)
+    }
+
+    fn bit_or(&self) -> Self::Output {
+        bitwise_agg(self, |l, r| l | r)
+    }
+
+    fn bit_xor(&self) -> Self::Output {
+        bitwise_agg(self, |l, r| l ^ r)
+    }
+
+    fn grouped_bit_and(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_bitwise_agg(self, |l, r| l & r, groups)
+    }
+
+    fn grouped_bit_or(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_bitwise_agg(self, |l, r| l | r, groups)
+    }
+
+    fn grouped_bit_xor(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_bitwise_agg(self, |l, r| l ^ r, groups)
+    }
+}
@@ -0,0 +1,65 @@
+use arrow2::array::BooleanArray as ArrowBooleanArray;
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftBoolAggable, GroupIndices};
+use crate::datatypes::BooleanArray;
+
+/// Reduces the non-null values at the given indices with `op`, or returns None if there are none.
+fn reduce_bool(
+    arrow_array: &ArrowBooleanArray,
+    indices: impl Iterator<Item = usize>,
+    op: impl Fn(bool, bool) -> bool,
+) -> Option<bool> {
+    indices
+        .filter(|idx| arrow_array.is_valid(*idx))
+        .map(|idx| arrow_array.value(idx))
+        .reduce(op)
+}
+
+fn bool_agg(
+    data_array: &BooleanArray,
+    op: impl Fn(bool, bool) -> bool,
+) -> DaftResult<BooleanArray> {
+    let arrow_array = data_array.as_arrow();
+    let result = reduce_bool(arrow_array, 0..arrow_array.len(), op);
+    Ok(BooleanArray::from((
+        data_array.name(),
+        Box::new(ArrowBooleanArray::from([result])),
+    )))
+}
+
+fn grouped_bool_agg(
+    data_array: &BooleanArray,
+    op: impl Fn(bool, bool) -> bool + Copy,
+    groups: &GroupIndices,
+) -> DaftResult<BooleanArray> {
+    let arrow_array = data_array.as_arrow();
+    let reduced_per_group = groups
+        .iter()
+        .map(|g| reduce_bool(arrow_array, g.iter().map(|i| *i as usize), op));
+    Ok(BooleanArray::from((
+        data_array.name(),
+        Box::new(ArrowBooleanArray::from_trusted_len_iter(reduced_per_group)),
+    )))
+}
+
+/// Nulls are ignored, and the result is null if there are no non-null values.
+impl DaftBoolAggable for BooleanArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn bool_and(&self) -> Self::Output {
+        bool_agg(self, |l, r| l && r)
+    }
+
+    fn bool_or(&self) -> Self::Output {
+        bool_agg(self, |l, r| l || r)
+    }
+
+    fn grouped_bool_and(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_bool_agg(self, |l, r| l && r, groups)
+    }
+
+    fn grouped_bool_or(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_bool_agg(self, |l, r| l || r, groups)
+    }
+}
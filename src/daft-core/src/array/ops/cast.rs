@@ -14,10 +14,10 @@ use crate::{
             TensorArray, TimeArray, TimestampArray,
         },
         DaftArrayType, DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageMode,
-        Int32Array, Int64Array, TimeUnit, UInt64Array, Utf8Array,
+        Int128Array, Int32Array, Int64Array, TimeUnit, UInt64Array, Utf8Array,
     },
     series::{IntoSeries, Series},
-    utils::display_table::display_time64,
+    utils::display_table::{display_decimal128, display_time64},
     with_match_daft_logical_primitive_types,
 };
 use common_error::{DaftError, DaftResult};
@@ -247,11 +247,63 @@ where
                 })?;
                 Ok(new_pyseries.into())
             }
+            DataType::Decimal128(precision, scale) if self.data_type() == &DataType::Utf8 => {
+                let utf8_array = self
+                    .data()
+                    .as_any()
+                    .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+                    .unwrap();
+                let values = utf8_array
+                    .iter()
+                    .map(|v| v.and_then(|v| parse_decimal128(v, *precision, *scale)));
+                let physical = Int128Array::from((
+                    self.name(),
+                    Box::new(arrow2::array::PrimitiveArray::from_trusted_len_iter(values)),
+                ));
+                Ok(
+                    Decimal128Array::new(Field::new(self.name(), dtype.clone()), physical)
+                        .into_series(),
+                )
+            }
             _ => arrow_cast(self, dtype),
         }
     }
 }
 
+/// Parses a string such as `"-12.345"` into an unscaled decimal value with the given precision and
+/// scale, rounding half away from zero if it has more fractional digits than the scale.
+/// Returns `None` if the string is not a decimal number or does not fit in the precision.
+fn parse_decimal128(s: &str, precision: usize, scale: usize) -> Option<i128> {
+    let s = s.trim();
+    let (negative, digits) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (integral, fractional) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if integral.is_empty() && fractional.is_empty()
+        || !is_digits(integral)
+        || !is_digits(fractional)
+    {
+        return None;
+    }
+    let mut value: i128 = 0;
+    for digit in integral.bytes().chain(fractional.bytes().take(scale)) {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as i128)?;
+    }
+    // Pad the fractional digits with zeros, or round away the digits beyond the scale.
+    let kept_fractional_digits = fractional.len().min(scale);
+    value = value.checked_mul(10i128.checked_pow((scale - kept_fractional_digits) as u32)?)?;
+    if fractional.as_bytes().get(scale).is_some_and(|d| *d >= b'5') {
+        value = value.checked_add(1)?;
+    }
+    if value.unsigned_abs() >= 10u128.checked_pow(precision as u32).unwrap_or(u128::MAX) {
+        return None;
+    }
+    Some(if negative { -value } else { value })
+}
+
 impl DateArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         let date_array = self
@@ -470,6 +522,17 @@ impl Decimal128Array {
             #[cfg(feature = "python")]
             DataType::Python => cast_logical_to_python_array(self, dtype),
             DataType::Int128 => Ok(self.physical.clone().into_series()),
+            DataType::Utf8 => {
+                let DataType::Decimal128(precision, scale) = self.data_type() else {
+                    unreachable!("Expected a Decimal128 type, got {}", self.data_type())
+                };
+                let values = self
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|v| display_decimal128(*v, *precision as u8, *scale as i8)));
+                let utf8_array = arrow2::array::Utf8Array::<i64>::from_trusted_len_iter(values);
+                Ok(Utf8Array::from((self.name(), Box::new(utf8_array))).into_series())
+            }
             _ => arrow_logical_cast(self, dtype),
         }
     }
@@ -0,0 +1,252 @@
+use arrow2::array::PrimitiveArray;
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::{
+        ops::{as_arrow::AsArrow, GroupIndices},
+        StructArray,
+    },
+    datatypes::{try_comoments_type, DataType, Field, Float64Array, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+use super::{DaftCoMomentsAggable, DaftCoMomentsMergeAggable};
+
+/// The count, means, sums of squared deviations and sum of co-deviations of pairs of values,
+/// which are updated with Welford's algorithm so that they stay numerically stable.
+#[derive(Clone, Copy, Default)]
+struct CoMoments {
+    count: u64,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+}
+
+impl CoMoments {
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let dx = x - self.mean_x;
+        self.mean_x += dx / n;
+        let dy = y - self.mean_y;
+        self.mean_y += dy / n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c_xy += dx * (y - self.mean_y);
+    }
+
+    /// Combines the co-moments of two disjoint sets of pairs (Chan et al.).
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let (n_a, n_b) = (self.count as f64, other.count as f64);
+        let n = n_a + n_b;
+        let dx = other.mean_x - self.mean_x;
+        let dy = other.mean_y - self.mean_y;
+        self.mean_x += dx * n_b / n;
+        self.mean_y += dy * n_b / n;
+        self.m2_x += other.m2_x + dx * dx * n_a * n_b / n;
+        self.m2_y += other.m2_y + dy * dy * n_a * n_b / n;
+        self.c_xy += other.c_xy + dx * dy * n_a * n_b / n;
+        self.count += other.count;
+    }
+
+    fn covariance(&self, ddof: u64) -> Option<f64> {
+        (self.count > ddof).then(|| self.c_xy / (self.count - ddof) as f64)
+    }
+
+    fn correlation(&self) -> Option<f64> {
+        let denominator = (self.m2_x * self.m2_y).sqrt();
+        (self.count > 1 && denominator > 0.0).then(|| self.c_xy / denominator)
+    }
+}
+
+/// Accumulates the pairs at the given indices where both values are non-null.
+fn accumulate(
+    x: &PrimitiveArray<f64>,
+    y: &PrimitiveArray<f64>,
+    indices: impl Iterator<Item = usize>,
+) -> CoMoments {
+    let mut moments = CoMoments::default();
+    for idx in indices {
+        if x.is_valid(idx) && y.is_valid(idx) {
+            moments.add(x.value(idx), y.value(idx));
+        }
+    }
+    moments
+}
+
+fn to_struct_array(name: &str, moments: &[CoMoments]) -> DaftResult<StructArray> {
+    let dtype = try_comoments_type(&DataType::Float64, &DataType::Float64)?;
+    let child_fields = match &dtype {
+        DataType::Struct(fields) => fields.clone(),
+        _ => unreachable!("co-moments are always a struct"),
+    };
+    let counts = Box::new(PrimitiveArray::from_vec(
+        moments.iter().map(|m| m.count).collect(),
+    ));
+    let floats: [fn(&CoMoments) -> f64; 5] = [
+        |m: &CoMoments| m.mean_x,
+        |m: &CoMoments| m.mean_y,
+        |m: &CoMoments| m.m2_x,
+        |m: &CoMoments| m.m2_y,
+        |m: &CoMoments| m.c_xy,
+    ];
+    let mut children =
+        vec![UInt64Array::from((child_fields[0].name.as_str(), counts)).into_series()];
+    for (field, get) in child_fields[1..].iter().zip(floats) {
+        let values = Box::new(PrimitiveArray::from_vec(moments.iter().map(get).collect()));
+        children.push(Float64Array::from((field.name.as_str(), values)).into_series());
+    }
+    Ok(StructArray::new(Field::new(name, dtype), children, None))
+}
+
+fn from_struct_array(array: &StructArray) -> DaftResult<Vec<CoMoments>> {
+    let [count, mean_x, mean_y, m2_x, m2_y, c_xy] = array.children.as_slice() else {
+        return Err(DaftError::ValueError(format!(
+            "Expected co-moments with 6 fields, got {}",
+            array.data_type()
+        )));
+    };
+    let (count, mean_x, mean_y) = (count.u64()?, mean_x.f64()?, mean_y.f64()?);
+    let (m2_x, m2_y, c_xy) = (m2_x.f64()?, m2_y.f64()?, c_xy.f64()?);
+    Ok((0..array.len())
+        .map(|i| CoMoments {
+            count: count.as_arrow().value(i),
+            mean_x: mean_x.as_arrow().value(i),
+            mean_y: mean_y.as_arrow().value(i),
+            m2_x: m2_x.as_arrow().value(i),
+            m2_y: m2_y.as_arrow().value(i),
+            c_xy: c_xy.as_arrow().value(i),
+        })
+        .collect())
+}
+
+/// The arrays contain the first and second values of each pair, which are skipped if either is null.
+impl DaftCoMomentsAggable for Float64Array {
+    type Output = DaftResult<StructArray>;
+
+    fn comoments(&self, other: &Self) -> Self::Output {
+        let moments = accumulate(self.as_arrow(), other.as_arrow(), 0..self.len());
+        to_struct_array(self.name(), &[moments])
+    }
+
+    fn grouped_comoments(&self, other: &Self, groups: &GroupIndices) -> Self::Output {
+        let (x, y) = (self.as_arrow(), other.as_arrow());
+        let moments = groups
+            .iter()
+            .map(|g| accumulate(x, y, g.iter().map(|i| *i as usize)))
+            .collect::<Vec<_>>();
+        to_struct_array(self.name(), &moments)
+    }
+}
+
+/// The array contains co-moments, as produced by [`DaftCoMomentsAggable`].
+impl DaftCoMomentsMergeAggable for StructArray {
+    type Output = DaftResult<StructArray>;
+
+    fn merge_comoments(&self) -> Self::Output {
+        let mut merged = CoMoments::default();
+        for moments in from_struct_array(self)? {
+            merged.merge(&moments);
+        }
+        to_struct_array(self.name(), &[merged])
+    }
+
+    fn grouped_merge_comoments(&self, groups: &GroupIndices) -> Self::Output {
+        let moments = from_struct_array(self)?;
+        let merged = groups
+            .iter()
+            .map(|g| {
+                let mut merged = CoMoments::default();
+                for i in g {
+                    merged.merge(&moments[*i as usize]);
+                }
+                merged
+            })
+            .collect::<Vec<_>>();
+        to_struct_array(self.name(), &merged)
+    }
+}
+
+impl StructArray {
+    fn comoments_statistic(&self, f: impl Fn(&CoMoments) -> Option<f64>) -> DaftResult<Series> {
+        let values = from_struct_array(self)?.iter().map(f).collect::<Vec<_>>();
+        let arrow_array = PrimitiveArray::<f64>::from(values);
+        Ok(Float64Array::from((self.name(), Box::new(arrow_array))).into_series())
+    }
+
+    /// Computes the covariance of each co-moments in this array with `ddof` delta degrees of
+    /// freedom, which is null if there are no more than `ddof` pairs.
+    pub fn comoments_covariance(&self, ddof: u64) -> DaftResult<Series> {
+        self.comoments_statistic(|m| m.covariance(ddof))
+    }
+
+    /// Computes the square root of [`StructArray::comoments_covariance`], which is the standard
+    /// deviation for co-moments of a column with itself.
+    pub fn comoments_stddev(&self, ddof: u64) -> DaftResult<Series> {
+        self.comoments_statistic(|m| m.covariance(ddof).map(f64::sqrt))
+    }
+
+    /// Computes the Pearson correlation of each co-moments in this array, which is null if there
+    /// are fewer than two pairs or either column is constant.
+    pub fn comoments_correlation(&self) -> DaftResult<Series> {
+        self.comoments_statistic(CoMoments::correlation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{DaftCoMomentsAggable, DaftCoMomentsMergeAggable},
+        datatypes::Float64Array,
+    };
+
+    #[test]
+    fn test_merged_comoments_match_single_pass() -> DaftResult<()> {
+        let x = Float64Array::from((
+            "x",
+            Box::new(arrow2::array::Float64Array::from([
+                Some(1e9 + 4.),
+                Some(1e9 + 7.),
+                None,
+                Some(1e9 + 13.),
+                Some(1e9 + 16.),
+            ])),
+        ));
+        let y = Float64Array::from((
+            "y",
+            Box::new(arrow2::array::Float64Array::from([
+                Some(2.),
+                Some(3.),
+                Some(100.),
+                Some(7.),
+                Some(6.),
+            ])),
+        ));
+
+        let single = x.comoments(&y)?;
+        let partials = x
+            .grouped_comoments(&y, &vec![vec![0, 1], vec![2, 3, 4]])?
+            .merge_comoments()?;
+
+        for merged in [&single, &partials] {
+            let covariance = merged.comoments_covariance(1)?;
+            let covariance = covariance.f64()?.get(0).unwrap();
+            assert!((covariance - 12.0).abs() < 1e-9);
+            let correlation = merged.comoments_correlation()?;
+            let correlation = correlation.f64()?.get(0).unwrap();
+            assert!((correlation - 0.920_357_986_616_844_5).abs() < 1e-9);
+        }
+        Ok(())
+    }
+}
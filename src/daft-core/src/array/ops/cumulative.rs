@@ -0,0 +1,59 @@
+use super::as_arrow::AsArrow;
+use super::{DaftCumulativeAggable, GroupIndices};
+use crate::datatypes::*;
+use arrow2::array::{Array, PrimitiveArray};
+
+use common_error::DaftResult;
+
+fn grouped_cumulative_native<T, F>(
+    data_array: &DataArray<T>,
+    op: F,
+    groups: &GroupIndices,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    // Each group is traversed in the given order, and every row receives the running value
+    // of the group up to and including itself. Nulls are skipped, so a row is only null
+    // if it precedes the first non-null value of its group.
+    let arrow_array = data_array.as_arrow();
+    let mut result: Vec<Option<T::Native>> = vec![None; arrow_array.len()];
+    for g in groups {
+        let mut acc: Option<T::Native> = None;
+        for i in g {
+            let idx = *i as usize;
+            if arrow_array.is_valid(idx) {
+                let val = unsafe { arrow_array.value_unchecked(idx) };
+                acc = Some(match acc {
+                    None => val,
+                    Some(acc) => op(acc, val),
+                });
+            }
+            result[idx] = acc;
+        }
+    }
+    Ok(DataArray::from((
+        data_array.field.name.as_ref(),
+        Box::new(PrimitiveArray::from(result)),
+    )))
+}
+
+impl<T> DaftCumulativeAggable for DataArray<T>
+where
+    T: DaftNumericType,
+{
+    type Output = DaftResult<DataArray<T>>;
+
+    fn grouped_cumulative_sum(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_cumulative_native(self, |l, r| l + r, groups)
+    }
+
+    fn grouped_cumulative_min(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_cumulative_native(self, |l, r| if l <= r { l } else { r }, groups)
+    }
+
+    fn grouped_cumulative_max(&self, groups: &GroupIndices) -> Self::Output {
+        grouped_cumulative_native(self, |l, r| if l >= r { l } else { r }, groups)
+    }
+}
@@ -1,13 +1,18 @@
 use crate::{
     datatypes::{
         logical::{DateArray, TimeArray, TimestampArray},
-        DaftArrayType, Field, Int32Array, Int64Array, TimeUnit, UInt32Array,
+        DaftArrayType, Field, Int32Array, Int64Array, TimeUnit, UInt32Array, Utf8Array,
     },
     DataType,
 };
 use arrow2::compute::arithmetics::ArraySub;
-use chrono::{Duration, NaiveDate, NaiveTime, Timelike};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, TimeZone, Timelike,
+};
 use common_error::{DaftError, DaftResult};
+use std::fmt::{Display, Write};
 
 use super::as_arrow::AsArrow;
 
@@ -70,6 +75,118 @@ macro_rules! datetime_to_timestamp {
     }};
 }
 
+/// A timezone of a Timestamp datatype, which is either a fixed offset such as `"+01:00"` or a
+/// name in the IANA timezone database such as `"America/New_York"`.
+pub(crate) enum ParsedTimeZone {
+    Offset(FixedOffset),
+    Named(chrono_tz::Tz),
+}
+
+impl ParsedTimeZone {
+    pub(crate) fn parse(tz: &str) -> DaftResult<Self> {
+        if let Ok(offset) = arrow2::temporal_conversions::parse_offset(tz) {
+            Ok(Self::Offset(offset))
+        } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+            Ok(Self::Named(tz))
+        } else {
+            Err(DaftError::TypeError(format!(
+                "Cannot parse timezone in Timestamp datatype: {}",
+                tz
+            )))
+        }
+    }
+
+    /// Gets the wall-clock time of a timestamp in this timezone.
+    fn to_local(&self, ts: i64, tu: arrow2::datatypes::TimeUnit) -> NaiveDateTime {
+        match self {
+            Self::Offset(tz) => {
+                arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, tz).naive_local()
+            }
+            Self::Named(tz) => {
+                arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, tz).naive_local()
+            }
+        }
+    }
+
+    /// Gets the UTC datetimes at which the given wall-clock time occurs in this timezone.
+    pub(crate) fn localize(&self, local: &NaiveDateTime) -> LocalResult<NaiveDateTime> {
+        match self {
+            Self::Offset(tz) => tz.from_local_datetime(local).map(|dt| dt.naive_utc()),
+            Self::Named(tz) => tz.from_local_datetime(local).map(|dt| dt.naive_utc()),
+        }
+    }
+
+    /// Gets the datetime of a timestamp in this timezone, along with its UTC offset.
+    fn to_datetime(&self, ts: i64, tu: arrow2::datatypes::TimeUnit) -> DateTime<FixedOffset> {
+        match self {
+            Self::Offset(tz) => arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, tz),
+            Self::Named(tz) => {
+                let dt = arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, tz);
+                dt.with_timezone(&dt.offset().fix())
+            }
+        }
+    }
+}
+
+/// How to resolve wall-clock times that occur more than once in a timezone.
+#[derive(Clone, Copy)]
+enum AmbiguousTime {
+    Raise,
+    Earliest,
+    Latest,
+    Null,
+}
+
+impl AmbiguousTime {
+    fn parse(ambiguous: &str) -> DaftResult<Self> {
+        match ambiguous {
+            "raise" => Ok(Self::Raise),
+            "earliest" => Ok(Self::Earliest),
+            "latest" => Ok(Self::Latest),
+            "null" => Ok(Self::Null),
+            _ => Err(DaftError::ValueError(format!(
+                "Invalid value for ambiguous: {ambiguous}. Expected one of: raise, earliest, latest, null"
+            ))),
+        }
+    }
+}
+
+/// Parses a chrono format string such as `"%Y-%m-%d %H:%M:%S"` into its items.
+pub(crate) fn parse_format_items(format: &str) -> DaftResult<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(DaftError::ValueError(format!(
+            "Invalid format string: {format}"
+        )));
+    }
+    Ok(items)
+}
+
+/// Renders already-formatted values, such as chrono's `DelayedFormat`, into a Utf8 array. Values
+/// that can't be rendered with the format string, e.g. a date with an hour specifier, raise an error.
+fn collect_formatted<T, I>(name: &str, values: I, format: &str) -> DaftResult<Utf8Array>
+where
+    T: Display,
+    I: Iterator<Item = Option<T>>,
+{
+    let arrow_result = values
+        .map(|value| {
+            value
+                .map(|value| {
+                    let mut formatted = String::new();
+                    write!(formatted, "{value}").map_err(|_| {
+                        DaftError::ValueError(format!(
+                            "Cannot format value with format string: {format}"
+                        ))
+                    })?;
+                    Ok(formatted)
+                })
+                .transpose()
+        })
+        .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>()?;
+    Ok(Utf8Array::from((name, Box::new(arrow_result))))
+}
+
 impl DateArray {
     pub fn day(&self) -> DaftResult<UInt32Array> {
         let input_array = self
@@ -110,57 +227,82 @@ impl DateArray {
         let day_arr = arrow2::compute::temporal::weekday(&input_array)?;
         Ok((self.name(), Box::new(day_arr.sub(&1))).into())
     }
+
+    fn map_dates<F>(&self, f: F) -> DaftResult<UInt32Array>
+    where
+        F: Fn(NaiveDate) -> u32,
+    {
+        let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let arrow_result = arrow2::array::UInt32Array::from_iter(
+            self.physical
+                .as_arrow()
+                .iter()
+                .map(|days| days.map(|days| f(epoch_date + Duration::days(*days as i64)))),
+        );
+        UInt32Array::new(
+            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
+            Box::new(arrow_result),
+        )
+    }
+
+    /// Gets the day of the year of each date, starting from 1 for January 1st.
+    pub fn day_of_year(&self) -> DaftResult<UInt32Array> {
+        self.map_dates(|date| date.ordinal())
+    }
+
+    /// Gets the ISO 8601 week number of each date, from 1 to 53. ISO weeks start on Monday, and the
+    /// first week of a year is the one containing its first Thursday.
+    pub fn week_of_year(&self) -> DaftResult<UInt32Array> {
+        self.map_dates(|date| date.iso_week().week())
+    }
+
+    /// Gets the quarter of the year of each date, from 1 to 4.
+    pub fn quarter(&self) -> DaftResult<UInt32Array> {
+        self.map_dates(|date| date.month0() / 3 + 1)
+    }
+
+    /// Formats each date as a string with a chrono format string such as `"%Y-%m-%d"`.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
+        let items = parse_format_items(format)?;
+        let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let dates = self.physical.as_arrow().iter().map(|days| {
+            days.map(|days| {
+                (epoch_date + Duration::days(*days as i64)).format_with_items(items.iter())
+            })
+        });
+        collect_formatted(self.name(), dates, format)
+    }
 }
 
 impl TimestampArray {
-    pub fn date(&self) -> DaftResult<DateArray> {
-        let physical = self.physical.as_arrow();
+    /// Gets the wall-clock time of each timestamp in the array's timezone, or the timestamp
+    /// itself if the array has no timezone.
+    fn local_datetimes(&self) -> DaftResult<Vec<Option<NaiveDateTime>>> {
         let DataType::Timestamp(timeunit, tz) = self.data_type() else {
             unreachable!("Timestamp array must have Timestamp datatype")
         };
         let tu = timeunit.to_arrow();
+        let tz = tz.as_deref().map(ParsedTimeZone::parse).transpose()?;
+        Ok(self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| match &tz {
+                    Some(tz) => tz.to_local(*ts, tu),
+                    None => arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu),
+                })
+            })
+            .collect())
+    }
+
+    pub fn date(&self) -> DaftResult<DateArray> {
         let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-        let date_arrow = match tz {
-            Some(tz) => {
-                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
-                    Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                        physical.iter().map(|ts| {
-                            ts.map(|ts| {
-                                (arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz)
-                                    .date_naive()
-                                    - epoch_date)
-                                    .num_days() as i32
-                            })
-                        }),
-                    ))
-                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
-                    Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                        physical.iter().map(|ts| {
-                            ts.map(|ts| {
-                                (arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz)
-                                    .date_naive()
-                                    - epoch_date)
-                                    .num_days() as i32
-                            })
-                        }),
-                    ))
-                } else {
-                    Err(DaftError::TypeError(format!(
-                        "Cannot parse timezone in Timestamp datatype: {}",
-                        tz
-                    )))
-                }
-            }
-            None => Ok(arrow2::array::PrimitiveArray::<i32>::from_iter(
-                physical.iter().map(|ts| {
-                    ts.map(|ts| {
-                        (arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu).date()
-                            - epoch_date)
-                            .num_days() as i32
-                    })
-                }),
-            )),
-        }?;
+        let date_arrow = arrow2::array::PrimitiveArray::<i32>::from_iter(
+            self.local_datetimes()?
+                .into_iter()
+                .map(|dt| dt.map(|dt| (dt.date() - epoch_date).num_days() as i32)),
+        );
         Ok(DateArray::new(
             Field::new(self.name(), DataType::Date),
             Int32Array::from((self.name(), Box::new(date_arrow))),
@@ -168,91 +310,170 @@ impl TimestampArray {
     }
 
     pub fn time(&self, timeunit_for_cast: &TimeUnit) -> DaftResult<TimeArray> {
-        let physical = self.physical.as_arrow();
-        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
-            unreachable!("Timestamp array must have Timestamp datatype")
-        };
-        let tu = timeunit.to_arrow();
         if !matches!(
             timeunit_for_cast,
             TimeUnit::Microseconds | TimeUnit::Nanoseconds
         ) {
             return Err(DaftError::ValueError(format!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}")));
         }
-        let time_arrow = match tz {
-            Some(tz) => match arrow2::temporal_conversions::parse_offset(tz) {
-                Ok(tz) => Ok(arrow2::array::PrimitiveArray::<i64>::from_iter(
-                    physical.iter().map(|ts| {
-                        ts.map(|ts| {
-                            let dt =
-                                arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz);
-                                let time_delta = dt.time() - NaiveTime::from_hms_opt(0,0,0).unwrap();
-                                match timeunit_for_cast {
-                                    TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
-                                    TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
-                                    _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
-                                }
-                        })
-                    }),
-                )),
-                Err(e) => Err(DaftError::TypeError(format!(
-                    "Cannot parse timezone in Timestamp datatype: {}, error: {}",
-                    tz, e
-                ))),
-            },
-            None => Ok(arrow2::array::PrimitiveArray::<i64>::from_iter(
-                physical.iter().map(|ts| {
-                    ts.map(|ts| {
-                        let dt = arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu);
-                        let time_delta = dt.time() - NaiveTime::from_hms_opt(0,0,0).unwrap();
-                        match timeunit_for_cast {
-                            TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
-                            TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
-                            _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
-                        }
-                    })
-                }),
-            )),
-        }?;
+        let time_arrow = arrow2::array::PrimitiveArray::<i64>::from_iter(
+            self.local_datetimes()?.into_iter().map(|dt| {
+                dt.map(|dt| {
+                    let time_delta = dt.time() - NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+                    match timeunit_for_cast {
+                        TimeUnit::Microseconds => time_delta.num_microseconds().unwrap(),
+                        TimeUnit::Nanoseconds => time_delta.num_nanoseconds().unwrap(),
+                        _ => unreachable!("Only microseconds and nanoseconds time units are supported for the Time dtype, but got {timeunit_for_cast}"),
+                    }
+                })
+            }),
+        );
         Ok(TimeArray::new(
             Field::new(self.name(), DataType::Time(*timeunit_for_cast)),
             Int64Array::from((self.name(), Box::new(time_arrow))),
         ))
     }
 
+    fn map_local_datetimes<F>(&self, f: F) -> DaftResult<UInt32Array>
+    where
+        F: Fn(NaiveDateTime) -> u32,
+    {
+        let arrow_result = arrow2::array::UInt32Array::from_iter(
+            self.local_datetimes()?.into_iter().map(|dt| dt.map(&f)),
+        );
+        UInt32Array::new(
+            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
+            Box::new(arrow_result),
+        )
+    }
+
     pub fn hour(&self) -> DaftResult<UInt32Array> {
-        let physical = self.physical.as_arrow();
+        self.map_local_datetimes(|dt| dt.hour())
+    }
+
+    pub fn minute(&self) -> DaftResult<UInt32Array> {
+        self.map_local_datetimes(|dt| dt.minute())
+    }
+
+    pub fn second(&self) -> DaftResult<UInt32Array> {
+        self.map_local_datetimes(|dt| dt.second())
+    }
+
+    /// Gets the milliseconds past the second of each timestamp, from 0 to 999.
+    pub fn millisecond(&self) -> DaftResult<UInt32Array> {
+        self.map_local_datetimes(|dt| dt.nanosecond() / 1_000_000)
+    }
+
+    /// Converts the timestamps to another timezone. The instants that they represent are
+    /// unchanged, so this only changes the timezone of the data type.
+    pub fn convert_time_zone(&self, timezone: &str) -> DaftResult<TimestampArray> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        if tz.is_none() {
+            return Err(DaftError::ValueError(format!(
+                "Cannot convert timezone-naive timestamps of type {}, use replace_time_zone to localize them first",
+                self.data_type()
+            )));
+        }
+        ParsedTimeZone::parse(timezone)?;
+        Ok(TimestampArray::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(timezone.to_string())),
+            ),
+            self.physical.clone(),
+        ))
+    }
+
+    /// Replaces the timezone of the timestamps while keeping their wall-clock times, or makes them
+    /// timezone-naive if `timezone` is `None`.
+    ///
+    /// Wall-clock times that occur twice in the new timezone, such as when clocks are turned back
+    /// for daylight saving time, are resolved with `ambiguous`: one of `"raise"`, `"earliest"`,
+    /// `"latest"` or `"null"`. Wall-clock times that don't occur at all are null if `ambiguous` is
+    /// `"null"`, and raise an error otherwise.
+    pub fn replace_time_zone(
+        &self,
+        timezone: Option<&str>,
+        ambiguous: &str,
+    ) -> DaftResult<TimestampArray> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let ambiguous = AmbiguousTime::parse(ambiguous)?;
+        let target_tz = timezone.map(ParsedTimeZone::parse).transpose()?;
+
+        let mut values = Vec::with_capacity(self.len());
+        for local in self.local_datetimes()? {
+            let utc = match (local, &target_tz) {
+                (None, _) => None,
+                (Some(local), None) => Some(local),
+                (Some(local), Some(tz)) => match (tz.localize(&local), ambiguous) {
+                    (LocalResult::Single(utc), _) => Some(utc),
+                    (LocalResult::Ambiguous(earliest, _), AmbiguousTime::Earliest) => {
+                        Some(earliest)
+                    }
+                    (LocalResult::Ambiguous(_, latest), AmbiguousTime::Latest) => Some(latest),
+                    (_, AmbiguousTime::Null) => None,
+                    (LocalResult::Ambiguous(..), _) => {
+                        return Err(DaftError::ValueError(format!(
+                            "Datetime {local} is ambiguous in timezone {}, set ambiguous to \"earliest\", \"latest\" or \"null\" to resolve it",
+                            timezone.unwrap()
+                        )))
+                    }
+                    (LocalResult::None, _) => {
+                        return Err(DaftError::ValueError(format!(
+                            "Datetime {local} does not exist in timezone {}",
+                            timezone.unwrap()
+                        )))
+                    }
+                },
+            };
+            values.push(
+                utc.map(|utc| datetime_to_timestamp!(utc, timeunit))
+                    .transpose()?,
+            );
+        }
+        Ok(TimestampArray::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, timezone.map(str::to_string)),
+            ),
+            Int64Array::from((
+                self.name(),
+                Box::new(arrow2::array::PrimitiveArray::from(values)),
+            )),
+        ))
+    }
+
+    /// Formats each timestamp as a string with a chrono format string such as
+    /// `"%Y-%m-%d %H:%M:%S"`, using its wall-clock time in the array's timezone.
+    pub fn strftime(&self, format: &str) -> DaftResult<Utf8Array> {
         let DataType::Timestamp(timeunit, tz) = self.data_type() else {
             unreachable!("Timestamp array must have Timestamp datatype")
         };
+        let items = parse_format_items(format)?;
         let tu = timeunit.to_arrow();
-        let date_arrow = match tz {
-            Some(tz) => match arrow2::temporal_conversions::parse_offset(tz) {
-                Ok(tz) => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                    |ts| {
-                        ts.map(|ts| {
-                            arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz).hour()
-                        })
-                    },
-                ))),
-                Err(e) => Err(DaftError::TypeError(format!(
-                    "Cannot parse timezone in Timestamp datatype: {}, error: {}",
-                    tz, e
-                ))),
-            },
-            None => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                |ts| {
+        let physical = self.physical.as_arrow();
+        match tz {
+            Some(tz) => {
+                let tz = ParsedTimeZone::parse(tz)?;
+                let datetimes = physical
+                    .iter()
+                    .map(|ts| ts.map(|ts| tz.to_datetime(*ts, tu).format_with_items(items.iter())));
+                collect_formatted(self.name(), datetimes, format)
+            }
+            None => {
+                let datetimes = physical.iter().map(|ts| {
                     ts.map(|ts| {
-                        arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu).hour()
+                        arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu)
+                            .format_with_items(items.iter())
                     })
-                },
-            ))),
-        }?;
-
-        UInt32Array::new(
-            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
-            Box::new(date_arrow),
-        )
+                });
+                collect_formatted(self.name(), datetimes, format)
+            }
+        }
     }
 
     pub fn truncate(&self, interval: &str, relative_to: &Option<i64>) -> DaftResult<Self> {
@@ -354,3 +575,42 @@ impl TimestampArray {
         ))
     }
 }
+
+impl TimeArray {
+    fn map_times<F>(&self, f: F) -> DaftResult<UInt32Array>
+    where
+        F: Fn(NaiveTime) -> u32,
+    {
+        let DataType::Time(timeunit) = self.data_type() else {
+            unreachable!("Time array must have Time datatype")
+        };
+        let tu = timeunit.to_arrow();
+        let arrow_result =
+            arrow2::array::UInt32Array::from_iter(self.physical.as_arrow().iter().map(|t| {
+                t.map(|t| {
+                    f(arrow2::temporal_conversions::timestamp_to_naive_datetime(*t, tu).time())
+                })
+            }));
+        UInt32Array::new(
+            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
+            Box::new(arrow_result),
+        )
+    }
+
+    pub fn hour(&self) -> DaftResult<UInt32Array> {
+        self.map_times(|t| t.hour())
+    }
+
+    pub fn minute(&self) -> DaftResult<UInt32Array> {
+        self.map_times(|t| t.minute())
+    }
+
+    pub fn second(&self) -> DaftResult<UInt32Array> {
+        self.map_times(|t| t.second())
+    }
+
+    /// Gets the milliseconds past the second of each time, from 0 to 999.
+    pub fn millisecond(&self) -> DaftResult<UInt32Array> {
+        self.map_times(|t| t.nanosecond() / 1_000_000)
+    }
+}
@@ -0,0 +1,64 @@
+use arrow2::array::PrimitiveArray;
+use common_error::{DaftError, DaftResult};
+use sketches_ddsketch::{Config, DDSketch};
+
+use crate::{
+    array::{
+        ops::{as_arrow::AsArrow, from_arrow::FromArrow, GroupIndices},
+        StructArray,
+    },
+    datatypes::{DataType, Field, Float64Array},
+};
+
+use super::DaftDDSketchAggable;
+
+/// Builds a sketch of the non-null values at the given indices, or None if there are none.
+fn sketch_values(
+    values: &PrimitiveArray<f64>,
+    indices: impl Iterator<Item = usize>,
+) -> Option<DDSketch> {
+    let mut sketch = DDSketch::new(Config::default());
+    for idx in indices {
+        if values.is_valid(idx) {
+            sketch.add(values.value(idx));
+        }
+    }
+    (sketch.count() > 0).then_some(sketch)
+}
+
+/// Serializes sketches into a StructArray with the arrow2 layout of [`daft_sketch::ARROW2_DDSKETCH_DTYPE`].
+pub(super) fn ddsketches_to_struct_array(
+    name: &str,
+    sketches: Vec<Option<DDSketch>>,
+) -> DaftResult<StructArray> {
+    let field = Field::new(name, DataType::from(&*daft_sketch::ARROW2_DDSKETCH_DTYPE));
+    StructArray::from_arrow(field.into(), daft_sketch::into_arrow2(sketches))
+}
+
+/// Deserializes the sketches of a StructArray built by [`ddsketches_to_struct_array`].
+pub(super) fn struct_array_to_ddsketches(array: &StructArray) -> DaftResult<Vec<Option<DDSketch>>> {
+    daft_sketch::from_arrow2(array.to_arrow()).map_err(|e| {
+        DaftError::ValueError(format!(
+            "Failed to deserialize DDSketches from column \"{}\": {e}",
+            array.name()
+        ))
+    })
+}
+
+impl DaftDDSketchAggable for Float64Array {
+    type Output = DaftResult<StructArray>;
+
+    fn ddsketch(&self) -> Self::Output {
+        let sketch = sketch_values(self.as_arrow(), 0..self.len());
+        ddsketches_to_struct_array(self.name(), vec![sketch])
+    }
+
+    fn grouped_ddsketch(&self, groups: &GroupIndices) -> Self::Output {
+        let values = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| sketch_values(values, g.iter().map(|i| *i as usize)))
+            .collect();
+        ddsketches_to_struct_array(self.name(), sketches)
+    }
+}
@@ -0,0 +1,46 @@
+use common_error::{DaftError, DaftResult};
+use sketches_ddsketch::DDSketch;
+
+use crate::array::{ops::GroupIndices, StructArray};
+
+use super::{
+    ddsketch::{ddsketches_to_struct_array, struct_array_to_ddsketches},
+    DaftDDSketchMergeAggable,
+};
+
+/// Merges the non-null sketches at the given indices, or returns None if there are none.
+fn merge_sketches(
+    sketches: &[Option<DDSketch>],
+    indices: impl Iterator<Item = usize>,
+) -> DaftResult<Option<DDSketch>> {
+    let mut merged: Option<DDSketch> = None;
+    for sketch in indices.filter_map(|idx| sketches[idx].as_ref()) {
+        match merged.as_mut() {
+            Some(merged) => merged
+                .merge(sketch)
+                .map_err(|e| DaftError::ComputeError(format!("Failed to merge DDSketches: {e}")))?,
+            None => merged = Some(sketch.clone()),
+        }
+    }
+    Ok(merged)
+}
+
+/// The array contains DDSketches, as produced by [`DaftDDSketchAggable`](super::DaftDDSketchAggable).
+impl DaftDDSketchMergeAggable for StructArray {
+    type Output = DaftResult<StructArray>;
+
+    fn ddsketch_merge(&self) -> Self::Output {
+        let sketches = struct_array_to_ddsketches(self)?;
+        let merged = merge_sketches(&sketches, 0..sketches.len())?;
+        ddsketches_to_struct_array(self.name(), vec![merged])
+    }
+
+    fn grouped_ddsketch_merge(&self, groups: &GroupIndices) -> Self::Output {
+        let sketches = struct_array_to_ddsketches(self)?;
+        let merged = groups
+            .iter()
+            .map(|g| merge_sketches(&sketches, g.iter().map(|i| *i as usize)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        ddsketches_to_struct_array(self.name(), merged)
+    }
+}
@@ -8,10 +8,60 @@ use crate::{
     kernels,
 };
 
-use common_error::DaftResult;
+use std::str::FromStr;
+
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
 
 use super::as_arrow::AsArrow;
 
+/// A hash function that values can be hashed with through [`crate::series::Series::hash_with_function`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HashFunctionKind {
+    XxHash64,
+    Murmur3_32,
+    Sha1,
+    Sha256,
+    Md5,
+}
+
+impl HashFunctionKind {
+    /// The type of the hashes, which are hex-encoded strings for the cryptographic digests.
+    pub fn output_dtype(&self) -> crate::DataType {
+        use HashFunctionKind::*;
+        match self {
+            XxHash64 => crate::DataType::UInt64,
+            Murmur3_32 => crate::DataType::Int32,
+            Sha1 | Sha256 | Md5 => crate::DataType::Utf8,
+        }
+    }
+
+    pub fn supports_seed(&self) -> bool {
+        matches!(
+            self,
+            HashFunctionKind::XxHash64 | HashFunctionKind::Murmur3_32
+        )
+    }
+}
+
+impl FromStr for HashFunctionKind {
+    type Err = DaftError;
+
+    fn from_str(function: &str) -> DaftResult<Self> {
+        use HashFunctionKind::*;
+        match function.to_lowercase().as_str() {
+            "xxhash64" | "xxhash" => Ok(XxHash64),
+            "murmur3_32" | "murmur3" => Ok(Murmur3_32),
+            "sha1" => Ok(Sha1),
+            "sha256" => Ok(Sha256),
+            "md5" => Ok(Md5),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported hash function: {function}, expected one of xxhash64, murmur3_32, sha1, sha256 or md5"
+            ))),
+        }
+    }
+}
+
 impl<T> DataArray<T>
 where
     T: DaftNumericType,
@@ -178,12 +228,7 @@ impl Decimal128Array {
         let arr = self.physical.as_arrow();
         let hashes = arr.into_iter().map(|d| {
             d.map(|d| {
-                let twos_compliment = u128::from_ne_bytes(d.to_ne_bytes());
-                let bits_needed = u128::BITS - twos_compliment.leading_zeros();
-                let bytes_needed = bits_needed.div_ceil(8) as usize;
-                let be_bytes = twos_compliment.to_be_bytes();
-                let unsigned =
-                    mur3::murmurhash3_x86_32(&be_bytes[(be_bytes.len() - bytes_needed)..], 0);
+                let unsigned = mur3::murmurhash3_x86_32(&decimal_hash_bytes(*d), 0);
                 i32::from_ne_bytes(unsigned.to_ne_bytes())
             })
         });
@@ -192,6 +237,16 @@ impl Decimal128Array {
     }
 }
 
+/// Gets the big-endian two's complement bytes of a decimal without its leading zero bytes, which
+/// is how Iceberg hashes decimals.
+pub(crate) fn decimal_hash_bytes(d: i128) -> Vec<u8> {
+    let twos_compliment = u128::from_ne_bytes(d.to_ne_bytes());
+    let bits_needed = u128::BITS - twos_compliment.leading_zeros();
+    let bytes_needed = bits_needed.div_ceil(8) as usize;
+    let be_bytes = twos_compliment.to_be_bytes();
+    be_bytes[(be_bytes.len() - bytes_needed)..].to_vec()
+}
+
 fn murmur3_32_hash_from_iter_with_nulls<B: AsRef<[u8]>>(
     name: &str,
     byte_iter: impl Iterator<Item = Option<B>>,
@@ -0,0 +1,49 @@
+use arrow2::array::PrimitiveArray;
+use common_error::{DaftError, DaftResult};
+use daft_sketch::HyperLogLog;
+
+use crate::{
+    array::ops::{as_arrow::AsArrow, GroupIndices},
+    datatypes::{BinaryArray, UInt64Array},
+};
+
+use super::DaftHllMergeAggable;
+
+/// Merges the non-null serialized sketches at the given indices and returns the estimated number
+/// of distinct values in their union.
+fn merge_sketches(
+    sketches: &arrow2::array::BinaryArray<i64>,
+    indices: impl Iterator<Item = usize>,
+) -> DaftResult<u64> {
+    let mut merged = HyperLogLog::new();
+    for idx in indices {
+        if sketches.is_valid(idx) {
+            let sketch =
+                HyperLogLog::from_bytes(sketches.value(idx)).map_err(DaftError::ValueError)?;
+            merged.merge(&sketch);
+        }
+    }
+    Ok(merged.count())
+}
+
+/// The array contains serialized HyperLogLog sketches, as produced by
+/// [`DaftHllSketchAggable`](super::DaftHllSketchAggable).
+impl DaftHllMergeAggable for BinaryArray {
+    type Output = DaftResult<UInt64Array>;
+
+    fn hll_merge(&self) -> Self::Output {
+        let count = merge_sketches(self.as_arrow(), 0..self.len())?;
+        let arrow_array = PrimitiveArray::from_vec(vec![count]);
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_array))))
+    }
+
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output {
+        let sketches = self.as_arrow();
+        let counts = groups
+            .iter()
+            .map(|g| merge_sketches(sketches, g.iter().map(|i| *i as usize)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_array = PrimitiveArray::from_vec(counts);
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
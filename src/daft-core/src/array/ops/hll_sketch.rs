@@ -0,0 +1,67 @@
+use arrow2::array::{BinaryArray as ArrowBinaryArray, PrimitiveArray};
+use common_error::DaftResult;
+use daft_sketch::HyperLogLog;
+
+use crate::{
+    array::ops::{as_arrow::AsArrow, GroupIndices},
+    datatypes::{BinaryArray, UInt64Array},
+};
+
+use super::{DaftApproxCountDistinctAggable, DaftHllSketchAggable};
+
+/// Builds a sketch of the non-null hashes at the given indices.
+fn sketch_hashes(
+    hashes: &PrimitiveArray<u64>,
+    indices: impl Iterator<Item = usize>,
+) -> HyperLogLog {
+    let mut sketch = HyperLogLog::new();
+    for idx in indices {
+        if hashes.is_valid(idx) {
+            sketch.add_hash(hashes.value(idx));
+        }
+    }
+    sketch
+}
+
+/// The array contains the hashes of the values to sketch, where null hashes are skipped.
+impl DaftHllSketchAggable for UInt64Array {
+    type Output = DaftResult<BinaryArray>;
+
+    fn hll_sketch(&self) -> Self::Output {
+        let sketch = sketch_hashes(self.as_arrow(), 0..self.len());
+        let arrow_array = ArrowBinaryArray::<i64>::from_slice([sketch.to_bytes()]);
+        Ok(BinaryArray::from((self.name(), Box::new(arrow_array))))
+    }
+
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output {
+        let hashes = self.as_arrow();
+        let sketches = groups
+            .iter()
+            .map(|g| sketch_hashes(hashes, g.iter().map(|i| *i as usize)))
+            .collect::<Vec<_>>();
+        let arrow_array = ArrowBinaryArray::<i64>::from_trusted_len_values_iter(
+            sketches.iter().map(|sketch| sketch.to_bytes()),
+        );
+        Ok(BinaryArray::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+impl DaftApproxCountDistinctAggable for UInt64Array {
+    type Output = DaftResult<UInt64Array>;
+
+    fn approx_count_distinct(&self) -> Self::Output {
+        let count = sketch_hashes(self.as_arrow(), 0..self.len()).count();
+        let arrow_array = PrimitiveArray::from_vec(vec![count]);
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_array))))
+    }
+
+    fn grouped_approx_count_distinct(&self, groups: &GroupIndices) -> Self::Output {
+        let hashes = self.as_arrow();
+        let counts = groups
+            .iter()
+            .map(|g| sketch_hashes(hashes, g.iter().map(|i| *i as usize)).count())
+            .collect::<Vec<_>>();
+        let arrow_array = PrimitiveArray::from_vec(counts);
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
@@ -1,20 +1,20 @@
 use std::iter::repeat;
 
-use crate::datatypes::{Int64Array, Utf8Array};
+use crate::datatypes::{BooleanArray, Field, Int64Array, Utf8Array};
 use crate::{
     array::{
         growable::{make_growable, Growable},
-        FixedSizeListArray, ListArray,
+        FixedSizeListArray, ListArray, StructArray,
     },
-    datatypes::UInt64Array,
+    datatypes::{logical::MapArray, UInt64Array},
 };
 use crate::{CountMode, DataType};
 
-use crate::series::Series;
+use crate::series::{IntoSeries, Series};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
-use super::as_arrow::AsArrow;
+use super::{as_arrow::AsArrow, DaftCompare, IntoGroups};
 
 fn join_arrow_list_of_utf8s(
     list_element: Option<&dyn arrow2::array::Array>,
@@ -376,3 +376,485 @@ macro_rules! impl_aggs_list_array {
 
 impl_aggs_list_array!(ListArray);
 impl_aggs_list_array!(FixedSizeListArray);
+
+/// Gets the value of `bound` that applies to row `i`, where `bound` either has a single value for
+/// all rows or one value per row.
+fn broadcasted_bound(bound: &Int64Array, i: usize) -> Option<i64> {
+    bound.get(if bound.len() == 1 { 0 } else { i })
+}
+
+/// Resolves a Python-style slice bound against a list of length `len`, where negative bounds count
+/// from the end of the list and null bounds are unbounded.
+fn resolve_slice_bound(bound: Option<i64>, len: usize, unbounded: usize) -> usize {
+    match bound {
+        None => unbounded,
+        Some(b) if b < 0 => (len as i64 + b).max(0) as usize,
+        Some(b) => (b as usize).min(len),
+    }
+}
+
+impl ListArray {
+    /// Checks whether each list contains `item`, where `item` is either a single item for all
+    /// lists or one item per list. The result is null if the list or the item is null.
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        let item = item.cast(self.child_data_type())?;
+        let item_idx: fn(usize) -> usize = match item.len() {
+            1 => |_| 0,
+            len if len == self.len() => |i| i,
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected list contains item to have length 1 or {}, but received {len}",
+                    self.len()
+                )))
+            }
+        };
+
+        // Align the items with the elements of each list, so that they can be compared against
+        // the elements in a single vectorized pass.
+        let offsets = self.offsets();
+        let base = *offsets.first() as usize;
+        let aligned_idx = (0..self.len())
+            .flat_map(|i| {
+                let (start, end) = offsets.start_end(i);
+                repeat(item_idx(i) as u64).take(end - start)
+            })
+            .collect::<Vec<_>>();
+        let aligned_item = item.take(&UInt64Array::from(("idx", aligned_idx)).into_series())?;
+        let matches = self
+            .flat_child
+            .slice(base, *offsets.last() as usize)?
+            .equal(&aligned_item)?;
+        let matches = matches.as_arrow();
+
+        let result = (0..self.len()).map(|i| {
+            let item_is_valid = item.validity().map_or(true, |v| v.get_bit(item_idx(i)));
+            (self.is_valid(i) && item_is_valid).then(|| {
+                let (start, end) = offsets.start_end(i);
+                (start..end).any(|j| matches.get(j - base).unwrap_or(false))
+            })
+        });
+        Ok(BooleanArray::from((
+            self.name(),
+            Box::new(arrow2::array::BooleanArray::from_trusted_len_iter(result)),
+        )))
+    }
+
+    /// Slices each list from `start` (inclusive) to `end` (exclusive). Negative bounds count from
+    /// the end of the list, null bounds are unbounded, and out of range bounds are clamped.
+    pub fn slice_lists(&self, start: &Int64Array, end: &Int64Array) -> DaftResult<ListArray> {
+        for bound in [start, end] {
+            if bound.len() != 1 && bound.len() != self.len() {
+                return Err(DaftError::ValueError(format!(
+                    "Expected list slice bounds to have length 1 or {}, but received {}",
+                    self.len(),
+                    bound.len()
+                )));
+            }
+        }
+
+        let mut growable = make_growable(
+            self.name(),
+            self.child_data_type(),
+            vec![&self.flat_child],
+            true,
+            0,
+        );
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        for i in 0..self.len() {
+            let (list_start, list_end) = self.offsets().start_end(i);
+            let len = list_end - list_start;
+            let slice_start = resolve_slice_bound(broadcasted_bound(start, i), len, 0);
+            let slice_end = resolve_slice_bound(broadcasted_bound(end, i), len, len);
+            let slice_len = if self.is_valid(i) {
+                slice_end.saturating_sub(slice_start)
+            } else {
+                0
+            };
+            if slice_len > 0 {
+                growable.extend(0, list_start + slice_start, slice_len);
+            }
+            offsets.push(offsets.last().unwrap() + slice_len as i64);
+        }
+
+        Ok(ListArray::new(
+            self.field.clone(),
+            growable.build()?,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            self.validity().cloned(),
+        ))
+    }
+
+    /// Sorts the elements of each list.
+    pub fn sort_lists(&self, descending: bool) -> DaftResult<ListArray> {
+        // Sort all elements at once by the list they belong to and then by value.
+        let offsets = self.offsets();
+        let base = *offsets.first();
+        let child = self
+            .flat_child
+            .slice(base as usize, *offsets.last() as usize)?;
+        let list_idx = (0..self.len())
+            .flat_map(|i| {
+                let (start, end) = offsets.start_end(i);
+                repeat(i as u64).take(end - start)
+            })
+            .collect::<Vec<_>>();
+        let list_idx = UInt64Array::from(("list_idx", list_idx)).into_series();
+        let sort_idx = Series::argsort_multikey(&[list_idx, child.clone()], &[false, descending])?;
+
+        Ok(ListArray::new(
+            self.field.clone(),
+            child.take(&sort_idx)?,
+            arrow2::offset::OffsetsBuffer::try_from(
+                offsets.iter().map(|o| o - base).collect::<Vec<_>>(),
+            )?,
+            self.validity().cloned(),
+        ))
+    }
+
+    /// For each list, finds the distinct elements in order of first occurrence. Returns the
+    /// indices of the first occurrences in the flat child, the number of occurrences of each,
+    /// and the offsets of the distinct elements of each list.
+    fn distinct_indices(&self, skip_nulls: bool) -> DaftResult<(Vec<u64>, Vec<u64>, Vec<i64>)> {
+        let child_validity = self.flat_child.validity();
+        let mut indices = vec![];
+        let mut counts = vec![];
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        for i in 0..self.len() {
+            let (start, end) = self.offsets().start_end(i);
+            if self.is_valid(i) && end > start {
+                let (first_indices, groups) = self.flat_child.slice(start, end)?.make_groups()?;
+                let mut distinct = first_indices
+                    .into_iter()
+                    .zip(groups.iter().map(|g| g.len() as u64))
+                    .map(|(idx, count)| (start as u64 + idx, count))
+                    .filter(|(idx, _)| {
+                        !skip_nulls || child_validity.map_or(true, |v| v.get_bit(*idx as usize))
+                    })
+                    .collect::<Vec<_>>();
+                distinct.sort_unstable();
+                for (idx, count) in distinct {
+                    indices.push(idx);
+                    counts.push(count);
+                }
+            }
+            offsets.push(indices.len() as i64);
+        }
+        Ok((indices, counts, offsets))
+    }
+
+    /// Gets the distinct elements of each list, in order of first occurrence.
+    pub fn distinct(&self) -> DaftResult<ListArray> {
+        let (indices, _, offsets) = self.distinct_indices(false)?;
+        let child = self
+            .flat_child
+            .take(&UInt64Array::from(("idx", indices)).into_series())?;
+        Ok(ListArray::new(
+            self.field.clone(),
+            child,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            self.validity().cloned(),
+        ))
+    }
+
+    /// Counts the occurrences of each distinct non-null element of each list, as a map from the
+    /// elements to their counts.
+    pub fn value_counts(&self) -> DaftResult<MapArray> {
+        let (indices, counts, offsets) = self.distinct_indices(true)?;
+        let keys = self
+            .flat_child
+            .take(&UInt64Array::from(("idx", indices)).into_series())?
+            .rename("key");
+        let values = UInt64Array::from(("value", counts)).into_series();
+        let entries_dtype = DataType::Struct(vec![
+            keys.field().clone(),
+            Field::new("value", DataType::UInt64),
+        ]);
+        let entries = StructArray::new(
+            Field::new("entries", entries_dtype.clone()),
+            vec![keys, values],
+            None,
+        );
+        let list = ListArray::new(
+            Field::new(self.name(), DataType::List(Box::new(entries_dtype.clone()))),
+            entries.into_series(),
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            self.validity().cloned(),
+        );
+        Ok(MapArray::new(
+            Field::new(self.name(), DataType::Map(Box::new(entries_dtype))),
+            list,
+        ))
+    }
+
+    /// Flattens a list of lists into a list of the elements of the inner lists.
+    pub fn flatten(&self) -> DaftResult<ListArray> {
+        let inner = match self.child_data_type() {
+            DataType::List(_) => self.flat_child.list()?.clone(),
+            DataType::FixedSizeList(..) => self.flat_child.fixed_size_list()?.to_list(),
+            dt => {
+                return Err(DaftError::TypeError(format!(
+                    "Expected a list of lists to flatten, but received a list of {dt}"
+                )))
+            }
+        };
+
+        let mut growable = make_growable(
+            self.name(),
+            inner.child_data_type(),
+            vec![&inner.flat_child],
+            true,
+            0,
+        );
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        for i in 0..self.len() {
+            let mut len = 0;
+            if self.is_valid(i) {
+                let (start, end) = self.offsets().start_end(i);
+                for j in (start..end).filter(|&j| inner.is_valid(j)) {
+                    let (inner_start, inner_end) = inner.offsets().start_end(j);
+                    growable.extend(0, inner_start, inner_end - inner_start);
+                    len += inner_end - inner_start;
+                }
+            }
+            offsets.push(offsets.last().unwrap() + len as i64);
+        }
+
+        Ok(ListArray::new(
+            Field::new(
+                self.name(),
+                DataType::List(Box::new(inner.child_data_type().clone())),
+            ),
+            growable.build()?,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            self.validity().cloned(),
+        ))
+    }
+}
+
+impl ListArray {
+    /// The elements of all lists, without any elements outside of the range of the offsets.
+    fn elements(&self) -> DaftResult<Series> {
+        let offsets = self.offsets();
+        self.flat_child
+            .slice(*offsets.first() as usize, *offsets.last() as usize)
+    }
+
+    /// The offsets of the lists into [`ListArray::elements`].
+    fn element_offsets(&self) -> DaftResult<arrow2::offset::OffsetsBuffer<i64>> {
+        let base = *self.offsets().first();
+        Ok(arrow2::offset::OffsetsBuffer::try_from(
+            self.offsets().iter().map(|o| o - base).collect::<Vec<_>>(),
+        )?)
+    }
+
+    /// Applies `f` to the elements of all lists at once, keeping the elements in their lists.
+    /// `f` must return either one value per element or a single value for all elements.
+    pub fn map_elements<F>(&self, f: F) -> DaftResult<ListArray>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        let elements = self.elements()?;
+        let mapped = f(&elements)?;
+        let mapped = match mapped.len() {
+            len if len == elements.len() => mapped,
+            1 => mapped.broadcast(elements.len())?,
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected list element expression to return 1 or {} values, but received {len}",
+                    elements.len()
+                )))
+            }
+        };
+        Ok(ListArray::new(
+            Field::new(
+                self.name(),
+                DataType::List(Box::new(mapped.data_type().clone())),
+            ),
+            mapped,
+            self.element_offsets()?,
+            self.validity().cloned(),
+        ))
+    }
+
+    /// Keeps the elements of each list for which the boolean mask returned by `f` is true. `f`
+    /// is applied to the elements of all lists at once, and must return either one value per
+    /// element or a single value for all elements.
+    pub fn filter_elements<F>(&self, f: F) -> DaftResult<ListArray>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        let elements = self.elements()?;
+        let mask = f(&elements)?;
+        if mask.data_type() != &DataType::Boolean {
+            return Err(DaftError::TypeError(format!(
+                "Expected list filter expression to be boolean, but received {}",
+                mask.data_type()
+            )));
+        }
+        let keep: Vec<bool> = match mask.len() {
+            len if len == elements.len() => {
+                mask.bool()?.into_iter().map(|b| b == Some(true)).collect()
+            }
+            1 => vec![mask.bool()?.get(0) == Some(true); elements.len()],
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected list filter expression to return 1 or {} values, but received {len}",
+                    elements.len()
+                )))
+            }
+        };
+
+        let element_offsets = self.element_offsets()?;
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        for i in 0..self.len() {
+            let (start, end) = element_offsets.start_end(i);
+            let kept = keep[start..end].iter().filter(|&&k| k).count();
+            offsets.push(offsets.last().unwrap() + kept as i64);
+        }
+        let filtered = elements.filter(&BooleanArray::from(("mask", keep.as_slice())))?;
+
+        Ok(ListArray::new(
+            self.field.clone(),
+            filtered,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            self.validity().cloned(),
+        ))
+    }
+}
+
+impl ListArray {
+    /// Zips the lists of each row into a single list of structs named `name`, with one field per
+    /// list named after it. Each zipped list is as long as the shortest of its lists, and is null
+    /// if any of its lists is null.
+    pub fn zip(name: &str, lists: &[ListArray]) -> DaftResult<ListArray> {
+        let Some(first) = lists.first() else {
+            return Err(DaftError::ValueError(
+                "Need at least 1 list to zip".to_string(),
+            ));
+        };
+        if let Some(list) = lists.iter().find(|list| list.len() != first.len()) {
+            return Err(DaftError::ValueError(format!(
+                "Expected lists to zip to have the same length, but received {} and {}",
+                first.len(),
+                list.len()
+            )));
+        }
+
+        let len = first.len();
+        let is_valid = (0..len)
+            .map(|i| lists.iter().all(|list| list.is_valid(i)))
+            .collect::<Vec<_>>();
+        let zipped_lens = (0..len)
+            .map(|i| {
+                if is_valid[i] {
+                    lists
+                        .iter()
+                        .map(|list| list.offsets().start_end(i))
+                        .map(|(start, end)| end - start)
+                        .min()
+                        .unwrap()
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<_>>();
+        let children = lists
+            .iter()
+            .map(|list| {
+                let idx = (0..len)
+                    .flat_map(|i| {
+                        let start = list.offsets().start_end(i).0 as u64;
+                        start..start + zipped_lens[i] as u64
+                    })
+                    .collect::<Vec<_>>();
+                Ok(list
+                    .flat_child
+                    .take(&UInt64Array::from(("idx", idx)).into_series())?
+                    .rename(list.name()))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let elements = Series::struct_pack(name, &children)?;
+
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        for zipped_len in zipped_lens {
+            offsets.push(offsets.last().unwrap() + zipped_len as i64);
+        }
+        let validity = is_valid
+            .contains(&false)
+            .then(|| arrow2::bitmap::Bitmap::from_iter(is_valid));
+        Ok(ListArray::new(
+            Field::new(name, DataType::List(Box::new(elements.data_type().clone()))),
+            elements,
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            validity,
+        ))
+    }
+}
+
+impl FixedSizeListArray {
+    /// Views this array as a variable-size list array, without copying the elements.
+    pub(crate) fn to_list(&self) -> ListArray {
+        let size = self.fixed_element_len() as i64;
+        let offsets = (0..=self.len() as i64)
+            .map(|i| i * size)
+            .collect::<Vec<_>>();
+        ListArray::new(
+            Field::new(
+                self.name(),
+                DataType::List(Box::new(self.child_data_type().clone())),
+            ),
+            self.flat_child.clone(),
+            arrow2::offset::OffsetsBuffer::try_from(offsets).unwrap(),
+            self.validity().cloned(),
+        )
+    }
+
+    pub fn contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        self.to_list().contains(item)
+    }
+
+    pub fn slice_lists(&self, start: &Int64Array, end: &Int64Array) -> DaftResult<ListArray> {
+        self.to_list().slice_lists(start, end)
+    }
+
+    /// Sorts the elements of each list, keeping the fixed size of the lists.
+    pub fn sort_lists(&self, descending: bool) -> DaftResult<FixedSizeListArray> {
+        let sorted = self.to_list().sort_lists(descending)?;
+        Ok(FixedSizeListArray::new(
+            self.field.clone(),
+            sorted.flat_child,
+            self.validity().cloned(),
+        ))
+    }
+
+    pub fn distinct(&self) -> DaftResult<ListArray> {
+        self.to_list().distinct()
+    }
+
+    pub fn value_counts(&self) -> DaftResult<MapArray> {
+        self.to_list().value_counts()
+    }
+
+    pub fn flatten(&self) -> DaftResult<ListArray> {
+        self.to_list().flatten()
+    }
+
+    pub fn map_elements<F>(&self, f: F) -> DaftResult<ListArray>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        self.to_list().map_elements(f)
+    }
+
+    pub fn filter_elements<F>(&self, f: F) -> DaftResult<ListArray>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        self.to_list().filter_elements(f)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    array::{growable::make_growable, ListArray, StructArray},
+    datatypes::{logical::MapArray, Field, UInt64Array},
+    DataType, IntoSeries, Series,
+};
+use common_error::{DaftError, DaftResult};
+
+use super::{as_arrow::AsArrow, DaftCompare};
+
+impl MapArray {
+    /// The entries of every map, as a struct array of keys and values.
+    fn entries(&self) -> DaftResult<&StructArray> {
+        self.physical.flat_child.struct_()
+    }
+
+    fn keys_and_values(&self) -> DaftResult<(&Series, &Series)> {
+        match self.entries()?.children.as_slice() {
+            [keys, values] => Ok((keys, values)),
+            children => Err(DaftError::ValueError(format!(
+                "Expected map entries to have a key and a value field, but found {} fields",
+                children.len()
+            ))),
+        }
+    }
+
+    fn entry_list(&self, child: &Series) -> ListArray {
+        ListArray::new(
+            Field::new(
+                self.name(),
+                DataType::List(Box::new(child.data_type().clone())),
+            ),
+            child.clone(),
+            self.physical.offsets().clone(),
+            self.physical.validity().cloned(),
+        )
+    }
+
+    /// Gets the keys of each map as a list.
+    pub fn map_keys(&self) -> DaftResult<ListArray> {
+        let (keys, _) = self.keys_and_values()?;
+        Ok(self.entry_list(keys))
+    }
+
+    /// Gets the values of each map as a list.
+    pub fn map_values(&self) -> DaftResult<ListArray> {
+        let (_, values) = self.keys_and_values()?;
+        Ok(self.entry_list(values))
+    }
+
+    /// Looks up `key` in each map, returning the value of the first matching entry or null if
+    /// there is none. `key` is either a single key for all maps or one key per map.
+    pub fn map_get(&self, key: &Series) -> DaftResult<Series> {
+        let (keys, values) = self.keys_and_values()?;
+        let key = key.cast(keys.data_type())?;
+        let key_idx: fn(usize) -> u64 = match key.len() {
+            1 => |_| 0,
+            len if len == self.len() => |i| i as u64,
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected map_get key to have length 1 or {}, but received {len}",
+                    self.len()
+                )))
+            }
+        };
+
+        // Align the keys to look up with the entries of each map, so that they can be compared
+        // against the map keys in a single vectorized pass.
+        let offsets = self.physical.offsets();
+        let aligned_idx = (0..self.len())
+            .flat_map(|i| {
+                let (start, end) = offsets.start_end(i);
+                std::iter::repeat(key_idx(i)).take(end - start)
+            })
+            .collect::<Vec<_>>();
+        let aligned_key = key.take(&UInt64Array::from(("idx", aligned_idx)).into_series())?;
+        let matches = keys
+            .slice(*offsets.first() as usize, *offsets.last() as usize)?
+            .equal(&aligned_key)?;
+        let matches = matches.as_arrow();
+
+        let mut growable = make_growable(
+            self.name(),
+            values.data_type(),
+            vec![values],
+            true,
+            self.len(),
+        );
+        let base = *offsets.first() as usize;
+        for i in 0..self.len() {
+            let (start, end) = offsets.start_end(i);
+            let found = self
+                .physical
+                .is_valid(i)
+                .then(|| (start..end).find(|&entry| matches.get(entry - base).unwrap_or(false)));
+            match found.flatten() {
+                Some(entry) => growable.extend(0, entry, 1),
+                None => growable.add_nulls(1),
+            }
+        }
+        growable.build()
+    }
+}
+
+impl ListArray {
+    /// Builds a map from each list of keys in this array and the list of values at the same
+    /// position in `values`. A map is null if either list is null, and the two lists must have
+    /// the same length otherwise. Map keys can't be null.
+    pub fn to_map(&self, values: &ListArray) -> DaftResult<MapArray> {
+        if self.len() != values.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected the keys and values of a map to have the same length, but received {} and {}",
+                self.len(),
+                values.len()
+            )));
+        }
+        let key_child = &self.flat_child;
+        let value_child = &values.flat_child;
+        let entry_fields = vec![
+            Field::new("key", key_child.data_type().clone()),
+            Field::new("value", value_child.data_type().clone()),
+        ];
+
+        let mut key_growable =
+            make_growable("key", key_child.data_type(), vec![key_child], true, 0);
+        let mut value_growable =
+            make_growable("value", value_child.data_type(), vec![value_child], true, 0);
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0i64);
+        let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(self.len());
+        for i in 0..self.len() {
+            let (key_start, key_end) = self.offsets().start_end(i);
+            let (value_start, value_end) = values.offsets().start_end(i);
+            let is_valid = self.is_valid(i) && values.is_valid(i);
+            if is_valid {
+                if key_end - key_start != value_end - value_start {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected the keys and values of a map to have the same length, but row {i} has {} keys and {} values",
+                        key_end - key_start,
+                        value_end - value_start
+                    )));
+                }
+                key_growable.extend(0, key_start, key_end - key_start);
+                value_growable.extend(0, value_start, value_end - value_start);
+            }
+            let len = if is_valid { key_end - key_start } else { 0 };
+            offsets.push(offsets.last().unwrap() + len as i64);
+            validity.push(is_valid);
+        }
+
+        let keys = key_growable.build()?;
+        if keys
+            .validity()
+            .is_some_and(|validity| validity.unset_bits() > 0)
+        {
+            return Err(DaftError::ValueError("Map keys cannot be null".to_string()));
+        }
+        let entries_dtype = DataType::Struct(entry_fields);
+        let entries = StructArray::new(
+            Field::new("entries", entries_dtype.clone()),
+            vec![keys, value_growable.build()?],
+            None,
+        );
+        let list = ListArray::new(
+            Field::new(self.name(), DataType::List(Box::new(entries_dtype.clone()))),
+            entries.into_series(),
+            arrow2::offset::OffsetsBuffer::try_from(offsets)?,
+            Some(validity.into()),
+        );
+        Ok(MapArray::new(
+            Field::new(self.name(), DataType::Map(Box::new(entries_dtype))),
+            list,
+        ))
+    }
+}
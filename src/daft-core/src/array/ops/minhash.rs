@@ -0,0 +1,225 @@
+use arrow2::array::{MutableBinaryArray, MutableUtf8Array, PrimitiveArray};
+use common_error::{DaftError, DaftResult};
+use itertools::Itertools;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::{
+    array::FixedSizeListArray,
+    datatypes::{Field, UInt32Array, UInt64Array, Utf8Array},
+    kernels, DataType, IntoSeries,
+};
+
+use super::as_arrow::AsArrow;
+
+const MERSENNE_PRIME: u64 = (1 << 61) - 1;
+
+/// Gets the coefficients `(a, b)` of `num_hashes` universal hash functions `(a * x + b) mod p`,
+/// which act as the random permutations of MinHash. They are derived from `seed` with xxh3 so
+/// that signatures are stable across runs and machines.
+fn minhash_permutations(num_hashes: usize, seed: u32) -> Vec<(u64, u64)> {
+    (0..num_hashes as u64)
+        .map(|i| {
+            let a = xxh3_64_with_seed(&(2 * i).to_le_bytes(), seed as u64) % (MERSENNE_PRIME - 1);
+            let b = xxh3_64_with_seed(&(2 * i + 1).to_le_bytes(), seed as u64) % MERSENNE_PRIME;
+            (a + 1, b)
+        })
+        .collect()
+}
+
+impl Utf8Array {
+    /// Computes a MinHash signature of `num_hashes` values for each string, whose shingles are its
+    /// word n-grams of `ngram_size` whitespace-separated words. Strings with fewer words have a
+    /// single shingle of all their words, and strings without any words have a signature of
+    /// `u32::MAX` values.
+    pub fn minhash(
+        &self,
+        num_hashes: usize,
+        ngram_size: usize,
+        seed: u32,
+    ) -> DaftResult<FixedSizeListArray> {
+        if num_hashes == 0 || ngram_size == 0 {
+            return Err(DaftError::ValueError(format!(
+                "Expected num_hashes and ngram_size of minhash to be positive, but received {num_hashes} and {ngram_size}"
+            )));
+        }
+        // Gather the shingles of all strings, so that they can be hashed at once.
+        let mut shingles = MutableUtf8Array::<i64>::new();
+        let mut num_shingles = 0;
+        let mut shingle_offsets = Vec::with_capacity(self.len() + 1);
+        shingle_offsets.push(num_shingles);
+        let mut shingle = String::new();
+        for val in self.as_arrow().iter() {
+            let words = val
+                .map(|val| val.split_whitespace().collect::<Vec<_>>())
+                .unwrap_or_default();
+            if !words.is_empty() {
+                for window in words.windows(ngram_size.min(words.len())) {
+                    shingle.clear();
+                    for (i, word) in window.iter().enumerate() {
+                        if i > 0 {
+                            shingle.push(' ');
+                        }
+                        shingle.push_str(word);
+                    }
+                    shingles.push(Some(shingle.as_str()));
+                    num_shingles += 1;
+                }
+            }
+            shingle_offsets.push(num_shingles);
+        }
+        let shingles: arrow2::array::Utf8Array<i64> = shingles.into();
+        let seeds = PrimitiveArray::<u64>::from_vec(vec![seed as u64; num_shingles]);
+        let shingle_hashes = kernels::hashing::hash(&shingles, Some(&seeds))?;
+
+        let permutations = minhash_permutations(num_hashes, seed);
+        let mut signatures = Vec::with_capacity(self.len() * num_hashes);
+        let mut min_hashes = vec![u32::MAX; num_hashes];
+        for (start, end) in shingle_offsets.iter().tuple_windows() {
+            min_hashes.fill(u32::MAX);
+            for hash in &shingle_hashes.values()[*start..*end] {
+                let hash = hash % MERSENNE_PRIME;
+                for (min_hash, (a, b)) in min_hashes.iter_mut().zip(permutations.iter()) {
+                    let permuted =
+                        (*a as u128 * hash as u128 + *b as u128) % MERSENNE_PRIME as u128;
+                    *min_hash = (*min_hash).min(permuted as u32);
+                }
+            }
+            signatures.extend_from_slice(&min_hashes);
+        }
+
+        Ok(FixedSizeListArray::new(
+            Field::new(
+                self.name(),
+                DataType::FixedSizeList(Box::new(DataType::UInt32), num_hashes),
+            ),
+            UInt32Array::from(("item", signatures)).into_series(),
+            self.as_arrow().validity().cloned(),
+        ))
+    }
+}
+
+impl FixedSizeListArray {
+    /// Splits each MinHash signature into `num_bands` bands of consecutive values and hashes each
+    /// band, for locality-sensitive hashing. Each band is hashed with its index as the seed, so
+    /// that two signatures share a band hash only if they agree on that same band.
+    pub fn lsh_bands(&self, num_bands: usize) -> DaftResult<FixedSizeListArray> {
+        let size = self.fixed_element_len();
+        if num_bands == 0 || size % num_bands != 0 {
+            return Err(DaftError::ValueError(format!(
+                "Expected the number of LSH bands to be a positive divisor of the signature length {size}, but received {num_bands}"
+            )));
+        }
+        let rows_per_band = size / num_bands;
+        let signatures = match self.flat_child.data_type() {
+            DataType::UInt32 => self.flat_child.u32()?.as_arrow(),
+            other => {
+                return Err(DaftError::TypeError(format!(
+                "Expected MinHash signatures of UInt32 values for lsh_bands, but received {other}"
+            )))
+            }
+        };
+
+        // Encode each band as the bytes of its values, each prefixed by whether it is valid, so
+        // that null values never collide with valid ones.
+        let mut bands = MutableBinaryArray::<i64>::with_capacity(self.len() * num_bands);
+        let mut band_bytes = Vec::with_capacity(rows_per_band * (1 + std::mem::size_of::<u32>()));
+        let mut band_seeds = Vec::with_capacity(self.len() * num_bands);
+        for signature_start in (0..self.len()).map(|i| i * size) {
+            for band_idx in 0..num_bands {
+                let band_start = signature_start + band_idx * rows_per_band;
+                band_bytes.clear();
+                for i in band_start..band_start + rows_per_band {
+                    if signatures.is_valid(i) {
+                        band_bytes.push(1);
+                        band_bytes.extend_from_slice(&signatures.value(i).to_le_bytes());
+                    } else {
+                        band_bytes.push(0);
+                    }
+                }
+                bands.push(Some(band_bytes.as_slice()));
+                band_seeds.push(band_idx as u64);
+            }
+        }
+        let bands: arrow2::array::BinaryArray<i64> = bands.into();
+        let band_hashes =
+            kernels::hashing::hash(&bands, Some(&PrimitiveArray::from_vec(band_seeds)))?;
+
+        Ok(FixedSizeListArray::new(
+            Field::new(
+                self.name(),
+                DataType::FixedSizeList(Box::new(DataType::UInt64), num_bands),
+            ),
+            UInt64Array::from(("item", Box::new(band_hashes))).into_series(),
+            self.validity().cloned(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_minhash_similar_documents() -> DaftResult<()> {
+        let docs = Utf8Array::from((
+            "docs",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("the quick brown fox jumps over the lazy dog"),
+                Some("the quick brown fox jumps over the lazy dog"),
+                Some("completely unrelated text about something else entirely"),
+                None,
+            ])),
+        ));
+        let signatures = docs.minhash(16, 2, 1)?;
+        assert_eq!(signatures.len(), 4);
+        let values = signatures.flat_child.u32()?.as_arrow().values().clone();
+        assert_eq!(values[0..16], values[16..32]);
+        assert_ne!(values[0..16], values[32..48]);
+        assert!(!signatures.validity().unwrap().get_bit(3));
+
+        let bands = signatures.lsh_bands(4)?;
+        let band_values = bands.flat_child.u64()?.as_arrow().values().clone();
+        assert_eq!(band_values[0..4], band_values[4..8]);
+        assert!(signatures.lsh_bands(3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_lsh_bands_null_values() -> DaftResult<()> {
+        let values = UInt32Array::from((
+            "item",
+            Box::new(PrimitiveArray::<u32>::from(vec![
+                Some(0),
+                None,
+                None,
+                Some(0),
+                Some(0),
+                Some(0),
+            ])),
+        ));
+        let signatures = FixedSizeListArray::new(
+            Field::new(
+                "signatures",
+                DataType::FixedSizeList(Box::new(DataType::UInt32), 2),
+            ),
+            values.into_series(),
+            None,
+        );
+        let bands = signatures.lsh_bands(1)?;
+        let band_values = bands.flat_child.u64()?.as_arrow().values().clone();
+        assert_ne!(band_values[0], band_values[1]);
+        assert_ne!(band_values[0], band_values[2]);
+        assert_ne!(band_values[1], band_values[2]);
+
+        let signatures = FixedSizeListArray::new(
+            Field::new(
+                "signatures",
+                DataType::FixedSizeList(Box::new(DataType::UInt64), 2),
+            ),
+            UInt64Array::from(("item", vec![0, 0])).into_series(),
+            None,
+        );
+        assert!(signatures.lsh_bands(1).is_err());
+        Ok(())
+    }
+}
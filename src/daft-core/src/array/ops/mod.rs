@@ -4,15 +4,21 @@ mod arange;
 mod arithmetic;
 pub mod arrow2;
 pub mod as_arrow;
+mod bitwise_agg;
+mod bool_agg;
 pub(crate) mod broadcast;
 pub(crate) mod cast;
 mod ceil;
+mod comoments;
 mod compare_agg;
 mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod cumulative;
 mod date;
+mod ddsketch;
+mod ddsketch_merge;
 mod exp;
 mod filter;
 mod float;
@@ -21,7 +27,9 @@ pub mod from_arrow;
 pub mod full;
 mod get;
 pub(crate) mod groups;
-mod hash;
+pub(crate) mod hash;
+mod hll_merge;
+mod hll_sketch;
 mod if_else;
 pub(crate) mod image;
 mod is_in;
@@ -30,13 +38,16 @@ mod len;
 mod list;
 mod list_agg;
 mod log;
+mod map;
 mod mean;
+mod minhash;
 mod null;
 mod pairwise;
 mod repr;
 mod round;
 mod search_sorted;
 mod sign;
+mod sketch_percentile;
 mod sort;
 mod struct_;
 mod sum;
@@ -46,7 +57,9 @@ pub mod trigonometry;
 mod truncate;
 mod utf8;
 
+pub use hash::HashFunctionKind;
 pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
+pub use utf8::Utf8NormalizeForm;
 
 use common_error::DaftResult;
 
@@ -141,6 +154,66 @@ pub trait DaftCompareAggable {
     fn grouped_max(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftBoolAggable {
+    type Output;
+    fn bool_and(&self) -> Self::Output;
+    fn bool_or(&self) -> Self::Output;
+    fn grouped_bool_and(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_bool_or(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftBitwiseAggable {
+    type Output;
+    fn bit_and(&self) -> Self::Output;
+    fn bit_or(&self) -> Self::Output;
+    fn bit_xor(&self) -> Self::Output;
+    fn grouped_bit_and(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_bit_or(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_bit_xor(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftCoMomentsAggable {
+    type Output;
+    fn comoments(&self, other: &Self) -> Self::Output;
+    fn grouped_comoments(&self, other: &Self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftCoMomentsMergeAggable {
+    type Output;
+    fn merge_comoments(&self) -> Self::Output;
+    fn grouped_merge_comoments(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftApproxCountDistinctAggable {
+    type Output;
+    fn approx_count_distinct(&self) -> Self::Output;
+    fn grouped_approx_count_distinct(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllSketchAggable {
+    type Output;
+    fn hll_sketch(&self) -> Self::Output;
+    fn grouped_hll_sketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftHllMergeAggable {
+    type Output;
+    fn hll_merge(&self) -> Self::Output;
+    fn grouped_hll_merge(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftDDSketchAggable {
+    type Output;
+    fn ddsketch(&self) -> Self::Output;
+    fn grouped_ddsketch(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftDDSketchMergeAggable {
+    type Output;
+    fn ddsketch_merge(&self) -> Self::Output;
+    fn grouped_ddsketch_merge(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftListAggable {
     type Output;
     fn list(&self) -> Self::Output;
@@ -152,3 +225,10 @@ pub trait DaftConcatAggable {
     fn concat(&self) -> Self::Output;
     fn grouped_concat(&self, groups: &GroupIndices) -> Self::Output;
 }
+
+pub trait DaftCumulativeAggable {
+    type Output;
+    fn grouped_cumulative_sum(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_cumulative_min(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_cumulative_max(&self, groups: &GroupIndices) -> Self::Output;
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use arrow2::{array::PrimitiveArray, bitmap::Bitmap, offset::OffsetsBuffer};
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::{ListArray, StructArray},
+    datatypes::{DataType, Field, Float64Array},
+    series::{IntoSeries, Series},
+};
+
+use super::ddsketch::struct_array_to_ddsketches;
+
+impl StructArray {
+    /// Computes the given percentiles of each DDSketch in this array.
+    ///
+    /// Returns a Float64 series for a single percentile, or a List<Float64> series with one entry
+    /// per percentile if there are several of them or `force_list_output` is set. Null sketches
+    /// yield nulls.
+    pub fn sketch_percentile(
+        &self,
+        percentiles: &[f64],
+        force_list_output: bool,
+    ) -> DaftResult<Series> {
+        let sketches = struct_array_to_ddsketches(self)?;
+        let quantiles = sketches
+            .iter()
+            .map(|sketch| {
+                sketch
+                    .as_ref()
+                    .map(|sketch| {
+                        percentiles
+                            .iter()
+                            .map(|p| sketch.quantile(*p))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DaftError::ComputeError(format!("Failed to compute percentile: {e}")))?;
+
+        if percentiles.len() == 1 && !force_list_output {
+            let values = quantiles.into_iter().map(|q| q.and_then(|q| q[0]));
+            let arrow_array = PrimitiveArray::<f64>::from_trusted_len_iter(values);
+            return Ok(Float64Array::from((self.name(), Box::new(arrow_array))).into_series());
+        }
+
+        let validity = Bitmap::from_iter(quantiles.iter().map(Option::is_some));
+        let flat_values = quantiles.into_iter().flat_map(|q| match q {
+            Some(q) => q,
+            None => vec![None; percentiles.len()],
+        });
+        let flat_child = Float64Array::from((
+            self.name(),
+            Box::new(PrimitiveArray::<f64>::from_iter(flat_values)),
+        ))
+        .into_series();
+        let offsets = OffsetsBuffer::try_from(
+            (0..=self.len())
+                .map(|i| (i * percentiles.len()) as i64)
+                .collect::<Vec<_>>(),
+        )?;
+        let field = Arc::new(Field::new(
+            self.name(),
+            DataType::List(Box::new(DataType::Float64)),
+        ));
+        Ok(ListArray::new(field, flat_child, offsets, Some(validity)).into_series())
+    }
+}
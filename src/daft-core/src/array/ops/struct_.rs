@@ -1,6 +1,6 @@
 use common_error::{DaftError, DaftResult};
 
-use crate::{array::StructArray, Series};
+use crate::{array::StructArray, datatypes::Field, DataType, Series};
 
 impl StructArray {
     pub fn get(&self, name: &str) -> DaftResult<Series> {
@@ -29,6 +29,68 @@ impl StructArray {
                 .collect::<Vec<&str>>()
         )))
     }
+
+    /// Renames the fields of this struct, in order, to `names`.
+    pub fn rename_fields(&self, names: &[String]) -> DaftResult<Self> {
+        if names.len() != self.children.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected {} field names to rename struct {} but received {}",
+                self.children.len(),
+                self.name(),
+                names.len()
+            )));
+        }
+        let children = self
+            .children
+            .iter()
+            .zip(names)
+            .map(|(child, name)| child.rename(name))
+            .collect::<Vec<_>>();
+        Ok(Self::from_children(
+            self.name(),
+            children,
+            self.validity().cloned(),
+        ))
+    }
+
+    /// Sets the field `name` of this struct to `value`, replacing the field if it already exists
+    /// and appending it otherwise. `value` is broadcasted if it has length 1.
+    pub fn with_field(&self, name: &str, value: &Series) -> DaftResult<Self> {
+        let value = match value.len() {
+            1 if self.len() != 1 => value.broadcast(self.len())?,
+            len if len == self.len() => value.clone(),
+            len => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected struct field value to have length 1 or {}, but received {len}",
+                    self.len()
+                )))
+            }
+        }
+        .rename(name);
+        let mut children = self.children.clone();
+        match children.iter_mut().find(|child| child.name() == name) {
+            Some(child) => *child = value,
+            None => children.push(value),
+        }
+        Ok(Self::from_children(
+            self.name(),
+            children,
+            self.validity().cloned(),
+        ))
+    }
+
+    fn from_children(
+        name: &str,
+        children: Vec<Series>,
+        validity: Option<arrow2::bitmap::Bitmap>,
+    ) -> Self {
+        let fields = children.iter().map(|child| child.field().clone()).collect();
+        Self::new(
+            Field::new(name, DataType::Struct(fields)),
+            children,
+            validity,
+        )
+    }
 }
 
 #[cfg(test)]
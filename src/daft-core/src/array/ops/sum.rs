@@ -3,9 +3,9 @@ use super::DaftSumAggable;
 use super::as_arrow::AsArrow;
 
 use crate::array::ops::GroupIndices;
-use crate::datatypes::*;
+use crate::datatypes::{logical::Decimal128Array, *};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use arrow2::array::Array;
 macro_rules! impl_daft_numeric_agg {
@@ -57,3 +57,53 @@ impl_daft_numeric_agg!(Int64Type, i64);
 impl_daft_numeric_agg!(UInt64Type, u64);
 impl_daft_numeric_agg!(Float32Type, f32);
 impl_daft_numeric_agg!(Float64Type, f64);
+
+/// Sums the values at the given indices, ignoring nulls.
+/// Returns `Ok(None)` if they are all null and an error if the sum overflows.
+fn sum_decimal128(
+    array: &arrow2::array::PrimitiveArray<i128>,
+    indices: impl Iterator<Item = usize>,
+    dtype: &DataType,
+) -> DaftResult<Option<i128>> {
+    let mut sum = None;
+    for idx in indices.filter(|idx| array.is_valid(*idx)) {
+        sum = Some(
+            sum.unwrap_or(0i128)
+                .checked_add(array.value(idx))
+                .ok_or_else(|| {
+                    DaftError::ComputeError(format!(
+                        "Decimal overflow: sum does not fit in {dtype}"
+                    ))
+                })?,
+        );
+    }
+    Ok(sum)
+}
+
+impl DaftSumAggable for &Decimal128Array {
+    type Output = DaftResult<Decimal128Array>;
+
+    fn sum(&self) -> Self::Output {
+        let dtype = try_sum_supertype(self.data_type())?;
+        let sum_value = sum_decimal128(self.as_arrow(), 0..self.len(), &dtype)?;
+        let arrow_array = Box::new(arrow2::array::PrimitiveArray::from([sum_value]));
+        Ok(Decimal128Array::new(
+            Field::new(self.name(), dtype),
+            Int128Array::from((self.name(), arrow_array)),
+        ))
+    }
+
+    fn grouped_sum(&self, groups: &GroupIndices) -> Self::Output {
+        let dtype = try_sum_supertype(self.data_type())?;
+        let arrow_array = self.as_arrow();
+        let sum_per_group = groups
+            .iter()
+            .map(|g| sum_decimal128(arrow_array, g.iter().map(|i| *i as usize), &dtype))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let arrow_array = Box::new(arrow2::array::PrimitiveArray::from(sum_per_group));
+        Ok(Decimal128Array::new(
+            Field::new(self.name(), dtype),
+            Int128Array::from((self.name(), arrow_array)),
+        ))
+    }
+}
@@ -1,20 +1,28 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr};
 
 use crate::{
     array::{DataArray, ListArray},
     datatypes::{
-        BooleanArray, DaftIntegerType, DaftNumericType, DaftPhysicalType, Field, Int64Array,
-        UInt64Array, Utf8Array,
+        logical::{DateArray, TimestampArray},
+        BooleanArray, DaftIntegerType, DaftNumericType, DaftPhysicalType, Field, Int32Array,
+        Int64Array, TimeUnit, UInt64Array, Utf8Array,
     },
     DataType, Series,
 };
 use arrow2::array::Array;
+use chrono::{format::Parsed, LocalResult, NaiveDate, NaiveDateTime};
 
 use common_error::{DaftError, DaftResult};
 use itertools::Itertools;
 use num_traits::NumCast;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
 
-use super::{as_arrow::AsArrow, full::FullNull};
+use super::{
+    as_arrow::AsArrow,
+    date::{parse_format_items, ParsedTimeZone},
+    full::FullNull,
+};
 
 enum BroadcastedStrIter<'a> {
     Repeat(std::iter::Take<std::iter::Repeat<Option<&'a str>>>),
@@ -95,6 +103,127 @@ where
     Ok((false, result_len))
 }
 
+/// Combines the expected sizes of inputs that had to be parsed separately because they have
+/// different types, where a size of 1 broadcasts to the other.
+fn merge_expected_sizes(size: usize, other_size: usize) -> Result<usize, String> {
+    match (size, other_size) {
+        (size, other_size) if size == other_size => Ok(size),
+        (1, size) | (size, 1) => Ok(size),
+        (size, other_size) => Err(format!("Inputs have invalid lengths: {size}, {other_size}")),
+    }
+}
+
+/// Gets the value of `arr` at `idx`, broadcasting it if it has a single value.
+fn broadcasted_i64(arr: &Int64Array, idx: usize) -> Option<i64> {
+    arr.get(if arr.len() == 1 { 0 } else { idx })
+}
+
+/// Translates a SQL LIKE pattern into an anchored regex, where `%` matches any sequence of
+/// characters, `_` matches any single character and `\` escapes the character after it.
+fn like_pattern_to_regex(pattern: &str, case_insensitive: bool) -> DaftResult<regex::Regex> {
+    let mut re = String::from(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => re.push_str(".*"),
+            '_' => re.push('.'),
+            '\\' => match chars.next() {
+                Some(escaped) => re.push_str(&regex::escape(&escaped.to_string())),
+                None => {
+                    return Err(DaftError::ValueError(format!(
+                        "LIKE pattern \"{pattern}\" cannot end with an escape character"
+                    )))
+                }
+            },
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+    Ok(regex::Regex::new(&re)?)
+}
+
+/// Pads `val` with repetitions of `pad` up to `length` characters, truncating it if it's longer.
+fn pad_str<'a>(val: &'a str, length: usize, pad: &str, left: bool) -> Cow<'a, str> {
+    let val_len = val.chars().count();
+    if val_len >= length {
+        return val
+            .char_indices()
+            .nth(length)
+            .map_or(val, |(i, _)| &val[..i])
+            .into();
+    }
+    if pad.is_empty() {
+        return val.into();
+    }
+    let fill = pad
+        .chars()
+        .cycle()
+        .take(length - val_len)
+        .collect::<String>();
+    if left {
+        (fill + val).into()
+    } else {
+        format!("{val}{fill}").into()
+    }
+}
+
+/// Gets the substring of `val` with `length` characters starting at the character `start`, or all
+/// characters after `start` if there is no length. A negative `start` counts from the end.
+fn substr_chars(val: &str, start: i64, length: Option<usize>) -> &str {
+    let num_chars = val.chars().count() as i64;
+    let start = if start < 0 {
+        (num_chars + start).max(0) as usize
+    } else {
+        start.min(num_chars) as usize
+    };
+    let byte_idx = |char_idx: usize| {
+        val.char_indices()
+            .nth(char_idx)
+            .map_or(val.len(), |(i, _)| i)
+    };
+    let start_byte = byte_idx(start);
+    let end_byte = match length {
+        Some(length) => byte_idx(start.saturating_add(length)),
+        None => val.len(),
+    };
+    &val[start_byte..end_byte]
+}
+
+fn non_negative_arg(value: i64, arg_name: &str, op_name: &str) -> DaftResult<usize> {
+    usize::try_from(value).map_err(|_| {
+        DaftError::ValueError(format!(
+            "Error in {op_name}: {arg_name} must be non-negative, but received {value}"
+        ))
+    })
+}
+
+/// A unicode normalization form, see <https://unicode.org/reports/tr15/>.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Utf8NormalizeForm {
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
+}
+
+impl FromStr for Utf8NormalizeForm {
+    type Err = DaftError;
+
+    fn from_str(form: &str) -> DaftResult<Self> {
+        use Utf8NormalizeForm::*;
+        match form.to_uppercase().as_str() {
+            "NFC" => Ok(NFC),
+            "NFD" => Ok(NFD),
+            "NFKC" => Ok(NFKC),
+            "NFKD" => Ok(NFKD),
+            _ => Err(DaftError::ValueError(format!(
+                "Unsupported unicode normalization form: {form}, expected one of NFC, NFD, NFKC or NFKD"
+            ))),
+        }
+    }
+}
+
 fn split_array_on_literal<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
     pattern_iter: impl Iterator<Item = Option<&'a str>>,
@@ -127,12 +256,16 @@ fn split_array_on_regex<'a>(
     splits: &mut arrow2::array::MutableUtf8Array<i64>,
     offsets: &mut arrow2::offset::Offsets<i64>,
     validity: &mut arrow2::bitmap::MutableBitmap,
+    skip_empty: bool,
 ) -> DaftResult<()> {
     for (val, re) in arr_iter.zip(regex_iter) {
         let mut num_splits = 0i64;
         match (val, re) {
             (Some(val), Some(re)) => {
                 for split in re?.split(val) {
+                    if skip_empty && split.is_empty() {
+                        continue;
+                    }
                     splits.push(Some(split));
                     num_splits += 1;
                 }
@@ -147,6 +280,26 @@ fn split_array_on_regex<'a>(
     Ok(())
 }
 
+fn build_utf8_list_array(
+    name: &str,
+    values: arrow2::array::MutableUtf8Array<i64>,
+    offsets: arrow2::offset::Offsets<i64>,
+    validity: arrow2::bitmap::MutableBitmap,
+) -> DaftResult<ListArray> {
+    let values: arrow2::array::Utf8Array<i64> = values.into();
+    let validity: Option<arrow2::bitmap::Bitmap> = match validity.unset_bits() {
+        0 => None,
+        _ => Some(validity.into()),
+    };
+    let flat_child = Series::try_from(("item", values.to_boxed()))?;
+    Ok(ListArray::new(
+        Field::new(name, DataType::List(Box::new(DataType::Utf8))),
+        flat_child,
+        offsets.into(),
+        validity,
+    ))
+}
+
 fn regex_extract_first_match<'a>(
     arr_iter: impl Iterator<Item = Option<&'a str>>,
     regex_iter: impl Iterator<Item = Option<Result<regex::Regex, regex::Error>>>,
@@ -268,6 +421,41 @@ fn replace_on_literal<'a>(
     Ok(Utf8Array::from((name, Box::new(arrow_result?))))
 }
 
+/// Resolves parsed datetime fields to a UTC datetime. A missing time defaults to midnight, and a
+/// missing UTC offset means the fields are a wall-clock time in `tz`, or in UTC if there is none.
+fn parse_naive_utc_datetime(
+    parsed: &Parsed,
+    tz: Option<&ParsedTimeZone>,
+) -> Result<NaiveDateTime, String> {
+    if parsed.offset.is_some() {
+        return parsed
+            .to_datetime()
+            .map(|dt| dt.naive_utc())
+            .map_err(|e| e.to_string());
+    }
+    let has_time_fields = parsed.hour_div_12.is_some()
+        || parsed.hour_mod_12.is_some()
+        || parsed.minute.is_some()
+        || parsed.second.is_some()
+        || parsed.nanosecond.is_some();
+    let local = match parsed.to_naive_datetime_with_offset(0) {
+        Ok(local) => local,
+        // Only a date without any time fields defaults to midnight, so that an incomplete time
+        // (e.g. an hour without minutes) is an error rather than being silently dropped.
+        Err(_) if !has_time_fields => parsed
+            .to_naive_date()
+            .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+            .map_err(|e| e.to_string())?,
+        Err(e) => return Err(e.to_string()),
+    };
+    match tz.map(|tz| tz.localize(&local)) {
+        None => Ok(local),
+        Some(LocalResult::Single(utc)) => Ok(utc),
+        Some(LocalResult::Ambiguous(..)) => Err(format!("{local} is ambiguous in the timezone")),
+        Some(LocalResult::None) => Err(format!("{local} does not exist in the timezone")),
+    }
+}
+
 impl Utf8Array {
     pub fn endswith(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(
@@ -356,6 +544,7 @@ impl Utf8Array {
                     &mut splits,
                     &mut offsets,
                     &mut validity,
+                    false,
                 )?
             }
             (true, _) => {
@@ -369,6 +558,7 @@ impl Utf8Array {
                     &mut splits,
                     &mut offsets,
                     &mut validity,
+                    false,
                 )?
             }
             (false, _) => {
@@ -725,6 +915,410 @@ impl Utf8Array {
         Ok(result)
     }
 
+    /// Parses each string as a date with a chrono format string such as `"%d/%m/%Y"`. Strings that
+    /// don't match the format raise an error if `raise_error_on_failure` is set, and are null
+    /// otherwise.
+    pub fn to_date(&self, format: &str, raise_error_on_failure: bool) -> DaftResult<DateArray> {
+        let items = parse_format_items(format)?;
+        let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let arrow_result = self
+            .as_arrow()
+            .iter()
+            .map(|val| {
+                let Some(val) = val else {
+                    return Ok(None);
+                };
+                let mut parsed = Parsed::new();
+                let date = chrono::format::parse(&mut parsed, val, items.iter())
+                    .and_then(|_| parsed.to_naive_date());
+                match date {
+                    Ok(date) => Ok(Some((date - epoch_date).num_days() as i32)),
+                    Err(e) if raise_error_on_failure => Err(DaftError::ValueError(format!(
+                        "Error parsing string \"{val}\" as a date with format \"{format}\": {e}"
+                    ))),
+                    Err(_) => Ok(None),
+                }
+            })
+            .collect::<DaftResult<arrow2::array::Int32Array>>()?;
+        Ok(DateArray::new(
+            Field::new(self.name(), DataType::Date),
+            Int32Array::from((self.name(), Box::new(arrow_result))),
+        ))
+    }
+
+    /// Parses each string as a microsecond timestamp with a chrono format string such as
+    /// `"%Y-%m-%d %H:%M:%S"`. Strings without a UTC offset are read as wall-clock times in
+    /// `timezone`, while strings with one are converted to that instant. Without a timezone, the
+    /// result is timezone-naive and offsets are normalized to UTC.
+    ///
+    /// Strings that don't match the format, or whose wall-clock time doesn't unambiguously exist in
+    /// `timezone`, raise an error if `raise_error_on_failure` is set, and are null otherwise.
+    pub fn to_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+        raise_error_on_failure: bool,
+    ) -> DaftResult<TimestampArray> {
+        let items = parse_format_items(format)?;
+        let tz = timezone.map(ParsedTimeZone::parse).transpose()?;
+        let arrow_result = self
+            .as_arrow()
+            .iter()
+            .map(|val| {
+                let Some(val) = val else {
+                    return Ok(None);
+                };
+                let mut parsed = Parsed::new();
+                let datetime = chrono::format::parse(&mut parsed, val, items.iter())
+                    .map_err(|e| e.to_string())
+                    .and_then(|_| parse_naive_utc_datetime(&parsed, tz.as_ref()));
+                match datetime {
+                    Ok(datetime) => Ok(Some(datetime.timestamp_micros())),
+                    Err(e) if raise_error_on_failure => Err(DaftError::ValueError(format!(
+                        "Error parsing string \"{val}\" as a datetime with format \"{format}\": {e}"
+                    ))),
+                    Err(_) => Ok(None),
+                }
+            })
+            .collect::<DaftResult<arrow2::array::Int64Array>>()?;
+        Ok(TimestampArray::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(TimeUnit::Microseconds, timezone.map(str::to_string)),
+            ),
+            Int64Array::from((self.name(), Box::new(arrow_result))),
+        ))
+    }
+
+    /// Splits each string into the non-empty tokens between matches of the regex `pattern`.
+    pub fn tokenize(&self, pattern: &str) -> DaftResult<ListArray> {
+        let regex = regex::Regex::new(pattern)?;
+        let mut tokens = arrow2::array::MutableUtf8Array::with_capacity(self.len());
+        let mut offsets = arrow2::offset::Offsets::with_capacity(self.len());
+        let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(self.len());
+        split_array_on_regex(
+            self.as_arrow().iter(),
+            std::iter::repeat(Some(Ok(regex))).take(self.len()),
+            &mut tokens,
+            &mut offsets,
+            &mut validity,
+            true,
+        )?;
+        build_utf8_list_array(self.name(), tokens, offsets, validity)
+    }
+
+    /// Gets the n-grams of each string, which are either the windows of `n` consecutive
+    /// characters, or of `n` consecutive whitespace-separated words joined by a single space.
+    /// Strings with fewer than `n` characters or words have no n-grams.
+    pub fn ngrams(&self, n: usize, chars: bool) -> DaftResult<ListArray> {
+        if n == 0 {
+            return Err(DaftError::ValueError(
+                "Error in ngrams: n must be positive".to_string(),
+            ));
+        }
+        let mut ngrams = arrow2::array::MutableUtf8Array::<i64>::with_capacity(self.len());
+        let mut offsets = arrow2::offset::Offsets::<i64>::with_capacity(self.len());
+        let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(self.len());
+        for val in self.as_arrow().iter() {
+            let mut num_ngrams = 0i64;
+            match val {
+                Some(val) if chars => {
+                    let char_bounds = val
+                        .char_indices()
+                        .map(|(i, _)| i)
+                        .chain(std::iter::once(val.len()))
+                        .collect::<Vec<_>>();
+                    for window in char_bounds.windows(n + 1) {
+                        ngrams.push(Some(&val[window[0]..window[n]]));
+                        num_ngrams += 1;
+                    }
+                }
+                Some(val) => {
+                    let words = val.split_whitespace().collect::<Vec<_>>();
+                    for window in words.windows(n) {
+                        ngrams.push(Some(window.join(" ")));
+                        num_ngrams += 1;
+                    }
+                }
+                None => {}
+            }
+            validity.push(val.is_some());
+            offsets.try_push(num_ngrams)?;
+        }
+        build_utf8_list_array(self.name(), ngrams, offsets, validity)
+    }
+
+    /// Counts the whitespace-separated words in each string.
+    pub fn word_count(&self) -> DaftResult<UInt64Array> {
+        let self_arrow = self.as_arrow();
+        let arrow_result = self_arrow
+            .iter()
+            .map(|val| Some(val?.split_whitespace().count() as u64))
+            .collect::<arrow2::array::UInt64Array>()
+            .with_validity(self_arrow.validity().cloned());
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_result))))
+    }
+
+    /// Left-pads each string with repetitions of `pad` up to `length` characters. Strings longer
+    /// than `length` are truncated to it.
+    pub fn lpad(&self, length: &Int64Array, pad: &Utf8Array) -> DaftResult<Utf8Array> {
+        self.pad(length, pad, true, "lpad")
+    }
+
+    /// Right-pads each string with repetitions of `pad` up to `length` characters. Strings longer
+    /// than `length` are truncated to it.
+    pub fn rpad(&self, length: &Int64Array, pad: &Utf8Array) -> DaftResult<Utf8Array> {
+        self.pad(length, pad, false, "rpad")
+    }
+
+    fn pad(
+        &self,
+        length: &Int64Array,
+        pad: &Utf8Array,
+        left: bool,
+        op_name: &str,
+    ) -> DaftResult<Utf8Array> {
+        let (length_is_full_null, length_size) = parse_inputs(self, &[length])
+            .map_err(|e| DaftError::ValueError(format!("Error in {op_name}: {e}")))?;
+        let (pad_is_full_null, pad_size) = parse_inputs(self, &[pad])
+            .map_err(|e| DaftError::ValueError(format!("Error in {op_name}: {e}")))?;
+        let expected_size = merge_expected_sizes(length_size, pad_size)
+            .map_err(|e| DaftError::ValueError(format!("Error in {op_name}: {e}")))?;
+        if length_is_full_null || pad_is_full_null {
+            return Ok(Utf8Array::full_null(
+                self.name(),
+                &DataType::Utf8,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(Utf8Array::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let pad_iter = create_broadcasted_str_iter(pad, expected_size);
+        let arrow_result = self_iter
+            .zip(pad_iter)
+            .enumerate()
+            .map(
+                |(i, (val, pad))| match (val, broadcasted_i64(length, i), pad) {
+                    (Some(val), Some(length), Some(pad)) => {
+                        let length = non_negative_arg(length, "length", op_name)?;
+                        Ok(Some(pad_str(val, length, pad, left)))
+                    }
+                    _ => Ok(None),
+                },
+            )
+            .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>()?;
+
+        let result = Utf8Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
+    /// Repeats each string `n` times.
+    pub fn repeat(&self, n: &Int64Array) -> DaftResult<Utf8Array> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[n])
+            .map_err(|e| DaftError::ValueError(format!("Error in repeat: {e}")))?;
+        if is_full_null {
+            return Ok(Utf8Array::full_null(
+                self.name(),
+                &DataType::Utf8,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(Utf8Array::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let arrow_result = self_iter
+            .enumerate()
+            .map(|(i, val)| match (val, broadcasted_i64(n, i)) {
+                (Some(val), Some(n)) => Ok(Some(val.repeat(non_negative_arg(n, "n", "repeat")?))),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>()?;
+
+        let result = Utf8Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
+    /// Gets the substring of each string with `length` characters starting at the character
+    /// `start`, where a negative `start` counts from the end of the string. A null `length` takes
+    /// all characters from `start` to the end of the string.
+    pub fn substr(&self, start: &Int64Array, length: &Int64Array) -> DaftResult<Utf8Array> {
+        let (is_full_null, start_size) = parse_inputs(self, &[start])
+            .map_err(|e| DaftError::ValueError(format!("Error in substr: {e}")))?;
+        let (_, length_size) = parse_inputs(self, &[length])
+            .map_err(|e| DaftError::ValueError(format!("Error in substr: {e}")))?;
+        let expected_size = merge_expected_sizes(start_size, length_size)
+            .map_err(|e| DaftError::ValueError(format!("Error in substr: {e}")))?;
+        if is_full_null {
+            return Ok(Utf8Array::full_null(
+                self.name(),
+                &DataType::Utf8,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(Utf8Array::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let arrow_result = self_iter
+            .enumerate()
+            .map(|(i, val)| match (val, broadcasted_i64(start, i)) {
+                (Some(val), Some(start)) => {
+                    let length = broadcasted_i64(length, i)
+                        .map(|length| non_negative_arg(length, "length", "substr"))
+                        .transpose()?;
+                    Ok(Some(substr_chars(val, start, length)))
+                }
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<arrow2::array::Utf8Array<i64>>>()?;
+
+        let result = Utf8Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
+    pub fn normalize(&self, form: Utf8NormalizeForm) -> DaftResult<Utf8Array> {
+        self.unary_broadcasted_op(|val| match form {
+            Utf8NormalizeForm::NFC => val.nfc().collect::<String>().into(),
+            Utf8NormalizeForm::NFD => val.nfd().collect::<String>().into(),
+            Utf8NormalizeForm::NFKC => val.nfkc().collect::<String>().into(),
+            Utf8NormalizeForm::NFKD => val.nfkd().collect::<String>().into(),
+        })
+    }
+
+    /// Counts the non-overlapping occurrences of `pattern` in each string.
+    pub fn count_matches(&self, pattern: &Utf8Array, regex: bool) -> DaftResult<UInt64Array> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[pattern])
+            .map_err(|e| DaftError::ValueError(format!("Error in count_matches: {e}")))?;
+        if is_full_null {
+            return Ok(UInt64Array::full_null(
+                self.name(),
+                &DataType::UInt64,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(UInt64Array::empty(self.name(), &DataType::UInt64));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let arrow_result = match (regex, pattern.len()) {
+            (true, 1) => {
+                let re = regex::Regex::new(pattern.get(0).unwrap())?;
+                self_iter
+                    .map(|val| Some(re.find_iter(val?).count() as u64))
+                    .collect::<arrow2::array::UInt64Array>()
+            }
+            (true, _) => self_iter
+                .zip(pattern.as_arrow().iter())
+                .map(|(val, pat)| match (val, pat) {
+                    (Some(val), Some(pat)) => {
+                        Ok(Some(regex::Regex::new(pat)?.find_iter(val).count() as u64))
+                    }
+                    _ => Ok(None),
+                })
+                .collect::<DaftResult<arrow2::array::UInt64Array>>()?,
+            (false, _) => {
+                let pattern_iter = create_broadcasted_str_iter(pattern, expected_size);
+                self_iter
+                    .zip(pattern_iter)
+                    .map(|(val, pat)| Some(val?.matches(pat?).count() as u64))
+                    .collect::<arrow2::array::UInt64Array>()
+            }
+        };
+
+        let result = UInt64Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
+    /// Matches each string against a SQL LIKE pattern, see [`like_pattern_to_regex`].
+    pub fn like(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
+        self.like_pattern(pattern, false, "like")
+    }
+
+    /// Matches each string against a SQL LIKE pattern, ignoring case.
+    pub fn ilike(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
+        self.like_pattern(pattern, true, "ilike")
+    }
+
+    fn like_pattern(
+        &self,
+        pattern: &Utf8Array,
+        case_insensitive: bool,
+        op_name: &str,
+    ) -> DaftResult<BooleanArray> {
+        if pattern.len() == 1 {
+            return match pattern.get(0) {
+                None => Ok(BooleanArray::full_null(
+                    self.name(),
+                    &DataType::Boolean,
+                    self.len(),
+                )),
+                Some(pattern_v) => {
+                    let re = like_pattern_to_regex(pattern_v, case_insensitive)?;
+                    let arrow_result: arrow2::array::BooleanArray = self
+                        .as_arrow()
+                        .into_iter()
+                        .map(|self_v| Some(re.is_match(self_v?)))
+                        .collect();
+                    Ok(BooleanArray::from((self.name(), arrow_result)))
+                }
+            };
+        }
+
+        self.binary_broadcasted_compare(
+            pattern,
+            |data: &str, pat: &str| Ok(like_pattern_to_regex(pat, case_insensitive)?.is_match(data)),
+            op_name,
+        )
+    }
+
+    /// Strips any of the characters in `chars` from the start and/or end of each string.
+    pub fn strip_chars(&self, chars: &Utf8Array, left: bool, right: bool) -> DaftResult<Utf8Array> {
+        let (is_full_null, expected_size) = parse_inputs(self, &[chars])
+            .map_err(|e| DaftError::ValueError(format!("Error in strip: {e}")))?;
+        if is_full_null {
+            return Ok(Utf8Array::full_null(
+                self.name(),
+                &DataType::Utf8,
+                expected_size,
+            ));
+        }
+        if expected_size == 0 {
+            return Ok(Utf8Array::empty(self.name(), &DataType::Utf8));
+        }
+
+        let self_iter = create_broadcasted_str_iter(self, expected_size);
+        let chars_iter = create_broadcasted_str_iter(chars, expected_size);
+        let arrow_result = self_iter
+            .zip(chars_iter)
+            .map(|(val, chars)| {
+                let (mut val, chars) = (val?, chars?);
+                let is_stripped = |c: char| chars.contains(c);
+                if left {
+                    val = val.trim_start_matches(is_stripped);
+                }
+                if right {
+                    val = val.trim_end_matches(is_stripped);
+                }
+                Some(val)
+            })
+            .collect::<arrow2::array::Utf8Array<i64>>();
+
+        let result = Utf8Array::from((self.name(), Box::new(arrow_result)));
+        assert_eq!(result.len(), expected_size);
+        Ok(result)
+    }
+
     fn binary_broadcasted_compare<ScalarKernel>(
         &self,
         other: &Self,
@@ -827,4 +1421,57 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    #[test]
+    fn check_like_pattern_to_regex() -> DaftResult<()> {
+        let re = like_pattern_to_regex("a%b_c\\%", false)?;
+        assert!(re.is_match("ab_c%"));
+        assert!(re.is_match("a.*\nbxc%"));
+        assert!(!re.is_match("abxcd"));
+        assert!(!re.is_match("Abxc%"));
+        assert!(like_pattern_to_regex("a%", true)?.is_match("ABC"));
+        assert!(like_pattern_to_regex("a\\", false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_to_datetime_time_defaults() -> DaftResult<()> {
+        let date = Utf8Array::from((
+            "date",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "2021-01-02".into()
+            ])),
+        ));
+        let result = date.to_datetime("%Y-%m-%d", None, true)?;
+        let midnight = NaiveDate::from_ymd_opt(2021, 1, 2)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(
+            result.physical.as_arrow().get(0),
+            Some(midnight.timestamp_micros())
+        );
+
+        // An hour without minutes is incomplete, rather than being dropped in favor of midnight.
+        let date_and_hour = Utf8Array::from((
+            "date_and_hour",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                "2021-01-02 03".into()
+            ])),
+        ));
+        let result = date_and_hour.to_datetime("%Y-%m-%d %H", None, false)?;
+        assert_eq!(result.physical.as_arrow().get(0), None);
+        assert!(date_and_hour
+            .to_datetime("%Y-%m-%d %H", None, true)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn check_substr_chars() {
+        assert_eq!(substr_chars("héllo", 1, Some(3)), "éll");
+        assert_eq!(substr_chars("héllo", -2, None), "lo");
+        assert_eq!(substr_chars("héllo", -10, Some(2)), "hé");
+        assert_eq!(substr_chars("héllo", 10, None), "");
+    }
 }
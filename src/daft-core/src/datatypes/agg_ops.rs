@@ -1,6 +1,7 @@
 use common_error::{DaftError, DaftResult};
 
-use super::DataType;
+use super::{DataType, Field};
+use crate::utils::supertype::DECIMAL128_MAX_PRECISION;
 
 /// Get the data type that the sum of a column of the given data type should be casted to.
 pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
@@ -10,6 +11,9 @@ pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
         UInt8 | UInt16 | UInt32 | UInt64 => Ok(UInt64),
         Float32 => Ok(Float32),
         Float64 => Ok(Float64),
+        // Widen decimals to the maximum precision so that sums of partial sums have the same type.
+        Decimal128(_, scale) => Ok(Decimal128(DECIMAL128_MAX_PRECISION, *scale)),
+        Duration(unit) => Ok(Duration(*unit)),
         other => Err(DaftError::TypeError(format!(
             "Invalid argument to sum supertype: {}",
             other
@@ -20,7 +24,10 @@ pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
 /// Get the data type that the mean of a column of the given data type should be casted to.
 pub fn try_mean_supertype(dtype: &DataType) -> DaftResult<DataType> {
     use DataType::*;
-    if dtype.is_numeric() {
+    if let Decimal128(..) | Duration(..) = dtype {
+        // The type of dividing the sum by a UInt64 count.
+        &try_sum_supertype(dtype)? / &UInt64
+    } else if dtype.is_numeric() {
         Ok(Float64)
     } else {
         Err(DaftError::TypeError(format!(
@@ -29,3 +36,44 @@ pub fn try_mean_supertype(dtype: &DataType) -> DaftResult<DataType> {
         )))
     }
 }
+
+/// Get the data type that the variance or standard deviation of a column of the given data type should be casted to.
+pub fn try_variance_supertype(dtype: &DataType) -> DaftResult<DataType> {
+    use DataType::*;
+    if dtype.is_numeric() {
+        Ok(Float64)
+    } else {
+        Err(DaftError::TypeError(format!(
+            "Invalid argument to variance supertype: {}",
+            dtype
+        )))
+    }
+}
+
+/// Get the data type that the covariance or correlation of two columns of the given data types should be casted to.
+pub fn try_covariance_supertype(left: &DataType, right: &DataType) -> DaftResult<DataType> {
+    use DataType::*;
+    if left.is_numeric() && right.is_numeric() {
+        Ok(Float64)
+    } else {
+        Err(DaftError::TypeError(format!(
+            "Invalid arguments to covariance supertype: {} and {}",
+            left, right
+        )))
+    }
+}
+
+/// Get the data type of the mergeable co-moments of two columns of the given data types, which are
+/// the partial state of a variance, standard deviation, covariance or correlation.
+pub fn try_comoments_type(left: &DataType, right: &DataType) -> DaftResult<DataType> {
+    use DataType::*;
+    try_covariance_supertype(left, right)?;
+    Ok(Struct(vec![
+        Field::new("count", UInt64),
+        Field::new("mean_x", Float64),
+        Field::new("mean_y", Float64),
+        Field::new("m2_x", Float64),
+        Field::new("m2_y", Float64),
+        Field::new("c_xy", Float64),
+    ]))
+}
@@ -2,7 +2,10 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use common_error::{DaftError, DaftResult};
 
-use crate::{impl_binary_trait_by_reference, utils::supertype::try_get_supertype};
+use crate::{
+    impl_binary_trait_by_reference,
+    utils::supertype::{decimal_precision_and_scale, try_get_supertype, DECIMAL128_MAX_PRECISION},
+};
 
 use super::DataType;
 
@@ -54,6 +57,18 @@ impl DataType {
                     let pt = intermediate_type.to_physical();
                     Ok((Boolean, Some(intermediate_type), pt))
                 }
+                (Duration(..), Duration(..)) => {
+                    let intermediate_type = try_get_supertype(self, other)?;
+                    let pt = intermediate_type.to_physical();
+                    Ok((Boolean, Some(intermediate_type), pt))
+                }
+                (Decimal128(..), o) | (o, Decimal128(..))
+                    if o.is_numeric() || matches!(o, Decimal128(..)) =>
+                {
+                    let intermediate_type = try_get_supertype(self, other)?;
+                    let pt = intermediate_type.to_physical();
+                    Ok((Boolean, Some(intermediate_type), pt))
+                }
                 _ => Err(DaftError::TypeError(format!(
                     "Cannot perform comparison on types: {}, {}",
                     self, other
@@ -100,6 +115,10 @@ impl Add for &DataType {
                 (du_self @ &Duration(..), du_other @ &Duration(..)) => Err(DaftError::TypeError(
                     format!("Cannot add due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", du_self, du_other)
                 )),
+                (Decimal128(..), _) | (_, Decimal128(..)) => decimal_arithmetic_type(self, other, "add", |(p1, s1), (p2, s2)| {
+                    let scale = s1.max(s2);
+                    ((p1 - s1).max(p2 - s2) + scale + 1, scale)
+                }),
                 (Null, other) | (other, Null) => {
                     match other {
                         // Condition is for backwards compatibility. TODO: remove
@@ -161,6 +180,10 @@ impl Sub for &DataType {
                 (du_self @ &Duration(..), du_other @ &Duration(..)) => Err(DaftError::TypeError(
                     format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", du_self, du_other)
                 )),
+                (Decimal128(..), _) | (_, Decimal128(..)) => decimal_arithmetic_type(self, other, "subtract", |(p1, s1), (p2, s2)| {
+                    let scale = s1.max(s2);
+                    ((p1 - s1).max(p2 - s2) + scale + 1, scale)
+                }),
                 _ => Err(DaftError::TypeError(
                     format!("Cannot subtract types: {}, {}", self, other)
                 ))
@@ -178,6 +201,13 @@ impl Div for &DataType {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
             (s, o) if s.is_numeric() && o.is_numeric() => Ok(Float64),
+            (Duration(unit), o) if o.is_integer() => Ok(Duration(*unit)),
+            (Decimal128(..), _) | (_, Decimal128(..)) => {
+                decimal_arithmetic_type(self, other, "divide", |(p1, s1), (p2, s2)| {
+                    let scale = 6.max(s1 + p2 + 1);
+                    (p1 - s1 + s2 + scale, scale)
+                })
+            }
             _ => Err(DaftError::TypeError(format!(
                 "Cannot divide types: {}, {}",
                 self, other
@@ -194,6 +224,12 @@ impl Mul for &DataType {
         try_numeric_supertype(self, other).or(match (self, other) {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
+            (Duration(unit), o) | (o, Duration(unit)) if o.is_integer() => Ok(Duration(*unit)),
+            (Decimal128(..), _) | (_, Decimal128(..)) => {
+                decimal_arithmetic_type(self, other, "multiply", |(p1, s1), (p2, s2)| {
+                    (p1 + p2 + 1, s1 + s2)
+                })
+            }
             _ => Err(DaftError::TypeError(format!(
                 "Cannot multiply types: {}, {}",
                 self, other
@@ -210,6 +246,15 @@ impl Rem for &DataType {
         try_numeric_supertype(self, other).or(match (self, other) {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
+            (Decimal128(..), _) | (_, Decimal128(..)) => decimal_arithmetic_type(
+                self,
+                other,
+                "take the remainder of",
+                |(p1, s1), (p2, s2)| {
+                    let scale = s1.max(s2);
+                    ((p1 - s1).min(p2 - s2) + scale, scale)
+                },
+            ),
             _ => Err(DaftError::TypeError(format!(
                 "Cannot multiply types: {}, {}",
                 self, other
@@ -218,6 +263,44 @@ impl Rem for &DataType {
     }
 }
 
+/// Get the output type of an arithmetic operation where at least one side is a decimal.
+///
+/// Integers are treated as decimals with a scale of 0, and `precision_and_scale` computes the
+/// precision and scale of the result from those of the operands. If the result needs more digits
+/// than a [`Decimal128`](DataType::Decimal128) can hold, its scale is reduced (to no fewer than 6
+/// digits) so that its integral digits still fit. Decimals combined with floats give a Float64.
+fn decimal_arithmetic_type(
+    l: &DataType,
+    r: &DataType,
+    op_name: &str,
+    precision_and_scale: impl Fn((usize, usize), (usize, usize)) -> (usize, usize),
+) -> DaftResult<DataType> {
+    use DataType::*;
+    match (l, r) {
+        (Decimal128(..), Float32 | Float64) | (Float32 | Float64, Decimal128(..)) => Ok(Float64),
+        _ => match (
+            decimal_precision_and_scale(l),
+            decimal_precision_and_scale(r),
+        ) {
+            (Some(l), Some(r)) => {
+                let (precision, scale) = precision_and_scale(l, r);
+                if precision <= DECIMAL128_MAX_PRECISION {
+                    Ok(Decimal128(precision, scale))
+                } else {
+                    let integral_digits = precision - scale;
+                    let scale = DECIMAL128_MAX_PRECISION
+                        .saturating_sub(integral_digits)
+                        .max(scale.min(6));
+                    Ok(Decimal128(DECIMAL128_MAX_PRECISION, scale))
+                }
+            }
+            _ => Err(DaftError::TypeError(format!(
+                "Cannot {op_name} types: {l}, {r}"
+            ))),
+        },
+    }
+}
+
 impl_binary_trait_by_reference!(DataType, Add, add);
 impl_binary_trait_by_reference!(DataType, Sub, sub);
 impl_binary_trait_by_reference!(DataType, Mul, mul);
@@ -3,8 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use crate::impl_bincode_py_state_serialization;
 use common_error::{DaftError, DaftResult};
-use daft_core::impl_bincode_py_state_serialization;
 #[cfg(feature = "python")]
 use pyo3::{
     exceptions::PyValueError, pyclass, pymethods, types::PyBytes, PyObject, PyResult, PyTypeInfo,
@@ -20,6 +20,9 @@ pub enum JoinType {
     Inner,
     Left,
     Right,
+    Outer,
+    Semi,
+    Anti,
 }
 
 #[cfg(feature = "python")]
@@ -28,7 +31,7 @@ impl JoinType {
     /// Create a JoinType from its string representation.
     ///
     /// Args:
-    ///     join_type: String representation of the join type, e.g. "inner", "left", or "right".
+    ///     join_type: String representation of the join type, e.g. "inner", "left", "right", "outer", "semi", or "anti".
     #[staticmethod]
     pub fn from_join_type_str(join_type: &str) -> PyResult<Self> {
         Self::from_str(join_type).map_err(|e| PyValueError::new_err(e.to_string()))
@@ -45,9 +48,27 @@ impl JoinType {
     pub fn iterator() -> std::slice::Iter<'static, JoinType> {
         use JoinType::*;
 
-        static JOIN_TYPES: [JoinType; 3] = [Inner, Left, Right];
+        static JOIN_TYPES: [JoinType; 6] = [Inner, Left, Right, Outer, Semi, Anti];
         JOIN_TYPES.iter()
     }
+
+    /// Whether the output of this join only contains columns from the left side of the join.
+    pub fn is_left_only(&self) -> bool {
+        matches!(self, Self::Semi | Self::Anti)
+    }
+
+    /// Whether the given side of the join can be broadcast to every partition of the other side.
+    ///
+    /// A side can only be broadcast if no unmatched rows need to be emitted for it, since each
+    /// partition of the receiving side only sees part of the matches of a broadcast row.
+    pub fn can_broadcast(&self, broadcast_left: bool) -> bool {
+        match self {
+            Self::Inner => true,
+            Self::Left | Self::Semi | Self::Anti => !broadcast_left,
+            Self::Right => broadcast_left,
+            Self::Outer => false,
+        }
+    }
 }
 
 impl FromStr for JoinType {
@@ -60,6 +81,9 @@ impl FromStr for JoinType {
             "inner" => Ok(Inner),
             "left" => Ok(Left),
             "right" => Ok(Right),
+            "outer" => Ok(Outer),
+            "semi" => Ok(Semi),
+            "anti" => Ok(Anti),
             _ => Err(DaftError::TypeError(format!(
                 "Join type {} is not supported; only the following types are supported: {:?}",
                 join_type,
@@ -8,7 +8,11 @@ use pyo3::{
 };
 
 use crate::{
-    array::{ops::DaftLogical, pseudo_arrow::PseudoArrowArray, DataArray},
+    array::{
+        ops::{DaftLogical, HashFunctionKind, Utf8NormalizeForm},
+        pseudo_arrow::PseudoArrowArray,
+        DataArray,
+    },
     count_mode::CountMode,
     datatypes::{DataType, Field, ImageFormat, ImageMode, PythonType},
     ffi,
@@ -416,6 +420,83 @@ impl PySeries {
         Ok(self.series.utf8_find(&substr.series)?.into())
     }
 
+    pub fn utf8_tokenize(&self, pattern: &str) -> PyResult<Self> {
+        Ok(self.series.utf8_tokenize(pattern)?.into())
+    }
+
+    pub fn utf8_ngrams(&self, n: usize, chars: bool) -> PyResult<Self> {
+        Ok(self.series.utf8_ngrams(n, chars)?.into())
+    }
+
+    pub fn utf8_word_count(&self) -> PyResult<Self> {
+        Ok(self.series.utf8_word_count()?.into())
+    }
+
+    pub fn utf8_lpad(&self, length: &Self, pad: &Self) -> PyResult<Self> {
+        Ok(self.series.utf8_lpad(&length.series, &pad.series)?.into())
+    }
+
+    pub fn utf8_rpad(&self, length: &Self, pad: &Self) -> PyResult<Self> {
+        Ok(self.series.utf8_rpad(&length.series, &pad.series)?.into())
+    }
+
+    pub fn utf8_repeat(&self, n: &Self) -> PyResult<Self> {
+        Ok(self.series.utf8_repeat(&n.series)?.into())
+    }
+
+    pub fn utf8_substr(&self, start: &Self, length: &Self) -> PyResult<Self> {
+        Ok(self
+            .series
+            .utf8_substr(&start.series, &length.series)?
+            .into())
+    }
+
+    pub fn utf8_normalize(&self, form: &str) -> PyResult<Self> {
+        let form = form.parse::<Utf8NormalizeForm>()?;
+        Ok(self.series.utf8_normalize(form)?.into())
+    }
+
+    pub fn utf8_count_matches(&self, pattern: &Self, regex: bool) -> PyResult<Self> {
+        Ok(self
+            .series
+            .utf8_count_matches(&pattern.series, regex)?
+            .into())
+    }
+
+    pub fn utf8_like(&self, pattern: &Self) -> PyResult<Self> {
+        Ok(self.series.utf8_like(&pattern.series)?.into())
+    }
+
+    pub fn utf8_ilike(&self, pattern: &Self) -> PyResult<Self> {
+        Ok(self.series.utf8_ilike(&pattern.series)?.into())
+    }
+
+    pub fn utf8_strip_chars(&self, chars: &Self, left: bool, right: bool) -> PyResult<Self> {
+        Ok(self
+            .series
+            .utf8_strip_chars(&chars.series, left, right)?
+            .into())
+    }
+
+    pub fn utf8_to_date(&self, format: &str, raise_error_on_failure: bool) -> PyResult<Self> {
+        Ok(self
+            .series
+            .utf8_to_date(format, raise_error_on_failure)?
+            .into())
+    }
+
+    pub fn utf8_to_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+        raise_error_on_failure: bool,
+    ) -> PyResult<Self> {
+        Ok(self
+            .series
+            .utf8_to_datetime(format, timezone, raise_error_on_failure)?
+            .into())
+    }
+
     pub fn is_nan(&self) -> PyResult<Self> {
         Ok(self.series.is_nan()?.into())
     }
@@ -432,6 +513,22 @@ impl PySeries {
         Ok(self.series.dt_hour()?.into())
     }
 
+    pub fn dt_minute(&self) -> PyResult<Self> {
+        Ok(self.series.dt_minute()?.into())
+    }
+
+    pub fn dt_second(&self) -> PyResult<Self> {
+        Ok(self.series.dt_second()?.into())
+    }
+
+    pub fn dt_millisecond(&self) -> PyResult<Self> {
+        Ok(self.series.dt_millisecond()?.into())
+    }
+
+    pub fn dt_time(&self) -> PyResult<Self> {
+        Ok(self.series.dt_time()?.into())
+    }
+
     pub fn dt_month(&self) -> PyResult<Self> {
         Ok(self.series.dt_month()?.into())
     }
@@ -444,6 +541,18 @@ impl PySeries {
         Ok(self.series.dt_day_of_week()?.into())
     }
 
+    pub fn dt_day_of_year(&self) -> PyResult<Self> {
+        Ok(self.series.dt_day_of_year()?.into())
+    }
+
+    pub fn dt_week_of_year(&self) -> PyResult<Self> {
+        Ok(self.series.dt_week_of_year()?.into())
+    }
+
+    pub fn dt_quarter(&self) -> PyResult<Self> {
+        Ok(self.series.dt_quarter()?.into())
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> PyResult<Self> {
         Ok(self
             .series
@@ -451,6 +560,21 @@ impl PySeries {
             .into())
     }
 
+    pub fn dt_convert_time_zone(&self, timezone: &str) -> PyResult<Self> {
+        Ok(self.series.dt_convert_time_zone(timezone)?.into())
+    }
+
+    pub fn dt_replace_time_zone(&self, timezone: Option<&str>, ambiguous: &str) -> PyResult<Self> {
+        Ok(self
+            .series
+            .dt_replace_time_zone(timezone, ambiguous)?
+            .into())
+    }
+
+    pub fn dt_strftime(&self, format: &str) -> PyResult<Self> {
+        Ok(self.series.dt_strftime(format)?.into())
+    }
+
     pub fn partitioning_days(&self) -> PyResult<Self> {
         Ok(self.series.partitioning_days()?.into())
     }
@@ -475,10 +599,23 @@ impl PySeries {
         Ok(self.series.partitioning_iceberg_truncate(w)?.into())
     }
 
+    pub fn hash_with_function(&self, hash_function: &str, seed: Option<u64>) -> PyResult<Self> {
+        let function = hash_function.parse::<HashFunctionKind>()?;
+        Ok(self.series.hash_with_function(function, seed)?.into())
+    }
+
     pub fn murmur3_32(&self) -> PyResult<Self> {
         Ok(self.series.murmur3_32()?.into_series().into())
     }
 
+    pub fn minhash(&self, num_hashes: usize, ngram_size: usize, seed: u32) -> PyResult<Self> {
+        Ok(self.series.minhash(num_hashes, ngram_size, seed)?.into())
+    }
+
+    pub fn lsh_bands(&self, num_bands: usize) -> PyResult<Self> {
+        Ok(self.series.lsh_bands(num_bands)?.into())
+    }
+
     pub fn list_count(&self, mode: CountMode) -> PyResult<Self> {
         Ok(self.series.list_count(mode)?.into_series().into())
     }
@@ -487,6 +624,57 @@ impl PySeries {
         Ok(self.series.list_get(&idx.series, &default.series)?.into())
     }
 
+    pub fn list_to_map(&self, values: &Self) -> PyResult<Self> {
+        Ok(self.series.list_to_map(&values.series)?.into())
+    }
+
+    pub fn list_contains(&self, item: &Self) -> PyResult<Self> {
+        Ok(self
+            .series
+            .list_contains(&item.series)?
+            .into_series()
+            .into())
+    }
+
+    pub fn list_slice(&self, start: &Self, end: &Self) -> PyResult<Self> {
+        Ok(self.series.list_slice(&start.series, &end.series)?.into())
+    }
+
+    pub fn list_sort(&self, descending: bool) -> PyResult<Self> {
+        Ok(self.series.list_sort(descending)?.into())
+    }
+
+    pub fn list_distinct(&self) -> PyResult<Self> {
+        Ok(self.series.list_distinct()?.into())
+    }
+
+    pub fn list_flatten(&self) -> PyResult<Self> {
+        Ok(self.series.list_flatten()?.into())
+    }
+
+    pub fn list_value_counts(&self) -> PyResult<Self> {
+        Ok(self.series.list_value_counts()?.into())
+    }
+
+    pub fn list_zip(&self, others: Vec<Self>) -> PyResult<Self> {
+        let lists = std::iter::once(self.series.clone())
+            .chain(others.into_iter().map(|s| s.series))
+            .collect::<Vec<_>>();
+        Ok(Series::list_zip(self.series.name(), &lists)?.into())
+    }
+
+    pub fn map_get(&self, key: &Self) -> PyResult<Self> {
+        Ok(self.series.map_get(&key.series)?.into())
+    }
+
+    pub fn map_keys(&self) -> PyResult<Self> {
+        Ok(self.series.map_keys()?.into())
+    }
+
+    pub fn map_values(&self) -> PyResult<Self> {
+        Ok(self.series.map_values()?.into())
+    }
+
     pub fn image_decode(&self, raise_error_on_failure: bool) -> PyResult<Self> {
         Ok(self.series.image_decode(raise_error_on_failure)?.into())
     }
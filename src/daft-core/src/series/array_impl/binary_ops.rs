@@ -12,6 +12,7 @@ use crate::{
         Int128Array,
     },
     series::series_like::SeriesLike,
+    utils::supertype::decimal_precision_and_scale,
     with_match_comparable_daft_types, with_match_numeric_daft_types, DataType,
 };
 
@@ -72,6 +73,21 @@ macro_rules! binary_op_unimplemented {
     };
 }
 
+/// Applies a decimal arithmetic operation after casting integer operands to decimals.
+fn decimal_binary_op<F>(lhs: &Series, rhs: &Series, op: F) -> DaftResult<Series>
+where
+    F: Fn(&Decimal128Array, &Decimal128Array) -> DaftResult<Decimal128Array>,
+{
+    let cast_to_decimal = |s: &Series| match decimal_precision_and_scale(s.data_type()) {
+        Some((precision, scale)) => s.cast(&DataType::Decimal128(precision, scale)),
+        None => unreachable!("Cannot cast {} to a decimal", s.data_type()),
+    };
+    let (lhs_decimal, rhs_decimal) = (cast_to_decimal(lhs)?, cast_to_decimal(rhs)?);
+    Ok(op(lhs_decimal.decimal128()?, rhs_decimal.decimal128()?)?
+        .into_series()
+        .rename(lhs.name()))
+}
+
 macro_rules! py_numeric_binary_op {
     ($self:expr, $rhs:expr, $op:ident, $pyop:expr) => {{
         let output_type = ($self.data_type().$op($rhs.data_type()))?;
@@ -91,6 +107,7 @@ macro_rules! py_numeric_binary_op {
                     )
                 })
             }
+            Decimal128(..) => decimal_binary_op(&lhs, $rhs, |l, r| l.$op(r)),
             _ => binary_op_unimplemented!(lhs, $pyop, $rhs, output_type),
         }
     }};
@@ -157,6 +174,7 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
                     cast_downcast_op_into_series!(lhs, rhs, output_type, <$T as DaftDataType>::ArrayType, add)
                 })
             }
+            Decimal128(..) => decimal_binary_op(&lhs, rhs, |l, r| l.add(r)),
             _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
         }
     }
@@ -164,6 +182,10 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
         py_numeric_binary_op!(self, rhs, sub, "sub")
     }
     fn mul(&self, rhs: &Series) -> DaftResult<Series> {
+        // Scaling a duration by an integer is commutative, so let the duration do the scaling.
+        if matches!(rhs.data_type(), DataType::Duration(..)) && self.data_type().is_integer() {
+            return Ok((rhs * &self.into_series())?.rename(self.name()));
+        }
         py_numeric_binary_op!(self, rhs, mul, "mul")
     }
     fn div(&self, rhs: &Series) -> DaftResult<Series> {
@@ -174,6 +196,7 @@ pub(crate) trait SeriesBinaryOps: SeriesLike {
             #[cfg(feature = "python")]
             Python => Ok(py_binary_op!(lhs, rhs, "truediv")),
             Float64 => cast_downcast_op_into_series!(lhs, rhs, &Float64, Float64Array, div),
+            Decimal128(..) => decimal_binary_op(&lhs, rhs, |l, r| l.div(r)),
             _ => binary_op_unimplemented!(lhs, "/", rhs, output_type),
         }
     }
@@ -250,7 +273,10 @@ impl SeriesBinaryOps for ArrayWrapper<DateArray> {
         let output_type = (self.data_type() - rhs.data_type())?;
         match rhs.data_type() {
             Date => {
-                let physical_result = self.0.physical.sub(&rhs.date()?.physical)?;
+                // The difference in days is converted to the seconds of the output duration.
+                let days = self.0.physical.sub(&rhs.date()?.physical)?;
+                let seconds_in_day = Int64Array::from(("SecondsInDay", vec![60 * 60 * 24]));
+                let physical_result = days.cast(&Int64)?.i64()?.mul(&seconds_in_day)?;
                 physical_result.cast(&output_type)
             }
             Duration(..) => {
@@ -297,6 +323,28 @@ impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
             _ => binary_op_unimplemented!(self, "-", rhs, output_type),
         }
     }
+
+    fn mul(&self, rhs: &Series) -> DaftResult<Series> {
+        let output_type = (self.data_type() * rhs.data_type())?;
+        match rhs.data_type() {
+            r if r.is_integer() => {
+                let physical_result = self.0.physical.mul(rhs.cast(&DataType::Int64)?.i64()?)?;
+                physical_result.cast(&output_type)
+            }
+            _ => binary_op_unimplemented!(self, "*", rhs, output_type),
+        }
+    }
+
+    fn div(&self, rhs: &Series) -> DaftResult<Series> {
+        let output_type = (self.data_type() / rhs.data_type())?;
+        match rhs.data_type() {
+            r if r.is_integer() => Ok(self
+                .0
+                .div(rhs.cast(&DataType::Int64)?.i64()?)?
+                .into_series()),
+            _ => binary_op_unimplemented!(self, "/", rhs, output_type),
+        }
+    }
 }
 
 impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
@@ -1,7 +1,11 @@
 use crate::array::ListArray;
 use crate::count_mode::CountMode;
+use crate::datatypes::logical::DurationArray;
 use crate::series::IntoSeries;
-use crate::{array::ops::GroupIndices, series::Series, with_match_physical_daft_types};
+use crate::{
+    array::ops::GroupIndices, series::Series, with_match_integer_daft_types,
+    with_match_physical_daft_types,
+};
 use arrow2::array::PrimitiveArray;
 use common_error::{DaftError, DaftResult};
 
@@ -61,6 +65,20 @@ impl Series {
                 .into_series()),
                 None => Ok(DaftSumAggable::sum(&self.downcast::<Float64Array>()?)?.into_series()),
             },
+            Decimal128(..) => match groups {
+                Some(groups) => {
+                    Ok(DaftSumAggable::grouped_sum(&self.decimal128()?, groups)?.into_series())
+                }
+                None => Ok(DaftSumAggable::sum(&self.decimal128()?)?.into_series()),
+            },
+            Duration(..) => {
+                let physical = &self.duration()?.physical;
+                let data_array = match groups {
+                    Some(groups) => DaftSumAggable::grouped_sum(&physical, groups)?,
+                    None => DaftSumAggable::sum(&physical)?,
+                };
+                Ok(DurationArray::new(self.field().clone(), data_array).into_series())
+            }
             other => Err(DaftError::TypeError(format!(
                 "Numeric sum is not implemented for type {}",
                 other
@@ -83,6 +101,10 @@ impl Series {
                     None => Ok(DaftMeanAggable::mean(&casted.f64()?)?.into_series()),
                 }
             }
+            // Divide the sum by the count, which gives null for groups without values.
+            Decimal128(..) | Duration(..) => {
+                &self.sum(groups)? / &self.count(groups, CountMode::Valid)?
+            }
             other => Err(DaftError::TypeError(format!(
                 "Numeric mean is not implemented for type {}",
                 other
@@ -171,4 +193,213 @@ impl Series {
             ))),
         }
     }
+
+    /// Hashes the values of this series for sketching, with nulls kept as null hashes.
+    fn sketch_hashes(&self) -> DaftResult<UInt64Array> {
+        self.hash(None)?.with_validity(self.validity().cloned())
+    }
+
+    pub fn approx_count_distinct(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftApproxCountDistinctAggable;
+        let hashes = self.sketch_hashes()?;
+        match groups {
+            Some(groups) => Ok(
+                DaftApproxCountDistinctAggable::grouped_approx_count_distinct(&hashes, groups)?
+                    .into_series(),
+            ),
+            None => {
+                Ok(DaftApproxCountDistinctAggable::approx_count_distinct(&hashes)?.into_series())
+            }
+        }
+    }
+
+    /// Builds a serialized HyperLogLog sketch of the distinct values of this series (per group).
+    pub fn hll_sketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftHllSketchAggable;
+        let hashes = self.sketch_hashes()?;
+        match groups {
+            Some(groups) => {
+                Ok(DaftHllSketchAggable::grouped_hll_sketch(&hashes, groups)?.into_series())
+            }
+            None => Ok(DaftHllSketchAggable::hll_sketch(&hashes)?.into_series()),
+        }
+    }
+
+    /// Merges the serialized HyperLogLog sketches of this series (per group), returning the
+    /// estimated number of distinct values.
+    pub fn hll_merge(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftHllMergeAggable;
+        match self.data_type() {
+            DataType::Binary => {
+                let downcasted = self.downcast::<BinaryArray>()?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftHllMergeAggable::grouped_hll_merge(downcasted, groups)?.into_series())
+                    }
+                    None => Ok(DaftHllMergeAggable::hll_merge(downcasted)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "HyperLogLog merge is only valid for serialized sketches of type Binary, got {other}"
+            ))),
+        }
+    }
+
+    /// Builds a DDSketch of the non-null numeric values of this series (per group).
+    pub fn ddsketch(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftDDSketchAggable;
+        if !self.data_type().is_numeric() {
+            return Err(DaftError::TypeError(format!(
+                "DDSketch is only valid for numeric types, got {}",
+                self.data_type()
+            )));
+        }
+        let casted = self.cast(&DataType::Float64)?;
+        let downcasted = casted.f64()?;
+        match groups {
+            Some(groups) => {
+                Ok(DaftDDSketchAggable::grouped_ddsketch(downcasted, groups)?.into_series())
+            }
+            None => Ok(DaftDDSketchAggable::ddsketch(downcasted)?.into_series()),
+        }
+    }
+
+    /// Merges the DDSketches of this series (per group) into a single sketch.
+    pub fn ddsketch_merge(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftDDSketchMergeAggable;
+        match self.data_type() {
+            DataType::Struct(..) => {
+                let downcasted = self.struct_()?;
+                match groups {
+                    Some(groups) => Ok(DaftDDSketchMergeAggable::grouped_ddsketch_merge(
+                        downcasted, groups,
+                    )?
+                    .into_series()),
+                    None => Ok(DaftDDSketchMergeAggable::ddsketch_merge(downcasted)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "DDSketch merge is only valid for sketches of type Struct, got {other}"
+            ))),
+        }
+    }
+
+    /// Computes the co-moments of the pairs of values of this series and `other` (per group), which
+    /// are the mergeable partial state of a variance, standard deviation, covariance or correlation.
+    pub fn comoments(&self, other: &Series, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftCoMomentsAggable;
+        let dtype = try_covariance_supertype(self.data_type(), other.data_type())?;
+        let x = self.cast(&dtype)?;
+        let y = other.cast(&dtype)?;
+        match groups {
+            Some(groups) => {
+                Ok(
+                    DaftCoMomentsAggable::grouped_comoments(x.f64()?, y.f64()?, groups)?
+                        .into_series(),
+                )
+            }
+            None => Ok(DaftCoMomentsAggable::comoments(x.f64()?, y.f64()?)?.into_series()),
+        }
+    }
+
+    /// Merges the co-moments of this series (per group), as built by [`Series::comoments`].
+    pub fn merge_comoments(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftCoMomentsMergeAggable;
+        match self.data_type() {
+            DataType::Struct(..) => {
+                let downcasted = self.struct_()?;
+                match groups {
+                    Some(groups) => Ok(DaftCoMomentsMergeAggable::grouped_merge_comoments(
+                        downcasted, groups,
+                    )?
+                    .into_series()),
+                    None => {
+                        Ok(DaftCoMomentsMergeAggable::merge_comoments(downcasted)?.into_series())
+                    }
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Merging co-moments is only valid for type Struct, got {other}"
+            ))),
+        }
+    }
+
+    pub fn bool_and(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBoolAggable;
+        let casted = self.cast_for_bool_agg("bool_and")?;
+        let downcasted = casted.bool()?;
+        match groups {
+            Some(groups) => {
+                Ok(DaftBoolAggable::grouped_bool_and(downcasted, groups)?.into_series())
+            }
+            None => Ok(DaftBoolAggable::bool_and(downcasted)?.into_series()),
+        }
+    }
+
+    pub fn bool_or(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBoolAggable;
+        let casted = self.cast_for_bool_agg("bool_or")?;
+        let downcasted = casted.bool()?;
+        match groups {
+            Some(groups) => Ok(DaftBoolAggable::grouped_bool_or(downcasted, groups)?.into_series()),
+            None => Ok(DaftBoolAggable::bool_or(downcasted)?.into_series()),
+        }
+    }
+
+    fn cast_for_bool_agg(&self, name: &str) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Boolean | DataType::Null => self.cast(&DataType::Boolean),
+            other => Err(DaftError::TypeError(format!(
+                "{name} aggregation is only valid for Boolean types, got {other}"
+            ))),
+        }
+    }
+
+    pub fn bit_and(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBitwiseAggable;
+        self.check_bitwise_agg_type("bit_and")?;
+        with_match_integer_daft_types!(self.data_type(), |$T| {
+            let downcasted = self.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            match groups {
+                Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_and(downcasted, groups)?.into_series()),
+                None => Ok(DaftBitwiseAggable::bit_and(downcasted)?.into_series()),
+            }
+        })
+    }
+
+    pub fn bit_or(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBitwiseAggable;
+        self.check_bitwise_agg_type("bit_or")?;
+        with_match_integer_daft_types!(self.data_type(), |$T| {
+            let downcasted = self.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            match groups {
+                Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_or(downcasted, groups)?.into_series()),
+                None => Ok(DaftBitwiseAggable::bit_or(downcasted)?.into_series()),
+            }
+        })
+    }
+
+    pub fn bit_xor(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBitwiseAggable;
+        self.check_bitwise_agg_type("bit_xor")?;
+        with_match_integer_daft_types!(self.data_type(), |$T| {
+            let downcasted = self.downcast::<<$T as DaftDataType>::ArrayType>()?;
+            match groups {
+                Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_xor(downcasted, groups)?.into_series()),
+                None => Ok(DaftBitwiseAggable::bit_xor(downcasted)?.into_series()),
+            }
+        })
+    }
+
+    fn check_bitwise_agg_type(&self, name: &str) -> DaftResult<()> {
+        match self.data_type() {
+            DataType::Int128 => Err(DaftError::TypeError(format!(
+                "{name} aggregation is not supported for Int128"
+            ))),
+            dtype if dtype.is_integer() => Ok(()),
+            other => Err(DaftError::TypeError(format!(
+                "{name} aggregation is only valid for integer types, got {other}"
+            ))),
+        }
+    }
 }
@@ -31,7 +31,7 @@ impl_arithmetic_for_series!(Rem, rem);
 #[cfg(test)]
 mod tests {
     use crate::array::ops::full::FullNull;
-    use crate::datatypes::{DataType, Float64Array, Int64Array, Utf8Array};
+    use crate::datatypes::{DataType, Float64Array, Int64Array, TimeUnit, Utf8Array};
     use crate::series::IntoSeries;
     use common_error::DaftResult;
 
@@ -70,6 +70,24 @@ mod tests {
         Ok(())
     }
     #[test]
+    fn mul_int_and_duration() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 3]));
+        let b = Int64Array::from(("b", vec![10, 20, 30]))
+            .into_series()
+            .cast(&DataType::Duration(TimeUnit::Seconds))?;
+        let c = (a.into_series() * b)?;
+        // The result is named after the lhs, as expressions expect.
+        assert_eq!(c.name(), "a");
+        assert_eq!(*c.data_type(), DataType::Duration(TimeUnit::Seconds));
+        let values = c.cast(&DataType::Int64)?;
+        let values = values.i64()?;
+        assert_eq!(
+            (0..3).map(|i| values.get(i)).collect::<Vec<_>>(),
+            vec![Some(10), Some(40), Some(90)]
+        );
+        Ok(())
+    }
+    #[test]
     fn div_int_and_float() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3]));
         let b = Float64Array::from(("b", vec![1., 2., 3.]));
@@ -0,0 +1,36 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use common_error::DaftError;
+use common_error::DaftResult;
+
+impl Series {
+    pub fn comoments_covariance(&self, ddof: u64) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => self.struct_()?.comoments_covariance(ddof),
+            dt => Err(DaftError::TypeError(format!(
+                "comoments_covariance not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn comoments_stddev(&self, ddof: u64) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => self.struct_()?.comoments_stddev(ddof),
+            dt => Err(DaftError::TypeError(format!(
+                "comoments_stddev not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn comoments_correlation(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => self.struct_()?.comoments_correlation(),
+            dt => Err(DaftError::TypeError(format!(
+                "comoments_correlation not implemented for {}",
+                dt
+            ))),
+        }
+    }
+}
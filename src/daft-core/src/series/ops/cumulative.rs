@@ -0,0 +1,83 @@
+use crate::series::IntoSeries;
+use crate::{array::ops::GroupIndices, series::Series, with_match_numeric_daft_types};
+use common_error::{DaftError, DaftResult};
+
+use crate::datatypes::*;
+
+impl Series {
+    pub fn cumulative_sum(&self, groups: &GroupIndices) -> DaftResult<Series> {
+        use crate::array::ops::DaftCumulativeAggable;
+        use crate::datatypes::DataType::*;
+
+        match self.data_type() {
+            // intX -> int64 (in line with sum)
+            Int8 | Int16 | Int32 | Int64 => {
+                let casted = self.cast(&Int64)?;
+                Ok(casted.i64()?.grouped_cumulative_sum(groups)?.into_series())
+            }
+            // uintX -> uint64 (in line with sum)
+            UInt8 | UInt16 | UInt32 | UInt64 => {
+                let casted = self.cast(&UInt64)?;
+                Ok(casted.u64()?.grouped_cumulative_sum(groups)?.into_series())
+            }
+            Float32 => Ok(self
+                .downcast::<Float32Array>()?
+                .grouped_cumulative_sum(groups)?
+                .into_series()),
+            Float64 => Ok(self
+                .downcast::<Float64Array>()?
+                .grouped_cumulative_sum(groups)?
+                .into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "Cumulative sum is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn cumulative_min(&self, groups: &GroupIndices) -> DaftResult<Series> {
+        use crate::array::ops::DaftCumulativeAggable;
+
+        match self.data_type() {
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64 => with_match_numeric_daft_types!(self.data_type(), |$T| {
+                Ok(self.downcast::<<$T as DaftDataType>::ArrayType>()?.grouped_cumulative_min(groups)?.into_series())
+            }),
+            other => Err(DaftError::TypeError(format!(
+                "Cumulative min is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn cumulative_max(&self, groups: &GroupIndices) -> DaftResult<Series> {
+        use crate::array::ops::DaftCumulativeAggable;
+
+        match self.data_type() {
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64 => with_match_numeric_daft_types!(self.data_type(), |$T| {
+                Ok(self.downcast::<<$T as DaftDataType>::ArrayType>()?.grouped_cumulative_max(groups)?.into_series())
+            }),
+            other => Err(DaftError::TypeError(format!(
+                "Cumulative max is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::series::array_impl::IntoSeries;
-use crate::{datatypes::DataType, series::Series};
+use crate::{
+    datatypes::{DataType, TimeUnit},
+    series::Series,
+};
 
 use common_error::{DaftError, DaftResult};
 
@@ -41,8 +44,81 @@ impl Series {
                 let ts_array = self.timestamp()?;
                 Ok(ts_array.hour()?.into_series())
             }
+            DataType::Time(..) => {
+                let time_array = self.time()?;
+                Ok(time_array.hour()?.into_series())
+            }
             _ => Err(DaftError::ComputeError(format!(
-                "Can only run day() operation on temporal types, got {}",
+                "Can only run hour() operation on timestamp or time types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_minute(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.minute()?.into_series())
+            }
+            DataType::Time(..) => {
+                let time_array = self.time()?;
+                Ok(time_array.minute()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run minute() operation on timestamp or time types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_second(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.second()?.into_series())
+            }
+            DataType::Time(..) => {
+                let time_array = self.time()?;
+                Ok(time_array.second()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run second() operation on timestamp or time types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_millisecond(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.millisecond()?.into_series())
+            }
+            DataType::Time(..) => {
+                let time_array = self.time()?;
+                Ok(time_array.millisecond()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run millisecond() operation on timestamp or time types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_time(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Time(..) => Ok(self.clone()),
+            DataType::Timestamp(tu, _) => {
+                let tu = match tu {
+                    TimeUnit::Nanoseconds => TimeUnit::Nanoseconds,
+                    _ => TimeUnit::Microseconds,
+                };
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.time(&tu)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run time() operation on timestamp or time types, got {}",
                 self.data_type()
             ))),
         }
@@ -99,6 +175,57 @@ impl Series {
         }
     }
 
+    pub fn dt_day_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.day_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.day_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run day_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_week_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.week_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.week_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run week_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_quarter(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.quarter()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.quarter()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run quarter() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> DaftResult<Self> {
         match (self.data_type(), relative_to.data_type()) {
             (DataType::Timestamp(self_tu,self_tz), DataType::Timestamp(start_tu,start_tz)) if self_tu == start_tu && self_tz == start_tz => {
@@ -130,4 +257,53 @@ impl Series {
             ))),
         }
     }
+
+    pub fn dt_convert_time_zone(&self, timezone: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.convert_time_zone(timezone)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run convert_time_zone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_replace_time_zone(
+        &self,
+        timezone: Option<&str>,
+        ambiguous: &str,
+    ) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array
+                    .replace_time_zone(timezone, ambiguous)?
+                    .into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run replace_time_zone() operation on timestamp types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_strftime(&self, format: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.strftime(format)?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.strftime(format)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run strftime() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
 }
@@ -1,9 +1,13 @@
 use crate::{
-    datatypes::{Int32Array, UInt64Array},
-    series::Series,
+    array::ops::{as_arrow::AsArrow, hash::decimal_hash_bytes, HashFunctionKind},
+    datatypes::{Int32Array, UInt64Array, Utf8Array},
+    series::{IntoSeries, Series},
     with_match_comparable_daft_types,
 };
-use common_error::DaftResult;
+use arrow2::{array::PrimitiveArray, types::NativeType};
+use common_error::{DaftError, DaftResult};
+use sha1::Digest;
+use xxhash_rust::xxh64::xxh64;
 
 impl Series {
     pub fn hash(&self, seed: Option<&UInt64Array>) -> DaftResult<UInt64Array> {
@@ -34,4 +38,202 @@ impl Series {
             v => panic!("murmur3 hash not implemented for datatype: {v}"),
         }
     }
+
+    /// Hashes each value with `function`, optionally seeded for xxhash64 and murmur3_32. Nulls
+    /// have null hashes.
+    ///
+    /// Values are hashed over the bytes that Iceberg hashes them over, so murmur3_32 hashes agree
+    /// with [`Series::murmur3_32`]: integers and booleans as little-endian 64-bit integers, floats
+    /// as little-endian 64-bit floats, temporal values in microseconds and decimals as their
+    /// big-endian two's complement. Nested values are hashed over the length-prefixed bytes of
+    /// their elements or fields.
+    pub fn hash_with_function(
+        &self,
+        function: HashFunctionKind,
+        seed: Option<u64>,
+    ) -> DaftResult<Series> {
+        if seed.is_some() && !function.supports_seed() {
+            return Err(DaftError::ValueError(format!(
+                "Hash function {function:?} does not support a seed"
+            )));
+        }
+        let name = self.name();
+        match function {
+            HashFunctionKind::XxHash64 => {
+                let seed = seed.unwrap_or(0);
+                let mut hashes = Vec::with_capacity(self.len());
+                self.for_each_hashable_bytes(&mut |v| hashes.push(v.map(|v| xxh64(v, seed))))?;
+                let array = Box::new(arrow2::array::UInt64Array::from(hashes));
+                Ok(UInt64Array::from((name, array)).into_series())
+            }
+            HashFunctionKind::Murmur3_32 => {
+                let seed = u32::try_from(seed.unwrap_or(0)).map_err(|_| {
+                    DaftError::ValueError(format!(
+                        "Expected the seed of murmur3_32 to fit in 32 bits, but received {}",
+                        seed.unwrap_or(0)
+                    ))
+                })?;
+                let mut hashes = Vec::with_capacity(self.len());
+                self.for_each_hashable_bytes(&mut |v| {
+                    hashes.push(v.map(|v| {
+                        let unsigned = mur3::murmurhash3_x86_32(v, seed);
+                        i32::from_ne_bytes(unsigned.to_ne_bytes())
+                    }))
+                })?;
+                let array = Box::new(arrow2::array::Int32Array::from(hashes));
+                Ok(Int32Array::from((name, array)).into_series())
+            }
+            HashFunctionKind::Sha1 => hex_digests(self, |v| format!("{:x}", sha1::Sha1::digest(v))),
+            HashFunctionKind::Sha256 => {
+                hex_digests(self, |v| format!("{:x}", sha2::Sha256::digest(v)))
+            }
+            HashFunctionKind::Md5 => hex_digests(self, |v| format!("{:x}", md5::compute(v))),
+        }
+    }
+
+    /// Calls `f` with the bytes that each value is hashed over by [`Series::hash_with_function`],
+    /// which are read directly from the underlying buffers for all but nested values.
+    fn for_each_hashable_bytes(&self, f: &mut dyn FnMut(Option<&[u8]>)) -> DaftResult<()> {
+        use crate::datatypes::TimeUnit::Microseconds;
+        use crate::DataType::*;
+        match self.data_type() {
+            Null => (0..self.len()).for_each(|_| f(None)),
+            Boolean => self.bool()?.as_arrow().iter().for_each(|v| {
+                f(v.map(|v| (v as i64).to_le_bytes())
+                    .as_ref()
+                    .map(|v| v.as_slice()))
+            }),
+            Int8 => for_each_le_bytes(self.i8()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            Int16 => for_each_le_bytes(self.i16()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            Int32 => for_each_le_bytes(self.i32()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            Int64 => for_each_le_bytes(self.i64()?.as_arrow(), i64::to_le_bytes, f),
+            UInt8 => for_each_le_bytes(self.u8()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            UInt16 => for_each_le_bytes(self.u16()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            UInt32 => for_each_le_bytes(self.u32()?.as_arrow(), |v| (v as i64).to_le_bytes(), f),
+            UInt64 => for_each_le_bytes(self.u64()?.as_arrow(), u64::to_le_bytes, f),
+            Float32 => for_each_le_bytes(self.f32()?.as_arrow(), |v| (v as f64).to_le_bytes(), f),
+            Float64 => for_each_le_bytes(self.f64()?.as_arrow(), f64::to_le_bytes, f),
+            Utf8 => self
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .for_each(|v| f(v.map(str::as_bytes))),
+            Binary => self.binary()?.as_arrow().iter().for_each(f),
+            Time(..) => {
+                return self
+                    .cast(&Time(Microseconds))?
+                    .as_physical()?
+                    .for_each_hashable_bytes(f)
+            }
+            Timestamp(_, tz) => {
+                return self
+                    .cast(&Timestamp(Microseconds, tz.clone()))?
+                    .as_physical()?
+                    .for_each_hashable_bytes(f)
+            }
+            Duration(..) => {
+                return self
+                    .cast(&Duration(Microseconds))?
+                    .as_physical()?
+                    .for_each_hashable_bytes(f)
+            }
+            Decimal128(..) => self
+                .decimal128()?
+                .physical
+                .as_arrow()
+                .iter()
+                .for_each(|v| f(v.map(|v| decimal_hash_bytes(*v)).as_deref())),
+            List(..) => {
+                let list = self.list()?;
+                let elements = list.flat_child.hashable_bytes()?;
+                for i in 0..list.len() {
+                    let bytes = is_valid(list.validity(), i).then(|| {
+                        let (start, end) = list.offsets().start_end(i);
+                        nested_hashable_bytes(elements[start..end].iter())
+                    });
+                    f(bytes.as_deref());
+                }
+            }
+            FixedSizeList(..) => {
+                let list = self.fixed_size_list()?;
+                let size = list.fixed_element_len();
+                let elements = list.flat_child.hashable_bytes()?;
+                for i in 0..list.len() {
+                    let bytes = is_valid(list.validity(), i).then(|| {
+                        nested_hashable_bytes(elements[(i * size)..((i + 1) * size)].iter())
+                    });
+                    f(bytes.as_deref());
+                }
+            }
+            Struct(..) => {
+                let array = self.struct_()?;
+                let fields = array
+                    .children
+                    .iter()
+                    .map(|child| child.hashable_bytes())
+                    .collect::<DaftResult<Vec<_>>>()?;
+                for i in 0..array.len() {
+                    let bytes = is_valid(array.validity(), i)
+                        .then(|| nested_hashable_bytes(fields.iter().map(|field| &field[i])));
+                    f(bytes.as_deref());
+                }
+            }
+            dt if dt.is_logical() => return self.as_physical()?.for_each_hashable_bytes(f),
+            dt => {
+                return Err(DaftError::TypeError(format!(
+                    "Hashing is not implemented for type {dt}"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the bytes that each value is hashed over by [`Series::hash_with_function`], which are
+    /// only materialized for the elements and fields of nested values.
+    fn hashable_bytes(&self) -> DaftResult<Vec<Option<Vec<u8>>>> {
+        let mut values = Vec::with_capacity(self.len());
+        self.for_each_hashable_bytes(&mut |v| values.push(v.map(<[u8]>::to_vec)))?;
+        Ok(values)
+    }
+}
+
+/// Calls `f` with the little-endian bytes of each value of `array`, as converted by `to_le_bytes`.
+fn for_each_le_bytes<T: NativeType, const N: usize>(
+    array: &PrimitiveArray<T>,
+    to_le_bytes: impl Fn(T) -> [u8; N],
+    f: &mut dyn FnMut(Option<&[u8]>),
+) {
+    array
+        .iter()
+        .for_each(|v| f(v.map(|v| to_le_bytes(*v)).as_ref().map(|v| v.as_slice())));
+}
+
+fn is_valid(validity: Option<&arrow2::bitmap::Bitmap>, idx: usize) -> bool {
+    validity.map_or(true, |v| v.get_bit(idx))
+}
+
+/// Concatenates the bytes of the elements of a nested value, each prefixed by its validity and
+/// length so that different nested values never have the same bytes.
+fn nested_hashable_bytes<'a>(
+    values: impl ExactSizeIterator<Item = &'a Option<Vec<u8>>>,
+) -> Vec<u8> {
+    let mut bytes = (values.len() as u64).to_le_bytes().to_vec();
+    for value in values {
+        match value {
+            Some(value) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&(value.len() as u64).to_le_bytes());
+                bytes.extend_from_slice(value);
+            }
+            None => bytes.push(0),
+        }
+    }
+    bytes
+}
+
+fn hex_digests(series: &Series, digest: impl Fn(&[u8]) -> String) -> DaftResult<Series> {
+    let mut digests = Vec::with_capacity(series.len());
+    series.for_each_hashable_bytes(&mut |v| digests.push(v.map(&digest)))?;
+    let array = Box::new(arrow2::array::Utf8Array::<i64>::from(digests));
+    Ok(Utf8Array::from((series.name(), array)).into_series())
 }
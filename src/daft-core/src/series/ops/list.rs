@@ -1,5 +1,6 @@
-use crate::datatypes::{DataType, UInt64Array, Utf8Array};
-use crate::series::Series;
+use crate::array::ListArray;
+use crate::datatypes::{BooleanArray, DataType, UInt64Array, Utf8Array};
+use crate::series::{IntoSeries, Series};
 use crate::CountMode;
 use common_error::DaftError;
 
@@ -112,4 +113,133 @@ impl Series {
             ))),
         }
     }
+
+    pub fn list_contains(&self, item: &Series) -> DaftResult<BooleanArray> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.contains(item),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.contains(item),
+            dt => Err(DaftError::TypeError(format!(
+                "Contains not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_slice(&self, start: &Series, end: &Series) -> DaftResult<Series> {
+        let start = start.cast(&DataType::Int64)?;
+        let end = end.cast(&DataType::Int64)?;
+        let (start, end) = (start.i64()?, end.i64()?);
+
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.slice_lists(start, end)?.into_series()),
+            DataType::FixedSizeList(..) => Ok(self
+                .fixed_size_list()?
+                .slice_lists(start, end)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Slice not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_sort(&self, descending: bool) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.sort_lists(descending)?.into_series()),
+            DataType::FixedSizeList(..) => Ok(self
+                .fixed_size_list()?
+                .sort_lists(descending)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Sort not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_distinct(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.distinct()?.into_series()),
+            DataType::FixedSizeList(..) => Ok(self.fixed_size_list()?.distinct()?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Distinct not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_flatten(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.flatten()?.into_series()),
+            DataType::FixedSizeList(..) => Ok(self.fixed_size_list()?.flatten()?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Flatten not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_value_counts(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.value_counts()?.into_series()),
+            DataType::FixedSizeList(..) => {
+                Ok(self.fixed_size_list()?.value_counts()?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Value counts not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    /// Zips the lists of `lists` row by row into a list of structs named `name`, with one field
+    /// per list named after it. See [`ListArray::zip`].
+    pub fn list_zip(name: &str, lists: &[Series]) -> DaftResult<Series> {
+        let lists = lists
+            .iter()
+            .map(|list| match list.data_type() {
+                DataType::List(_) => Ok(list.list()?.clone()),
+                DataType::FixedSizeList(..) => Ok(list.fixed_size_list()?.to_list()),
+                dt => Err(DaftError::TypeError(format!(
+                    "Zip not implemented for {}",
+                    dt
+                ))),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(ListArray::zip(name, &lists)?.into_series())
+    }
+
+    /// Applies `f` to the elements of all lists at once, keeping the elements in their lists.
+    pub fn list_map_elements<F>(&self, f: F) -> DaftResult<Series>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.map_elements(f)?.into_series()),
+            DataType::FixedSizeList(..) => {
+                Ok(self.fixed_size_list()?.map_elements(f)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Eval not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    /// Keeps the elements of each list for which the boolean mask returned by `f` is true.
+    pub fn list_filter_elements<F>(&self, f: F) -> DaftResult<Series>
+    where
+        F: FnOnce(&Series) -> DaftResult<Series>,
+    {
+        match self.data_type() {
+            DataType::List(_) => Ok(self.list()?.filter_elements(f)?.into_series()),
+            DataType::FixedSizeList(..) => {
+                Ok(self.fixed_size_list()?.filter_elements(f)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Filter not implemented for {}",
+                dt
+            ))),
+        }
+    }
 }
@@ -0,0 +1,49 @@
+use crate::datatypes::DataType;
+use crate::series::array_impl::IntoSeries;
+use crate::series::Series;
+use common_error::DaftError;
+use common_error::DaftResult;
+
+impl Series {
+    pub fn map_get(&self, key: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Map(_) => self.map()?.map_get(key),
+            dt => Err(DaftError::TypeError(format!(
+                "map_get not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn map_keys(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Map(_) => Ok(self.map()?.map_keys()?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "map_keys not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn map_values(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Map(_) => Ok(self.map()?.map_values()?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "map_values not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_to_map(&self, values: &Series) -> DaftResult<Series> {
+        match (self.data_type(), values.data_type()) {
+            (DataType::List(_), DataType::List(_)) => {
+                Ok(self.list()?.to_map(values.list()?)?.into_series())
+            }
+            (dt, values_dt) => Err(DaftError::TypeError(format!(
+                "to_map not implemented for keys of type {} and values of type {}",
+                dt, values_dt
+            ))),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{datatypes::DataType, series::Series, IntoSeries};
+
+impl Series {
+    pub fn minhash(&self, num_hashes: usize, ngram_size: usize, seed: u32) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .minhash(num_hashes, ngram_size, seed)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "MinHash not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn lsh_bands(&self, num_bands: usize) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::FixedSizeList(..) => {
+                Ok(self.fixed_size_list()?.lsh_bands(num_bands)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "LSH bands not implemented for type {dt}"
+            ))),
+        }
+    }
+}
@@ -9,8 +9,10 @@ pub mod arithmetic;
 pub mod broadcast;
 pub mod cast;
 pub mod ceil;
+pub mod comoments;
 pub mod comparison;
 pub mod concat;
+pub mod cumulative;
 pub mod date;
 pub mod downcast;
 mod exp;
@@ -26,12 +28,15 @@ pub mod json;
 pub mod len;
 pub mod list;
 pub mod log;
+pub mod map;
+pub mod minhash;
 pub mod not;
 pub mod null;
 pub mod partitioning;
 pub mod round;
 pub mod search_sorted;
 pub mod sign;
+pub mod sketch_percentile;
 pub mod sort;
 pub mod struct_;
 pub mod take;
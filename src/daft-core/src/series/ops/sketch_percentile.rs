@@ -0,0 +1,22 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use common_error::DaftError;
+use common_error::DaftResult;
+
+impl Series {
+    pub fn sketch_percentile(
+        &self,
+        percentiles: &[f64],
+        force_list_output: bool,
+    ) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => self
+                .struct_()?
+                .sketch_percentile(percentiles, force_list_output),
+            dt => Err(DaftError::TypeError(format!(
+                "sketch_percentile not implemented for {}",
+                dt
+            ))),
+        }
+    }
+}
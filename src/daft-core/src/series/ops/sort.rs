@@ -1,12 +1,41 @@
-use crate::{series::Series, with_match_comparable_daft_types};
+use crate::{datatypes::DataType, series::Series, with_match_comparable_daft_types};
 use common_error::DaftError;
 
 use common_error::DaftResult;
 
 use crate::series::array_impl::IntoSeries;
 
+/// Replaces struct sort keys with their fields, so that structs are ordered by their fields in
+/// order. Each struct is preceded by whether it is null, which sorts null structs last, or first
+/// when descending, like the nulls of any other type.
+fn flatten_struct_sort_keys(
+    sort_keys: &[Series],
+    descending: &[bool],
+) -> DaftResult<(Vec<Series>, Vec<bool>)> {
+    let mut flat_keys = Vec::with_capacity(sort_keys.len());
+    let mut flat_descending = Vec::with_capacity(descending.len());
+    for (key, desc) in sort_keys.iter().zip(descending.iter()) {
+        if let DataType::Struct(_) = key.data_type() {
+            let children = &key.struct_()?.children;
+            let (child_keys, child_descending) =
+                flatten_struct_sort_keys(children, &vec![*desc; children.len()])?;
+            flat_keys.push(key.is_null()?);
+            flat_descending.push(*desc);
+            flat_keys.extend(child_keys);
+            flat_descending.extend(child_descending);
+        } else {
+            flat_keys.push(key.clone());
+            flat_descending.push(*desc);
+        }
+    }
+    Ok((flat_keys, flat_descending))
+}
+
 impl Series {
     pub fn argsort(&self, descending: bool) -> DaftResult<Series> {
+        if let DataType::Struct(_) = self.data_type() {
+            return Self::argsort_multikey(&[self.clone()], &[descending]);
+        }
         let series = self.as_physical()?;
         with_match_comparable_daft_types!(series.data_type(), |$T| {
             let downcasted = series.downcast::<<$T as DaftDataType>::ArrayType>()?;
@@ -23,6 +52,14 @@ impl Series {
             )));
         }
 
+        if sort_keys
+            .iter()
+            .any(|key| matches!(key.data_type(), DataType::Struct(_)))
+        {
+            let (sort_keys, descending) = flatten_struct_sort_keys(sort_keys, descending)?;
+            return Self::argsort_multikey(&sort_keys, &descending);
+        }
+
         if sort_keys.len() == 1 {
             return sort_keys
                 .first()
@@ -1,5 +1,8 @@
-use crate::datatypes::DataType;
-use crate::series::Series;
+use std::collections::HashSet;
+
+use crate::array::StructArray;
+use crate::datatypes::{DataType, Field};
+use crate::series::{IntoSeries, Series};
 use common_error::DaftError;
 use common_error::DaftResult;
 
@@ -13,4 +16,58 @@ impl Series {
             ))),
         }
     }
+
+    pub fn struct_rename_fields(&self, names: &[String]) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => Ok(self.struct_()?.rename_fields(names)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "rename_fields not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn struct_with_field(&self, name: &str, value: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Struct(_) => Ok(self.struct_()?.with_field(name, value)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "with_field not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    /// Packs `children` into a struct series named `name`, with one field per child named after
+    /// the child. Children of length 1 are broadcasted to the length of the others.
+    pub fn struct_pack(name: &str, children: &[Series]) -> DaftResult<Series> {
+        if children.is_empty() {
+            return Err(DaftError::ValueError(
+                "Need at least 1 series to pack into a struct".to_string(),
+            ));
+        }
+        let mut seen = HashSet::new();
+        if let Some(child) = children.iter().find(|child| !seen.insert(child.name())) {
+            return Err(DaftError::ValueError(format!(
+                "Cannot pack struct {name} with duplicate field name: {}",
+                child.name()
+            )));
+        }
+        let len = children.iter().map(|child| child.len()).max().unwrap();
+        let children = children
+            .iter()
+            .map(|child| match child.len() {
+                l if l == len => Ok(child.clone()),
+                1 => child.broadcast(len),
+                l => Err(DaftError::ValueError(format!(
+                    "Expected struct field {} to have length 1 or {len}, but received {l}",
+                    child.name()
+                ))),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let fields = children.iter().map(|child| child.field().clone()).collect();
+        Ok(
+            StructArray::new(Field::new(name, DataType::Struct(fields)), children, None)
+                .into_series(),
+        )
+    }
 }
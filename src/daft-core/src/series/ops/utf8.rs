@@ -1,3 +1,4 @@
+use crate::array::ops::Utf8NormalizeForm;
 use crate::series::Series;
 use common_error::{DaftError, DaftResult};
 
@@ -238,4 +239,171 @@ impl Series {
             ))),
         }
     }
+
+    pub fn utf8_tokenize(&self, pattern: &str) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.tokenize(pattern)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Tokenize not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_ngrams(&self, n: usize, chars: bool) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.ngrams(n, chars)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "NGrams not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_word_count(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.word_count()?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "WordCount not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_lpad(&self, length: &Series, pad: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => {
+                let length = length.cast(&DataType::Int64)?;
+                Ok(self.utf8()?.lpad(length.i64()?, pad.utf8()?)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Lpad not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_rpad(&self, length: &Series, pad: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => {
+                let length = length.cast(&DataType::Int64)?;
+                Ok(self.utf8()?.rpad(length.i64()?, pad.utf8()?)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Rpad not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_repeat(&self, n: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => {
+                let n = n.cast(&DataType::Int64)?;
+                Ok(self.utf8()?.repeat(n.i64()?)?.into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Repeat not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_substr(&self, start: &Series, length: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => {
+                let start = start.cast(&DataType::Int64)?;
+                let length = length.cast(&DataType::Int64)?;
+                Ok(self
+                    .utf8()?
+                    .substr(start.i64()?, length.i64()?)?
+                    .into_series())
+            }
+            dt => Err(DaftError::TypeError(format!(
+                "Substr not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_normalize(&self, form: Utf8NormalizeForm) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.normalize(form)?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Normalize not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_count_matches(&self, pattern: &Series, regex: bool) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .count_matches(pattern.utf8()?, regex)?
+                .into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "CountMatches not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_like(&self, pattern: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.like(pattern.utf8()?)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Like not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_ilike(&self, pattern: &Series) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.ilike(pattern.utf8()?)?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "Ilike not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_strip_chars(&self, chars: &Series, left: bool, right: bool) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .strip_chars(chars.utf8()?, left, right)?
+                .into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Strip not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_to_date(&self, format: &str, raise_error_on_failure: bool) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .to_date(format, raise_error_on_failure)?
+                .into_series()),
+            DataType::Null => self.cast(&DataType::Date),
+            dt => Err(DaftError::TypeError(format!(
+                "ToDate not implemented for type {dt}"
+            ))),
+        }
+    }
+
+    pub fn utf8_to_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+        raise_error_on_failure: bool,
+    ) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self
+                .utf8()?
+                .to_datetime(format, timezone, raise_error_on_failure)?
+                .into_series()),
+            DataType::Null => self.cast(&DataType::Timestamp(
+                TimeUnit::Microseconds,
+                timezone.map(str::to_string),
+            )),
+            dt => Err(DaftError::TypeError(format!(
+                "ToDatetime not implemented for type {dt}"
+            ))),
+        }
+    }
 }
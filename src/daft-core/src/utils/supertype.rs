@@ -14,6 +14,40 @@ fn get_time_units(tu_l: &TimeUnit, tu_r: &TimeUnit) -> TimeUnit {
     }
 }
 
+/// The maximum number of decimal digits that a [`DataType::Decimal128`] can hold.
+pub const DECIMAL128_MAX_PRECISION: usize = 38;
+
+/// Get the precision and scale of the smallest decimal that can exactly hold every value of the
+/// given decimal or integer type.
+pub fn decimal_precision_and_scale(dtype: &DataType) -> Option<(usize, usize)> {
+    use DataType::*;
+    match dtype {
+        Decimal128(precision, scale) => Some((*precision, *scale)),
+        Int8 | UInt8 => Some((3, 0)),
+        Int16 | UInt16 => Some((5, 0)),
+        Int32 | UInt32 => Some((10, 0)),
+        Int64 => Some((19, 0)),
+        UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
+
+/// Get the decimal that can hold the integral digits and the scale of both of the given decimal or
+/// integer types. If that exceeds [`DECIMAL128_MAX_PRECISION`], the scale is reduced so that no
+/// integral digits are lost.
+fn decimal_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
+    let (l_precision, l_scale) = decimal_precision_and_scale(l)?;
+    let (r_precision, r_scale) = decimal_precision_and_scale(r)?;
+    let integral_digits = (l_precision - l_scale).max(r_precision - r_scale);
+    if integral_digits > DECIMAL128_MAX_PRECISION {
+        return None;
+    }
+    let scale = l_scale
+        .max(r_scale)
+        .min(DECIMAL128_MAX_PRECISION - integral_digits);
+    Some(DataType::Decimal128(integral_digits + scale, scale))
+}
+
 pub fn try_get_supertype(l: &DataType, r: &DataType) -> DaftResult<DataType> {
     match get_supertype(l, r) {
         Some(dt) => Ok(dt),
@@ -170,6 +204,11 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 Some(Timestamp(tu, tz_r.clone()))
             }
 
+            (Decimal128(..), Decimal128(..))
+            | (Decimal128(..), Int8 | Int16 | Int32 | Int64)
+            | (Decimal128(..), UInt8 | UInt16 | UInt32 | UInt64) => decimal_supertype(l, r),
+            (Decimal128(..), Float32 | Float64) => Some(Float64),
+
             //TODO(sammy): add time, struct related dtypes
             (Boolean, Float32) => Some(Float32),
             (Boolean, Float64) => Some(Float64),
@@ -217,4 +256,42 @@ mod tests {
         assert_eq!(result, None);
         Ok(())
     }
+
+    #[test]
+    fn check_decimal_supertype() -> DaftResult<()> {
+        use DataType::*;
+        assert_eq!(
+            get_supertype(&Decimal128(10, 2), &Decimal128(5, 4)),
+            Some(Decimal128(12, 4))
+        );
+        assert_eq!(
+            get_supertype(&Int32, &Decimal128(10, 2)),
+            Some(Decimal128(12, 2))
+        );
+        assert_eq!(
+            get_supertype(&Decimal128(38, 10), &UInt64),
+            Some(Decimal128(38, 10))
+        );
+        assert_eq!(get_supertype(&Decimal128(10, 2), &Float32), Some(Float64));
+        Ok(())
+    }
+
+    #[test]
+    fn check_decimal_supertype_reduces_scale_to_fit() -> DaftResult<()> {
+        use DataType::*;
+        // Int64 needs 19 integral digits, which leaves room for only 19 of the 30 fractional digits.
+        assert_eq!(
+            get_supertype(&Decimal128(38, 30), &Int64),
+            Some(Decimal128(38, 19))
+        );
+        assert_eq!(
+            get_supertype(&UInt64, &Decimal128(30, 25)),
+            Some(Decimal128(38, 18))
+        );
+        assert_eq!(
+            get_supertype(&Decimal128(38, 0), &Decimal128(38, 38)),
+            Some(Decimal128(38, 0))
+        );
+        Ok(())
+    }
 }
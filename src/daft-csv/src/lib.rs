@@ -11,12 +11,14 @@ pub mod options;
 pub mod python;
 pub mod read;
 mod schema;
+pub mod write;
 
 pub use metadata::read_csv_schema_bulk;
 pub use options::{char_to_byte, CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_csv, read_csv_bulk};
+pub use write::{write_csv, write_csv_to_writer, CsvWriteOptions};
 
 #[derive(Debug, Snafu)]
 pub enum Error {
@@ -0,0 +1,295 @@
+use std::{borrow::Cow, pin::Pin, sync::Arc};
+
+use async_compat::CompatExt;
+use common_error::{DaftError, DaftResult};
+use csv_async::{AsyncWriterBuilder, QuoteStyle};
+use daft_compression::CompressionCodec;
+use daft_core::{schema::Schema, DataType};
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use snafu::ResultExt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{CSVSnafu, CsvParseOptions};
+
+/// Options for writing CSV files, which mirror the [`CsvParseOptions`] used to read them back.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CsvWriteOptions {
+    pub has_header: bool,
+    pub delimiter: u8,
+    pub double_quote: bool,
+    pub quote: u8,
+    pub escape_char: Option<u8>,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            delimiter: b',',
+            double_quote: true,
+            quote: b'"',
+            escape_char: None,
+        }
+    }
+}
+
+impl From<&CsvParseOptions> for CsvWriteOptions {
+    fn from(parse_options: &CsvParseOptions) -> Self {
+        Self {
+            has_header: parse_options.has_header,
+            delimiter: parse_options.delimiter,
+            double_quote: parse_options.double_quote,
+            quote: parse_options.quote,
+            escape_char: parse_options.escape_char,
+        }
+    }
+}
+
+/// Writes the rows of `tables` as CSV into `writer`, returning it once all rows are written.
+///
+/// Nulls are written as empty fields and empty strings as quoted empty fields, so that readers such
+/// as Spark can tell them apart. Nested columns are rejected.
+pub async fn write_csv_to_writer<W: AsyncWrite + Unpin + Send>(
+    writer: W,
+    tables: &[Table],
+    schema: &Schema,
+    options: &CsvWriteOptions,
+) -> DaftResult<W> {
+    if let Some(field) = schema.fields.values().find(|f| f.dtype.is_nested()) {
+        return Err(DaftError::TypeError(format!(
+            "Unable to write column {} of type {} to CSV, consider writing JSON instead",
+            field.name, field.dtype
+        )));
+    }
+
+    // Fields are quoted by `quote_field`, since the CSV writer never quotes empty strings.
+    let mut csv_writer = AsyncWriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote_style(QuoteStyle::Never)
+        .has_headers(false)
+        .create_writer(writer.compat());
+
+    if options.has_header {
+        csv_writer
+            .write_record(schema.fields.keys().map(|name| quote_field(name, options)))
+            .await
+            .context(CSVSnafu)?;
+    }
+    for table in tables {
+        if table.schema.as_ref() != schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Unable to write table with schema {} into CSV with schema {}",
+                table.schema, schema
+            )));
+        }
+        let columns = (0..table.num_columns())
+            .map(|i| table.get_column_by_index(i)?.cast(&DataType::Utf8))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let columns = columns
+            .iter()
+            .map(|column| column.utf8())
+            .collect::<DaftResult<Vec<_>>>()?;
+        for row in 0..table.len() {
+            csv_writer
+                .write_record(columns.iter().map(|column| match column.get(row) {
+                    Some(value) => quote_field(value, options),
+                    None => Cow::Borrowed(&b""[..]),
+                }))
+                .await
+                .context(CSVSnafu)?;
+        }
+    }
+    let writer = csv_writer
+        .into_inner()
+        .await
+        .map_err(|err| std::io::Error::new(err.error().kind(), err.to_string()))?;
+    Ok(writer.into_inner())
+}
+
+/// Quotes `field` wherever the CSV writer would, as well as when it is empty.
+fn quote_field<'a>(field: &'a str, options: &CsvWriteOptions) -> Cow<'a, [u8]> {
+    let field = field.as_bytes();
+    let escape = options.escape_char.unwrap_or(b'\\');
+    let needs_quotes = field.is_empty()
+        || field.iter().any(|&b| {
+            b == options.delimiter
+                || b == options.quote
+                || b == b'\r'
+                || b == b'\n'
+                || (!options.double_quote && b == escape)
+        });
+    if !needs_quotes {
+        return Cow::Borrowed(field);
+    }
+    let mut quoted = Vec::with_capacity(field.len() + 2);
+    quoted.push(options.quote);
+    for &b in field {
+        if options.double_quote {
+            if b == options.quote {
+                quoted.push(options.quote);
+            }
+        } else if b == options.quote || b == escape {
+            quoted.push(escape);
+        }
+        quoted.push(b);
+    }
+    quoted.push(options.quote);
+    Cow::Owned(quoted)
+}
+
+/// Writes `tables` into a CSV file at `uri` in any store supported by `io_client`, compressed with
+/// `compression` if given, e.g. with the codec inferred from the extension of `uri` by
+/// [`CompressionCodec::from_uri`].
+pub fn write_csv(
+    uri: &str,
+    tables: &[Table],
+    schema: &Schema,
+    options: &CsvWriteOptions,
+    compression: Option<CompressionCodec>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        let mut object_writer = io_client.open_writer(uri.to_string(), io_stats).await?;
+
+        let result: DaftResult<()> = async {
+            let writer: Pin<Box<dyn AsyncWrite + Send + '_>> = match compression {
+                Some(compression) => compression.to_encoder(&mut object_writer),
+                None => Box::pin(&mut object_writer),
+            };
+            let mut writer = write_csv_to_writer(writer, tables, schema, options).await?;
+            writer.shutdown().await?;
+            Ok(())
+        }
+        .await;
+        if result.is_err() {
+            if let Err(abort_err) = object_writer.abort().await {
+                log::warn!("Unable to abort write of CSV file {uri}: {abort_err}");
+            }
+        }
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_compression::CompressionCodec;
+    use daft_core::{
+        datatypes::{Field, Int64Array, Utf8Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
+    use daft_io::{get_runtime, IOClient, IOConfig};
+    use daft_table::Table;
+    use rstest::rstest;
+
+    use super::{write_csv, write_csv_to_writer, CsvWriteOptions};
+    use crate::{read::read_csv, CsvParseOptions};
+
+    #[test]
+    fn test_csv_write_nulls_and_empty_strings() -> DaftResult<()> {
+        let x = Utf8Array::from_iter(
+            "x",
+            vec![Some("a"), Some(""), None, Some("b,\"c\"")].into_iter(),
+        );
+        let y = Int64Array::from_iter("y", vec![Some(1), Some(2), Some(3), None].into_iter());
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Utf8),
+            Field::new("y", DataType::Int64),
+        ])?;
+        let table = Table::new(schema, vec![x.into_series(), y.into_series()])?;
+
+        let written = get_runtime(false)?.block_on(write_csv_to_writer(
+            Vec::new(),
+            std::slice::from_ref(&table),
+            &table.schema,
+            &CsvWriteOptions::default(),
+        ))?;
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "x,y\na,1\n\"\",2\n,3\n\"b,\"\"c\"\"\",\n"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_csv_write_roundtrip(
+        #[values(None, Some("gz"), Some("zst"))] compression: Option<&str>,
+        #[values(b',', b'|')] delimiter: u8,
+        #[values(None, Some(b'\\'))] escape_char: Option<u8>,
+    ) -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"));
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        // Values that need their quote and escape characters to be escaped.
+        let notes = ["a\\b", "ends with \\", "say \"hi\"", "\\\"", "plain"];
+        let notes = Utf8Array::from_iter(
+            "notes",
+            (0..table.len())
+                .map(|i| Some(notes[i % notes.len()]))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        let mut columns = (0..table.num_columns())
+            .map(|i| table.get_column_by_index(i).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        columns.push(notes.into_series());
+        let table = Table::from_columns(columns)?;
+
+        let dir = tempfile::tempdir()?;
+        let out = format!(
+            "{}/out/iris.csv{}",
+            dir.path().to_string_lossy(),
+            compression.map_or("".to_string(), |ext| format!(".{}", ext))
+        );
+        let parse_options = CsvParseOptions::default()
+            .with_delimiter(delimiter)
+            .with_escape_char(escape_char)
+            .with_double_quote(escape_char.is_none());
+        write_csv(
+            &out,
+            std::slice::from_ref(&table),
+            &table.schema,
+            &CsvWriteOptions::from(&parse_options),
+            CompressionCodec::from_uri(&out),
+            io_client.clone(),
+            None,
+        )?;
+
+        let read = read_csv(
+            out.as_ref(),
+            None,
+            Some(parse_options),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(read.schema, table.schema);
+        assert_eq!(read.len(), table.len());
+        for name in table.schema.names() {
+            assert_eq!(
+                read.get_column(&name)?.to_arrow(),
+                table.get_column(&name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+}
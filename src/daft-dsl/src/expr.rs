@@ -1,8 +1,11 @@
 use daft_core::{
     count_mode::CountMode,
-    datatypes::{try_mean_supertype, try_sum_supertype, DataType, Field, FieldID},
+    datatypes::{
+        try_comoments_type, try_covariance_supertype, try_mean_supertype, try_sum_supertype,
+        try_variance_supertype, DataType, Field, FieldID,
+    },
     schema::Schema,
-    utils::supertype::try_get_supertype,
+    utils::{hashable_float_wrapper::FloatWrapper, supertype::try_get_supertype},
 };
 
 use crate::{
@@ -50,6 +53,10 @@ pub enum Expr {
         if_false: ExprRef,
         predicate: ExprRef,
     },
+    Window(WindowExpr, WindowSpec),
+    /// A reference to each element of a list, within the sub-expression of a higher-order list
+    /// function such as [`ListExpr::Eval`](crate::functions::list::ListExpr::Eval).
+    Element,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -57,9 +64,34 @@ pub enum AggExpr {
     Count(ExprRef, CountMode),
     Sum(ExprRef),
     Mean(ExprRef),
+    /// The standard deviation with the given delta degrees of freedom.
+    Stddev(ExprRef, u64),
+    /// The variance with the given delta degrees of freedom.
+    Variance(ExprRef, u64),
+    /// The covariance of two columns with the given delta degrees of freedom.
+    Covariance(ExprRef, ExprRef, u64),
+    /// The Pearson correlation of two columns.
+    Correlation(ExprRef, ExprRef),
+    /// Computes the co-moments of pairs of values, as the first stage of a variance, standard
+    /// deviation, covariance or correlation.
+    CoMoments(ExprRef, ExprRef),
+    /// Merges co-moments computed by [`AggExpr::CoMoments`], as the second stage of a variance,
+    /// standard deviation, covariance or correlation.
+    MergeCoMoments(ExprRef),
     Min(ExprRef),
     Max(ExprRef),
     AnyValue(ExprRef, bool),
+    BoolAnd(ExprRef),
+    BoolOr(ExprRef),
+    BitAnd(ExprRef),
+    BitOr(ExprRef),
+    BitXor(ExprRef),
+    ApproxCountDistinct(ExprRef),
+    ApproxPercentile(ApproxPercentileParams),
+    /// Builds a mergeable sketch of the values, as the first stage of an approximate aggregation.
+    ApproxSketch(ExprRef, SketchType),
+    /// Merges sketches built by [`AggExpr::ApproxSketch`], as the second stage of an approximate aggregation.
+    MergeSketch(ExprRef, SketchType),
     List(ExprRef),
     Concat(ExprRef),
     MapGroups {
@@ -68,10 +100,77 @@ pub enum AggExpr {
     },
 }
 
+/// The kind of sketch used for the partial states of an approximate aggregation.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SketchType {
+    /// A HyperLogLog sketch, serialized as Binary, whose merged estimate is the number of distinct values.
+    HyperLogLog,
+    /// A DDSketch, serialized as a Struct, which merges into another DDSketch.
+    DDSketch,
+}
+
+impl Display for SketchType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::HyperLogLog => write!(f, "hll"),
+            Self::DDSketch => write!(f, "ddsketch"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ApproxPercentileParams {
+    pub child: ExprRef,
+    /// The percentiles to compute, each between 0.0 and 1.0.
+    pub percentiles: Vec<FloatWrapper<f64>>,
+    /// Whether to return a list even if there is a single percentile.
+    pub force_list_output: bool,
+}
+
+/// A function that is evaluated over a window of rows, i.e. all rows sharing the same
+/// partition keys, in the order given by the window's order keys.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowExpr {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag {
+        expr: ExprRef,
+        offset: usize,
+        default: ExprRef,
+    },
+    Lead {
+        expr: ExprRef,
+        offset: usize,
+        default: ExprRef,
+    },
+    CumSum(ExprRef),
+    CumMin(ExprRef),
+    CumMax(ExprRef),
+}
+
+/// The partitioning and ordering of rows that a [`WindowExpr`] is evaluated over.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+}
+
 pub fn col<S: Into<Arc<str>>>(name: S) -> ExprRef {
     Expr::Column(name.into()).into()
 }
 
+/// The name of the column that holds list elements when evaluating an [`Expr::Element`].
+///
+/// The leading NUL character reserves the name, so that it can't collide with a column of the
+/// table that the enclosing list expression is evaluated over.
+pub const ELEMENT_NAME: &str = "\0element";
+
+pub fn element() -> ExprRef {
+    Expr::Element.into()
+}
+
 pub fn binary_op(op: Operator, left: ExprRef, right: ExprRef) -> ExprRef {
     Expr::BinaryOp { op, left, right }.into()
 }
@@ -83,9 +182,24 @@ impl AggExpr {
             Count(expr, ..)
             | Sum(expr)
             | Mean(expr)
+            | Stddev(expr, _)
+            | Variance(expr, _)
+            | Covariance(expr, ..)
+            | Correlation(expr, _)
+            | CoMoments(expr, _)
+            | MergeCoMoments(expr)
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | BoolAnd(expr)
+            | BoolOr(expr)
+            | BitAnd(expr)
+            | BitOr(expr)
+            | BitXor(expr)
+            | ApproxCountDistinct(expr)
+            | ApproxPercentile(ApproxPercentileParams { child: expr, .. })
+            | ApproxSketch(expr, _)
+            | MergeSketch(expr, _)
             | List(expr)
             | Concat(expr) => expr.name(),
             MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
@@ -107,6 +221,35 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_mean()"))
             }
+            Stddev(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_stddev(ddof={ddof})"))
+            }
+            Variance(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_variance(ddof={ddof})"))
+            }
+            Covariance(left, right, ddof) => {
+                let left_id = left.semantic_id(schema);
+                let right_id = right.semantic_id(schema);
+                FieldID::new(format!(
+                    "{left_id}.local_covariance({right_id},ddof={ddof})"
+                ))
+            }
+            Correlation(left, right) => {
+                let left_id = left.semantic_id(schema);
+                let right_id = right.semantic_id(schema);
+                FieldID::new(format!("{left_id}.local_correlation({right_id})"))
+            }
+            CoMoments(left, right) => {
+                let left_id = left.semantic_id(schema);
+                let right_id = right.semantic_id(schema);
+                FieldID::new(format!("{left_id}.local_comoments({right_id})"))
+            }
+            MergeCoMoments(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_comoments()"))
+            }
             Min(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_min()"))
@@ -121,6 +264,49 @@ impl AggExpr {
                     "{child_id}.local_any_value(ignore_nulls={ignore_nulls})"
                 ))
             }
+            BoolAnd(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bool_and()"))
+            }
+            BoolOr(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bool_or()"))
+            }
+            BitAnd(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_and()"))
+            }
+            BitOr(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_or()"))
+            }
+            BitXor(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_xor()"))
+            }
+            ApproxCountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_count_distinct()"))
+            }
+            ApproxPercentile(ApproxPercentileParams {
+                child: expr,
+                percentiles,
+                force_list_output,
+            }) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_approx_percentiles(percentiles={:?},force_list_output={force_list_output})",
+                    percentiles.iter().map(|p| p.0).collect::<Vec<_>>(),
+                ))
+            }
+            ApproxSketch(expr, sketch_type) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_approx_sketch({sketch_type})"))
+            }
+            MergeSketch(expr, sketch_type) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_merge_sketch({sketch_type})"))
+            }
             List(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_list()"))
@@ -139,11 +325,26 @@ impl AggExpr {
             Count(expr, ..)
             | Sum(expr)
             | Mean(expr)
+            | Stddev(expr, _)
+            | Variance(expr, _)
+            | MergeCoMoments(expr)
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | BoolAnd(expr)
+            | BoolOr(expr)
+            | BitAnd(expr)
+            | BitOr(expr)
+            | BitXor(expr)
+            | ApproxCountDistinct(expr)
+            | ApproxPercentile(ApproxPercentileParams { child: expr, .. })
+            | ApproxSketch(expr, _)
+            | MergeSketch(expr, _)
             | List(expr)
             | Concat(expr) => vec![expr.clone()],
+            Covariance(left, right, _) | Correlation(left, right) | CoMoments(left, right) => {
+                vec![left.clone(), right.clone()]
+            }
             MapGroups { func: _, inputs } => inputs.clone(),
         }
     }
@@ -151,18 +352,37 @@ impl AggExpr {
     pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
         use AggExpr::*;
 
-        if let MapGroups { func: _, inputs } = &self {
-            assert_eq!(children.len(), inputs.len());
-        } else {
-            assert_eq!(children.len(), 1);
-        }
+        assert_eq!(children.len(), self.children().len());
         match self {
             Count(_, count_mode) => Count(children[0].clone(), *count_mode),
             Sum(_) => Sum(children[0].clone()),
             Mean(_) => Mean(children[0].clone()),
+            Stddev(_, ddof) => Stddev(children[0].clone(), *ddof),
+            Variance(_, ddof) => Variance(children[0].clone(), *ddof),
+            Covariance(.., ddof) => Covariance(children[0].clone(), children[1].clone(), *ddof),
+            Correlation(..) => Correlation(children[0].clone(), children[1].clone()),
+            CoMoments(..) => CoMoments(children[0].clone(), children[1].clone()),
+            MergeCoMoments(_) => MergeCoMoments(children[0].clone()),
             Min(_) => Min(children[0].clone()),
             Max(_) => Mean(children[0].clone()),
             AnyValue(_, ignore_nulls) => AnyValue(children[0].clone(), *ignore_nulls),
+            BoolAnd(_) => BoolAnd(children[0].clone()),
+            BoolOr(_) => BoolOr(children[0].clone()),
+            BitAnd(_) => BitAnd(children[0].clone()),
+            BitOr(_) => BitOr(children[0].clone()),
+            BitXor(_) => BitXor(children[0].clone()),
+            ApproxCountDistinct(_) => ApproxCountDistinct(children[0].clone()),
+            ApproxPercentile(ApproxPercentileParams {
+                percentiles,
+                force_list_output,
+                ..
+            }) => ApproxPercentile(ApproxPercentileParams {
+                child: children[0].clone(),
+                percentiles: percentiles.clone(),
+                force_list_output: *force_list_output,
+            }),
+            ApproxSketch(_, sketch_type) => ApproxSketch(children[0].clone(), *sketch_type),
+            MergeSketch(_, sketch_type) => MergeSketch(children[0].clone(), *sketch_type),
             List(_) => List(children[0].clone()),
             Concat(_) => Concat(children[0].clone()),
             MapGroups { func, inputs: _ } => MapGroups {
@@ -193,10 +413,135 @@ impl AggExpr {
                     try_mean_supertype(&field.dtype)?,
                 ))
             }
+            Stddev(expr, _) | Variance(expr, _) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_variance_supertype(&field.dtype)?,
+                ))
+            }
+            Covariance(left, right, _) | Correlation(left, right) => {
+                let left_field = left.to_field(schema)?;
+                let right_field = right.to_field(schema)?;
+                Ok(Field::new(
+                    left_field.name.as_str(),
+                    try_covariance_supertype(&left_field.dtype, &right_field.dtype)?,
+                ))
+            }
+            CoMoments(left, right) => {
+                let left_field = left.to_field(schema)?;
+                let right_field = right.to_field(schema)?;
+                Ok(Field::new(
+                    left_field.name.as_str(),
+                    try_comoments_type(&left_field.dtype, &right_field.dtype)?,
+                ))
+            }
+            MergeCoMoments(expr) => {
+                let field = expr.to_field(schema)?;
+                match field.dtype {
+                    DataType::Struct(..) => Ok(field),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected co-moments of type Struct to merge, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    ))),
+                }
+            }
             Min(expr) | Max(expr) | AnyValue(expr, _) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
+            BoolAnd(expr) | BoolOr(expr) => {
+                let field = expr.to_field(schema)?;
+                match field.dtype {
+                    DataType::Boolean | DataType::Null => {
+                        Ok(Field::new(field.name.as_str(), DataType::Boolean))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to {} to be Boolean, got dtype {} for column \"{}\"",
+                        self, field.dtype, field.name
+                    ))),
+                }
+            }
+            BitAnd(expr) | BitOr(expr) | BitXor(expr) => {
+                let field = expr.to_field(schema)?;
+                if field.dtype.is_integer() && field.dtype != DataType::Int128 {
+                    Ok(field)
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to {} to be an integer, got dtype {} for column \"{}\"",
+                        self, field.dtype, field.name
+                    )))
+                }
+            }
+            ApproxCountDistinct(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(field.name.as_str(), DataType::UInt64))
+            }
+            ApproxPercentile(ApproxPercentileParams {
+                child: expr,
+                percentiles,
+                force_list_output,
+            }) => {
+                let field = expr.to_field(schema)?;
+                if !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to approx_percentiles() to be numeric, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                if percentiles.is_empty() {
+                    return Err(DaftError::ValueError(
+                        "approx_percentiles() requires at least one percentile".to_string(),
+                    ));
+                }
+                if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(&p.0)) {
+                    return Err(DaftError::ValueError(format!(
+                        "Expected percentiles to be between 0.0 and 1.0, got {}",
+                        p.0
+                    )));
+                }
+                let dtype = if percentiles.len() == 1 && !force_list_output {
+                    DataType::Float64
+                } else {
+                    DataType::List(Box::new(DataType::Float64))
+                };
+                Ok(Field::new(field.name.as_str(), dtype))
+            }
+            ApproxSketch(expr, sketch_type) => {
+                let field = expr.to_field(schema)?;
+                match sketch_type {
+                    SketchType::HyperLogLog => {
+                        Ok(Field::new(field.name.as_str(), DataType::Binary))
+                    }
+                    SketchType::DDSketch if field.dtype.is_numeric() => Ok(Field::new(
+                        field.name.as_str(),
+                        DataType::from(&*daft_sketch::ARROW2_DDSKETCH_DTYPE),
+                    )),
+                    SketchType::DDSketch => Err(DaftError::TypeError(format!(
+                        "Expected numeric input to build a DDSketch, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    ))),
+                }
+            }
+            MergeSketch(expr, sketch_type) => {
+                let field = expr.to_field(schema)?;
+                match sketch_type {
+                    SketchType::HyperLogLog => match field.dtype {
+                        DataType::Binary => Ok(Field::new(field.name.as_str(), DataType::UInt64)),
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expected HyperLogLog sketches of type Binary to merge, got dtype {} for column \"{}\"",
+                            field.dtype, field.name
+                        ))),
+                    },
+                    SketchType::DDSketch => match field.dtype {
+                        DataType::Struct(..) => Ok(field),
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expected DDSketches of type Struct to merge, got dtype {} for column \"{}\"",
+                            field.dtype, field.name
+                        ))),
+                    },
+                }
+            }
             List(expr) => expr.to_field(schema)?.to_list_field(),
             Concat(expr) => {
                 let field = expr.to_field(schema)?;
@@ -220,9 +565,17 @@ impl AggExpr {
             "count" => Ok(Count(child, CountMode::Valid)),
             "sum" => Ok(Sum(child)),
             "mean" => Ok(Mean(child)),
+            "stddev" => Ok(Stddev(child, 1)),
+            "variance" => Ok(Variance(child, 1)),
             "min" => Ok(Min(child)),
             "max" => Ok(Max(child)),
             "list" => Ok(List(child)),
+            "bool_and" => Ok(BoolAnd(child)),
+            "bool_or" => Ok(BoolOr(child)),
+            "bit_and" => Ok(BitAnd(child)),
+            "bit_or" => Ok(BitOr(child)),
+            "bit_xor" => Ok(BitXor(child)),
+            "approx_count_distinct" => Ok(ApproxCountDistinct(child)),
             _ => Err(DaftError::ValueError(format!(
                 "{} not a valid aggregation name",
                 name
@@ -231,6 +584,155 @@ impl AggExpr {
     }
 }
 
+impl WindowExpr {
+    pub fn name(&self) -> DaftResult<&str> {
+        use WindowExpr::*;
+        match self {
+            RowNumber => Ok("row_number"),
+            Rank => Ok("rank"),
+            DenseRank => Ok("dense_rank"),
+            Lag { expr, .. } | Lead { expr, .. } | CumSum(expr) | CumMin(expr) | CumMax(expr) => {
+                expr.name()
+            }
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> FieldID {
+        use WindowExpr::*;
+        match self {
+            RowNumber => FieldID::new("row_number()"),
+            Rank => FieldID::new("rank()"),
+            DenseRank => FieldID::new("dense_rank()"),
+            Lag {
+                expr,
+                offset,
+                default,
+            } => {
+                let child_id = expr.semantic_id(schema);
+                let default_id = default.semantic_id(schema);
+                FieldID::new(format!("{child_id}.lag({offset}, {default_id})"))
+            }
+            Lead {
+                expr,
+                offset,
+                default,
+            } => {
+                let child_id = expr.semantic_id(schema);
+                let default_id = default.semantic_id(schema);
+                FieldID::new(format!("{child_id}.lead({offset}, {default_id})"))
+            }
+            CumSum(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.cum_sum()"))
+            }
+            CumMin(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.cum_min()"))
+            }
+            CumMax(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.cum_max()"))
+            }
+        }
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        use WindowExpr::*;
+        match self {
+            RowNumber | Rank | DenseRank => vec![],
+            Lag { expr, default, .. } | Lead { expr, default, .. } => {
+                vec![expr.clone(), default.clone()]
+            }
+            CumSum(expr) | CumMin(expr) | CumMax(expr) => vec![expr.clone()],
+        }
+    }
+
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        use WindowExpr::*;
+        assert_eq!(children.len(), self.children().len());
+        match self {
+            RowNumber | Rank | DenseRank => self.clone(),
+            Lag { offset, .. } => Lag {
+                expr: children[0].clone(),
+                offset: *offset,
+                default: children[1].clone(),
+            },
+            Lead { offset, .. } => Lead {
+                expr: children[0].clone(),
+                offset: *offset,
+                default: children[1].clone(),
+            },
+            CumSum(_) => CumSum(children[0].clone()),
+            CumMin(_) => CumMin(children[0].clone()),
+            CumMax(_) => CumMax(children[0].clone()),
+        }
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        use WindowExpr::*;
+        match self {
+            RowNumber | Rank | DenseRank => Ok(Field::new(self.name()?, DataType::UInt64)),
+            Lag { expr, default, .. } | Lead { expr, default, .. } => {
+                let field = expr.to_field(schema)?;
+                let default_field = default.to_field(schema)?;
+                match try_get_supertype(&field.dtype, &default_field.dtype) {
+                    Ok(supertype) => Ok(Field::new(field.name.as_str(), supertype)),
+                    Err(_) => Err(DaftError::TypeError(format!(
+                        "Expected expr and default arguments for {self} to be castable to the same supertype, but received {field} and {default_field}",
+                    ))),
+                }
+            }
+            CumSum(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_sum_supertype(&field.dtype)?,
+                ))
+            }
+            CumMin(expr) | CumMax(expr) => {
+                let field = expr.to_field(schema)?;
+                if !field.dtype.is_numeric() {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected input to {self} to be numeric, but received {field}",
+                    )));
+                }
+                Ok(field)
+            }
+        }
+    }
+
+    pub fn over(self, spec: WindowSpec) -> ExprRef {
+        Expr::Window(self, spec).into()
+    }
+}
+
+impl WindowSpec {
+    pub fn new(partition_by: Vec<ExprRef>, order_by: Vec<ExprRef>, descending: Vec<bool>) -> Self {
+        Self {
+            partition_by,
+            order_by,
+            descending,
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> String {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.semantic_id(schema).id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter())
+            .map(|(e, desc)| format!("{}(desc={desc})", e.semantic_id(schema)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("partition_by=[{partition_by}], order_by=[{order_by}]")
+    }
+}
+
 impl AsRef<Expr> for Expr {
     fn as_ref(&self) -> &Expr {
         self
@@ -271,6 +773,22 @@ impl Expr {
         Expr::Agg(AggExpr::Mean(self)).into()
     }
 
+    pub fn stddev(self: ExprRef, ddof: u64) -> ExprRef {
+        Expr::Agg(AggExpr::Stddev(self, ddof)).into()
+    }
+
+    pub fn variance(self: ExprRef, ddof: u64) -> ExprRef {
+        Expr::Agg(AggExpr::Variance(self, ddof)).into()
+    }
+
+    pub fn covariance(self: ExprRef, other: ExprRef, ddof: u64) -> ExprRef {
+        Expr::Agg(AggExpr::Covariance(self, other, ddof)).into()
+    }
+
+    pub fn correlation(self: ExprRef, other: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::Correlation(self, other)).into()
+    }
+
     pub fn min(self: ExprRef) -> ExprRef {
         Expr::Agg(AggExpr::Min(self)).into()
     }
@@ -283,6 +801,43 @@ impl Expr {
         Expr::Agg(AggExpr::AnyValue(self, ignore_nulls)).into()
     }
 
+    pub fn bool_and(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::BoolAnd(self)).into()
+    }
+
+    pub fn bool_or(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::BoolOr(self)).into()
+    }
+
+    pub fn bit_and(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::BitAnd(self)).into()
+    }
+
+    pub fn bit_or(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::BitOr(self)).into()
+    }
+
+    pub fn bit_xor(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::BitXor(self)).into()
+    }
+
+    pub fn approx_count_distinct(self: ExprRef) -> ExprRef {
+        Expr::Agg(AggExpr::ApproxCountDistinct(self)).into()
+    }
+
+    pub fn approx_percentiles(
+        self: ExprRef,
+        percentiles: &[f64],
+        force_list_output: bool,
+    ) -> ExprRef {
+        Expr::Agg(AggExpr::ApproxPercentile(ApproxPercentileParams {
+            child: self,
+            percentiles: percentiles.iter().map(|p| FloatWrapper(*p)).collect(),
+            force_list_output,
+        }))
+        .into()
+    }
+
     pub fn agg_list(self: ExprRef) -> ExprRef {
         Expr::Agg(AggExpr::List(self)).into()
     }
@@ -291,6 +846,34 @@ impl Expr {
         Expr::Agg(AggExpr::Concat(self)).into()
     }
 
+    pub fn lag(self: ExprRef, offset: usize, default: ExprRef) -> WindowExpr {
+        WindowExpr::Lag {
+            expr: self,
+            offset,
+            default,
+        }
+    }
+
+    pub fn lead(self: ExprRef, offset: usize, default: ExprRef) -> WindowExpr {
+        WindowExpr::Lead {
+            expr: self,
+            offset,
+            default,
+        }
+    }
+
+    pub fn cum_sum(self: ExprRef) -> WindowExpr {
+        WindowExpr::CumSum(self)
+    }
+
+    pub fn cum_min(self: ExprRef) -> WindowExpr {
+        WindowExpr::CumMin(self)
+    }
+
+    pub fn cum_max(self: ExprRef) -> WindowExpr {
+        WindowExpr::CumMax(self)
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn not(self: ExprRef) -> ExprRef {
         Expr::Not(self).into()
@@ -354,6 +937,9 @@ impl Expr {
             // Base case - literal.
             Literal(value) => FieldID::new(format!("Literal({value:?})")),
 
+            // Base case - list element reference.
+            Element => FieldID::new("element()"),
+
             // Recursive cases.
             Cast(expr, dtype) => {
                 let child_id = expr.semantic_id(schema);
@@ -405,6 +991,12 @@ impl Expr {
 
             // Agg: Separate path.
             Agg(agg_expr) => agg_expr.semantic_id(schema),
+
+            Window(window_expr, spec) => {
+                let window_id = window_expr.semantic_id(schema);
+                let spec_id = spec.semantic_id(schema);
+                FieldID::new(format!("{window_id}.over({spec_id})"))
+            }
         }
     }
 
@@ -414,6 +1006,7 @@ impl Expr {
             // No children.
             Column(..) => vec![],
             Literal(..) => vec![],
+            Element => vec![],
 
             // One child.
             Not(expr) | IsNull(expr) | NotNull(expr) | Cast(expr, ..) | Alias(expr, ..) => {
@@ -435,6 +1028,12 @@ impl Expr {
                 vec![if_true.clone(), if_false.clone(), predicate.clone()]
             }
             FillNull(expr, fill_value) => vec![expr.clone(), fill_value.clone()],
+            Window(window_expr, spec) => window_expr
+                .children()
+                .into_iter()
+                .chain(spec.partition_by.iter().cloned())
+                .chain(spec.order_by.iter().cloned())
+                .collect(),
         }
     }
 
@@ -442,7 +1041,7 @@ impl Expr {
         use Expr::*;
         match self {
             // no children
-            Column(..) | Literal(..) => self.clone(),
+            Column(..) | Literal(..) | Element => self.clone(),
             // 1 child
             Not(..) => Not(children.first().expect("Should have 1 child").clone()),
             Alias(.., name) => Alias(
@@ -481,6 +1080,18 @@ impl Expr {
                 func: func.clone(),
                 inputs: children,
             },
+            Window(window_expr, spec) => {
+                let num_window_children = window_expr.children().len();
+                let num_partition_by = spec.partition_by.len();
+                let mut children = children.into_iter();
+                let window_children = children.by_ref().take(num_window_children).collect();
+                let partition_by = children.by_ref().take(num_partition_by).collect();
+                let order_by = children.collect();
+                Window(
+                    window_expr.with_new_children(window_children),
+                    WindowSpec::new(partition_by, order_by, spec.descending.clone()),
+                )
+            }
         }
     }
 
@@ -588,6 +1199,13 @@ impl Expr {
                     Err(_) => Err(DaftError::TypeError(format!("Expected if_true and if_false arguments for if_else to be castable to the same supertype, but received {if_true_field} and {if_false_field}")))
                 }
             }
+            Window(window_expr, _) => window_expr.to_field(schema),
+            Element => schema.get_field(ELEMENT_NAME).cloned().map_err(|_| {
+                DaftError::ValueError(
+                    "element() can only be used within a higher-order list expression such as list.eval"
+                        .to_string(),
+                )
+            }),
         }
     }
 
@@ -614,6 +1232,8 @@ impl Expr {
                 right: _,
             } => left.name(),
             IfElse { if_true, .. } => if_true.name(),
+            Window(window_expr, _) => window_expr.name(),
+            Element => Ok(ELEMENT_NAME),
         }
     }
 
@@ -694,7 +1314,9 @@ impl Expr {
                 | Expr::Cast(..)
                 | Expr::IsIn(..)
                 | Expr::Function { .. }
-                | Expr::FillNull(..) => Err(io::Error::new(
+                | Expr::FillNull(..)
+                | Expr::Window(..)
+                | Expr::Element => Err(io::Error::new(
                     io::ErrorKind::Other,
                     "Unsupported expression for SQL translation",
                 )),
@@ -743,10 +1365,55 @@ impl Display for Expr {
             } => {
                 write!(f, "if [{predicate}] then [{if_true}] else [{if_false}]")
             }
+            Window(window_expr, spec) => write!(f, "{window_expr} over ({spec})"),
+            Element => write!(f, "element()"),
+        }
+    }
+}
+
+impl Display for WindowExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use WindowExpr::*;
+        match self {
+            RowNumber => write!(f, "row_number()"),
+            Rank => write!(f, "rank()"),
+            DenseRank => write!(f, "dense_rank()"),
+            Lag {
+                expr,
+                offset,
+                default,
+            } => write!(f, "lag({expr}, {offset}, {default})"),
+            Lead {
+                expr,
+                offset,
+                default,
+            } => write!(f, "lead({expr}, {offset}, {default})"),
+            CumSum(expr) => write!(f, "cum_sum({expr})"),
+            CumMin(expr) => write!(f, "cum_min({expr})"),
+            CumMax(expr) => write!(f, "cum_max({expr})"),
         }
     }
 }
 
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter())
+            .map(|(e, desc)| format!("{e} {}", if *desc { "desc" } else { "asc" }))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "partition by [{partition_by}] order by [{order_by}]")
+    }
+}
+
 impl Display for AggExpr {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         use AggExpr::*;
@@ -754,11 +1421,34 @@ impl Display for AggExpr {
             Count(expr, mode) => write!(f, "count({expr}, {mode})"),
             Sum(expr) => write!(f, "sum({expr})"),
             Mean(expr) => write!(f, "mean({expr})"),
+            Stddev(expr, ddof) => write!(f, "stddev({expr}, ddof={ddof})"),
+            Variance(expr, ddof) => write!(f, "variance({expr}, ddof={ddof})"),
+            Covariance(left, right, ddof) => write!(f, "covariance({left}, {right}, ddof={ddof})"),
+            Correlation(left, right) => write!(f, "correlation({left}, {right})"),
+            CoMoments(left, right) => write!(f, "comoments({left}, {right})"),
+            MergeCoMoments(expr) => write!(f, "merge_comoments({expr})"),
             Min(expr) => write!(f, "min({expr})"),
             Max(expr) => write!(f, "max({expr})"),
             AnyValue(expr, ignore_nulls) => {
                 write!(f, "any_value({expr}, ignore_nulls={ignore_nulls})")
             }
+            BoolAnd(expr) => write!(f, "bool_and({expr})"),
+            BoolOr(expr) => write!(f, "bool_or({expr})"),
+            BitAnd(expr) => write!(f, "bit_and({expr})"),
+            BitOr(expr) => write!(f, "bit_or({expr})"),
+            BitXor(expr) => write!(f, "bit_xor({expr})"),
+            ApproxCountDistinct(expr) => write!(f, "approx_count_distinct({expr})"),
+            ApproxPercentile(ApproxPercentileParams {
+                child: expr,
+                percentiles,
+                ..
+            }) => write!(
+                f,
+                "approx_percentiles({expr}, percentiles={:?})",
+                percentiles.iter().map(|p| p.0).collect::<Vec<_>>(),
+            ),
+            ApproxSketch(expr, sketch_type) => write!(f, "approx_sketch({expr}, {sketch_type})"),
+            MergeSketch(expr, sketch_type) => write!(f, "merge_sketch({expr}, {sketch_type})"),
             List(expr) => write!(f, "list({expr})"),
             Concat(expr) => write!(f, "list({expr})"),
             MapGroups { func, inputs } => function_display(f, func, inputs),
@@ -0,0 +1,57 @@
+use daft_core::{datatypes::Field, schema::Schema, series::Series};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, HashingExpr};
+
+pub(super) struct HashEvaluator {}
+
+impl FunctionEvaluator for HashEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "hash"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let (function, seed) = match expr {
+                    FunctionExpr::Hashing(HashingExpr::Hash { function, seed }) => (function, seed),
+                    _ => panic!("Expected Hashing Hash Expr, got {expr}"),
+                };
+                if seed.is_some() && !function.supports_seed() {
+                    return Err(DaftError::ValueError(format!(
+                        "Hash function {function:?} does not support a seed"
+                    )));
+                }
+                let input_field = input.to_field(schema)?;
+                Ok(Field::new(input_field.name, function.output_dtype()))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::Hashing(HashingExpr::Hash { function, seed }) => {
+                    input.hash_with_function(*function, *seed)
+                }
+                _ => panic!("Expected Hashing Hash Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod hash;
+
+use daft_core::array::ops::HashFunctionKind;
+use hash::HashEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::{Expr, ExprRef};
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HashingExpr {
+    Hash {
+        function: HashFunctionKind,
+        seed: Option<u64>,
+    },
+}
+
+impl HashingExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use HashingExpr::*;
+        match self {
+            Hash { .. } => &HashEvaluator {},
+        }
+    }
+}
+
+pub fn hash(input: ExprRef, function: HashFunctionKind, seed: Option<u64>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Hashing(HashingExpr::Hash { function, seed }),
+        inputs: vec![input],
+    }
+    .into()
+}
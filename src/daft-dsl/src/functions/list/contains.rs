@@ -0,0 +1,45 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::{IntoSeries, Series},
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct ContainsEvaluator {}
+
+impl FunctionEvaluator for ContainsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "contains"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input, item] => {
+                let input_field = input.to_field(schema)?;
+                let _item_field = item.to_field(schema)?;
+                let _exploded_field = input_field.to_exploded_field()?;
+
+                Ok(Field::new(input_field.name, DataType::Boolean))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input, item] => Ok(input.list_contains(item)?.into_series()),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
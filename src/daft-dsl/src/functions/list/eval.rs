@@ -0,0 +1,52 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, element_expr_field, ListExpr};
+
+pub(super) struct EvalEvaluator {}
+
+impl FunctionEvaluator for EvalEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "eval"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let element_expr = match expr {
+                    FunctionExpr::List(ListExpr::Eval(element_expr)) => element_expr,
+                    _ => panic!("Expected List Eval Expr, got {expr}"),
+                };
+                let element_field = element_expr_field(&input_field, element_expr)?;
+
+                Ok(Field::new(
+                    input_field.name,
+                    DataType::List(Box::new(element_field.dtype)),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, _: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        Err(DaftError::ValueError(format!(
+            "{expr} expressions must be evaluated by a table, which evaluates their element expression"
+        )))
+    }
+}
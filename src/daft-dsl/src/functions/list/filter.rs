@@ -0,0 +1,59 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, element_expr_field, ListExpr};
+
+pub(super) struct FilterEvaluator {}
+
+impl FunctionEvaluator for FilterEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "filter"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let predicate = match expr {
+                    FunctionExpr::List(ListExpr::Filter(predicate)) => predicate,
+                    _ => panic!("Expected List Filter Expr, got {expr}"),
+                };
+                let predicate_field = element_expr_field(&input_field, predicate)?;
+                if predicate_field.dtype != DataType::Boolean {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected list filter predicate to be boolean, received: {}",
+                        predicate_field.dtype
+                    )));
+                }
+
+                let exploded_field = input_field.to_exploded_field()?;
+                Ok(Field::new(
+                    input_field.name,
+                    DataType::List(Box::new(exploded_field.dtype)),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, _: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        Err(DaftError::ValueError(format!(
+            "{expr} expressions must be evaluated by a table, which evaluates their element expression"
+        )))
+    }
+}
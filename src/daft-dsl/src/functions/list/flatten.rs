@@ -0,0 +1,56 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct FlattenEvaluator {}
+
+impl FunctionEvaluator for FlattenEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "flatten"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let inner_field = input_field.to_exploded_field()?;
+
+                match inner_field.dtype {
+                    DataType::List(..) | DataType::FixedSizeList(..) => {
+                        let element_field = inner_field.to_exploded_field()?;
+                        Ok(Field::new(
+                            input_field.name,
+                            DataType::List(Box::new(element_field.dtype)),
+                        ))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a list of lists, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => Ok(input.list_flatten()?),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
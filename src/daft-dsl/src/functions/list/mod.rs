@@ -1,24 +1,44 @@
+mod contains;
 mod count;
+mod distinct;
+mod eval;
 mod explode;
+mod filter;
+mod flatten;
 mod get;
 mod join;
 mod max;
 mod mean;
 mod min;
+mod slice;
+mod sort;
 mod sum;
+mod value_counts;
+mod zip;
 
+use common_error::{DaftError, DaftResult};
+use common_treenode::{TreeNode, TreeNodeRecursion};
+use contains::ContainsEvaluator;
 use count::CountEvaluator;
-use daft_core::CountMode;
+use daft_core::{datatypes::Field, schema::Schema, CountMode};
+use distinct::DistinctEvaluator;
+use eval::EvalEvaluator;
 use explode::ExplodeEvaluator;
+use filter::FilterEvaluator;
+use flatten::FlattenEvaluator;
 use get::GetEvaluator;
 use join::JoinEvaluator;
 use max::MaxEvaluator;
 use mean::MeanEvaluator;
 use min::MinEvaluator;
 use serde::{Deserialize, Serialize};
+use slice::SliceEvaluator;
+use sort::SortEvaluator;
 use sum::SumEvaluator;
+use value_counts::ValueCountsEvaluator;
+use zip::ZipEvaluator;
 
-use crate::{Expr, ExprRef};
+use crate::{Expr, ExprRef, ELEMENT_NAME};
 
 use super::FunctionEvaluator;
 
@@ -32,6 +52,19 @@ pub enum ListExpr {
     Mean,
     Min,
     Max,
+    Contains,
+    Slice,
+    Sort(bool),
+    Distinct,
+    Flatten,
+    ValueCounts,
+    Zip,
+    /// Evaluates an expression over the elements of each list, where the elements are referred
+    /// to by [`Expr::Element`].
+    Eval(ExprRef),
+    /// Keeps the elements of each list for which a predicate is true, where the elements are
+    /// referred to by [`Expr::Element`].
+    Filter(ExprRef),
 }
 
 impl ListExpr {
@@ -47,10 +80,42 @@ impl ListExpr {
             Mean => &MeanEvaluator {},
             Min => &MinEvaluator {},
             Max => &MaxEvaluator {},
+            Contains => &ContainsEvaluator {},
+            Slice => &SliceEvaluator {},
+            Sort(_) => &SortEvaluator {},
+            Distinct => &DistinctEvaluator {},
+            Flatten => &FlattenEvaluator {},
+            ValueCounts => &ValueCountsEvaluator {},
+            Zip => &ZipEvaluator {},
+            Eval(_) => &EvalEvaluator {},
+            Filter(_) => &FilterEvaluator {},
         }
     }
 }
 
+/// Gets the field of `element_expr` when it is evaluated over the elements of a list field.
+///
+/// Aggregations and window functions are rejected, since they would be evaluated over the elements
+/// of all lists at once rather than over each list.
+fn element_expr_field(list_field: &Field, element_expr: &ExprRef) -> DaftResult<Field> {
+    let mut has_agg_or_window = false;
+    element_expr.apply(&mut |e: &ExprRef| {
+        if matches!(e.as_ref(), Expr::Agg(..) | Expr::Window(..)) {
+            has_agg_or_window = true;
+            return Ok(TreeNodeRecursion::Stop);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    if has_agg_or_window {
+        return Err(DaftError::ValueError(format!(
+            "Aggregation and window expressions are not supported over list elements, but got: {element_expr}"
+        )));
+    }
+    let element_field = list_field.to_exploded_field()?;
+    let element_schema = Schema::new(vec![Field::new(ELEMENT_NAME, element_field.dtype)])?;
+    element_expr.to_field(&element_schema)
+}
+
 pub fn explode(input: ExprRef) -> ExprRef {
     Expr::Function {
         func: super::FunctionExpr::List(ListExpr::Explode),
@@ -114,3 +179,75 @@ pub fn max(input: ExprRef) -> ExprRef {
     }
     .into()
 }
+
+pub fn contains(input: ExprRef, item: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Contains),
+        inputs: vec![input, item],
+    }
+    .into()
+}
+
+pub fn slice(input: ExprRef, start: ExprRef, end: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Slice),
+        inputs: vec![input, start, end],
+    }
+    .into()
+}
+
+pub fn sort(input: ExprRef, descending: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Sort(descending)),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn distinct(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Distinct),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn flatten(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Flatten),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn value_counts(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::ValueCounts),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn zip(inputs: Vec<ExprRef>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Zip),
+        inputs,
+    }
+    .into()
+}
+
+pub fn eval(input: ExprRef, element_expr: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Eval(element_expr)),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn filter(input: ExprRef, predicate: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Filter(predicate)),
+        inputs: vec![input],
+    }
+    .into()
+}
@@ -0,0 +1,56 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct SliceEvaluator {}
+
+impl FunctionEvaluator for SliceEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "slice"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input, start, end] => {
+                let input_field = input.to_field(schema)?;
+                for bound in [start, end] {
+                    let bound_field = bound.to_field(schema)?;
+                    if !bound_field.dtype.is_integer() && bound_field.dtype != DataType::Null {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected slice bounds to be integer, received: {}",
+                            bound_field.dtype
+                        )));
+                    }
+                }
+
+                let exploded_field = input_field.to_exploded_field()?;
+                Ok(Field::new(
+                    input_field.name,
+                    DataType::List(Box::new(exploded_field.dtype)),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input, start, end] => Ok(input.list_slice(start, end)?),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
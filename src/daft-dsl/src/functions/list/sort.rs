@@ -0,0 +1,47 @@
+use crate::ExprRef;
+use daft_core::{datatypes::Field, schema::Schema, series::Series};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, ListExpr};
+
+pub(super) struct SortEvaluator {}
+
+impl FunctionEvaluator for SortEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "sort"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let _exploded_field = input_field.to_exploded_field()?;
+
+                Ok(input_field)
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let descending = match expr {
+                    FunctionExpr::List(ListExpr::Sort(descending)) => *descending,
+                    _ => panic!("Expected List Sort Expr, got {expr}"),
+                };
+
+                Ok(input.list_sort(descending)?)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
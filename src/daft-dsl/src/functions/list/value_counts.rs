@@ -0,0 +1,50 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct ValueCountsEvaluator {}
+
+impl FunctionEvaluator for ValueCountsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "value_counts"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let exploded_field = input_field.to_exploded_field()?;
+
+                Ok(Field::new(
+                    input_field.name,
+                    DataType::Map(Box::new(DataType::Struct(vec![
+                        Field::new("key", exploded_field.dtype),
+                        Field::new("value", DataType::UInt64),
+                    ]))),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => Ok(input.list_value_counts()?),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
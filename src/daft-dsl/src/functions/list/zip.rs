@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct ZipEvaluator {}
+
+impl FunctionEvaluator for ZipEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "zip"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        if inputs.is_empty() {
+            return Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg, got 0".to_string(),
+            ));
+        }
+        let fields = inputs
+            .iter()
+            .map(|input| {
+                let input_field = input.to_field(schema)?;
+                let exploded_field = input_field.to_exploded_field()?;
+                Ok(Field::new(input_field.name, exploded_field.dtype))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mut seen = HashSet::new();
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !seen.insert(field.name.as_str()))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Cannot zip lists with duplicate name: {}",
+                field.name
+            )));
+        }
+        Ok(Field::new(
+            fields[0].name.as_str(),
+            DataType::List(Box::new(DataType::Struct(fields))),
+        ))
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs.first() {
+            Some(first) => Series::list_zip(first.name(), inputs),
+            None => Err(DaftError::ValueError(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+        }
+    }
+}
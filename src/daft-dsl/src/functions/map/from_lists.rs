@@ -0,0 +1,58 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct FromListsEvaluator {}
+
+impl FunctionEvaluator for FromListsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "map_from_lists"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [keys, values] => {
+                let keys_field = keys.to_field(schema)?;
+                let values_field = values.to_field(schema)?;
+                match (&keys_field.dtype, &values_field.dtype) {
+                    (DataType::List(key_dtype), DataType::List(value_dtype)) => {
+                        let entries = DataType::Struct(vec![
+                            Field::new("key", key_dtype.as_ref().clone()),
+                            Field::new("value", value_dtype.as_ref().clone()),
+                        ]);
+                        Ok(Field::new(
+                            keys_field.name,
+                            DataType::Map(Box::new(entries)),
+                        ))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected keys and values of a map to be lists, received: {} and {}",
+                        keys_field.dtype, values_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [keys, values] => keys.list_to_map(values),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
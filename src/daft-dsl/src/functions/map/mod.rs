@@ -0,0 +1,86 @@
+mod from_lists;
+mod get;
+mod keys;
+mod values;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::datatypes::{DataType, Field};
+use from_lists::FromListsEvaluator;
+use get::GetEvaluator;
+use keys::KeysEvaluator;
+use serde::{Deserialize, Serialize};
+use values::ValuesEvaluator;
+
+use crate::{Expr, ExprRef};
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MapExpr {
+    Get,
+    Keys,
+    Values,
+    FromLists,
+}
+
+impl MapExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use MapExpr::*;
+        match self {
+            Get => &GetEvaluator {},
+            Keys => &KeysEvaluator {},
+            Values => &ValuesEvaluator {},
+            FromLists => &FromListsEvaluator {},
+        }
+    }
+}
+
+/// Gets the key and value fields of the entries of a map type.
+fn key_and_value_fields(field: &Field) -> DaftResult<(&Field, &Field)> {
+    match &field.dtype {
+        DataType::Map(entries) => match entries.as_ref() {
+            DataType::Struct(fields) if fields.len() == 2 => Ok((&fields[0], &fields[1])),
+            _ => Err(DaftError::TypeError(format!(
+                "Expected map entries to be a struct of a key and a value, received: {}",
+                entries
+            ))),
+        },
+        _ => Err(DaftError::TypeError(format!(
+            "Expected input to be a map type, received: {}",
+            field.dtype
+        ))),
+    }
+}
+
+pub fn get(input: ExprRef, key: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Map(MapExpr::Get),
+        inputs: vec![input, key],
+    }
+    .into()
+}
+
+pub fn keys(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Map(MapExpr::Keys),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn values(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Map(MapExpr::Values),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn from_lists(keys: ExprRef, values: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Map(MapExpr::FromLists),
+        inputs: vec![keys, values],
+    }
+    .into()
+}
@@ -0,0 +1,71 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, MinHashExpr};
+
+pub(super) struct LshBandsEvaluator {}
+
+impl FunctionEvaluator for LshBandsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "lsh_bands"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let num_bands = match expr {
+                    FunctionExpr::MinHash(MinHashExpr::LshBands { num_bands }) => *num_bands,
+                    _ => panic!("Expected MinHash LshBands Expr, got {expr}"),
+                };
+                let input_field = input.to_field(schema)?;
+                match &input_field.dtype {
+                    DataType::FixedSizeList(child, size) if child.as_ref() == &DataType::UInt32 => {
+                        if num_bands == 0 || size % num_bands != 0 {
+                            return Err(DaftError::ValueError(format!(
+                                "Expected the number of LSH bands to be a positive divisor of the signature length {size}, but received {num_bands}"
+                            )));
+                        }
+                        Ok(Field::new(
+                            input_field.name,
+                            DataType::FixedSizeList(Box::new(DataType::UInt64), num_bands),
+                        ))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to lsh_bands to be a fixed size list of UInt32 such as a minhash signature, but received {input_field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::MinHash(MinHashExpr::LshBands { num_bands }) => {
+                    input.lsh_bands(*num_bands)
+                }
+                _ => panic!("Expected MinHash LshBands Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
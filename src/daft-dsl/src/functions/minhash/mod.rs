@@ -0,0 +1,54 @@
+mod lsh_bands;
+mod signature;
+
+use lsh_bands::LshBandsEvaluator;
+use serde::{Deserialize, Serialize};
+use signature::SignatureEvaluator;
+
+use crate::{Expr, ExprRef};
+
+use super::FunctionEvaluator;
+
+/// Functions for near-duplicate detection with MinHash and locality-sensitive hashing.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MinHashExpr {
+    Signature {
+        num_hashes: usize,
+        ngram_size: usize,
+        seed: u32,
+    },
+    LshBands {
+        num_bands: usize,
+    },
+}
+
+impl MinHashExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use MinHashExpr::*;
+        match self {
+            Signature { .. } => &SignatureEvaluator {},
+            LshBands { .. } => &LshBandsEvaluator {},
+        }
+    }
+}
+
+pub fn minhash(input: ExprRef, num_hashes: usize, ngram_size: usize, seed: u32) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::MinHash(MinHashExpr::Signature {
+            num_hashes,
+            ngram_size,
+            seed,
+        }),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn lsh_bands(input: ExprRef, num_bands: usize) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::MinHash(MinHashExpr::LshBands { num_bands }),
+        inputs: vec![input],
+    }
+    .into()
+}
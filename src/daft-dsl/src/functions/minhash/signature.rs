@@ -0,0 +1,66 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, MinHashExpr};
+
+pub(super) struct SignatureEvaluator {}
+
+impl FunctionEvaluator for SignatureEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "minhash"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let num_hashes = match expr {
+                    FunctionExpr::MinHash(MinHashExpr::Signature { num_hashes, .. }) => *num_hashes,
+                    _ => panic!("Expected MinHash Signature Expr, got {expr}"),
+                };
+                let input_field = input.to_field(schema)?;
+                match &input_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        input_field.name,
+                        DataType::FixedSizeList(Box::new(DataType::UInt32), num_hashes),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to minhash to be utf8, but received {input_field}",
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::MinHash(MinHashExpr::Signature {
+                    num_hashes,
+                    ngram_size,
+                    seed,
+                }) => input.minhash(*num_hashes, *ngram_size, *seed),
+                _ => panic!("Expected MinHash Signature Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
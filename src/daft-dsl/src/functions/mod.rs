@@ -1,9 +1,14 @@
 pub mod float;
+pub mod hashing;
 pub mod image;
 pub mod json;
 pub mod list;
+pub mod map;
+pub mod minhash;
+pub mod moments;
 pub mod numeric;
 pub mod partitioning;
+pub mod sketch;
 pub mod struct_;
 pub mod temporal;
 pub mod uri;
@@ -13,11 +18,16 @@ use std::fmt::{Display, Formatter, Result};
 
 use crate::ExprRef;
 
+use self::hashing::HashingExpr;
 use self::image::ImageExpr;
 use self::json::JsonExpr;
 use self::list::ListExpr;
+use self::map::MapExpr;
+use self::minhash::MinHashExpr;
+use self::moments::MomentsExpr;
 use self::numeric::NumericExpr;
 use self::partitioning::PartitioningExpr;
+use self::sketch::SketchExpr;
 use self::struct_::StructExpr;
 use self::temporal::TemporalExpr;
 use self::utf8::Utf8Expr;
@@ -39,6 +49,7 @@ pub enum FunctionExpr {
     Utf8(Utf8Expr),
     Temporal(TemporalExpr),
     List(ListExpr),
+    Map(MapExpr),
     Struct(StructExpr),
     Json(JsonExpr),
     Image(ImageExpr),
@@ -46,6 +57,10 @@ pub enum FunctionExpr {
     Python(PythonUDF),
     Partitioning(PartitioningExpr),
     Uri(UriExpr),
+    Sketch(SketchExpr),
+    Moments(MomentsExpr),
+    MinHash(MinHashExpr),
+    Hashing(HashingExpr),
 }
 
 pub trait FunctionEvaluator {
@@ -69,6 +84,7 @@ impl FunctionExpr {
             Utf8(expr) => expr.get_evaluator(),
             Temporal(expr) => expr.get_evaluator(),
             List(expr) => expr.get_evaluator(),
+            Map(expr) => expr.get_evaluator(),
             Struct(expr) => expr.get_evaluator(),
             Json(expr) => expr.query_evaluator(),
             Image(expr) => expr.get_evaluator(),
@@ -76,6 +92,10 @@ impl FunctionExpr {
             #[cfg(feature = "python")]
             Python(expr) => expr,
             Partitioning(expr) => expr.get_evaluator(),
+            Sketch(expr) => expr.get_evaluator(),
+            Moments(expr) => expr.get_evaluator(),
+            MinHash(expr) => expr.get_evaluator(),
+            Hashing(expr) => expr.get_evaluator(),
         }
     }
 }
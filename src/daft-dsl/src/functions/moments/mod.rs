@@ -0,0 +1,40 @@
+mod statistic;
+
+use serde::{Deserialize, Serialize};
+use statistic::StatisticEvaluator;
+
+use crate::{Expr, ExprRef};
+
+use super::FunctionEvaluator;
+
+/// A statistic computed from the co-moments built by [`crate::AggExpr::CoMoments`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum MomentsExpr {
+    Variance { ddof: u64 },
+    Stddev { ddof: u64 },
+    Covariance { ddof: u64 },
+    Correlation,
+}
+
+impl MomentsExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use MomentsExpr::*;
+        match self {
+            Variance { .. } => &StatisticEvaluator { name: "variance" },
+            Stddev { .. } => &StatisticEvaluator { name: "stddev" },
+            Covariance { .. } => &StatisticEvaluator { name: "covariance" },
+            Correlation => &StatisticEvaluator {
+                name: "correlation",
+            },
+        }
+    }
+}
+
+pub fn statistic(input: ExprRef, statistic: MomentsExpr) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Moments(statistic),
+        inputs: vec![input],
+    }
+    .into()
+}
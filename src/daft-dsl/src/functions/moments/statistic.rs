@@ -0,0 +1,60 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, MomentsExpr};
+
+pub(super) struct StatisticEvaluator {
+    pub name: &'static str,
+}
+
+impl FunctionEvaluator for StatisticEvaluator {
+    fn fn_name(&self) -> &'static str {
+        self.name
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                match input_field.dtype {
+                    DataType::Struct(_) => Ok(Field::new(input_field.name, DataType::Float64)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to {} to be co-moments of struct type, received: {}",
+                        self.name, input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => match expr {
+                FunctionExpr::Moments(MomentsExpr::Variance { ddof })
+                | FunctionExpr::Moments(MomentsExpr::Covariance { ddof }) => {
+                    input.comoments_covariance(*ddof)
+                }
+                FunctionExpr::Moments(MomentsExpr::Stddev { ddof }) => {
+                    input.comoments_stddev(*ddof)
+                }
+                FunctionExpr::Moments(MomentsExpr::Correlation) => input.comoments_correlation(),
+                _ => panic!("Expected Moments Expr, got {expr}"),
+            },
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+mod percentile;
+
+use daft_core::utils::hashable_float_wrapper::FloatWrapper;
+use percentile::PercentileEvaluator;
+use serde::{Deserialize, Serialize};
+
+use crate::{Expr, ExprRef};
+
+use super::FunctionEvaluator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum SketchExpr {
+    Percentile {
+        percentiles: Vec<FloatWrapper<f64>>,
+        force_list_output: bool,
+    },
+}
+
+impl SketchExpr {
+    #[inline]
+    pub fn get_evaluator(&self) -> &dyn FunctionEvaluator {
+        use SketchExpr::*;
+        match self {
+            Percentile { .. } => &PercentileEvaluator {},
+        }
+    }
+}
+
+pub fn sketch_percentile(input: ExprRef, percentiles: &[f64], force_list_output: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Sketch(SketchExpr::Percentile {
+            percentiles: percentiles.iter().map(|p| FloatWrapper(*p)).collect(),
+            force_list_output,
+        }),
+        inputs: vec![input],
+    }
+    .into()
+}
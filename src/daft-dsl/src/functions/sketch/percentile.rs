@@ -0,0 +1,79 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, SketchExpr};
+
+pub(super) struct PercentileEvaluator {}
+
+impl FunctionEvaluator for PercentileEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "sketch_percentile"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let (percentiles, force_list_output) = match expr {
+                    FunctionExpr::Sketch(SketchExpr::Percentile {
+                        percentiles,
+                        force_list_output,
+                    }) => (percentiles, *force_list_output),
+                    _ => panic!("Expected Sketch Percentile Expr, got {expr}"),
+                };
+
+                match input_field.dtype {
+                    DataType::Struct(_) => {
+                        let dtype = if percentiles.len() == 1 && !force_list_output {
+                            DataType::Float64
+                        } else {
+                            DataType::List(Box::new(DataType::Float64))
+                        };
+                        Ok(Field::new(input_field.name, dtype))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a sketch of struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let (percentiles, force_list_output) = match expr {
+                    FunctionExpr::Sketch(SketchExpr::Percentile {
+                        percentiles,
+                        force_list_output,
+                    }) => (percentiles, *force_list_output),
+                    _ => panic!("Expected Sketch Percentile Expr, got {expr}"),
+                };
+                let percentiles = percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+
+                input.sketch_percentile(&percentiles, force_list_output)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
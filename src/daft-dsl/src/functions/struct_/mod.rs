@@ -1,7 +1,13 @@
 mod get;
+mod pack;
+mod rename_fields;
+mod with_field;
 
 use get::GetEvaluator;
+use pack::PackEvaluator;
+use rename_fields::RenameFieldsEvaluator;
 use serde::{Deserialize, Serialize};
+use with_field::WithFieldEvaluator;
 
 use crate::{Expr, ExprRef};
 
@@ -10,6 +16,9 @@ use super::FunctionEvaluator;
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum StructExpr {
     Get(String),
+    Pack,
+    RenameFields(Vec<String>),
+    WithField(String),
 }
 
 impl StructExpr {
@@ -18,6 +27,9 @@ impl StructExpr {
         use StructExpr::*;
         match self {
             Get(_) => &GetEvaluator {},
+            Pack => &PackEvaluator {},
+            RenameFields(_) => &RenameFieldsEvaluator {},
+            WithField(_) => &WithFieldEvaluator {},
         }
     }
 }
@@ -29,3 +41,27 @@ pub fn get(input: ExprRef, name: &str) -> ExprRef {
     }
     .into()
 }
+
+pub fn pack(inputs: Vec<ExprRef>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::Pack),
+        inputs,
+    }
+    .into()
+}
+
+pub fn rename_fields(input: ExprRef, names: Vec<String>) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::RenameFields(names)),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn with_field(input: ExprRef, name: &str, value: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Struct(StructExpr::WithField(name.to_string())),
+        inputs: vec![input, value],
+    }
+    .into()
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct PackEvaluator {}
+
+impl FunctionEvaluator for PackEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "pack"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        if inputs.is_empty() {
+            return Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input arg, got 0".to_string(),
+            ));
+        }
+        let fields = inputs
+            .iter()
+            .map(|input| input.to_field(schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mut seen = HashSet::new();
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !seen.insert(field.name.as_str()))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Cannot pack struct with duplicate field name: {}",
+                field.name
+            )));
+        }
+        Ok(Field::new(
+            fields[0].name.as_str(),
+            DataType::Struct(fields),
+        ))
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs.first() {
+            Some(first) => Series::struct_pack(first.name(), inputs),
+            None => Err(DaftError::ValueError(
+                "Expected at least 1 input arg, got 0".to_string(),
+            )),
+        }
+    }
+}
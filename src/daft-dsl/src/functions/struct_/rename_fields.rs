@@ -0,0 +1,78 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, StructExpr};
+
+pub(super) struct RenameFieldsEvaluator {}
+
+impl FunctionEvaluator for RenameFieldsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "rename_fields"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let names = match expr {
+                    FunctionExpr::Struct(StructExpr::RenameFields(names)) => names,
+                    _ => panic!("Expected Struct RenameFields Expr, got {expr}"),
+                };
+
+                match input_field.dtype {
+                    DataType::Struct(fields) if fields.len() == names.len() => {
+                        let fields = fields
+                            .into_iter()
+                            .zip(names)
+                            .map(|(field, name)| Field::new(name, field.dtype))
+                            .collect();
+                        Ok(Field::new(input_field.name, DataType::Struct(fields)))
+                    }
+                    DataType::Struct(fields) => Err(DaftError::ValueError(format!(
+                        "Expected {} field names to rename struct {} but received {}",
+                        fields.len(),
+                        input_field.name,
+                        names.len()
+                    ))),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let names = match expr {
+                    FunctionExpr::Struct(StructExpr::RenameFields(names)) => names,
+                    _ => panic!("Expected Struct RenameFields Expr, got {expr}"),
+                };
+
+                input.struct_rename_fields(names)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ExprRef;
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, StructExpr};
+
+pub(super) struct WithFieldEvaluator {}
+
+impl FunctionEvaluator for WithFieldEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "with_field"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        match inputs {
+            [input, value] => {
+                let input_field = input.to_field(schema)?;
+                let value_field = value.to_field(schema)?;
+                let name = match expr {
+                    FunctionExpr::Struct(StructExpr::WithField(name)) => name,
+                    _ => panic!("Expected Struct WithField Expr, got {expr}"),
+                };
+
+                match input_field.dtype {
+                    DataType::Struct(mut fields) => {
+                        let new_field = Field::new(name, value_field.dtype);
+                        match fields.iter_mut().find(|f| f.name == *name) {
+                            Some(field) => *field = new_field,
+                            None => fields.push(new_field),
+                        }
+                        Ok(Field::new(input_field.name, DataType::Struct(fields)))
+                    }
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expected input to be a struct type, received: {}",
+                        input_field.dtype
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [input, value] => {
+                let name = match expr {
+                    FunctionExpr::Struct(StructExpr::WithField(name)) => name,
+                    _ => panic!("Expected Struct WithField Expr, got {expr}"),
+                };
+
+                input.struct_with_field(name, value)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
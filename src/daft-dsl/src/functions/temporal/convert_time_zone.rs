@@ -0,0 +1,66 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+
+use super::{super::FunctionEvaluator, TemporalExpr};
+
+pub(super) struct ConvertTimeZoneEvaluator {}
+
+impl FunctionEvaluator for ConvertTimeZoneEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "convert_time_zone"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        let timezone = match expr {
+            FunctionExpr::Temporal(TemporalExpr::ConvertTimeZone(timezone)) => timezone,
+            _ => panic!("Expected ConvertTimeZone Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(Field {
+                    name,
+                    dtype: DataType::Timestamp(timeunit, Some(_)),
+                    ..
+                }) => Ok(Field::new(
+                    name,
+                    DataType::Timestamp(timeunit, Some(timezone.clone())),
+                )),
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to convert_time_zone to be a timezone-aware timestamp, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        let timezone = match expr {
+            FunctionExpr::Temporal(TemporalExpr::ConvertTimeZone(timezone)) => timezone,
+            _ => panic!("Expected ConvertTimeZone Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => input.dt_convert_time_zone(timezone),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -20,11 +20,13 @@ impl FunctionEvaluator for HourEvaluator {
     fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
         match inputs {
             [input] => match input.to_field(schema) {
-                Ok(field) if field.dtype.is_temporal() => {
+                Ok(field)
+                    if matches!(field.dtype, DataType::Timestamp(..) | DataType::Time(..)) =>
+                {
                     Ok(Field::new(field.name, DataType::UInt32))
                 }
                 Ok(field) => Err(DaftError::TypeError(format!(
-                    "Expected input to hour to be temporal, got {}",
+                    "Expected input to hour to be a timestamp or time, got {}",
                     field.dtype
                 ))),
                 Err(e) => Err(e),
@@ -1,16 +1,30 @@
+mod convert_time_zone;
 mod date;
 mod day;
 mod day_of_week;
+mod day_of_year;
 mod hour;
+mod millisecond;
+mod minute;
 mod month;
+mod quarter;
+mod replace_time_zone;
+mod second;
+mod strftime;
+mod time;
 mod truncate;
+mod week_of_year;
 mod year;
 
 use serde::{Deserialize, Serialize};
 
 use crate::functions::temporal::{
-    date::DateEvaluator, day::DayEvaluator, day_of_week::DayOfWeekEvaluator, hour::HourEvaluator,
-    month::MonthEvaluator, truncate::TruncateEvaluator, year::YearEvaluator,
+    convert_time_zone::ConvertTimeZoneEvaluator, date::DateEvaluator, day::DayEvaluator,
+    day_of_week::DayOfWeekEvaluator, day_of_year::DayOfYearEvaluator, hour::HourEvaluator,
+    millisecond::MillisecondEvaluator, minute::MinuteEvaluator, month::MonthEvaluator,
+    quarter::QuarterEvaluator, replace_time_zone::ReplaceTimeZoneEvaluator,
+    second::SecondEvaluator, strftime::StrftimeEvaluator, time::TimeEvaluator,
+    truncate::TruncateEvaluator, week_of_year::WeekOfYearEvaluator, year::YearEvaluator,
 };
 use crate::{Expr, ExprRef};
 
@@ -24,7 +38,20 @@ pub enum TemporalExpr {
     Year,
     DayOfWeek,
     Date,
+    Minute,
+    Second,
+    Millisecond,
+    DayOfYear,
+    WeekOfYear,
+    Quarter,
+    Time,
     Truncate(String),
+    ConvertTimeZone(String),
+    ReplaceTimeZone {
+        timezone: Option<String>,
+        ambiguous: String,
+    },
+    Strftime(String),
 }
 
 impl TemporalExpr {
@@ -38,7 +65,17 @@ impl TemporalExpr {
             Year => &YearEvaluator {},
             DayOfWeek => &DayOfWeekEvaluator {},
             Date => &DateEvaluator {},
+            Minute => &MinuteEvaluator {},
+            Second => &SecondEvaluator {},
+            Millisecond => &MillisecondEvaluator {},
+            DayOfYear => &DayOfYearEvaluator {},
+            WeekOfYear => &WeekOfYearEvaluator {},
+            Quarter => &QuarterEvaluator {},
+            Time => &TimeEvaluator {},
             Truncate(..) => &TruncateEvaluator {},
+            ConvertTimeZone(..) => &ConvertTimeZoneEvaluator {},
+            ReplaceTimeZone { .. } => &ReplaceTimeZoneEvaluator {},
+            Strftime(..) => &StrftimeEvaluator {},
         }
     }
 }
@@ -91,9 +128,92 @@ pub fn day_of_week(input: ExprRef) -> ExprRef {
     .into()
 }
 
+pub fn minute(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Minute),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn second(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Second),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn millisecond(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Millisecond),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn day_of_year(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::DayOfYear),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn week_of_year(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::WeekOfYear),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn quarter(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Quarter),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn time(input: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Time),
+        inputs: vec![input],
+    }
+    .into()
+}
+
 pub fn truncate(input: ExprRef, freq: &str, relative_to: ExprRef) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::Temporal(TemporalExpr::Truncate(freq.to_string())),
         inputs: vec![input, relative_to],
     }
 }
+
+pub fn convert_time_zone(input: ExprRef, timezone: &str) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::ConvertTimeZone(timezone.to_string())),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn replace_time_zone(input: ExprRef, timezone: Option<&str>, ambiguous: &str) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::ReplaceTimeZone {
+            timezone: timezone.map(|tz| tz.to_string()),
+            ambiguous: ambiguous.to_string(),
+        }),
+        inputs: vec![input],
+    }
+    .into()
+}
+
+pub fn strftime(input: ExprRef, format: &str) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Temporal(TemporalExpr::Strftime(format.to_string())),
+        inputs: vec![input],
+    }
+    .into()
+}
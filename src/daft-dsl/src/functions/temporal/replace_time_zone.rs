@@ -0,0 +1,69 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+
+use super::{super::FunctionEvaluator, TemporalExpr};
+
+pub(super) struct ReplaceTimeZoneEvaluator {}
+
+impl FunctionEvaluator for ReplaceTimeZoneEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "replace_time_zone"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        let timezone = match expr {
+            FunctionExpr::Temporal(TemporalExpr::ReplaceTimeZone { timezone, .. }) => timezone,
+            _ => panic!("Expected ReplaceTimeZone Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(Field {
+                    name,
+                    dtype: DataType::Timestamp(timeunit, _),
+                    ..
+                }) => Ok(Field::new(
+                    name,
+                    DataType::Timestamp(timeunit, timezone.clone()),
+                )),
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to replace_time_zone to be a timestamp, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        let (timezone, ambiguous) = match expr {
+            FunctionExpr::Temporal(TemporalExpr::ReplaceTimeZone {
+                timezone,
+                ambiguous,
+            }) => (timezone, ambiguous),
+            _ => panic!("Expected ReplaceTimeZone Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => input.dt_replace_time_zone(timezone.as_deref(), ambiguous),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
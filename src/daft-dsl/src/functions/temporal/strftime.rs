@@ -0,0 +1,52 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+
+use super::{super::FunctionEvaluator, TemporalExpr};
+
+pub(super) struct StrftimeEvaluator {}
+
+impl FunctionEvaluator for StrftimeEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(field) if matches!(field.dtype, DataType::Date | DataType::Timestamp(..)) => {
+                    Ok(Field::new(field.name, DataType::Utf8))
+                }
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to strftime to be a date or timestamp, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        let format = match expr {
+            FunctionExpr::Temporal(TemporalExpr::Strftime(format)) => format,
+            _ => panic!("Expected Strftime Expr, got {expr}"),
+        };
+        match inputs {
+            [input] => input.dt_strftime(format),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
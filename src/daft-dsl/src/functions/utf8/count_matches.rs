@@ -0,0 +1,58 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::ExprRef;
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, Utf8Expr};
+
+pub(super) struct CountMatchesEvaluator {}
+
+impl FunctionEvaluator for CountMatchesEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "count_matches"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [data, pattern] => match (data.to_field(schema), pattern.to_field(schema)) {
+                (Ok(data_field), Ok(pattern_field)) => {
+                    match (&data_field.dtype, &pattern_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::UInt64))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to count_matches to be utf8, but received {data_field} and {pattern_field}",
+                        ))),
+                    }
+                }
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data, pattern] => {
+                let regex = match expr {
+                    FunctionExpr::Utf8(Utf8Expr::CountMatches(regex)) => regex,
+                    _ => panic!("Expected Utf8 CountMatches Expr, got {expr}"),
+                };
+                data.utf8_count_matches(pattern, *regex)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -1,32 +1,51 @@
 mod capitalize;
 mod contains;
+mod count_matches;
 mod endswith;
 mod extract;
 mod extract_all;
 mod find;
 mod left;
 mod length;
+mod like;
 mod lower;
 mod lstrip;
 mod match_;
+mod ngrams;
+mod normalize;
+mod pad;
+mod repeat;
 mod replace;
 mod reverse;
 mod right;
 mod rstrip;
 mod split;
 mod startswith;
+mod strip_chars;
+mod substr;
+mod to_date;
+mod to_datetime;
+mod tokenize;
 mod upper;
+mod word_count;
 
 use capitalize::CapitalizeEvaluator;
 use contains::ContainsEvaluator;
+use count_matches::CountMatchesEvaluator;
+use daft_core::array::ops::Utf8NormalizeForm;
 use endswith::EndswithEvaluator;
 use extract::ExtractEvaluator;
 use extract_all::ExtractAllEvaluator;
 use find::FindEvaluator;
 use left::LeftEvaluator;
 use length::LengthEvaluator;
+use like::LikeEvaluator;
 use lower::LowerEvaluator;
 use lstrip::LstripEvaluator;
+use ngrams::NGramsEvaluator;
+use normalize::NormalizeEvaluator;
+use pad::PadEvaluator;
+use repeat::RepeatEvaluator;
 use replace::ReplaceEvaluator;
 use reverse::ReverseEvaluator;
 use right::RightEvaluator;
@@ -34,7 +53,13 @@ use rstrip::RstripEvaluator;
 use serde::{Deserialize, Serialize};
 use split::SplitEvaluator;
 use startswith::StartswithEvaluator;
+use strip_chars::StripCharsEvaluator;
+use substr::SubstrEvaluator;
+use to_date::ToDateEvaluator;
+use to_datetime::ToDatetimeEvaluator;
+use tokenize::TokenizeEvaluator;
 use upper::UpperEvaluator;
+use word_count::WordCountEvaluator;
 
 use crate::{functions::utf8::match_::MatchEvaluator, Expr, ExprRef};
 
@@ -60,6 +85,33 @@ pub enum Utf8Expr {
     Left,
     Right,
     Find,
+    Lpad,
+    Rpad,
+    Repeat,
+    Substr,
+    Normalize(Utf8NormalizeForm),
+    CountMatches(bool),
+    Like,
+    Ilike,
+    StripChars {
+        left: bool,
+        right: bool,
+    },
+    Tokenize(String),
+    NGrams {
+        n: usize,
+        chars: bool,
+    },
+    WordCount,
+    ToDate {
+        format: String,
+        raise_error_on_failure: bool,
+    },
+    ToDatetime {
+        format: String,
+        timezone: Option<String>,
+        raise_error_on_failure: bool,
+    },
 }
 
 impl Utf8Expr {
@@ -85,6 +137,18 @@ impl Utf8Expr {
             Left => &LeftEvaluator {},
             Right => &RightEvaluator {},
             Find => &FindEvaluator {},
+            Lpad | Rpad => &PadEvaluator {},
+            Repeat => &RepeatEvaluator {},
+            Substr => &SubstrEvaluator {},
+            Normalize(_) => &NormalizeEvaluator {},
+            CountMatches(_) => &CountMatchesEvaluator {},
+            Like | Ilike => &LikeEvaluator {},
+            StripChars { .. } => &StripCharsEvaluator {},
+            Tokenize(_) => &TokenizeEvaluator {},
+            NGrams { .. } => &NGramsEvaluator {},
+            WordCount => &WordCountEvaluator {},
+            ToDate { .. } => &ToDateEvaluator {},
+            ToDatetime { .. } => &ToDatetimeEvaluator {},
         }
     }
 }
@@ -232,3 +296,127 @@ pub fn find(data: ExprRef, pattern: ExprRef) -> ExprRef {
     }
     .into()
 }
+
+pub fn lpad(data: ExprRef, length: ExprRef, pad: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Lpad),
+        inputs: vec![data, length, pad],
+    }
+    .into()
+}
+
+pub fn rpad(data: ExprRef, length: ExprRef, pad: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Rpad),
+        inputs: vec![data, length, pad],
+    }
+    .into()
+}
+
+pub fn repeat(data: ExprRef, n: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Repeat),
+        inputs: vec![data, n],
+    }
+    .into()
+}
+
+pub fn substr(data: ExprRef, start: ExprRef, length: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Substr),
+        inputs: vec![data, start, length],
+    }
+    .into()
+}
+
+pub fn normalize(data: ExprRef, form: Utf8NormalizeForm) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Normalize(form)),
+        inputs: vec![data],
+    }
+    .into()
+}
+
+pub fn count_matches(data: ExprRef, pattern: ExprRef, regex: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::CountMatches(regex)),
+        inputs: vec![data, pattern],
+    }
+    .into()
+}
+
+pub fn like(data: ExprRef, pattern: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Like),
+        inputs: vec![data, pattern],
+    }
+    .into()
+}
+
+pub fn ilike(data: ExprRef, pattern: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Ilike),
+        inputs: vec![data, pattern],
+    }
+    .into()
+}
+
+pub fn strip_chars(data: ExprRef, chars: ExprRef, left: bool, right: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::StripChars { left, right }),
+        inputs: vec![data, chars],
+    }
+    .into()
+}
+
+pub fn tokenize(data: ExprRef, pattern: &str) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::Tokenize(pattern.to_string())),
+        inputs: vec![data],
+    }
+    .into()
+}
+
+pub fn ngrams(data: ExprRef, n: usize, chars: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::NGrams { n, chars }),
+        inputs: vec![data],
+    }
+    .into()
+}
+
+pub fn word_count(data: ExprRef) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::WordCount),
+        inputs: vec![data],
+    }
+    .into()
+}
+
+pub fn to_date(data: ExprRef, format: &str, raise_error_on_failure: bool) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::ToDate {
+            format: format.to_string(),
+            raise_error_on_failure,
+        }),
+        inputs: vec![data],
+    }
+    .into()
+}
+
+pub fn to_datetime(
+    data: ExprRef,
+    format: &str,
+    timezone: Option<&str>,
+    raise_error_on_failure: bool,
+) -> ExprRef {
+    Expr::Function {
+        func: super::FunctionExpr::Utf8(Utf8Expr::ToDatetime {
+            format: format.to_string(),
+            timezone: timezone.map(|tz| tz.to_string()),
+            raise_error_on_failure,
+        }),
+        inputs: vec![data],
+    }
+    .into()
+}
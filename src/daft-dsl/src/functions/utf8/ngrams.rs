@@ -0,0 +1,56 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, Utf8Expr};
+
+pub(super) struct NGramsEvaluator {}
+
+impl FunctionEvaluator for NGramsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "ngrams"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        data_field.name,
+                        DataType::List(Box::new(DataType::Utf8)),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to ngrams to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let (n, chars) = match expr {
+                    FunctionExpr::Utf8(Utf8Expr::NGrams { n, chars }) => (n, chars),
+                    _ => panic!("Expected Utf8 NGrams Expr, got {expr}"),
+                };
+                data.utf8_ngrams(*n, *chars)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::ExprRef;
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, Utf8Expr};
+
+pub(super) struct StripCharsEvaluator {}
+
+impl FunctionEvaluator for StripCharsEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "strip_chars"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [data, chars] => {
+                match (data.to_field(schema), chars.to_field(schema)) {
+                    (Ok(data_field), Ok(chars_field)) => {
+                        match (&data_field.dtype, &chars_field.dtype) {
+                        (DataType::Utf8, DataType::Utf8) => {
+                            Ok(Field::new(data_field.name, DataType::Utf8))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to strip to be utf8, but received {data_field} and {chars_field}",
+                        ))),
+                    }
+                    }
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data, chars] => {
+                let (left, right) = match expr {
+                    FunctionExpr::Utf8(Utf8Expr::StripChars { left, right }) => (left, right),
+                    _ => panic!("Expected Utf8 StripChars Expr, got {expr}"),
+                };
+                data.utf8_strip_chars(chars, *left, *right)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
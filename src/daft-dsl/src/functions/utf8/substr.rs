@@ -0,0 +1,59 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::ExprRef;
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::super::FunctionEvaluator;
+
+pub(super) struct SubstrEvaluator {}
+
+impl FunctionEvaluator for SubstrEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "substr"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [data, start, length] => match (
+                data.to_field(schema),
+                start.to_field(schema),
+                length.to_field(schema),
+            ) {
+                (Ok(data_field), Ok(start_field), Ok(length_field)) => {
+                    match (&data_field.dtype, &start_field.dtype, &length_field.dtype) {
+                        (DataType::Utf8, start_dt, length_dt)
+                            if start_dt.is_integer()
+                                && (length_dt.is_integer() || length_dt == &DataType::Null) =>
+                        {
+                            Ok(Field::new(data_field.name, DataType::Utf8))
+                        }
+                        _ => Err(DaftError::TypeError(format!(
+                            "Expects inputs to substr to be utf8 and integers, but received {data_field}, {start_field} and {length_field}",
+                        ))),
+                    }
+                }
+                (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], _: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data, start, length] => data.utf8_substr(start, length),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 3 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
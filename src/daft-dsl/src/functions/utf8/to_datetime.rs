@@ -0,0 +1,70 @@
+use daft_core::{
+    datatypes::{DataType, Field, TimeUnit},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::ExprRef;
+
+use crate::functions::FunctionExpr;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, Utf8Expr};
+
+pub(super) struct ToDatetimeEvaluator {}
+
+impl FunctionEvaluator for ToDatetimeEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "to_datetime"
+    }
+
+    fn to_field(
+        &self,
+        inputs: &[ExprRef],
+        schema: &Schema,
+        expr: &FunctionExpr,
+    ) -> DaftResult<Field> {
+        let timezone = match expr {
+            FunctionExpr::Utf8(Utf8Expr::ToDatetime { timezone, .. }) => timezone,
+            _ => panic!("Expected Utf8 ToDatetime Expr, got {expr}"),
+        };
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        data_field.name,
+                        DataType::Timestamp(TimeUnit::Microseconds, timezone.clone()),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to to_datetime to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let (format, timezone, raise_error_on_failure) = match expr {
+                    FunctionExpr::Utf8(Utf8Expr::ToDatetime {
+                        format,
+                        timezone,
+                        raise_error_on_failure,
+                    }) => (format, timezone, *raise_error_on_failure),
+                    _ => panic!("Expected Utf8 ToDatetime Expr, got {expr}"),
+                };
+                data.utf8_to_datetime(format, timezone.as_deref(), raise_error_on_failure)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use daft_core::{
+    datatypes::{DataType, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use crate::functions::FunctionExpr;
+use crate::ExprRef;
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, Utf8Expr};
+
+pub(super) struct TokenizeEvaluator {}
+
+impl FunctionEvaluator for TokenizeEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "tokenize"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema, _: &FunctionExpr) -> DaftResult<Field> {
+        match inputs {
+            [data] => match data.to_field(schema) {
+                Ok(data_field) => match &data_field.dtype {
+                    DataType::Utf8 => Ok(Field::new(
+                        data_field.name,
+                        DataType::List(Box::new(DataType::Utf8)),
+                    )),
+                    _ => Err(DaftError::TypeError(format!(
+                        "Expects input to tokenize to be utf8, but received {data_field}",
+                    ))),
+                },
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &FunctionExpr) -> DaftResult<Series> {
+        match inputs {
+            [data] => {
+                let pattern = match expr {
+                    FunctionExpr::Utf8(Utf8Expr::Tokenize(pattern)) => pattern,
+                    _ => panic!("Expected Utf8 Tokenize Expr, got {expr}"),
+                };
+                data.utf8_tokenize(pattern)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
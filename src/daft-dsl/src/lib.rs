@@ -12,7 +12,10 @@ mod treenode;
 pub use common_treenode;
 pub use expr::binary_op;
 pub use expr::col;
-pub use expr::{AggExpr, Expr, ExprRef, Operator};
+pub use expr::{element, ELEMENT_NAME};
+pub use expr::{
+    AggExpr, ApproxPercentileParams, Expr, ExprRef, Operator, SketchType, WindowExpr, WindowSpec,
+};
 pub use lit::{lit, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
@@ -23,6 +26,7 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
 
     parent.add_wrapped(wrap_pyfunction!(python::col))?;
     parent.add_wrapped(wrap_pyfunction!(python::lit))?;
+    parent.add_wrapped(wrap_pyfunction!(python::element))?;
     parent.add_wrapped(wrap_pyfunction!(python::date_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::time_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::timestamp_lit))?;
@@ -30,6 +34,10 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_wrapped(wrap_pyfunction!(python::series_lit))?;
     parent.add_wrapped(wrap_pyfunction!(python::udf))?;
     parent.add_wrapped(wrap_pyfunction!(python::eq))?;
+    parent.add_wrapped(wrap_pyfunction!(python::row_number))?;
+    parent.add_wrapped(wrap_pyfunction!(python::rank))?;
+    parent.add_wrapped(wrap_pyfunction!(python::dense_rank))?;
+    parent.add_wrapped(wrap_pyfunction!(python::struct_pack))?;
 
     Ok(())
 }
@@ -22,7 +22,7 @@ pub fn requires_computation(e: &Expr) -> bool {
     // Returns whether or not this expression runs any computation on the underlying data
     match e {
         Expr::Alias(child, _) => requires_computation(child),
-        Expr::Column(..) | Expr::Literal(_) => false,
+        Expr::Column(..) | Expr::Literal(_) | Expr::Element => false,
         Expr::Agg(..)
         | Expr::BinaryOp { .. }
         | Expr::Cast(..)
@@ -32,7 +32,8 @@ pub fn requires_computation(e: &Expr) -> bool {
         | Expr::NotNull(..)
         | Expr::FillNull(..)
         | Expr::IsIn { .. }
-        | Expr::IfElse { .. } => true,
+        | Expr::IfElse { .. }
+        | Expr::Window(..) => true,
     }
 }
 
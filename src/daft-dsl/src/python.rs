@@ -8,7 +8,7 @@ use daft_core::python::datatype::PyTimeUnit;
 use daft_core::python::PySeries;
 use serde::{Deserialize, Serialize};
 
-use crate::{functions, Expr, ExprRef, LiteralValue};
+use crate::{functions, Expr, ExprRef, LiteralValue, WindowExpr, WindowSpec};
 use daft_core::{
     count_mode::CountMode,
     datatypes::ImageFormat,
@@ -103,6 +103,11 @@ pub fn series_lit(series: PySeries) -> PyResult<PyExpr> {
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn element() -> PyResult<PyExpr> {
+    Ok(PyExpr::from(crate::element()))
+}
+
 #[pyfunction]
 pub fn lit(item: &PyAny) -> PyResult<PyExpr> {
     if item.is_instance_of::<PyBool>() {
@@ -164,6 +169,61 @@ pub fn udf(
     })
 }
 
+fn window_spec(
+    partition_by: Vec<PyExpr>,
+    order_by: Vec<PyExpr>,
+    descending: Vec<bool>,
+) -> PyResult<WindowSpec> {
+    if order_by.len() != descending.len() {
+        return Err(PyValueError::new_err(format!(
+            "order_by and descending length must match, got {} vs {}",
+            order_by.len(),
+            descending.len()
+        )));
+    }
+    Ok(WindowSpec::new(
+        partition_by.into_iter().map(|e| e.expr).collect(),
+        order_by.into_iter().map(|e| e.expr).collect(),
+        descending,
+    ))
+}
+
+#[pyfunction]
+pub fn row_number(
+    partition_by: Vec<PyExpr>,
+    order_by: Vec<PyExpr>,
+    descending: Vec<bool>,
+) -> PyResult<PyExpr> {
+    let spec = window_spec(partition_by, order_by, descending)?;
+    Ok(WindowExpr::RowNumber.over(spec).into())
+}
+
+#[pyfunction]
+pub fn rank(
+    partition_by: Vec<PyExpr>,
+    order_by: Vec<PyExpr>,
+    descending: Vec<bool>,
+) -> PyResult<PyExpr> {
+    let spec = window_spec(partition_by, order_by, descending)?;
+    Ok(WindowExpr::Rank.over(spec).into())
+}
+
+#[pyfunction]
+pub fn dense_rank(
+    partition_by: Vec<PyExpr>,
+    order_by: Vec<PyExpr>,
+    descending: Vec<bool>,
+) -> PyResult<PyExpr> {
+    let spec = window_spec(partition_by, order_by, descending)?;
+    Ok(WindowExpr::DenseRank.over(spec).into())
+}
+
+#[pyfunction]
+pub fn struct_pack(inputs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    use crate::functions::struct_::pack;
+    Ok(pack(inputs.into_iter().map(|e| e.expr).collect()).into())
+}
+
 #[pyclass(module = "daft.daft")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PyExpr {
@@ -299,6 +359,26 @@ impl PyExpr {
         Ok(self.expr.clone().mean().into())
     }
 
+    pub fn stddev(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.clone().stddev(ddof).into())
+    }
+
+    pub fn variance(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.clone().variance(ddof).into())
+    }
+
+    pub fn covariance(&self, other: &Self, ddof: u64) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .covariance(other.expr.clone(), ddof)
+            .into())
+    }
+
+    pub fn correlation(&self, other: &Self) -> PyResult<Self> {
+        Ok(self.expr.clone().correlation(other.expr.clone()).into())
+    }
+
     pub fn min(&self) -> PyResult<Self> {
         Ok(self.expr.clone().min().into())
     }
@@ -311,6 +391,42 @@ impl PyExpr {
         Ok(self.expr.clone().any_value(ignore_nulls).into())
     }
 
+    pub fn bool_and(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().bool_and().into())
+    }
+
+    pub fn bool_or(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().bool_or().into())
+    }
+
+    pub fn bit_and(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().bit_and().into())
+    }
+
+    pub fn bit_or(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().bit_or().into())
+    }
+
+    pub fn bit_xor(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().bit_xor().into())
+    }
+
+    pub fn approx_count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.clone().approx_count_distinct().into())
+    }
+
+    pub fn approx_percentiles(
+        &self,
+        percentiles: Vec<f64>,
+        force_list_output: bool,
+    ) -> PyResult<Self> {
+        Ok(self
+            .expr
+            .clone()
+            .approx_percentiles(percentiles.as_slice(), force_list_output)
+            .into())
+    }
+
     pub fn agg_list(&self) -> PyResult<Self> {
         Ok(self.expr.clone().agg_list().into())
     }
@@ -319,6 +435,70 @@ impl PyExpr {
         Ok(self.expr.clone().agg_concat().into())
     }
 
+    pub fn lag(
+        &self,
+        offset: usize,
+        default: &Self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let spec = window_spec(partition_by, order_by, descending)?;
+        Ok(self
+            .expr
+            .clone()
+            .lag(offset, default.expr.clone())
+            .over(spec)
+            .into())
+    }
+
+    pub fn lead(
+        &self,
+        offset: usize,
+        default: &Self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let spec = window_spec(partition_by, order_by, descending)?;
+        Ok(self
+            .expr
+            .clone()
+            .lead(offset, default.expr.clone())
+            .over(spec)
+            .into())
+    }
+
+    pub fn cum_sum(
+        &self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let spec = window_spec(partition_by, order_by, descending)?;
+        Ok(self.expr.clone().cum_sum().over(spec).into())
+    }
+
+    pub fn cum_min(
+        &self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let spec = window_spec(partition_by, order_by, descending)?;
+        Ok(self.expr.clone().cum_min().over(spec).into())
+    }
+
+    pub fn cum_max(
+        &self,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let spec = window_spec(partition_by, order_by, descending)?;
+        Ok(self.expr.clone().cum_max().over(spec).into())
+    }
+
     pub fn explode(&self) -> PyResult<Self> {
         use functions::list::explode;
         Ok(explode(self.into()).into())
@@ -402,6 +582,13 @@ impl PyExpr {
         Ok(self.expr.clone().is_in(other.expr.clone()).into())
     }
 
+    pub fn hash(&self, hash_function: &str, seed: Option<u64>) -> PyResult<Self> {
+        use crate::functions::hashing::hash;
+        use daft_core::array::ops::HashFunctionKind;
+        let function = hash_function.parse::<HashFunctionKind>()?;
+        Ok(hash(self.into(), function, seed).into())
+    }
+
     pub fn name(&self) -> PyResult<&str> {
         Ok(self.expr.name()?)
     }
@@ -459,11 +646,61 @@ impl PyExpr {
         Ok(day_of_week(self.into()).into())
     }
 
+    pub fn dt_minute(&self) -> PyResult<Self> {
+        use functions::temporal::minute;
+        Ok(minute(self.into()).into())
+    }
+
+    pub fn dt_second(&self) -> PyResult<Self> {
+        use functions::temporal::second;
+        Ok(second(self.into()).into())
+    }
+
+    pub fn dt_millisecond(&self) -> PyResult<Self> {
+        use functions::temporal::millisecond;
+        Ok(millisecond(self.into()).into())
+    }
+
+    pub fn dt_day_of_year(&self) -> PyResult<Self> {
+        use functions::temporal::day_of_year;
+        Ok(day_of_year(self.into()).into())
+    }
+
+    pub fn dt_week_of_year(&self) -> PyResult<Self> {
+        use functions::temporal::week_of_year;
+        Ok(week_of_year(self.into()).into())
+    }
+
+    pub fn dt_quarter(&self) -> PyResult<Self> {
+        use functions::temporal::quarter;
+        Ok(quarter(self.into()).into())
+    }
+
+    pub fn dt_time(&self) -> PyResult<Self> {
+        use functions::temporal::time;
+        Ok(time(self.into()).into())
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> PyResult<Self> {
         use functions::temporal::truncate;
         Ok(truncate(self.into(), interval, relative_to.expr.clone()).into())
     }
 
+    pub fn dt_convert_time_zone(&self, timezone: &str) -> PyResult<Self> {
+        use functions::temporal::convert_time_zone;
+        Ok(convert_time_zone(self.into(), timezone).into())
+    }
+
+    pub fn dt_replace_time_zone(&self, timezone: Option<&str>, ambiguous: &str) -> PyResult<Self> {
+        use functions::temporal::replace_time_zone;
+        Ok(replace_time_zone(self.into(), timezone, ambiguous).into())
+    }
+
+    pub fn dt_strftime(&self, format: &str) -> PyResult<Self> {
+        use functions::temporal::strftime;
+        Ok(strftime(self.into(), format).into())
+    }
+
     pub fn utf8_endswith(&self, pattern: &Self) -> PyResult<Self> {
         use crate::functions::utf8::endswith;
         Ok(endswith(self.into(), pattern.expr.clone()).into())
@@ -560,6 +797,83 @@ impl PyExpr {
         Ok(find(self.into(), substr.into()).into())
     }
 
+    pub fn utf8_tokenize(&self, pattern: &str) -> PyResult<Self> {
+        use crate::functions::utf8::tokenize;
+        Ok(tokenize(self.into(), pattern).into())
+    }
+
+    pub fn utf8_ngrams(&self, n: usize, chars: bool) -> PyResult<Self> {
+        use crate::functions::utf8::ngrams;
+        Ok(ngrams(self.into(), n, chars).into())
+    }
+
+    pub fn utf8_word_count(&self) -> PyResult<Self> {
+        use crate::functions::utf8::word_count;
+        Ok(word_count(self.into()).into())
+    }
+
+    pub fn utf8_lpad(&self, length: &Self, pad: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::lpad;
+        Ok(lpad(self.into(), length.into(), pad.into()).into())
+    }
+
+    pub fn utf8_rpad(&self, length: &Self, pad: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::rpad;
+        Ok(rpad(self.into(), length.into(), pad.into()).into())
+    }
+
+    pub fn utf8_repeat(&self, n: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::repeat;
+        Ok(repeat(self.into(), n.into()).into())
+    }
+
+    pub fn utf8_substr(&self, start: &Self, length: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::substr;
+        Ok(substr(self.into(), start.into(), length.into()).into())
+    }
+
+    pub fn utf8_normalize(&self, form: &str) -> PyResult<Self> {
+        use crate::functions::utf8::normalize;
+        use daft_core::array::ops::Utf8NormalizeForm;
+        let form = form.parse::<Utf8NormalizeForm>()?;
+        Ok(normalize(self.into(), form).into())
+    }
+
+    pub fn utf8_count_matches(&self, pattern: &Self, regex: bool) -> PyResult<Self> {
+        use crate::functions::utf8::count_matches;
+        Ok(count_matches(self.into(), pattern.into(), regex).into())
+    }
+
+    pub fn utf8_like(&self, pattern: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::like;
+        Ok(like(self.into(), pattern.into()).into())
+    }
+
+    pub fn utf8_ilike(&self, pattern: &Self) -> PyResult<Self> {
+        use crate::functions::utf8::ilike;
+        Ok(ilike(self.into(), pattern.into()).into())
+    }
+
+    pub fn utf8_strip_chars(&self, chars: &Self, left: bool, right: bool) -> PyResult<Self> {
+        use crate::functions::utf8::strip_chars;
+        Ok(strip_chars(self.into(), chars.into(), left, right).into())
+    }
+
+    pub fn utf8_to_date(&self, format: &str, raise_error_on_failure: bool) -> PyResult<Self> {
+        use crate::functions::utf8::to_date;
+        Ok(to_date(self.into(), format, raise_error_on_failure).into())
+    }
+
+    pub fn utf8_to_datetime(
+        &self,
+        format: &str,
+        timezone: Option<&str>,
+        raise_error_on_failure: bool,
+    ) -> PyResult<Self> {
+        use crate::functions::utf8::to_datetime;
+        Ok(to_datetime(self.into(), format, timezone, raise_error_on_failure).into())
+    }
+
     pub fn image_decode(&self, raise_error_on_failure: bool) -> PyResult<Self> {
         use crate::functions::image::decode;
         Ok(decode(self.into(), raise_error_on_failure).into())
@@ -605,6 +919,11 @@ impl PyExpr {
         Ok(get(self.into(), idx.into(), default.into()).into())
     }
 
+    pub fn list_to_map(&self, values: &Self) -> PyResult<Self> {
+        use crate::functions::map::from_lists;
+        Ok(from_lists(self.into(), values.into()).into())
+    }
+
     pub fn list_sum(&self) -> PyResult<Self> {
         use crate::functions::list::sum;
         Ok(sum(self.into()).into())
@@ -625,11 +944,84 @@ impl PyExpr {
         Ok(max(self.into()).into())
     }
 
+    pub fn list_contains(&self, item: &Self) -> PyResult<Self> {
+        use crate::functions::list::contains;
+        Ok(contains(self.into(), item.into()).into())
+    }
+
+    pub fn list_slice(&self, start: &Self, end: &Self) -> PyResult<Self> {
+        use crate::functions::list::slice;
+        Ok(slice(self.into(), start.into(), end.into()).into())
+    }
+
+    pub fn list_sort(&self, descending: bool) -> PyResult<Self> {
+        use crate::functions::list::sort;
+        Ok(sort(self.into(), descending).into())
+    }
+
+    pub fn list_distinct(&self) -> PyResult<Self> {
+        use crate::functions::list::distinct;
+        Ok(distinct(self.into()).into())
+    }
+
+    pub fn list_flatten(&self) -> PyResult<Self> {
+        use crate::functions::list::flatten;
+        Ok(flatten(self.into()).into())
+    }
+
+    pub fn list_value_counts(&self) -> PyResult<Self> {
+        use crate::functions::list::value_counts;
+        Ok(value_counts(self.into()).into())
+    }
+
+    pub fn list_zip(&self, others: Vec<Self>) -> PyResult<Self> {
+        use crate::functions::list::zip;
+        let inputs = std::iter::once(self.expr.clone())
+            .chain(others.into_iter().map(|e| e.expr))
+            .collect();
+        Ok(zip(inputs).into())
+    }
+
+    pub fn list_eval(&self, element_expr: &Self) -> PyResult<Self> {
+        use crate::functions::list::eval;
+        Ok(eval(self.into(), element_expr.into()).into())
+    }
+
+    pub fn list_filter(&self, predicate: &Self) -> PyResult<Self> {
+        use crate::functions::list::filter;
+        Ok(filter(self.into(), predicate.into()).into())
+    }
+
     pub fn struct_get(&self, name: &str) -> PyResult<Self> {
         use crate::functions::struct_::get;
         Ok(get(self.into(), name).into())
     }
 
+    pub fn struct_rename_fields(&self, names: Vec<String>) -> PyResult<Self> {
+        use crate::functions::struct_::rename_fields;
+        Ok(rename_fields(self.into(), names).into())
+    }
+
+    pub fn struct_with_field(&self, name: &str, value: &Self) -> PyResult<Self> {
+        use crate::functions::struct_::with_field;
+        Ok(with_field(self.into(), name, value.into()).into())
+    }
+
+    pub fn map_get(&self, key: &Self) -> PyResult<Self> {
+        use crate::functions::map::get;
+        Ok(get(self.into(), key.into()).into())
+    }
+
+    pub fn map_keys(&self) -> PyResult<Self> {
+        use crate::functions::map::keys;
+        Ok(keys(self.into()).into())
+    }
+
+    pub fn map_values(&self) -> PyResult<Self> {
+        use crate::functions::map::values;
+        Ok(values(self.into()).into())
+    }
+
     pub fn partitioning_days(&self) -> PyResult<Self> {
         use crate::functions::partitioning::days;
         Ok(days(self.into()).into())
@@ -660,6 +1052,16 @@ impl PyExpr {
         Ok(iceberg_truncate(self.into(), w).into())
     }
 
+    pub fn minhash(&self, num_hashes: usize, ngram_size: usize, seed: u32) -> PyResult<Self> {
+        use crate::functions::minhash::minhash;
+        Ok(minhash(self.into(), num_hashes, ngram_size, seed).into())
+    }
+
+    pub fn lsh_bands(&self, num_bands: usize) -> PyResult<Self> {
+        use crate::functions::minhash::lsh_bands;
+        Ok(lsh_bands(self.into(), num_bands).into())
+    }
+
     pub fn json_query(&self, _query: &str) -> PyResult<Self> {
         use crate::functions::json::query;
         Ok(query(self.into(), _query).into())
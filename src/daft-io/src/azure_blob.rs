@@ -5,12 +5,13 @@ use azure_storage_blobs::{
     container::{operations::BlobItem, Container},
     prelude::*,
 };
+use bytes::Bytes;
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{IntoError, ResultExt, Snafu};
-use std::{ops::Range, sync::Arc};
+use std::{ops::Range, sync::Arc, time::Duration};
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult, ObjectSource},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload, ObjectSource},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     GetResult,
@@ -19,6 +20,8 @@ use common_io_config::AzureConfig;
 
 const AZURE_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+/// Longest interval between checks of the status of a pending copy.
+const MAX_COPY_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 #[derive(Debug, Snafu)]
 enum Error {
@@ -54,6 +57,38 @@ enum Error {
         source: azure_storage::Error,
     },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWriteFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display("Unable to copy {}: {}", path, source))]
+    UnableToCopyFile {
+        path: String,
+        source: azure_storage::Error,
+    },
+
+    #[snafu(display(
+        "Copy of {} to {} ended with status {}: {}",
+        src,
+        dst,
+        status,
+        description
+    ))]
+    CopyNotSuccessful {
+        src: String,
+        dst: String,
+        status: String,
+        description: String,
+    },
+
     #[snafu(display("Not Found: \"{}\"", path))]
     NotFound { path: String },
 
@@ -82,6 +117,25 @@ impl From<Error> for super::Error {
                     },
                 }
             }
+            UnableToWriteFile { path, source }
+            | UnableToDeleteFile { path, source }
+            | UnableToCopyFile { path, source } => {
+                match source.as_http_error().map(|v| v.status().into()) {
+                    Some(404) | Some(410) => super::Error::NotFound {
+                        path,
+                        source: source.into(),
+                    },
+                    Some(401) => super::Error::Unauthorized {
+                        store: super::SourceType::AzureBlob,
+                        path,
+                        source: source.into(),
+                    },
+                    None | Some(_) => super::Error::UnableToWriteFile {
+                        path,
+                        source: source.into(),
+                    },
+                }
+            }
             NotFound { ref path } => super::Error::NotFound {
                 path: path.into(),
                 source: error.into(),
@@ -95,6 +149,72 @@ impl From<Error> for super::Error {
     }
 }
 
+/// Parses the container and blob name of an Azure URI, e.g. `az://container/path/file`.
+fn parse_blob_uri(uri: &str) -> super::Result<(String, String)> {
+    let parsed = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+    let container = match parsed.host_str() {
+        Some(s) => Ok(s),
+        None => Err(Error::InvalidUrl {
+            path: uri.into(),
+            source: url::ParseError::EmptyHost,
+        }),
+    }?;
+    let key = parsed.path();
+
+    if key.is_empty() {
+        return Err(Error::NotAFile { path: uri.into() }.into());
+    }
+    Ok((container.to_string(), key.to_string()))
+}
+
+/// Multipart upload of a single Azure block blob, which stages each part as an uncommitted block
+/// and commits the list of blocks once completed.
+pub(crate) struct AzureMultipartUpload {
+    uri: String,
+    blob_client: BlobClient,
+    block_list: BlockList,
+    io_stats: Option<IOStatsRef>,
+}
+
+#[async_trait]
+impl MultipartUpload for AzureMultipartUpload {
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()> {
+        // Block ids must all have the same length within a blob.
+        let block_id = BlockId::new(format!("{:032}", self.block_list.blocks.len()));
+        let num_bytes = data.len();
+        self.blob_client
+            .put_block(block_id.clone(), data)
+            .await
+            .context(UnableToWriteFileSnafu {
+                path: self.uri.as_str(),
+            })?;
+        self.block_list
+            .blocks
+            .push(BlobBlockType::Uncommitted(block_id));
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn complete(self: Box<Self>) -> super::Result<()> {
+        self.blob_client
+            .put_block_list(self.block_list.clone())
+            .await
+            .context(UnableToWriteFileSnafu {
+                path: self.uri.as_str(),
+            })?;
+        Ok(())
+    }
+
+    async fn abort(self: Box<Self>) -> super::Result<()> {
+        // Uncommitted blocks are garbage collected by Azure after a week, so there is nothing to
+        // clean up until the block list is committed.
+        Ok(())
+    }
+}
+
 pub(crate) struct AzureBlobSource {
     blob_client: Arc<BlobServiceClient>,
 }
@@ -480,6 +600,121 @@ impl ObjectSource for AzureBlobSource {
         Ok(metadata.blob.properties.content_length as usize)
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (container, key) = parse_blob_uri(uri)?;
+        let blob_client = self
+            .blob_client
+            .container_client(container)
+            .blob_client(key);
+        let num_bytes = data.len();
+        blob_client
+            .put_block_blob(data)
+            .await
+            .context(UnableToWriteFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>> {
+        let (container, key) = parse_blob_uri(uri)?;
+        let blob_client = self
+            .blob_client
+            .container_client(container)
+            .blob_client(key);
+        Ok(Box::new(AzureMultipartUpload {
+            uri: uri.to_string(),
+            blob_client,
+            block_list: BlockList::default(),
+            io_stats,
+        }))
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (container, key) = parse_blob_uri(uri)?;
+        let blob_client = self
+            .blob_client
+            .container_client(container)
+            .blob_client(key);
+        blob_client
+            .delete()
+            .await
+            .context(UnableToDeleteFileSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (src_container, src_key) = parse_blob_uri(src)?;
+        let (dst_container, dst_key) = parse_blob_uri(dst)?;
+        let src_url = self
+            .blob_client
+            .container_client(src_container)
+            .blob_client(src_key)
+            .url()
+            .context(UnableToCopyFileSnafu::<String> { path: src.into() })?;
+        let blob_client = self
+            .blob_client
+            .container_client(dst_container)
+            .blob_client(dst_key);
+        let response = blob_client
+            .copy(src_url)
+            .await
+            .context(UnableToCopyFileSnafu::<String> { path: src.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1)
+        }
+
+        // Copies may complete asynchronously, so poll the status of the destination until the
+        // copy is done, since callers such as `rename` delete the source afterwards.
+        let mut status = response.copy_status;
+        let mut description = None;
+        let mut poll_interval = Duration::from_millis(50);
+        while status == CopyStatus::Pending {
+            tokio::time::sleep(poll_interval).await;
+            poll_interval = (poll_interval * 2).min(MAX_COPY_POLL_INTERVAL);
+            let properties = blob_client
+                .get_properties()
+                .await
+                .context(UnableToCopyFileSnafu::<String> { path: src.into() })?
+                .blob
+                .properties;
+            if let Some(is) = io_stats.as_ref() {
+                is.mark_head_requests(1)
+            }
+            if properties.copy_id.as_ref() != Some(&response.copy_id) {
+                // Another copy to the destination replaced this one.
+                return Err(Error::CopyNotSuccessful {
+                    src: src.into(),
+                    dst: dst.into(),
+                    status: "superseded".to_string(),
+                    description: "another copy to the destination was started".to_string(),
+                }
+                .into());
+            }
+            status = properties.copy_status.unwrap_or(CopyStatus::Success);
+            description = properties.copy_status_description;
+        }
+        if status != CopyStatus::Success {
+            return Err(Error::CopyNotSuccessful {
+                src: src.into(),
+                dst: dst.into(),
+                status: format!("{status:?}"),
+                description: description.unwrap_or_default(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -6,8 +6,13 @@ use futures::TryStreamExt;
 use google_cloud_storage::client::ClientConfig;
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use google_cloud_storage::client::Client;
+use google_cloud_storage::http::objects::copy::CopyObjectRequest;
+use google_cloud_storage::http::objects::delete::DeleteObjectRequest;
 use google_cloud_storage::http::objects::get::GetObjectRequest;
+use google_cloud_storage::http::objects::upload::{Media, UploadObjectRequest, UploadType};
+use google_cloud_storage::http::resumable_upload_client::{ChunkSize, ResumableUploadClient};
 
 use google_cloud_storage::http::objects::list::ListObjectsRequest;
 use google_cloud_storage::http::Error as GError;
@@ -18,6 +23,7 @@ use snafu::Snafu;
 use crate::object_io::FileMetadata;
 use crate::object_io::FileType;
 use crate::object_io::LSResult;
+use crate::object_io::MultipartUpload;
 use crate::object_io::ObjectSource;
 use crate::stats::IOStatsRef;
 use crate::stream_utils::io_stats_on_bytestream;
@@ -39,6 +45,15 @@ enum Error {
     #[snafu(display("Unable to read data from {}: {}", path, source))]
     UnableToReadBytes { path: String, source: GError },
 
+    #[snafu(display("Unable to write data to {}: {}", path, source))]
+    UnableToWriteFile { path: String, source: GError },
+
+    #[snafu(display("Unable to delete {}: {}", path, source))]
+    UnableToDeleteFile { path: String, source: GError },
+
+    #[snafu(display("Unable to copy {}: {}", path, source))]
+    UnableToCopyFile { path: String, source: GError },
+
     #[snafu(display("Unable to parse URL: \"{}\"", path))]
     InvalidUrl {
         path: String,
@@ -96,6 +111,44 @@ impl From<Error> for super::Error {
                     source: err,
                 },
             },
+            UnableToWriteFile { path, source }
+            | UnableToDeleteFile { path, source }
+            | UnableToCopyFile { path, source } => match source {
+                GError::HttpClient(err) => match err.status().map(|s| s.as_u16()) {
+                    Some(404) | Some(410) => super::Error::NotFound {
+                        path,
+                        source: err.into(),
+                    },
+                    Some(401) => super::Error::Unauthorized {
+                        store: super::SourceType::GCS,
+                        path,
+                        source: err.into(),
+                    },
+                    _ => super::Error::UnableToWriteFile {
+                        path,
+                        source: err.into(),
+                    },
+                },
+                GError::Response(err) => match err.code {
+                    404 | 410 => super::Error::NotFound {
+                        path,
+                        source: err.into(),
+                    },
+                    401 => super::Error::Unauthorized {
+                        store: super::SourceType::GCS,
+                        path,
+                        source: err.into(),
+                    },
+                    _ => super::Error::UnableToWriteFile {
+                        path,
+                        source: err.into(),
+                    },
+                },
+                GError::TokenSource(err) => super::Error::UnableToLoadCredentials {
+                    store: super::SourceType::GCS,
+                    source: err,
+                },
+            },
             NotFound { ref path } => super::Error::NotFound {
                 path: path.into(),
                 source: error.into(),
@@ -327,6 +380,173 @@ impl GCSClientWrapper {
             .await
         }
     }
+
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let num_bytes = data.len();
+        client
+            .upload_object(&req, data, &UploadType::Simple(Media::new(key.to_string())))
+            .await
+            .context(UnableToWriteFileSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GCSMultipartUpload> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = UploadObjectRequest {
+            bucket: bucket.into(),
+            ..Default::default()
+        };
+        let upload_client = client
+            .prepare_resumable_upload(&req, &UploadType::Simple(Media::new(key.to_string())))
+            .await
+            .context(UnableToWriteFileSnafu {
+                path: uri.to_string(),
+            })?;
+        Ok(GCSMultipartUpload {
+            uri: uri.to_string(),
+            upload_client,
+            offset: 0,
+            pending_part: None,
+            io_stats,
+        })
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let uri = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let (bucket, key) = parse_uri(&uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = &self.0;
+        let req = DeleteObjectRequest {
+            bucket: bucket.into(),
+            object: key.into(),
+            ..Default::default()
+        };
+        client
+            .delete_object(&req)
+            .await
+            .context(UnableToDeleteFileSnafu {
+                path: uri.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let src = url::Url::parse(src).with_context(|_| InvalidUrlSnafu { path: src })?;
+        let dst = url::Url::parse(dst).with_context(|_| InvalidUrlSnafu { path: dst })?;
+        let (src_bucket, src_key) = parse_uri(&src)?;
+        let (dst_bucket, dst_key) = parse_uri(&dst)?;
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if dst_key.is_empty() {
+            return Err(Error::NotAFile { path: dst.into() }.into());
+        }
+        let client = &self.0;
+        let req = CopyObjectRequest {
+            source_bucket: src_bucket.into(),
+            source_object: src_key.into(),
+            destination_bucket: dst_bucket.into(),
+            destination_object: dst_key.into(),
+            ..Default::default()
+        };
+        client
+            .copy_object(&req)
+            .await
+            .context(UnableToCopyFileSnafu {
+                path: src.to_string(),
+            })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1)
+        }
+        Ok(())
+    }
+}
+
+/// Resumable upload of a single GCS object.
+///
+/// GCS needs the total size of the object along with its last chunk, so each part is held back
+/// until the next one arrives or the upload is completed.
+pub(crate) struct GCSMultipartUpload {
+    uri: String,
+    upload_client: ResumableUploadClient,
+    offset: u64,
+    pending_part: Option<Bytes>,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl GCSMultipartUpload {
+    async fn upload_chunk(&mut self, data: Bytes, is_last: bool) -> super::Result<()> {
+        let num_bytes = data.len() as u64;
+        let end = self.offset + num_bytes;
+        let total_size = if is_last { Some(end) } else { None };
+        let chunk_size = ChunkSize::new(self.offset, end.max(self.offset + 1) - 1, total_size);
+        self.upload_client
+            .upload_multiple_chunk(data, &chunk_size)
+            .await
+            .context(UnableToWriteFileSnafu {
+                path: self.uri.as_str(),
+            })?;
+        self.offset = end;
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes as usize);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for GCSMultipartUpload {
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()> {
+        if let Some(part) = self.pending_part.replace(data) {
+            self.upload_chunk(part, false).await?;
+        }
+        Ok(())
+    }
+
+    async fn complete(mut self: Box<Self>) -> super::Result<()> {
+        let part = self.pending_part.take().unwrap_or_default();
+        self.upload_chunk(part, true).await
+    }
+
+    async fn abort(self: Box<Self>) -> super::Result<()> {
+        let uri = self.uri;
+        self.upload_client
+            .cancel()
+            .await
+            .context(UnableToWriteFileSnafu { path: uri })?;
+        Ok(())
+    }
 }
 
 pub(crate) struct GCSSource {
@@ -375,6 +595,28 @@ impl ObjectSource for GCSSource {
         self.client.get_size(uri, io_stats).await
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.put(uri, data, io_stats).await
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>> {
+        Ok(Box::new(
+            self.client.create_multipart_upload(uri, io_stats).await?,
+        ))
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.delete(uri, io_stats).await
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        self.client.copy(src, dst, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -10,7 +10,7 @@ use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
 };
@@ -246,6 +246,48 @@ impl ObjectSource for HttpSource {
         }
     }
 
+    async fn put(
+        &self,
+        _uri: &str,
+        _data: bytes::Bytes,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "put".to_string(),
+        })
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        _uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "create_multipart_upload".to_string(),
+        })
+    }
+
+    async fn delete(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "delete".to_string(),
+        })
+    }
+
+    async fn copy(
+        &self,
+        _src: &str,
+        _dst: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        Err(super::Error::NotImplementedMethod {
+            store: super::SourceType::Http,
+            method: "copy".to_string(),
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
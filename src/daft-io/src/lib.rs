@@ -7,6 +7,7 @@ mod http;
 mod local;
 mod object_io;
 mod object_store_glob;
+mod object_writer;
 mod s3_like;
 mod stats;
 mod stream_utils;
@@ -19,6 +20,7 @@ pub mod python;
 pub use common_io_config::{AzureConfig, IOConfig, S3Config};
 pub use object_io::FileMetadata;
 pub use object_io::GetResult;
+pub use object_writer::ObjectWriter;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use stats::{IOStatsContext, IOStatsRef};
@@ -80,6 +82,9 @@ pub enum Error {
     ))]
     SocketError { path: String, source: DynError },
 
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteFile { path: String, source: DynError },
+
     #[snafu(display("Unable to convert URL \"{}\" to path", path))]
     InvalidUrl {
         path: String,
@@ -108,6 +113,9 @@ pub enum Error {
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display("{} is not supported by store: {}", method, store))]
+    NotImplementedMethod { store: SourceType, method: String },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
@@ -242,6 +250,65 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    pub async fn single_url_put(
+        &self,
+        input: String,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source.put(path.as_ref(), data, io_stats).await
+    }
+
+    /// Opens an [`ObjectWriter`] that streams bytes into the object at `input`, which is written
+    /// once the writer is shut down.
+    pub async fn open_writer(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<ObjectWriter> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        Ok(ObjectWriter::new(path.into_owned(), source, io_stats))
+    }
+
+    pub async fn single_url_delete(
+        &self,
+        input: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source.delete(path.as_ref(), io_stats).await
+    }
+
+    pub async fn single_url_copy(
+        &self,
+        src: String,
+        dst: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, src_path, dst_path) = parse_url_pair(&src, &dst)?;
+        let source = self.get_source(&scheme).await?;
+        source
+            .copy(src_path.as_ref(), dst_path.as_ref(), io_stats)
+            .await
+    }
+
+    pub async fn single_url_rename(
+        &self,
+        src: String,
+        dst: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<()> {
+        let (scheme, src_path, dst_path) = parse_url_pair(&src, &dst)?;
+        let source = self.get_source(&scheme).await?;
+        source
+            .rename(src_path.as_ref(), dst_path.as_ref(), io_stats)
+            .await
+    }
+
     async fn single_url_download(
         &self,
         index: usize,
@@ -325,6 +392,23 @@ pub fn parse_url(input: &str) -> Result<(SourceType, Cow<'_, str>)> {
         _ => Err(Error::NotImplementedSource { store: scheme }),
     }
 }
+/// Parses the source and destination of a copy or rename, which must be in the same store.
+fn parse_url_pair<'a>(
+    src: &'a str,
+    dst: &'a str,
+) -> Result<(SourceType, Cow<'a, str>, Cow<'a, str>)> {
+    let (src_scheme, src_path) = parse_url(src)?;
+    let (dst_scheme, dst_path) = parse_url(dst)?;
+    if src_scheme != dst_scheme {
+        return Err(Error::InvalidArgument {
+            msg: format!(
+                "Unable to copy {src} to {dst} across different stores: {src_scheme} and {dst_scheme}"
+            ),
+        });
+    }
+    Ok((src_scheme, src_path, dst_path))
+}
+
 type CacheKey = (bool, Arc<IOConfig>);
 lazy_static! {
     static ref NUM_CPUS: usize = std::thread::available_parallelism().unwrap().get();
@@ -1,8 +1,8 @@
 use std::io::SeekFrom;
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::object_io::{self, FileMetadata, LSResult};
+use crate::object_io::{self, FileMetadata, LSResult, MultipartUpload};
 use crate::stats::IOStatsRef;
 
 use super::object_io::{GetResult, ObjectSource};
@@ -15,7 +15,7 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use snafu::{ResultExt, Snafu};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 /// NOTE: We hardcode this even for Windows
 ///
@@ -23,6 +23,8 @@ use tokio::io::{AsyncReadExt, AsyncSeekExt};
 /// as long as there is no "mix" of "\" and "/".
 const PATH_SEGMENT_DELIMITER: &str = "/";
 
+const LOCAL_PROTOCOL: &str = "file://";
+
 pub(crate) struct LocalSource {}
 
 #[derive(Debug, Snafu)]
@@ -59,6 +61,32 @@ enum Error {
     #[snafu(display("Unexpected symlink when processing directory {}: {}", path, source))]
     UnexpectedSymlink { path: String, source: DaftError },
 
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteToFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to delete file {}: {}", path, source))]
+    UnableToDeleteFile {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to copy file {} to {}: {}", path, dst, source))]
+    UnableToCopyFile {
+        path: String,
+        dst: String,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Unable to rename file {} to {}: {}", path, dst, source))]
+    UnableToRenameFile {
+        path: String,
+        dst: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to convert URL \"{}\" to local file path", path))]
     InvalidFilePath { path: String },
 }
@@ -94,6 +122,22 @@ impl From<Error> for super::Error {
                 }
             }
             UnableToReadBytes { path, source } => super::Error::UnableToReadBytes { path, source },
+            UnableToWriteToFile { path, source } => super::Error::UnableToWriteFile {
+                path,
+                source: source.into(),
+            },
+            UnableToDeleteFile { path, source }
+            | UnableToCopyFile { path, source, .. }
+            | UnableToRenameFile { path, source, .. } => match source.kind() {
+                std::io::ErrorKind::NotFound => super::Error::NotFound {
+                    path,
+                    source: source.into(),
+                },
+                _ => super::Error::UnableToWriteFile {
+                    path,
+                    source: source.into(),
+                },
+            },
             _ => super::Error::Generic {
                 store: super::SourceType::File,
                 source: error.into(),
@@ -108,6 +152,70 @@ impl LocalSource {
     }
 }
 
+fn to_local_path(uri: &str) -> super::Result<&str> {
+    match uri.strip_prefix(LOCAL_PROTOCOL) {
+        Some(path) => Ok(path),
+        None => Err(Error::InvalidFilePath { path: uri.into() }.into()),
+    }
+}
+
+async fn create_parent_dir(path: &str) -> Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .context(UnableToWriteToFileSnafu { path })?;
+    }
+    Ok(())
+}
+
+/// Multipart upload that appends parts to a temporary file next to the destination, which is
+/// only moved into place once the upload completes.
+pub(crate) struct LocalMultipartUpload {
+    path: String,
+    tmp_path: String,
+    file: tokio::fs::File,
+    io_stats: Option<IOStatsRef>,
+}
+
+#[async_trait]
+impl MultipartUpload for LocalMultipartUpload {
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()> {
+        self.file
+            .write_all(&data)
+            .await
+            .context(UnableToWriteToFileSnafu {
+                path: self.tmp_path.as_str(),
+            })?;
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(data.len());
+        }
+        Ok(())
+    }
+
+    async fn complete(mut self: Box<Self>) -> super::Result<()> {
+        self.file.flush().await.context(UnableToWriteToFileSnafu {
+            path: self.tmp_path.as_str(),
+        })?;
+        tokio::fs::rename(&self.tmp_path, &self.path)
+            .await
+            .context(UnableToRenameFileSnafu {
+                path: self.tmp_path.as_str(),
+                dst: self.path.as_str(),
+            })?;
+        Ok(())
+    }
+
+    async fn abort(self: Box<Self>) -> super::Result<()> {
+        let Self { tmp_path, file, .. } = *self;
+        drop(file);
+        tokio::fs::remove_file(&tmp_path)
+            .await
+            .context(UnableToDeleteFileSnafu { path: tmp_path })?;
+        Ok(())
+    }
+}
+
 pub struct LocalFile {
     pub path: PathBuf,
     pub range: Option<Range<usize>>,
@@ -152,6 +260,80 @@ impl ObjectSource for LocalSource {
         }
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let path = to_local_path(uri)?;
+        create_parent_dir(path).await?;
+        tokio::fs::write(path, &data)
+            .await
+            .context(UnableToWriteToFileSnafu { path })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(data.len());
+        }
+        Ok(())
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>> {
+        let path = to_local_path(uri)?;
+        create_parent_dir(path).await?;
+        let tmp_path = format!("{path}.{:016x}.tmp", rand::random::<u64>());
+        let file = tokio::fs::File::create(&tmp_path)
+            .await
+            .context(UnableToWriteToFileSnafu {
+                path: tmp_path.as_str(),
+            })?;
+        Ok(Box::new(LocalMultipartUpload {
+            path: path.to_string(),
+            tmp_path,
+            file,
+            io_stats,
+        }))
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let path = to_local_path(uri)?;
+        tokio::fs::remove_file(path)
+            .await
+            .context(UnableToDeleteFileSnafu { path })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let (src, dst) = (to_local_path(src)?, to_local_path(dst)?);
+        create_parent_dir(dst).await?;
+        tokio::fs::copy(src, dst)
+            .await
+            .context(UnableToCopyFileSnafu { path: src, dst })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn rename(
+        &self,
+        src: &str,
+        dst: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let (src, dst) = (to_local_path(src)?, to_local_path(dst)?);
+        create_parent_dir(dst).await?;
+        tokio::fs::rename(src, dst)
+            .await
+            .context(UnableToRenameFileSnafu { path: src, dst })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_rename_requests(1)
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -335,8 +517,8 @@ mod tests {
     use std::io::Write;
 
     use crate::object_io::{FileMetadata, FileType, ObjectSource};
-    use crate::Result;
-    use crate::{HttpSource, LocalSource};
+    use crate::{Error, Result};
+    use crate::{HttpSource, IOClient, IOConfig, IOStatsContext, LocalSource};
 
     async fn write_remote_parquet_to_local_file(
         f: &mut tempfile::NamedTempFile,
@@ -456,4 +638,123 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_local_put_copy_rename_delete() -> Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = format!("file://{}", dir.path().to_string_lossy().replace('\\', "/"));
+        let client = LocalSource::get_client().await?;
+        let io_stats = IOStatsContext::new("test_local_put_copy_rename_delete");
+        let data = bytes::Bytes::from_static(b"daft");
+
+        let original = format!("{dir_path}/nested/original.bin");
+        client
+            .put(&original, data.clone(), Some(io_stats.clone()))
+            .await?;
+        assert_eq!(
+            client.get(&original, None, None).await?.bytes().await?,
+            data
+        );
+
+        let copied = format!("{dir_path}/copied/copied.bin");
+        client
+            .copy(&original, &copied, Some(io_stats.clone()))
+            .await?;
+        assert_eq!(client.get(&copied, None, None).await?.bytes().await?, data);
+
+        let renamed = format!("{dir_path}/renamed.bin");
+        client
+            .rename(&copied, &renamed, Some(io_stats.clone()))
+            .await?;
+        assert_eq!(client.get(&renamed, None, None).await?.bytes().await?, data);
+        assert!(matches!(
+            client.get_size(&copied, None).await,
+            Err(Error::NotFound { .. })
+        ));
+
+        client.delete(&original, Some(io_stats.clone())).await?;
+        assert!(matches!(
+            client.delete(&original, None).await,
+            Err(Error::NotFound { .. })
+        ));
+
+        assert_eq!(io_stats.load_put_requests(), 1);
+        assert_eq!(io_stats.load_bytes_written(), data.len());
+        assert_eq!(io_stats.load_copy_requests(), 1);
+        assert_eq!(io_stats.load_rename_requests(), 1);
+        assert_eq!(io_stats.load_delete_requests(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_object_writer() -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = format!("file://{}", dir.path().to_string_lossy().replace('\\', "/"));
+        let io_client = IOClient::new(IOConfig::default().into())?;
+
+        // Small objects are written with a single put while larger ones are uploaded in parts.
+        for num_bytes in [0, 1024, 25 * 1024 * 1024 + 7] {
+            let data = (0..num_bytes).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+            let path = format!("{dir_path}/out/{num_bytes}.bin");
+            let mut writer = io_client.open_writer(path.clone(), None).await?;
+            for chunk in data.chunks(1024 * 1024) {
+                writer.write_all(chunk).await.unwrap();
+            }
+            writer.shutdown().await.unwrap();
+
+            let written = io_client
+                .single_url_get(path, None, None)
+                .await?
+                .bytes()
+                .await?;
+            assert_eq!(written.len(), data.len());
+            assert_eq!(written.as_ref(), data.as_slice());
+        }
+
+        let leftover_files = std::fs::read_dir(dir.path().join("out")).unwrap().count();
+        assert_eq!(leftover_files, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_local_object_writer_abort() -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let dir_path = format!("file://{}", dir.path().to_string_lossy().replace('\\', "/"));
+        let io_client = IOClient::new(IOConfig::default().into())?;
+        let data = vec![7u8; 25 * 1024 * 1024];
+        let num_files = || std::fs::read_dir(dir.path().join("out")).unwrap().count();
+
+        // Aborting removes the parts uploaded so far without writing the object.
+        let mut writer = io_client
+            .open_writer(format!("{dir_path}/out/aborted.bin"), None)
+            .await?;
+        writer.write_all(&data).await.unwrap();
+        assert_eq!(num_files(), 1);
+        writer.abort().await?;
+        assert_eq!(num_files(), 0);
+        assert!(writer.write_all(&data).await.is_err());
+
+        // Dropping a writer that wasn't shut down aborts its upload in the background.
+        let mut writer = io_client
+            .open_writer(format!("{dir_path}/out/dropped.bin"), None)
+            .await?;
+        writer.write_all(&data).await.unwrap();
+        assert_eq!(num_files(), 1);
+        drop(writer);
+        for _ in 0..100 {
+            if num_files() == 0 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(num_files(), 0);
+
+        Ok(())
+    }
 }
@@ -93,6 +93,25 @@ pub struct LSResult {
 
 use async_stream::stream;
 
+/// An in-progress upload of a single object in parts, created by
+/// [`ObjectSource::create_multipart_upload`].
+///
+/// Parts are uploaded in order and the object only becomes visible once the upload is completed.
+/// Every part except the last must be a non-zero multiple of [`MIN_MULTIPART_UPLOAD_PART_SIZE`]
+/// bytes.
+#[async_trait]
+pub(crate) trait MultipartUpload: Send {
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()>;
+
+    async fn complete(self: Box<Self>) -> super::Result<()>;
+
+    async fn abort(self: Box<Self>) -> super::Result<()>;
+}
+
+/// Part size granularity accepted by all object stores for every part but the last of a multipart
+/// upload, which is the S3 minimum part size and a multiple of the 256KiB GCS chunk size.
+pub(crate) const MIN_MULTIPART_UPLOAD_PART_SIZE: usize = 5 * 1024 * 1024;
+
 #[async_trait]
 pub(crate) trait ObjectSource: Sync + Send {
     async fn get(
@@ -104,6 +123,30 @@ pub(crate) trait ObjectSource: Sync + Send {
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>>;
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()>;
+
+    /// Moves the object at `src` to `dst`, which defaults to a copy followed by a delete for
+    /// stores without a native rename.
+    async fn rename(
+        &self,
+        src: &str,
+        dst: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.copy(src, dst, io_stats.clone()).await?;
+        self.delete(src, io_stats).await
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -0,0 +1,259 @@
+use std::{
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::{Bytes, BytesMut};
+use futures::{future::BoxFuture, ready, FutureExt};
+use tokio::{io::AsyncWrite, runtime::Handle};
+
+use crate::{
+    object_io::{MultipartUpload, ObjectSource, MIN_MULTIPART_UPLOAD_PART_SIZE},
+    stats::IOStatsRef,
+};
+
+/// Size of the parts of multipart uploads, which bounds the memory buffered by an [`ObjectWriter`].
+const PART_SIZE: usize = 2 * MIN_MULTIPART_UPLOAD_PART_SIZE;
+
+enum WriterState {
+    Buffering(Option<Box<dyn MultipartUpload>>),
+    Uploading(BoxFuture<'static, super::Result<Box<dyn MultipartUpload>>>),
+    Closing(BoxFuture<'static, super::Result<()>>),
+    Closed,
+    Failed,
+}
+
+/// Streams bytes into a single object of any [`ObjectSource`], created by
+/// [`IOClient::open_writer`](crate::IOClient::open_writer).
+///
+/// Objects that fit in a single part are written with one put, and larger ones with a multipart
+/// upload that is aborted if any part fails. The object is only written once the writer is shut
+/// down. Writers that fail before then should be [aborted](ObjectWriter::abort), and writers that
+/// are dropped before then abort their multipart upload in the background.
+pub struct ObjectWriter {
+    uri: Arc<String>,
+    source: Arc<dyn ObjectSource>,
+    io_stats: Option<IOStatsRef>,
+    buffer: BytesMut,
+    state: WriterState,
+    /// Runtime that the writer was opened on, to abort multipart uploads on when dropped.
+    runtime_handle: Option<Handle>,
+}
+
+impl ObjectWriter {
+    pub(crate) fn new(
+        uri: String,
+        source: Arc<dyn ObjectSource>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Self {
+        Self {
+            uri: Arc::new(uri),
+            source,
+            io_stats,
+            buffer: BytesMut::new(),
+            state: WriterState::Buffering(None),
+            runtime_handle: Handle::try_current().ok(),
+        }
+    }
+
+    /// Abandons the object, aborting the multipart upload if one was started so that no uploaded
+    /// parts are left behind. Has no effect if the writer was already shut down.
+    pub async fn abort(&mut self) -> super::Result<()> {
+        self.buffer.clear();
+        match std::mem::replace(&mut self.state, WriterState::Failed) {
+            WriterState::Closing(finish) => {
+                // The object is already being completed, or aborted if that fails.
+                if finish.await.is_ok() {
+                    self.state = WriterState::Closed;
+                }
+                Ok(())
+            }
+            WriterState::Closed => {
+                self.state = WriterState::Closed;
+                Ok(())
+            }
+            state => match pending_upload(state).await {
+                Some(upload) => upload.abort().await,
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn upload_part(
+        &self,
+        upload: Option<Box<dyn MultipartUpload>>,
+        part: Bytes,
+    ) -> BoxFuture<'static, super::Result<Box<dyn MultipartUpload>>> {
+        let (uri, source, io_stats) =
+            (self.uri.clone(), self.source.clone(), self.io_stats.clone());
+        async move {
+            let mut upload = match upload {
+                Some(upload) => upload,
+                None => source.create_multipart_upload(&uri, io_stats).await?,
+            };
+            match upload.upload_part(part).await {
+                Ok(()) => Ok(upload),
+                Err(err) => {
+                    if let Err(abort_err) = upload.abort().await {
+                        log::warn!("Unable to abort multipart upload to {uri}: {abort_err}");
+                    }
+                    Err(err)
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn finish(
+        &self,
+        upload: Option<Box<dyn MultipartUpload>>,
+        last_part: Bytes,
+    ) -> BoxFuture<'static, super::Result<()>> {
+        let (uri, source, io_stats) =
+            (self.uri.clone(), self.source.clone(), self.io_stats.clone());
+        async move {
+            let Some(mut upload) = upload else {
+                return source.put(&uri, last_part, io_stats).await;
+            };
+            if !last_part.is_empty() {
+                if let Err(err) = upload.upload_part(last_part).await {
+                    if let Err(abort_err) = upload.abort().await {
+                        log::warn!("Unable to abort multipart upload to {uri}: {abort_err}");
+                    }
+                    return Err(err);
+                }
+            }
+            upload.complete().await
+        }
+        .boxed()
+    }
+}
+
+/// Waits for the part being uploaded in `state`, returning the multipart upload that is left to
+/// complete or abort, if any.
+fn pending_upload(state: WriterState) -> BoxFuture<'static, Option<Box<dyn MultipartUpload>>> {
+    async move {
+        match state {
+            WriterState::Buffering(upload) => upload,
+            // Uploads whose part failed are already aborted.
+            WriterState::Uploading(upload) => upload.await.ok(),
+            WriterState::Closing(_) | WriterState::Closed | WriterState::Failed => None,
+        }
+    }
+    .boxed()
+}
+
+impl Drop for ObjectWriter {
+    fn drop(&mut self) {
+        if !matches!(
+            self.state,
+            WriterState::Buffering(Some(_)) | WriterState::Uploading(_)
+        ) {
+            return;
+        }
+        let upload = pending_upload(std::mem::replace(&mut self.state, WriterState::Failed));
+        let uri = self.uri.clone();
+        let Some(runtime_handle) = self.runtime_handle.as_ref() else {
+            log::warn!("Unable to abort multipart upload to {uri} outside of a runtime");
+            return;
+        };
+        runtime_handle.spawn(async move {
+            if let Some(upload) = upload.await {
+                if let Err(err) = upload.abort().await {
+                    log::warn!("Unable to abort multipart upload to {uri}: {err}");
+                }
+            }
+        });
+    }
+}
+
+fn closed_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "ObjectWriter is already shut down",
+    )
+}
+
+fn failed_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "ObjectWriter is unusable after a failed or aborted upload",
+    )
+}
+
+impl AsyncWrite for ObjectWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                WriterState::Uploading(_) => {
+                    ready!(Pin::new(&mut *this).poll_flush(cx))?;
+                }
+                WriterState::Buffering(upload) => {
+                    if this.buffer.len() >= PART_SIZE {
+                        let upload = upload.take();
+                        let part = this.buffer.split_to(PART_SIZE).freeze();
+                        this.state = WriterState::Uploading(this.upload_part(upload, part));
+                        continue;
+                    }
+                    let num_bytes = buf.len().min(PART_SIZE - this.buffer.len());
+                    this.buffer.extend_from_slice(&buf[..num_bytes]);
+                    return Poll::Ready(Ok(num_bytes));
+                }
+                WriterState::Closing(_) | WriterState::Closed => {
+                    return Poll::Ready(Err(closed_error()))
+                }
+                WriterState::Failed => return Poll::Ready(Err(failed_error())),
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // Buffered bytes can only be written as a whole part, so flushing only waits for the part
+        // that is currently being uploaded.
+        let this = self.get_mut();
+        if let WriterState::Uploading(upload) = &mut this.state {
+            let upload = ready!(upload.poll_unpin(cx));
+            match upload {
+                Ok(upload) => this.state = WriterState::Buffering(Some(upload)),
+                Err(err) => {
+                    this.state = WriterState::Failed;
+                    return Poll::Ready(Err(err.into()));
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                WriterState::Uploading(_) => {
+                    ready!(Pin::new(&mut *this).poll_flush(cx))?;
+                }
+                WriterState::Buffering(upload) => {
+                    let upload = upload.take();
+                    let last_part = this.buffer.split().freeze();
+                    this.state = WriterState::Closing(this.finish(upload, last_part));
+                }
+                WriterState::Closing(finish) => {
+                    let result = ready!(finish.poll_unpin(cx));
+                    this.state = match &result {
+                        Ok(_) => WriterState::Closed,
+                        Err(_) => WriterState::Failed,
+                    };
+                    return Poll::Ready(result.map_err(Into::into));
+                }
+                WriterState::Closed => return Poll::Ready(Ok(())),
+                WriterState::Failed => return Poll::Ready(Err(failed_error())),
+            }
+        }
+    }
+}
@@ -3,13 +3,24 @@ use aws_config::meta::credentials::CredentialsProviderChain;
 use aws_config::retry::RetryMode;
 use aws_config::timeout::TimeoutConfig;
 use aws_smithy_async::rt::sleep::TokioSleep;
+use bytes::Bytes;
 use futures::stream::BoxStream;
 use reqwest::StatusCode;
+use s3::operation::abort_multipart_upload::AbortMultipartUploadError;
+use s3::operation::complete_multipart_upload::CompleteMultipartUploadError;
+use s3::operation::copy_object::CopyObjectError;
+use s3::operation::create_multipart_upload::CreateMultipartUploadError;
+use s3::operation::delete_object::DeleteObjectError;
 use s3::operation::head_object::HeadObjectError;
 use s3::operation::list_objects_v2::ListObjectsV2Error;
+use s3::operation::put_object::PutObjectError;
+use s3::operation::upload_part::UploadPartError;
+use s3::operation::upload_part_copy::UploadPartCopyError;
+use s3::primitives::ByteStream;
+use s3::types::{CompletedMultipartUpload, CompletedPart};
 use tokio::sync::{OwnedSemaphorePermit, SemaphorePermit};
 
-use crate::object_io::{FileMetadata, FileType, LSResult};
+use crate::object_io::{FileMetadata, FileType, LSResult, MultipartUpload};
 use crate::stats::IOStatsRef;
 use crate::stream_utils::io_stats_on_bytestream;
 use crate::{get_io_pool_num_threads, InvalidArgumentSnafu, SourceType};
@@ -41,6 +52,13 @@ use std::time::Duration;
 
 const S3_DELIMITER: &str = "/";
 const DEFAULT_GLOB_FANOUT_LIMIT: usize = 1024;
+/// Largest object that S3 can copy with a single request.
+const MAX_COPY_OBJECT_SIZE: usize = 5 * 1024 * 1024 * 1024;
+/// Smallest part size of multipart copies, which is raised to stay within the S3 limit of 10,000
+/// parts for objects larger than 5TiB.
+const MIN_MULTIPART_COPY_PART_SIZE: usize = 512 * 1024 * 1024;
+const MAX_MULTIPART_UPLOAD_PARTS: usize = 10_000;
+
 pub(crate) struct S3LikeSource {
     region_to_client_map: tokio::sync::RwLock<HashMap<Region, Arc<s3::Client>>>,
     connection_pool_sema: Arc<tokio::sync::Semaphore>,
@@ -69,6 +87,83 @@ enum Error {
         source: SdkError<ListObjectsV2Error, Response>,
     },
 
+    #[snafu(display("Unable to put {}: {}", path, s3::error::DisplayErrorContext(source)))]
+    UnableToPutFile {
+        path: String,
+        source: SdkError<PutObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to create multipart upload for {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCreateMultipartUpload {
+        path: String,
+        source: SdkError<CreateMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to upload part of {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToUploadPart {
+        path: String,
+        source: SdkError<UploadPartError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to complete multipart upload for {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCompleteMultipartUpload {
+        path: String,
+        source: SdkError<CompleteMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to abort multipart upload for {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToAbortMultipartUpload {
+        path: String,
+        source: SdkError<AbortMultipartUploadError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to delete {}: {}",
+        path,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToDeleteFile {
+        path: String,
+        source: SdkError<DeleteObjectError, Response>,
+    },
+
+    #[snafu(display("Unable to copy {}: {}", path, s3::error::DisplayErrorContext(source)))]
+    UnableToCopyFile {
+        path: String,
+        source: SdkError<CopyObjectError, Response>,
+    },
+
+    #[snafu(display(
+        "Unable to copy part of {} to {}: {}",
+        path,
+        dst,
+        s3::error::DisplayErrorContext(source)
+    ))]
+    UnableToCopyPart {
+        path: String,
+        dst: String,
+        source: SdkError<UploadPartCopyError, Response>,
+    },
+
+    #[snafu(display("Missing upload id when creating multipart upload for: {path}"))]
+    MissingUploadId { path: String },
+
     #[snafu(display("Unable missing header: {header} when performing request for: {path}"))]
     MissingHeader { path: String, header: String },
 
@@ -224,6 +319,14 @@ impl From<Error> for super::Error {
                     },
                 },
             },
+            UnableToPutFile { path, source } => write_error(path, source),
+            UnableToCreateMultipartUpload { path, source } => write_error(path, source),
+            UnableToUploadPart { path, source } => write_error(path, source),
+            UnableToCompleteMultipartUpload { path, source } => write_error(path, source),
+            UnableToAbortMultipartUpload { path, source } => write_error(path, source),
+            UnableToDeleteFile { path, source } => write_error(path, source),
+            UnableToCopyFile { path, source } => write_error(path, source),
+            UnableToCopyPart { path, source, .. } => write_error(path, source),
             InvalidUrl { path, source } => super::Error::InvalidUrl { path, source },
             UnableToReadBytes { path, source } => {
                 use std::error::Error;
@@ -256,6 +359,41 @@ impl From<Error> for super::Error {
     }
 }
 
+/// Converts the error of a request that writes, deletes or copies objects into a [`super::Error`].
+fn write_error<E>(path: String, source: SdkError<E, Response>) -> super::Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    match source {
+        SdkError::TimeoutError(_) => super::Error::ReadTimeout {
+            path,
+            source: source.into(),
+        },
+        SdkError::DispatchFailure(ref dispatch) => {
+            if dispatch.is_timeout() {
+                super::Error::ConnectTimeout {
+                    path,
+                    source: source.into(),
+                }
+            } else if dispatch.is_io() {
+                super::Error::SocketError {
+                    path,
+                    source: source.into(),
+                }
+            } else {
+                super::Error::UnableToWriteFile {
+                    path,
+                    source: source.into(),
+                }
+            }
+        }
+        _ => super::Error::UnableToWriteFile {
+            path,
+            source: source.into(),
+        },
+    }
+}
+
 /// Retrieves an S3Config from the environment by leveraging the AWS SDK's credentials chain
 pub(crate) async fn s3_config_from_env() -> super::Result<S3Config> {
     let default_s3_config = S3Config::default();
@@ -524,6 +662,42 @@ async fn build_client(config: &S3Config) -> super::Result<S3LikeSource> {
 }
 const REGION_HEADER: &str = "x-amz-bucket-region";
 
+/// Returns the region of the bucket of `uri` if a request failed because it was sent to a client
+/// of a different region.
+fn moved_region<E>(uri: &str, err: &SdkError<E, Response>) -> super::Result<Option<Region>> {
+    let SdkError::ServiceError(err) = err else {
+        return Ok(None);
+    };
+    let bad_response = err.raw().http();
+    if bad_response.status() != StatusCode::MOVED_PERMANENTLY {
+        return Ok(None);
+    }
+    let new_region = bad_response
+        .headers()
+        .get(REGION_HEADER)
+        .ok_or(Error::MissingHeader {
+            path: uri.into(),
+            header: REGION_HEADER.into(),
+        })?;
+    let region_name = String::from_utf8(new_region.as_bytes().to_vec())
+        .with_context(|_| UnableToParseUtf8Snafu::<String> { path: uri.into() })?;
+    Ok(Some(Region::new(region_name)))
+}
+
+/// Formats the `x-amz-copy-source` of a CopyObject request, which must be URL-encoded.
+fn encode_copy_source(bucket: &str, key: &str) -> String {
+    let mut copy_source = format!("{bucket}{S3_DELIMITER}");
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                copy_source.push(byte as char)
+            }
+            _ => copy_source.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    copy_source
+}
+
 impl S3LikeSource {
     pub async fn get_client(config: &S3Config) -> super::Result<Arc<S3LikeSource>> {
         Ok(build_client(config).await?.into())
@@ -900,6 +1074,408 @@ impl S3LikeSource {
                 .into()),
         }
     }
+
+    #[async_recursion]
+    async fn _put_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        uri: &str,
+        data: Bytes,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!(
+            "S3 put at {uri} of {} bytes in region: {region}",
+            data.len()
+        );
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from(data.clone()));
+
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(err) => match moved_region(uri, &err)? {
+                Some(new_region) => {
+                    log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting PUT in that region with new client", new_region, region);
+                    self._put_impl(_permit, uri, data, &new_region).await
+                }
+                None => Err(UnableToPutFileSnafu { path: uri }.into_error(err).into()),
+            },
+        }
+    }
+
+    #[async_recursion]
+    async fn _create_multipart_upload_impl(
+        &self,
+        uri: &str,
+        region: &Region,
+    ) -> super::Result<(Arc<s3::Client>, String)> {
+        log::debug!("S3 create multipart upload at {uri} in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = self.get_s3_client(region).await?;
+        let request = client.create_multipart_upload().bucket(bucket).key(key);
+
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(v) => {
+                let upload_id = v
+                    .upload_id()
+                    .ok_or(Error::MissingUploadId { path: uri.into() })?;
+                Ok((client, upload_id.to_string()))
+            }
+            Err(err) => match moved_region(uri, &err)? {
+                Some(new_region) => {
+                    log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting multipart upload in that region with new client", new_region, region);
+                    self._create_multipart_upload_impl(uri, &new_region).await
+                }
+                None => Err(UnableToCreateMultipartUploadSnafu { path: uri }
+                    .into_error(err)
+                    .into()),
+            },
+        }
+    }
+
+    #[async_recursion]
+    async fn _delete_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        uri: &str,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 delete at {uri} in region: {region}");
+        let (_scheme, bucket, key) = parse_url(uri)?;
+
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .delete_object()
+            .bucket(bucket)
+            .key(key);
+
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(err) => match moved_region(uri, &err)? {
+                Some(new_region) => {
+                    log::debug!("S3 Region of {uri} different than client {:?} vs {:?} Attempting DELETE in that region with new client", new_region, region);
+                    self._delete_impl(_permit, uri, &new_region).await
+                }
+                None => Err(UnableToDeleteFileSnafu { path: uri }.into_error(err).into()),
+            },
+        }
+    }
+
+    #[async_recursion]
+    async fn _copy_impl(
+        &self,
+        _permit: SemaphorePermit<'async_recursion>,
+        src: &str,
+        dst: &str,
+        region: &Region,
+    ) -> super::Result<()> {
+        log::debug!("S3 copy from {src} to {dst} in region: {region}");
+        let (_, src_bucket, src_key) = parse_url(src)?;
+        let (_scheme, bucket, key) = parse_url(dst)?;
+
+        if src_key.is_empty() {
+            return Err(Error::NotAFile { path: src.into() }.into());
+        }
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: dst.into() }.into());
+        }
+        let request = self
+            .get_s3_client(region)
+            .await?
+            .copy_object()
+            .copy_source(encode_copy_source(&src_bucket, &src_key))
+            .bucket(bucket)
+            .key(key);
+
+        let request = if self.s3_config.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(err) => match moved_region(dst, &err)? {
+                Some(new_region) => {
+                    log::debug!("S3 Region of {dst} different than client {:?} vs {:?} Attempting COPY in that region with new client", new_region, region);
+                    self._copy_impl(_permit, src, dst, &new_region).await
+                }
+                None => Err(UnableToCopyFileSnafu { path: src }.into_error(err).into()),
+            },
+        }
+    }
+
+    async fn s3_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<S3MultipartUpload> {
+        let (client, upload_id) = {
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            self._create_multipart_upload_impl(uri, &self.default_region)
+                .await?
+        };
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        Ok(S3MultipartUpload {
+            client,
+            connection_pool_sema: self.connection_pool_sema.clone(),
+            requester_pays: self.s3_config.requester_pays,
+            uri: uri.to_string(),
+            bucket,
+            key,
+            upload_id,
+            parts: vec![],
+            io_stats,
+        })
+    }
+
+    /// Copies an object of `size` bytes that is too large for a single copy request, by copying
+    /// its byte ranges as the parts of a multipart upload.
+    async fn multipart_copy(
+        &self,
+        src: &str,
+        dst: &str,
+        size: usize,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        let part_size = MIN_MULTIPART_COPY_PART_SIZE.max(size.div_ceil(MAX_MULTIPART_UPLOAD_PARTS));
+        let mut upload = self.s3_multipart_upload(dst, io_stats).await?;
+        for start in (0..size).step_by(part_size) {
+            let range = start..(start + part_size).min(size);
+            if let Err(err) = upload.upload_part_copy(src, range).await {
+                if let Err(abort_err) = Box::new(upload).abort().await {
+                    log::warn!("Unable to abort multipart copy to {dst}: {abort_err}");
+                }
+                return Err(err);
+            }
+        }
+        Box::new(upload).complete().await
+    }
+}
+
+/// Multipart upload of a single S3 object, bound to the client of the region of its bucket.
+pub(crate) struct S3MultipartUpload {
+    client: Arc<s3::Client>,
+    connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    requester_pays: bool,
+    uri: String,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    parts: Vec<CompletedPart>,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl S3MultipartUpload {
+    /// Uploads the bytes `range` of the object at `src` as the next part, copying them within S3
+    /// rather than downloading them.
+    async fn upload_part_copy(&mut self, src: &str, range: Range<usize>) -> super::Result<()> {
+        let (_scheme, src_bucket, src_key) = parse_url(src)?;
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let part_number = (self.parts.len() + 1) as i32;
+        log::debug!(
+            "S3 copy part {part_number} of bytes {range:?} of {src} for {}",
+            self.uri
+        );
+        let request = self
+            .client
+            .upload_part_copy()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .copy_source(encode_copy_source(&src_bucket, &src_key))
+            .copy_source_range(format!("bytes={}-{}", range.start, range.end - 1));
+
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        let response = request.send().await.context(UnableToCopyPartSnafu {
+            path: src,
+            dst: self.uri.as_str(),
+        })?;
+        let e_tag = response
+            .copy_part_result()
+            .and_then(|result| result.e_tag())
+            .ok_or(Error::MissingHeader {
+                path: self.uri.clone(),
+                header: "ETag".into(),
+            })?;
+        self.parts.push(
+            CompletedPart::builder()
+                .e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_copy_requests(1);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for S3MultipartUpload {
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let part_number = (self.parts.len() + 1) as i32;
+        log::debug!(
+            "S3 upload part {part_number} of {} bytes for {}",
+            data.len(),
+            self.uri
+        );
+        let num_bytes = data.len();
+        let request = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(data));
+
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        let response = request.send().await.context(UnableToUploadPartSnafu {
+            path: self.uri.as_str(),
+        })?;
+        let e_tag = response.e_tag().ok_or(Error::MissingHeader {
+            path: self.uri.clone(),
+            header: "ETag".into(),
+        })?;
+        self.parts.push(
+            CompletedPart::builder()
+                .e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        if let Some(is) = self.io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn complete(self: Box<Self>) -> super::Result<()> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        log::debug!(
+            "S3 complete multipart upload of {} parts for {}",
+            self.parts.len(),
+            self.uri
+        );
+        let request = self
+            .client
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(self.parts.clone()))
+                    .build(),
+            );
+
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        request
+            .send()
+            .await
+            .context(UnableToCompleteMultipartUploadSnafu {
+                path: self.uri.as_str(),
+            })?;
+        Ok(())
+    }
+
+    async fn abort(self: Box<Self>) -> super::Result<()> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        log::debug!("S3 abort multipart upload for {}", self.uri);
+        let request = self
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id);
+
+        let request = if self.requester_pays {
+            request.request_payer(s3::types::RequestPayer::Requester)
+        } else {
+            request
+        };
+
+        request
+            .send()
+            .await
+            .context(UnableToAbortMultipartUploadSnafu {
+                path: self.uri.as_str(),
+            })?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -951,6 +1527,61 @@ impl ObjectSource for S3LikeSource {
         Ok(head_result)
     }
 
+    async fn put(&self, uri: &str, data: Bytes, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let num_bytes = data.len();
+        self._put_impl(permit, uri, data, &self.default_region)
+            .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_written(num_bytes);
+        }
+        Ok(())
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Box<dyn MultipartUpload>> {
+        Ok(Box::new(self.s3_multipart_upload(uri, io_stats).await?))
+    }
+
+    async fn delete(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._delete_impl(permit, uri, &self.default_region).await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_delete_requests(1)
+        }
+        Ok(())
+    }
+
+    async fn copy(&self, src: &str, dst: &str, io_stats: Option<IOStatsRef>) -> super::Result<()> {
+        let size = self.get_size(src, io_stats.clone()).await?;
+        if size > MAX_COPY_OBJECT_SIZE {
+            return self.multipart_copy(src, dst, size, io_stats).await;
+        }
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self._copy_impl(permit, src, dst, &self.default_region)
+            .await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_copy_requests(1)
+        }
+        Ok(())
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -1083,11 +1714,121 @@ impl ObjectSource for S3LikeSource {
 #[cfg(test)]
 mod tests {
 
+    use std::sync::Arc;
+
+    use bytes::Bytes;
+    use tokio::io::AsyncWriteExt;
+
     use crate::object_io::ObjectSource;
+    use crate::object_writer::ObjectWriter;
     use crate::Result;
     use crate::S3LikeSource;
     use common_io_config::S3Config;
 
+    const MINIO_BUCKET: &str = "daft-io-test-bucket";
+
+    /// Returns a client of the MinIO server of the integration tests, along with a unique prefix
+    /// in its test bucket.
+    async fn minio_client() -> Result<(Arc<S3LikeSource>, String)> {
+        let config = S3Config {
+            endpoint_url: Some("http://127.0.0.1:9000".to_string()),
+            key_id: Some("minioadmin".to_string()),
+            access_key: Some("minioadmin".to_string()),
+            ..Default::default()
+        };
+        let client = S3LikeSource::get_client(&config).await?;
+        // Fails if the bucket was already created by an earlier test.
+        let _ = client
+            .get_s3_client(&client.default_region)
+            .await?
+            .create_bucket()
+            .bucket(MINIO_BUCKET)
+            .send()
+            .await;
+        let prefix = format!("s3://{MINIO_BUCKET}/{:016x}", rand::random::<u64>());
+        Ok((client, prefix))
+    }
+
+    fn test_bytes(len: usize) -> Bytes {
+        (0..len).map(|i| (i % 251) as u8).collect::<Vec<_>>().into()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires the MinIO server of the integration tests"]
+    async fn test_put_and_delete_minio() -> Result<()> {
+        let (client, prefix) = minio_client().await?;
+        let uri = format!("{prefix}/put.bin");
+        let data = test_bytes(1024);
+
+        client.put(&uri, data.clone(), None).await?;
+        assert_eq!(client.get(&uri, None, None).await?.bytes().await?, data);
+        assert_eq!(client.get_size(&uri, None).await?, data.len());
+
+        client.delete(&uri, None).await?;
+        assert!(matches!(
+            client.get_size(&uri, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "requires the MinIO server of the integration tests"]
+    async fn test_multipart_upload_minio() -> Result<()> {
+        let (client, prefix) = minio_client().await?;
+        let uri = format!("{prefix}/multipart.bin");
+        // Spans two full parts and a smaller last part.
+        let data = test_bytes(25 * 1024 * 1024);
+
+        let mut writer = ObjectWriter::new(uri.clone(), client.clone(), None);
+        writer.write_all(&data).await.unwrap();
+        writer.shutdown().await.unwrap();
+        assert_eq!(client.get(&uri, None, None).await?.bytes().await?, data);
+
+        let aborted_uri = format!("{prefix}/aborted.bin");
+        let mut writer = ObjectWriter::new(aborted_uri.clone(), client.clone(), None);
+        writer.write_all(&data).await.unwrap();
+        writer.abort().await?;
+        assert!(writer.shutdown().await.is_err());
+        assert!(matches!(
+            client.get_size(&aborted_uri, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+
+        client.delete(&uri, None).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "requires the MinIO server of the integration tests"]
+    async fn test_copy_and_rename_minio() -> Result<()> {
+        let (client, prefix) = minio_client().await?;
+        let src = format!("{prefix}/src.bin");
+        let dst = format!("{prefix}/dst.bin");
+        let renamed = format!("{prefix}/renamed.bin");
+        let data = test_bytes(3 * 1024 * 1024 + 7);
+        client.put(&src, data.clone(), None).await?;
+
+        client.copy(&src, &dst, None).await?;
+        assert_eq!(client.get(&dst, None, None).await?.bytes().await?, data);
+
+        // Objects over 5GiB are copied in parts, which works the same way for small objects.
+        client.delete(&dst, None).await?;
+        client.multipart_copy(&src, &dst, data.len(), None).await?;
+        assert_eq!(client.get(&dst, None, None).await?.bytes().await?, data);
+
+        client.rename(&dst, &renamed, None).await?;
+        assert_eq!(client.get(&renamed, None, None).await?.bytes().await?, data);
+        assert!(matches!(
+            client.get_size(&dst, None).await,
+            Err(crate::Error::NotFound { .. })
+        ));
+
+        client.delete(&src, None).await?;
+        client.delete(&renamed, None).await?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_full_get_from_s3() -> Result<()> {
         let parquet_file_path = "s3://daft-public-data/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
@@ -14,7 +14,14 @@ pub struct IOStatsContext {
     num_get_requests: atomic::AtomicUsize,
     num_head_requests: atomic::AtomicUsize,
     num_list_requests: atomic::AtomicUsize,
+    num_put_requests: atomic::AtomicUsize,
+    num_delete_requests: atomic::AtomicUsize,
+    num_copy_requests: atomic::AtomicUsize,
+    num_rename_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
+    bytes_written: atomic::AtomicUsize,
+    num_row_groups_skipped: atomic::AtomicUsize,
+    num_pages_skipped: atomic::AtomicUsize,
 }
 
 impl Drop for IOStatsContext {
@@ -23,13 +30,20 @@ impl Drop for IOStatsContext {
         let num_gets = self.load_get_requests();
         let mean_size = (bytes_read as f64) / (num_gets as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, Puts: {}, Deletes: {}, Copies: {}, Renames: {}, BytesRead: {}, AvgGetSize: {}, BytesWritten: {}, RowGroupsSkipped: {}, PagesSkipped: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
             self.load_list_requests(),
+            self.load_put_requests(),
+            self.load_delete_requests(),
+            self.load_copy_requests(),
+            self.load_rename_requests(),
             bytes_read,
-            mean_size as i64
+            mean_size as i64,
+            self.load_bytes_written(),
+            self.load_row_groups_skipped(),
+            self.load_pages_skipped()
         );
     }
 }
@@ -47,7 +61,14 @@ impl IOStatsContext {
             num_get_requests: atomic::AtomicUsize::new(0),
             num_head_requests: atomic::AtomicUsize::new(0),
             num_list_requests: atomic::AtomicUsize::new(0),
+            num_put_requests: atomic::AtomicUsize::new(0),
+            num_delete_requests: atomic::AtomicUsize::new(0),
+            num_copy_requests: atomic::AtomicUsize::new(0),
+            num_rename_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
+            bytes_written: atomic::AtomicUsize::new(0),
+            num_row_groups_skipped: atomic::AtomicUsize::new(0),
+            num_pages_skipped: atomic::AtomicUsize::new(0),
         })
     }
 
@@ -69,6 +90,32 @@ impl IOStatsContext {
             .fetch_add(num_requests, atomic::Ordering::Relaxed);
     }
 
+    /// Marks requests that upload data, counting each part of a multipart upload separately.
+    #[inline]
+    pub(crate) fn mark_put_requests(&self, num_requests: usize) {
+        self.num_put_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_delete_requests(&self, num_requests: usize) {
+        self.num_delete_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_copy_requests(&self, num_requests: usize) {
+        self.num_copy_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
+    /// Marks native renames, which stores without them perform as a copy and a delete instead.
+    #[inline]
+    pub(crate) fn mark_rename_requests(&self, num_requests: usize) {
+        self.num_rename_requests
+            .fetch_add(num_requests, atomic::Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn load_get_requests(&self) -> usize {
         self.num_get_requests.load(atomic::Ordering::Acquire)
@@ -84,6 +131,26 @@ impl IOStatsContext {
         self.num_list_requests.load(atomic::Ordering::Acquire)
     }
 
+    #[inline]
+    pub fn load_put_requests(&self) -> usize {
+        self.num_put_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_delete_requests(&self) -> usize {
+        self.num_delete_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_copy_requests(&self) -> usize {
+        self.num_copy_requests.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_rename_requests(&self) -> usize {
+        self.num_rename_requests.load(atomic::Ordering::Acquire)
+    }
+
     #[inline]
     pub(crate) fn mark_bytes_read(&self, bytes_read: usize) {
         self.bytes_read
@@ -94,6 +161,41 @@ impl IOStatsContext {
     pub fn load_bytes_read(&self) -> usize {
         self.bytes_read.load(atomic::Ordering::Acquire)
     }
+
+    #[inline]
+    pub(crate) fn mark_bytes_written(&self, bytes_written: usize) {
+        self.bytes_written
+            .fetch_add(bytes_written, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn load_bytes_written(&self) -> usize {
+        self.bytes_written.load(atomic::Ordering::Acquire)
+    }
+
+    /// Marks row groups of a file that were pruned by its statistics, bloom filters or page index.
+    #[inline]
+    pub fn mark_row_groups_skipped(&self, num_row_groups: usize) {
+        self.num_row_groups_skipped
+            .fetch_add(num_row_groups, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn load_row_groups_skipped(&self) -> usize {
+        self.num_row_groups_skipped.load(atomic::Ordering::Acquire)
+    }
+
+    /// Marks pages of selected row groups that were pruned by the page index of a file.
+    #[inline]
+    pub fn mark_pages_skipped(&self, num_pages: usize) {
+        self.num_pages_skipped
+            .fetch_add(num_pages, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn load_pages_skipped(&self) -> usize {
+        self.num_pages_skipped.load(atomic::Ordering::Acquire)
+    }
 }
 
 impl IOStatsByteStreamContextHandle {
@@ -13,12 +13,14 @@ pub mod options;
 pub mod python;
 pub mod read;
 pub mod schema;
+pub mod write;
 
 // pub use metadata::read_json_schema_bulk;
 pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_json, read_json_bulk};
+pub use write::{write_json, write_json_to_writer};
 
 #[derive(Debug, Snafu)]
 pub enum Error {
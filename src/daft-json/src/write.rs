@@ -0,0 +1,187 @@
+use std::{pin::Pin, sync::Arc};
+
+use arrow2::{
+    array::{Array, StructArray},
+    datatypes::DataType,
+    io::ndjson::write::{FallibleStreamingIterator, Serializer},
+};
+use common_error::{DaftError, DaftResult};
+use daft_compression::CompressionCodec;
+use daft_core::schema::Schema;
+use daft_io::{get_runtime, IOClient, IOStatsRef};
+use daft_table::Table;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Number of rows serialized at a time, bounding the size of the buffered output.
+const ROWS_PER_CHUNK: usize = 8 * 1024;
+
+/// Writes the rows of `tables` as newline-delimited JSON objects into `writer`, returning it once
+/// all rows are written.
+///
+/// Struct and list columns are written as nested JSON objects and arrays.
+pub async fn write_json_to_writer<W: AsyncWrite + Unpin + Send>(
+    mut writer: W,
+    tables: &[Table],
+    schema: &Schema,
+) -> DaftResult<W> {
+    let arrow_schema = schema.to_arrow()?;
+    if let Some(field) = arrow_schema
+        .fields
+        .iter()
+        .find(|f| !is_json_serializable(f.data_type()))
+    {
+        return Err(DaftError::TypeError(format!(
+            "Unable to write column {} of type {} to JSON",
+            field.name,
+            schema.get_field(&field.name)?.dtype
+        )));
+    }
+    let rows_type = DataType::Struct(arrow_schema.fields);
+
+    for table in tables {
+        if table.schema.as_ref() != schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Unable to write table with schema {} into JSON with schema {}",
+                table.schema, schema
+            )));
+        }
+        let columns = (0..table.num_columns())
+            .map(|i| Ok(table.get_column_by_index(i)?.to_arrow()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let rows = StructArray::try_new(rows_type.clone(), columns, None)?;
+
+        let chunks = (0..rows.len()).step_by(ROWS_PER_CHUNK).map(|start| {
+            Ok(Array::sliced(
+                &rows,
+                start,
+                ROWS_PER_CHUNK.min(rows.len() - start),
+            ))
+        });
+        let mut serializer = Serializer::new(chunks, vec![]);
+        while let Some(lines) = serializer.next()? {
+            writer.write_all(lines).await?;
+        }
+    }
+    Ok(writer)
+}
+
+fn is_json_serializable(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Null
+        | DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(..)
+        | DataType::Duration(_) => true,
+        DataType::Struct(fields) => fields.iter().all(|f| is_json_serializable(f.data_type())),
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            is_json_serializable(field.data_type())
+        }
+        _ => false,
+    }
+}
+
+/// Writes `tables` into a newline-delimited JSON file at `uri` in any store supported by
+/// `io_client`, compressed with `compression` if given, e.g. with the codec inferred from the
+/// extension of `uri` by [`CompressionCodec::from_uri`].
+pub fn write_json(
+    uri: &str,
+    tables: &[Table],
+    schema: &Schema,
+    compression: Option<CompressionCodec>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let runtime_handle = get_runtime(true)?;
+    let _rt_guard = runtime_handle.enter();
+    runtime_handle.block_on(async {
+        let mut object_writer = io_client.open_writer(uri.to_string(), io_stats).await?;
+
+        let result: DaftResult<()> = async {
+            let writer: Pin<Box<dyn AsyncWrite + Send + '_>> = match compression {
+                Some(compression) => compression.to_encoder(&mut object_writer),
+                None => Box::pin(&mut object_writer),
+            };
+            let mut writer = write_json_to_writer(writer, tables, schema).await?;
+            writer.shutdown().await?;
+            Ok(())
+        }
+        .await;
+        if result.is_err() {
+            if let Err(abort_err) = object_writer.abort().await {
+                log::warn!("Unable to abort write of JSON file {uri}: {abort_err}");
+            }
+        }
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_compression::CompressionCodec;
+    use daft_io::{IOClient, IOConfig};
+    use rstest::rstest;
+
+    use super::write_json;
+    use crate::read::read_json;
+
+    #[rstest]
+    fn test_json_write_roundtrip(
+        #[values(None, Some("gz"), Some("zst"))] compression: Option<&str>,
+    ) -> DaftResult<()> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"));
+        let table = read_json(
+            file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+
+        let dir = tempfile::tempdir()?;
+        let out = format!(
+            "{}/out/iris.jsonl{}",
+            dir.path().to_string_lossy(),
+            compression.map_or("".to_string(), |ext| format!(".{}", ext))
+        );
+        write_json(
+            &out,
+            std::slice::from_ref(&table),
+            &table.schema,
+            CompressionCodec::from_uri(&out),
+            io_client.clone(),
+            None,
+        )?;
+
+        let read = read_json(out.as_ref(), None, None, None, io_client, None, true, None)?;
+        assert_eq!(read.schema, table.schema);
+        assert_eq!(read.len(), table.len());
+        for name in table.schema.names() {
+            assert_eq!(
+                read.get_column(&name)?.to_arrow(),
+                table.get_column(&name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use snafu::ResultExt;
 
 use crate::{micropartition::MicroPartition, DaftCoreComputeSnafu};
 
-use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use daft_stats::{ColumnStatistics, TableStatistics};
 
 fn infer_schema(exprs: &[ExprRef], schema: &Schema) -> DaftResult<Schema> {
     let fields = exprs
@@ -63,12 +63,13 @@ impl MicroPartition {
             .collect::<DaftResult<Vec<_>>>()?;
         let expected_new_columns = infer_schema(exprs, &self.schema)?;
         let eval_stats = if let Some(stats) = &self.statistics {
-            let mut new_stats = stats.columns.clone();
+            // Exploding changes the number of rows, so only the ranges of other columns still hold
+            let mut new_stats = stats.without_counts().columns;
             for (name, _) in expected_new_columns.fields.iter() {
                 if let Some(v) = new_stats.get_mut(name) {
-                    *v = ColumnRangeStatistics::Missing;
+                    *v = ColumnStatistics::missing();
                 } else {
-                    new_stats.insert(name.to_string(), ColumnRangeStatistics::Missing);
+                    new_stats.insert(name.to_string(), ColumnStatistics::missing());
                 }
             }
             Some(TableStatistics { columns: new_stats })
@@ -1,67 +1,112 @@
 use common_error::DaftResult;
-use daft_core::array::ops::DaftCompare;
+use daft_core::{array::ops::DaftCompare, join::JoinType};
 use daft_dsl::ExprRef;
-use daft_io::IOStatsContext;
-use daft_table::infer_join_schema;
+use daft_io::{IOStatsContext, IOStatsRef};
+use daft_table::{infer_join_schema, Table};
 
 use crate::micropartition::MicroPartition;
 
 use daft_stats::TruthValue;
 
 impl MicroPartition {
-    pub fn hash_join(
+    fn join<F>(
         &self,
         right: &Self,
+        io_stats: IOStatsRef,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
-    ) -> DaftResult<Self> {
-        let io_stats = IOStatsContext::new("MicroPartition::hash_join");
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
+        how: JoinType,
+        table_join: F,
+    ) -> DaftResult<Self>
+    where
+        F: FnOnce(&Table, &Table, &[ExprRef], &[ExprRef], JoinType) -> DaftResult<Table>,
+    {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
 
-        if self.len() == 0 || right.len() == 0 {
+        // Whether the join can only produce an empty output, given which sides are empty.
+        let is_output_empty = match how {
+            JoinType::Inner | JoinType::Semi => self.len() == 0 || right.len() == 0,
+            JoinType::Left | JoinType::Anti => self.len() == 0,
+            JoinType::Right => right.len() == 0,
+            JoinType::Outer => self.len() == 0 && right.len() == 0,
+        };
+        if is_output_empty {
             return Ok(Self::empty(Some(join_schema.into())));
         }
 
-        let tv = match (&self.statistics, &right.statistics) {
-            (_, None) => TruthValue::Maybe,
-            (None, _) => TruthValue::Maybe,
-            (Some(l), Some(r)) => {
-                let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
-                let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
-                let mut curr_tv = TruthValue::Maybe;
-                for (lc, rc) in l_eval_stats
-                    .columns
-                    .values()
-                    .zip(r_eval_stats.columns.values())
-                {
-                    if let TruthValue::False = lc.equal(rc)?.to_truth_value() {
-                        curr_tv = TruthValue::False;
-                        break;
+        // Statistics can only prune joins that never emit unmatched rows.
+        if matches!(how, JoinType::Inner | JoinType::Semi) {
+            let tv = match (&self.statistics, &right.statistics) {
+                (_, None) => TruthValue::Maybe,
+                (None, _) => TruthValue::Maybe,
+                (Some(l), Some(r)) => {
+                    let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
+                    let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
+                    let mut curr_tv = TruthValue::Maybe;
+                    for (lc, rc) in l_eval_stats
+                        .columns
+                        .values()
+                        .zip(r_eval_stats.columns.values())
+                    {
+                        if let TruthValue::False = lc.range.equal(&rc.range)?.to_truth_value() {
+                            curr_tv = TruthValue::False;
+                            break;
+                        }
                     }
+                    curr_tv
                 }
-                curr_tv
+            };
+            if let TruthValue::False = tv {
+                return Ok(Self::empty(Some(join_schema.into())));
             }
-        };
-        if let TruthValue::False = tv {
-            return Ok(Self::empty(Some(join_schema.into())));
         }
 
         // TODO(Clark): Elide concatenations where possible by doing a chunk-aware local table join.
         let lt = self.concat_or_get(io_stats.clone())?;
         let rt = right.concat_or_get(io_stats)?;
 
-        match (lt.as_slice(), rt.as_slice()) {
-            ([], _) | (_, []) => Ok(Self::empty(Some(join_schema.into()))),
-            ([lt], [rt]) => {
-                let joined_table = lt.hash_join(rt, left_on, right_on)?;
-                Ok(MicroPartition::new_loaded(
-                    join_schema.into(),
-                    vec![joined_table].into(),
-                    None,
-                ))
+        let empty_lt;
+        let lt = match lt.as_slice() {
+            [] => {
+                empty_lt = Table::empty(Some(self.schema.clone()))?;
+                &empty_lt
             }
+            [lt] => lt,
             _ => unreachable!(),
-        }
+        };
+        let empty_rt;
+        let rt = match rt.as_slice() {
+            [] => {
+                empty_rt = Table::empty(Some(right.schema.clone()))?;
+                &empty_rt
+            }
+            [rt] => rt,
+            _ => unreachable!(),
+        };
+        let joined_table = table_join(lt, rt, left_on, right_on, how)?;
+        Ok(MicroPartition::new_loaded(
+            join_schema.into(),
+            vec![joined_table].into(),
+            None,
+        ))
+    }
+
+    pub fn hash_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::hash_join");
+        self.join(
+            right,
+            io_stats,
+            left_on,
+            right_on,
+            how,
+            |lt, rt, left_on, right_on, how| lt.hash_join(rt, left_on, right_on, how),
+        )
     }
 
     pub fn sort_merge_join(
@@ -69,54 +114,31 @@ impl MicroPartition {
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        how: JoinType,
         is_sorted: bool,
     ) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::sort_merge_join");
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
-
-        if self.len() == 0 || right.len() == 0 {
-            return Ok(Self::empty(Some(join_schema.into())));
-        }
-
-        let tv = match (&self.statistics, &right.statistics) {
-            (_, None) => TruthValue::Maybe,
-            (None, _) => TruthValue::Maybe,
-            (Some(l), Some(r)) => {
-                let l_eval_stats = l.eval_expression_list(left_on, &self.schema)?;
-                let r_eval_stats = r.eval_expression_list(right_on, &right.schema)?;
-                let mut curr_tv = TruthValue::Maybe;
-                for (lc, rc) in l_eval_stats
-                    .columns
-                    .values()
-                    .zip(r_eval_stats.columns.values())
-                {
-                    if let TruthValue::False = lc.equal(rc)?.to_truth_value() {
-                        curr_tv = TruthValue::False;
-                        break;
-                    }
-                }
-                curr_tv
-            }
-        };
-        if let TruthValue::False = tv {
-            return Ok(Self::empty(Some(join_schema.into())));
-        }
-
-        // TODO(Clark): Elide concatenations where possible by doing a chunk-aware local table join.
-        let lt = self.concat_or_get(io_stats.clone())?;
-        let rt = right.concat_or_get(io_stats)?;
+        self.join(
+            right,
+            io_stats,
+            left_on,
+            right_on,
+            how,
+            |lt, rt, left_on, right_on, how| {
+                lt.sort_merge_join(rt, left_on, right_on, how, is_sorted)
+            },
+        )
+    }
 
-        match (lt.as_slice(), rt.as_slice()) {
-            ([], _) | (_, []) => Ok(Self::empty(Some(join_schema.into()))),
-            ([lt], [rt]) => {
-                let joined_table = lt.sort_merge_join(rt, left_on, right_on, is_sorted)?;
-                Ok(MicroPartition::new_loaded(
-                    join_schema.into(),
-                    vec![joined_table].into(),
-                    None,
-                ))
-            }
-            _ => unreachable!(),
-        }
+    pub fn nested_loop_join(
+        &self,
+        right: &Self,
+        predicate: Option<&ExprRef>,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::nested_loop_join");
+        self.join(right, io_stats, &[], &[], how, |lt, rt, _, _, how| {
+            lt.nested_loop_join(rt, predicate, how)
+        })
     }
 }
@@ -24,7 +24,8 @@ impl MicroPartition {
                 Ok(Self::new_loaded(
                     self.schema.clone(),
                     Arc::new(vec![taken]),
-                    self.statistics.clone(),
+                    // Null indices take null rows, so counts of nulls no longer hold
+                    self.statistics.as_ref().map(|s| s.without_counts()),
                 ))
             }
             [single] => {
@@ -32,7 +33,7 @@ impl MicroPartition {
                 Ok(Self::new_loaded(
                     self.schema.clone(),
                     Arc::new(vec![taken]),
-                    self.statistics.clone(),
+                    self.statistics.as_ref().map(|s| s.without_counts()),
                 ))
             }
             _ => unreachable!(),
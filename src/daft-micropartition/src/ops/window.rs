@@ -0,0 +1,42 @@
+use common_error::DaftResult;
+use daft_dsl::ExprRef;
+use daft_io::IOStatsContext;
+use daft_table::Table;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn window(
+        &self,
+        window_exprs: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+
+        match tables.as_slice() {
+            [] => {
+                let empty_table = Table::empty(Some(self.schema.clone()))?;
+                let windowed =
+                    empty_table.window(window_exprs, partition_by, order_by, descending)?;
+                Ok(MicroPartition::new_loaded(
+                    windowed.schema.clone(),
+                    vec![windowed].into(),
+                    None,
+                ))
+            }
+            [t] => {
+                let windowed = t.window(window_exprs, partition_by, order_by, descending)?;
+                Ok(MicroPartition::new_loaded(
+                    windowed.schema.clone(),
+                    vec![windowed].into(),
+                    None,
+                ))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
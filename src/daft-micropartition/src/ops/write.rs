@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_compression::CompressionCodec;
+use daft_csv::{write_csv, CsvWriteOptions};
+use daft_io::{IOClient, IOStatsContext};
+use daft_json::write_json;
+use daft_parquet::write::{write_parquet, ParquetWriteOptions};
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    /// Writes this MicroPartition into a single Parquet file at `uri`, returning the size of the file.
+    pub fn write_parquet(
+        &self,
+        uri: &str,
+        options: ParquetWriteOptions,
+        io_client: Arc<IOClient>,
+    ) -> DaftResult<u64> {
+        let io_stats = IOStatsContext::new(format!("MicroPartition::write_parquet to {uri}"));
+
+        let tables = self.concat_or_get(io_stats.clone())?;
+        write_parquet(
+            uri,
+            tables.as_slice(),
+            self.schema.clone(),
+            options,
+            io_client,
+            Some(io_stats),
+        )
+    }
+
+    /// Writes this MicroPartition into a single CSV file at `uri`, compressed with the codec
+    /// inferred from its extension, if any.
+    pub fn write_csv(
+        &self,
+        uri: &str,
+        options: &CsvWriteOptions,
+        io_client: Arc<IOClient>,
+    ) -> DaftResult<()> {
+        let io_stats = IOStatsContext::new(format!("MicroPartition::write_csv to {uri}"));
+
+        let tables = self.concat_or_get(io_stats.clone())?;
+        write_csv(
+            uri,
+            tables.as_slice(),
+            &self.schema,
+            options,
+            CompressionCodec::from_uri(uri),
+            io_client,
+            Some(io_stats),
+        )
+    }
+
+    /// Writes this MicroPartition into a single newline-delimited JSON file at `uri`, compressed
+    /// with the codec inferred from its extension, if any.
+    pub fn write_json(&self, uri: &str, io_client: Arc<IOClient>) -> DaftResult<()> {
+        let io_stats = IOStatsContext::new(format!("MicroPartition::write_json to {uri}"));
+
+        let tables = self.concat_or_get(io_stats.clone())?;
+        write_json(
+            uri,
+            tables.as_slice(),
+            &self.schema,
+            CompressionCodec::from_uri(uri),
+            io_client,
+            Some(io_stats),
+        )
+    }
+}
@@ -5,15 +5,19 @@ use std::{
 
 use common_error::DaftResult;
 use daft_core::{
+    join::JoinType,
     python::{datatype::PyTimeUnit, schema::PySchema, PySeries},
     schema::Schema,
     Series,
 };
-use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
+use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions, CsvWriteOptions};
 use daft_dsl::python::PyExpr;
-use daft_io::{python::IOConfig, IOStatsContext};
+use daft_io::{get_io_client, python::IOConfig, IOStatsContext};
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
-use daft_parquet::read::ParquetSchemaInferenceOptions;
+use daft_parquet::{
+    read::ParquetSchemaInferenceOptions,
+    write::{parse_compression, ParquetWriteOptions},
+};
 use daft_scan::{python::pylib::PyScanTask, storage_config::PyStorageConfig, ScanTask};
 use daft_stats::TableStatistics;
 use daft_table::python::PyTable;
@@ -229,13 +233,19 @@ impl PyMicroPartition {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::ExprRef> = left_on.into_iter().map(|e| e.into()).collect();
         let right_exprs: Vec<daft_dsl::ExprRef> = right_on.into_iter().map(|e| e.into()).collect();
         py.allow_threads(|| {
             Ok(self
                 .inner
-                .hash_join(&right.inner, left_exprs.as_slice(), right_exprs.as_slice())?
+                .hash_join(
+                    &right.inner,
+                    left_exprs.as_slice(),
+                    right_exprs.as_slice(),
+                    how,
+                )?
                 .into())
         })
     }
@@ -246,6 +256,7 @@ impl PyMicroPartition {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::ExprRef> = left_on.into_iter().map(|e| e.into()).collect();
@@ -257,12 +268,56 @@ impl PyMicroPartition {
                     &right.inner,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    how,
                     is_sorted,
                 )?
                 .into())
         })
     }
 
+    pub fn nested_loop_join(
+        &self,
+        py: Python,
+        right: &Self,
+        predicate: Option<PyExpr>,
+        how: JoinType,
+    ) -> PyResult<Self> {
+        let predicate: Option<daft_dsl::ExprRef> = predicate.map(|e| e.into());
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .nested_loop_join(&right.inner, predicate.as_ref(), how)?
+                .into())
+        })
+    }
+
+    pub fn window(
+        &self,
+        py: Python,
+        window_exprs: Vec<PyExpr>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let converted_window_exprs: Vec<daft_dsl::ExprRef> =
+            window_exprs.into_iter().map(|e| e.into()).collect();
+        let converted_partition_by: Vec<daft_dsl::ExprRef> =
+            partition_by.into_iter().map(|e| e.into()).collect();
+        let converted_order_by: Vec<daft_dsl::ExprRef> =
+            order_by.into_iter().map(|e| e.into()).collect();
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .window(
+                    converted_window_exprs.as_slice(),
+                    converted_partition_by.as_slice(),
+                    converted_order_by.as_slice(),
+                    descending.as_slice(),
+                )?
+                .into())
+        })
+    }
+
     pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
@@ -595,6 +650,51 @@ impl PyMicroPartition {
         Ok(mp.into())
     }
 
+    /// Writes this MicroPartition into a single Parquet file at `uri`, returning the size of the file.
+    pub fn write_parquet(
+        &self,
+        py: Python,
+        uri: &str,
+        compression: Option<&str>,
+        row_group_size: usize,
+        inflation_factor: f64,
+        io_config: Option<IOConfig>,
+    ) -> PyResult<u64> {
+        py.allow_threads(|| {
+            let mut options = ParquetWriteOptions {
+                row_group_size,
+                inflation_factor,
+                ..Default::default()
+            };
+            if let Some(compression) = compression {
+                options.compression = parse_compression(compression, None)?;
+            }
+            let io_config = io_config.unwrap_or_default().config.into();
+            let io_client = get_io_client(true, io_config)?;
+            Ok(self.inner.write_parquet(uri, options, io_client)?)
+        })
+    }
+
+    /// Writes this MicroPartition into a single CSV file at `uri`.
+    pub fn write_csv(&self, py: Python, uri: &str, io_config: Option<IOConfig>) -> PyResult<()> {
+        py.allow_threads(|| {
+            let io_config = io_config.unwrap_or_default().config.into();
+            let io_client = get_io_client(true, io_config)?;
+            Ok(self
+                .inner
+                .write_csv(uri, &CsvWriteOptions::default(), io_client)?)
+        })
+    }
+
+    /// Writes this MicroPartition into a single newline-delimited JSON file at `uri`.
+    pub fn write_json(&self, py: Python, uri: &str, io_config: Option<IOConfig>) -> PyResult<()> {
+        py.allow_threads(|| {
+            let io_config = io_config.unwrap_or_default().config.into();
+            let io_client = get_io_client(true, io_config)?;
+            Ok(self.inner.write_json(uri, io_client)?)
+        })
+    }
+
     #[staticmethod]
     pub fn _from_unloaded_table_state(
         schema_bytes: &PyBytes,
@@ -1,5 +1,6 @@
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
+    ops::Range,
     sync::Arc,
 };
 
@@ -22,6 +23,7 @@ use snafu::ResultExt;
 
 use crate::{
     metadata::read_parquet_metadata,
+    pruning::{self, PageSelection},
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     statistics, JoinSnafu, OneShotRecvSnafu, UnableToConvertRowGroupMetadataToStatsSnafu,
@@ -33,6 +35,8 @@ use arrow2::io::parquet::read::column_iter_to_arrays;
 pub(crate) struct ParquetReaderBuilder {
     pub uri: String,
     pub metadata: parquet2::metadata::FileMetaData,
+    file_size: usize,
+    io_stats: Option<IOStatsRef>,
     selected_columns: Option<HashSet<String>>,
     row_start_offset: usize,
     limit: Option<usize>,
@@ -56,6 +60,28 @@ fn streaming_decompression<S: futures::Stream<Item = parquet2::error::Result<Com
     }
 }
 
+/// Stops reading a stream of pages after `num_data_pages` data pages, for column chunks of which
+/// only some pages were fetched.
+fn limit_data_pages<S: futures::Stream<Item = parquet2::error::Result<CompressedPage>>>(
+    input: S,
+    num_data_pages: Option<usize>,
+) -> impl futures::Stream<Item = parquet2::error::Result<CompressedPage>> {
+    async_stream::stream! {
+        let mut remaining = num_data_pages.unwrap_or(usize::MAX);
+        futures::pin_mut!(input);
+        while remaining > 0 {
+            match input.next().await {
+                Some(Ok(CompressedPage::Data(page))) => {
+                    remaining -= 1;
+                    yield Ok(CompressedPage::Data(page));
+                }
+                Some(page) => yield page,
+                None => break,
+            }
+        }
+    }
+}
+
 pub struct StreamIterator<S> {
     curr: Option<Page>,
     src: tokio::sync::Mutex<S>,
@@ -100,6 +126,7 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn build_row_ranges(
     limit: Option<usize>,
     row_start_offset: usize,
@@ -108,6 +135,7 @@ pub(crate) fn build_row_ranges(
     schema: &Schema,
     metadata: &parquet2::metadata::FileMetaData,
     uri: &str,
+    io_stats: Option<IOStatsRef>,
 ) -> super::Result<Vec<RowGroupRange>> {
     let limit = limit.map(|v| v as i64);
     let mut row_ranges = vec![];
@@ -140,6 +168,9 @@ pub(crate) fn build_row_ranges(
                     }
                 })?;
                 if evaled.to_truth_value() == TruthValue::False {
+                    if let Some(io_stats) = io_stats.as_ref() {
+                        io_stats.mark_row_groups_skipped(1);
+                    }
                     continue;
                 }
             }
@@ -171,6 +202,9 @@ pub(crate) fn build_row_ranges(
                         }
                     })?;
                     if evaled.to_truth_value() == TruthValue::False {
+                        if let Some(io_stats) = io_stats.as_ref() {
+                            io_stats.mark_row_groups_skipped(1);
+                        }
                         curr_row_index += rg.num_rows();
                         continue;
                     }
@@ -203,10 +237,12 @@ impl ParquetReaderBuilder {
             .single_url_get_size(uri.into(), io_stats.clone())
             .await?;
         let metadata =
-            read_parquet_metadata(uri, size, io_client, io_stats, field_id_mapping).await?;
+            read_parquet_metadata(uri, size, io_client, io_stats.clone(), field_id_mapping).await?;
         Ok(ParquetReaderBuilder {
             uri: uri.into(),
             metadata,
+            file_size: size,
+            io_stats,
             selected_columns: None,
             row_start_offset: 0,
             limit: None,
@@ -298,12 +334,35 @@ impl ParquetReaderBuilder {
             &daft_schema,
             &self.metadata,
             &self.uri,
+            self.io_stats,
         )?;
 
-        ParquetFileReader::new(self.uri, self.metadata, arrow_schema, row_ranges)
+        ParquetFileReader::new(
+            self.uri,
+            self.metadata,
+            self.file_size,
+            arrow_schema,
+            row_ranges,
+            self.predicate,
+        )
     }
 }
 
+/// Gets the byte ranges to read of a column chunk, which hold all of its pages unless the page index
+/// selected some of them.
+fn column_byte_ranges(
+    metadata: &parquet2::metadata::FileMetaData,
+    page_selections: &HashMap<(usize, usize), PageSelection>,
+    row_group_index: usize,
+    col_idx: usize,
+) -> Vec<Range<usize>> {
+    if let Some(selection) = page_selections.get(&(row_group_index, col_idx)) {
+        return selection.byte_ranges.clone();
+    }
+    let (start, len) = metadata.row_groups[row_group_index].columns()[col_idx].byte_range();
+    vec![start as usize..(start + len) as usize]
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct RowGroupRange {
     pub row_group_index: usize,
@@ -314,25 +373,73 @@ pub(crate) struct RowGroupRange {
 pub(crate) struct ParquetFileReader {
     uri: String,
     metadata: Arc<parquet2::metadata::FileMetaData>,
+    file_size: usize,
     arrow_schema: arrow2::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
+    predicate: Option<ExprRef>,
+    /// Pages to read of the column chunks that don't need all of them, keyed by the indices of
+    /// their row group and column.
+    page_selections: Arc<HashMap<(usize, usize), PageSelection>>,
 }
 
 impl ParquetFileReader {
     fn new(
         uri: String,
         metadata: parquet2::metadata::FileMetaData,
+        file_size: usize,
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
+        predicate: Option<ExprRef>,
     ) -> super::Result<Self> {
         Ok(ParquetFileReader {
             uri,
             metadata: Arc::new(metadata),
+            file_size,
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
+            predicate,
+            page_selections: Default::default(),
         })
     }
 
+    /// Prunes the selected row groups with the bloom filters of the columns that the predicate
+    /// checks for equality, and then the pages of the remaining row groups with the page index.
+    pub async fn prune_with_indexes(
+        mut self,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let Some(predicate) = self.predicate.clone() else {
+            return Ok(self);
+        };
+        let daft_schema = Schema::try_from(self.arrow_schema.as_ref())?;
+        let mut row_ranges = self.row_ranges.as_ref().clone();
+        pruning::prune_row_groups_with_bloom_filters(
+            &self.uri,
+            &self.metadata,
+            &mut row_ranges,
+            &predicate,
+            &daft_schema,
+            self.file_size,
+            io_client.clone(),
+            io_stats.clone(),
+        )
+        .await?;
+        let page_selections = pruning::prune_pages(
+            &self.uri,
+            &self.metadata,
+            &mut row_ranges,
+            &predicate,
+            &daft_schema,
+            io_client,
+            io_stats,
+        )
+        .await?;
+        self.row_ranges = Arc::new(row_ranges);
+        self.page_selections = Arc::new(page_selections);
+        Ok(self)
+    }
+
     pub fn arrow_schema(&self) -> &Arc<arrow2::datatypes::Schema> {
         &self.arrow_schema
     }
@@ -352,16 +459,22 @@ impl ParquetFileReader {
             let columns = rg.columns();
             for field in arrow_fields.iter() {
                 let field_name = field.name.clone();
-                let filtered_cols = columns
+                let filtered_cols_idx = columns
                     .iter()
-                    .filter(|x| x.descriptor().path_in_schema[0] == field_name)
+                    .enumerate()
+                    .filter(|(_, x)| x.descriptor().path_in_schema[0] == field_name)
+                    .map(|(i, _)| i)
                     .collect::<Vec<_>>();
 
-                for col in filtered_cols {
-                    let (start, len) = col.byte_range();
-                    let end = start + len;
-
-                    read_planner.add_range(start as usize, end as usize);
+                for col_idx in filtered_cols_idx {
+                    for range in column_byte_ranges(
+                        &self.metadata,
+                        &self.page_selections,
+                        row_group_range.row_group_index,
+                        col_idx,
+                    ) {
+                        read_planner.add_range(range.start, range.end);
+                    }
                 }
             }
         }
@@ -428,20 +541,39 @@ impl ParquetFileReader {
                         let needed_byte_ranges = filtered_cols_idx
                             .iter()
                             .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                start as usize..end as usize
+                                column_byte_ranges(
+                                    &metadata,
+                                    &self.page_selections,
+                                    row_range.row_group_index,
+                                    *i,
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        let selections = filtered_cols_idx
+                            .iter()
+                            .map(|i| {
+                                self.page_selections
+                                    .get(&(row_range.row_group_index, *i))
+                                    .cloned()
                             })
                             .collect::<Vec<_>>();
+                        // Column chunks whose leading pages were skipped decode rows from their first selected page
+                        let first_row = selections
+                            .iter()
+                            .flatten()
+                            .map(|s| s.first_row)
+                            .next()
+                            .unwrap_or(0);
+                        let num_rows = num_rows - first_row;
+                        let rows_to_skip = row_range.start - first_row;
 
                         let ranges = ranges.clone();
 
                         let handle = tokio::task::spawn(async move {
                             let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
 
-                            for range in needed_byte_ranges.into_iter() {
-                                let range_reader = ranges.get_range_reader(range).await?;
+                            for byte_ranges in needed_byte_ranges.iter() {
+                                let range_reader = ranges.get_ranges_reader(byte_ranges).await?;
                                 range_readers.push(Box::pin(range_reader))
                             }
 
@@ -449,8 +581,10 @@ impl ParquetFileReader {
                                 Vec::with_capacity(filtered_cols_idx.len());
                             let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
 
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
+                            for ((col_idx, range_reader), selection) in filtered_cols_idx
+                                .into_iter()
+                                .zip(range_readers)
+                                .zip(selections)
                             {
                                 let col = metadata
                                     .row_groups
@@ -478,6 +612,10 @@ impl ParquetFileReader {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
+                                let compressed_page_stream = limit_data_pages(
+                                    compressed_page_stream,
+                                    selection.map(|s| s.num_data_pages),
+                                );
                                 let page_stream = streaming_decompression(compressed_page_stream);
                                 let pinned_stream = Box::pin(page_stream);
                                 decompressed_iters
@@ -500,12 +638,12 @@ impl ParquetFileReader {
 
                                     for arr in arr_iter? {
                                         let arr = arr?;
-                                        if (curr_index + arr.len()) < row_range.start {
+                                        if (curr_index + arr.len()) < rows_to_skip {
                                             // throw arrays less than what we need
                                             curr_index += arr.len();
                                             continue;
-                                        } else if curr_index < row_range.start {
-                                            let offset = row_range.start.saturating_sub(curr_index);
+                                        } else if curr_index < rows_to_skip {
+                                            let offset = rows_to_skip.saturating_sub(curr_index);
                                             all_arrays.push(arr.sliced(offset, arr.len() - offset));
                                             curr_index += arr.len();
                                         } else {
@@ -609,19 +747,38 @@ impl ParquetFileReader {
                         let needed_byte_ranges = filtered_cols_idx
                             .iter()
                             .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                start as usize..end as usize
+                                column_byte_ranges(
+                                    &metadata,
+                                    &self.page_selections,
+                                    row_range.row_group_index,
+                                    *i,
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        let selections = filtered_cols_idx
+                            .iter()
+                            .map(|i| {
+                                self.page_selections
+                                    .get(&(row_range.row_group_index, *i))
+                                    .cloned()
                             })
                             .collect::<Vec<_>>();
+                        // Column chunks whose leading pages were skipped decode rows from their first selected page
+                        let first_row = selections
+                            .iter()
+                            .flatten()
+                            .map(|s| s.first_row)
+                            .next()
+                            .unwrap_or(0);
+                        let num_rows = num_rows - first_row;
+                        let rows_to_skip = row_range.start - first_row;
                         let metadata = metadata.clone();
                         let ranges = ranges.clone();
                         let handle = tokio::task::spawn(async move {
                             let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
 
-                            for range in needed_byte_ranges.into_iter() {
-                                let range_reader = ranges.get_range_reader(range).await?;
+                            for byte_ranges in needed_byte_ranges.iter() {
+                                let range_reader = ranges.get_ranges_reader(byte_ranges).await?;
                                 range_readers.push(Box::pin(range_reader))
                             }
 
@@ -629,8 +786,10 @@ impl ParquetFileReader {
                                 Vec::with_capacity(filtered_cols_idx.len());
                             let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
 
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
+                            for ((col_idx, range_reader), selection) in filtered_cols_idx
+                                .into_iter()
+                                .zip(range_readers)
+                                .zip(selections)
                             {
                                 let col = metadata
                                     .row_groups
@@ -658,6 +817,10 @@ impl ParquetFileReader {
                                             path: owned_uri.clone(),
                                         }
                                     })?;
+                                let compressed_page_stream = limit_data_pages(
+                                    compressed_page_stream,
+                                    selection.map(|s| s.num_data_pages),
+                                );
                                 let page_stream = streaming_decompression(compressed_page_stream);
                                 let pinned_stream = Box::pin(page_stream);
                                 decompressed_iters
@@ -680,12 +843,12 @@ impl ParquetFileReader {
 
                                     for arr in arr_iter? {
                                         let arr = arr?;
-                                        if (curr_index + arr.len()) < row_range.start {
+                                        if (curr_index + arr.len()) < rows_to_skip {
                                             // throw arrays less than what we need
                                             curr_index += arr.len();
                                             continue;
-                                        } else if curr_index < row_range.start {
-                                            let offset = row_range.start.saturating_sub(curr_index);
+                                        } else if curr_index < rows_to_skip {
+                                            let offset = rows_to_skip.saturating_sub(curr_index);
                                             all_arrays.push(arr.sliced(offset, arr.len() - offset));
                                             curr_index += arr.len();
                                         } else {
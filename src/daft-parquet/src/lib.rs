@@ -6,6 +6,7 @@ use snafu::Snafu;
 
 mod file;
 pub mod metadata;
+mod pruning;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
@@ -13,6 +14,7 @@ mod statistics;
 pub use statistics::row_group_metadata_to_table_stats;
 mod read_planner;
 mod stream_reader;
+pub mod write;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 
@@ -171,6 +173,12 @@ pub enum Error {
         source: daft_stats::Error,
     },
 
+    #[snafu(display("Unable to write parquet file {}: {}", path, source))]
+    UnableToWriteParquet {
+        path: String,
+        source: parquet2::error::Error,
+    },
+
     #[snafu(display("Error joining spawned task: {} for path: {}", source, path))]
     JoinError {
         path: String,
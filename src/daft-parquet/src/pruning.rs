@@ -0,0 +1,875 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    io::{Read, Seek},
+    ops::Range,
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::{array::ops::as_arrow::AsArrow, schema::Schema, DataType, Series};
+use daft_dsl::{optimization::get_required_columns, Expr, ExprRef, Operator};
+use daft_io::{IOClient, IOStatsRef};
+use daft_stats::{ColumnStatistics, TableStatistics, TruthValue};
+use indexmap::IndexMap;
+use parquet2::{
+    bloom_filter::{hash_byte, hash_native, is_in_set},
+    indexes::PageLocation,
+    metadata::{ColumnChunkMetaData, FileMetaData, RowGroupMetaData},
+    read::{read_columns_indexes, read_pages_locations},
+    schema::types::PhysicalType,
+    thrift_format::{
+        thrift::protocol::TCompactInputProtocol, BloomFilterAlgorithm, BloomFilterCompression,
+        BloomFilterHeader, SplitBlockAlgorithm, Uncompressed,
+    },
+};
+use snafu::ResultExt;
+
+use crate::{
+    file::RowGroupRange, read_planner::SpanReader, statistics, InternalIOSnafu,
+    UnableToConvertRowGroupMetadataToStatsSnafu, UnableToRunExpressionOnStatsSnafu,
+};
+
+/// Number of bytes to read from the start of a bloom filter, which holds its header and usually
+/// its whole bitset, before its size is known.
+const BLOOM_FILTER_READ_AHEAD: usize = 64 * 1024;
+
+/// Largest span of a file to read the page indexes of its row groups from, which writers place
+/// together right before the footer.
+const MAX_PAGE_INDEX_SPAN: usize = 16 * 1024 * 1024;
+
+/// The pages of a column chunk to read for a window of the rows of its row group.
+#[derive(Clone, Debug)]
+pub(crate) struct PageSelection {
+    /// Byte ranges of the pages preceding the data pages, such as the dictionary page, followed
+    /// by those of the selected data pages.
+    pub byte_ranges: Vec<Range<usize>>,
+    /// Index within the row group of the first row of the first selected data page.
+    pub first_row: usize,
+    pub num_data_pages: usize,
+}
+
+impl PageSelection {
+    /// Selects the data pages of a column chunk spanning the bytes `chunk_range` that hold `rows`,
+    /// along with the number of data pages skipped. Returns `None` when every page is needed or
+    /// the offset index is invalid.
+    fn new(
+        chunk_range: Range<usize>,
+        locations: &[PageLocation],
+        rows: &Range<usize>,
+    ) -> Option<(Self, usize)> {
+        let first_rows = locations
+            .iter()
+            .map(|l| usize::try_from(l.first_row_index).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let first = first_rows
+            .partition_point(|r| *r <= rows.start)
+            .checked_sub(1)?;
+        let last = first_rows
+            .partition_point(|r| *r < rows.end)
+            .checked_sub(1)?;
+        if first == 0 && last == locations.len() - 1 {
+            return None;
+        }
+
+        let Range {
+            start: chunk_start,
+            end: chunk_end,
+        } = chunk_range;
+        let data_start = usize::try_from(locations[0].offset).ok()?;
+        let pages_start = usize::try_from(locations[first].offset).ok()?;
+        let pages_end = usize::try_from(locations[last].offset).ok()?
+            + usize::try_from(locations[last].compressed_page_size).ok()?;
+        if data_start < chunk_start || pages_end > chunk_end || pages_start < data_start {
+            return None;
+        }
+
+        let byte_ranges = if first == 0 {
+            vec![chunk_start..pages_end]
+        } else if data_start > chunk_start {
+            vec![chunk_start..data_start, pages_start..pages_end]
+        } else {
+            vec![pages_start..pages_end]
+        };
+        let num_data_pages = last - first + 1;
+        Some((
+            Self {
+                byte_ranges,
+                first_row: first_rows[first],
+                num_data_pages,
+            },
+            locations.len() - num_data_pages,
+        ))
+    }
+}
+
+/// Gets the Parquet column of a top-level field that isn't nested, along with its index in the
+/// row group.
+fn flat_column<'a>(
+    rg: &'a RowGroupMetaData,
+    name: &str,
+) -> Option<(usize, &'a ColumnChunkMetaData)> {
+    let mut columns = rg
+        .columns()
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.descriptor().path_in_schema[0] == name);
+    let (idx, column) = columns.next()?;
+    let descriptor = column.descriptor();
+    if columns.next().is_some()
+        || descriptor.path_in_schema.len() != 1
+        || descriptor.descriptor.max_rep_level != 0
+    {
+        return None;
+    }
+    Some((idx, column))
+}
+
+/// Gets the values that the `Eq` and `IsIn` predicates among the conjuncts of `expr` require of
+/// each column.
+fn equality_predicates(expr: &Expr, predicates: &mut Vec<(Arc<str>, Series)>) {
+    match expr {
+        Expr::Alias(expr, _) => equality_predicates(expr, predicates),
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            equality_predicates(left, predicates);
+            equality_predicates(right, predicates);
+        }
+        Expr::BinaryOp {
+            op: Operator::Eq,
+            left,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (Expr::Column(name), Expr::Literal(value))
+            | (Expr::Literal(value), Expr::Column(name)) => {
+                predicates.push((name.clone(), value.to_series()));
+            }
+            _ => {}
+        },
+        Expr::IsIn(input, items) => {
+            if let (Expr::Column(name), Expr::Literal(items)) = (input.as_ref(), items.as_ref()) {
+                predicates.push((name.clone(), items.to_series()));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Hashes `values` as they are stored in a Parquet column of type `dtype`, for probing its bloom
+/// filter. Returns `None` for values that can't be hashed this way, which includes floats since
+/// their equality doesn't match that of their bits. Values that can't be cast to `dtype` can't
+/// equal any value of the column and are left out.
+fn bloom_filter_hashes(
+    values: &Series,
+    dtype: &DataType,
+    ptype: &PhysicalType,
+) -> DaftResult<Option<Vec<u64>>> {
+    let comparable = if dtype.is_integer() {
+        values.data_type().is_integer()
+    } else {
+        matches!(dtype, DataType::Utf8 | DataType::Binary | DataType::Date)
+            && values.data_type() == dtype
+    };
+    if !comparable || values.validity().is_some_and(|v| v.unset_bits() > 0) {
+        return Ok(None);
+    }
+    let values = values.cast(dtype)?;
+    let hashes = match (dtype, ptype) {
+        // Unsigned integers are stored with the bits of the signed integers of the same width
+        (DataType::UInt64, PhysicalType::Int64) => values
+            .u64()?
+            .as_arrow()
+            .iter()
+            .flatten()
+            .map(|v| hash_native(*v as i64))
+            .collect(),
+        (_, PhysicalType::Int32 | PhysicalType::Int64)
+            if dtype.is_integer() || *dtype == DataType::Date =>
+        {
+            let values = values.as_physical()?.cast(&DataType::Int64)?;
+            let values = values.i64()?.as_arrow().iter().flatten();
+            if matches!(ptype, PhysicalType::Int32) {
+                values.map(|v| hash_native(*v as i32)).collect()
+            } else {
+                values.map(|v| hash_native(*v)).collect()
+            }
+        }
+        (DataType::Utf8, PhysicalType::ByteArray) => values
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .flatten()
+            .map(|v| hash_byte(v.as_bytes()))
+            .collect(),
+        (DataType::Binary, PhysicalType::ByteArray) => values
+            .binary()?
+            .as_arrow()
+            .iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        _ => return Ok(None),
+    };
+    Ok(Some(hashes))
+}
+
+/// Checks whether the bloom filter of a column chunk may contain any of the values of `hashes`,
+/// which is the case when the column chunk has no bloom filter or one that can't be read.
+async fn bloom_filter_may_contain(
+    uri: &str,
+    column: &ColumnChunkMetaData,
+    hashes: &[u64],
+    file_size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<bool> {
+    let Some(offset) = column
+        .metadata()
+        .bloom_filter_offset
+        .and_then(|offset| usize::try_from(offset).ok())
+        .filter(|offset| *offset < file_size)
+    else {
+        return Ok(true);
+    };
+    let read_ahead_end = (offset + BLOOM_FILTER_READ_AHEAD).min(file_size);
+    let mut reader = SpanReader::fetch(
+        uri,
+        offset..read_ahead_end,
+        io_client.clone(),
+        io_stats.clone(),
+    )
+    .await?;
+    let header = {
+        let mut protocol = TCompactInputProtocol::new(&mut reader, BLOOM_FILTER_READ_AHEAD);
+        BloomFilterHeader::read_from_in_protocol(&mut protocol)
+    };
+    let header = match header {
+        Ok(header) => header,
+        Err(err) => {
+            log::warn!("Unable to read bloom filter header of Parquet file {uri}: {err}");
+            return Ok(true);
+        }
+    };
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(true);
+    }
+    let bitset_start = reader
+        .stream_position()
+        .context(InternalIOSnafu { path: uri })? as usize;
+    let Some(bitset_end) = usize::try_from(header.num_bytes)
+        .ok()
+        .map(|num_bytes| bitset_start + num_bytes)
+        .filter(|end| *end <= file_size)
+    else {
+        return Ok(true);
+    };
+
+    let mut bitset = Vec::with_capacity(bitset_end - bitset_start);
+    reader
+        .take((bitset_end - bitset_start) as u64)
+        .read_to_end(&mut bitset)
+        .context(InternalIOSnafu { path: uri })?;
+    if bitset_start + bitset.len() < bitset_end {
+        let rest = SpanReader::fetch(
+            uri,
+            bitset_start + bitset.len()..bitset_end,
+            io_client,
+            io_stats,
+        )
+        .await?;
+        bitset.extend_from_slice(rest.bytes());
+    }
+    Ok(hashes.iter().any(|hash| is_in_set(&bitset, *hash)))
+}
+
+/// Drops the row groups of `row_ranges` whose bloom filters rule out all values that `predicate`
+/// requires of a column for equality or membership.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn prune_row_groups_with_bloom_filters(
+    uri: &str,
+    metadata: &FileMetaData,
+    row_ranges: &mut Vec<RowGroupRange>,
+    predicate: &Expr,
+    schema: &Schema,
+    file_size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<()> {
+    let mut predicates = vec![];
+    equality_predicates(predicate, &mut predicates);
+    let mut probes = vec![];
+    for (name, values) in predicates {
+        let Ok(field) = schema.get_field(&name) else {
+            continue;
+        };
+        probes.push((name, values, field.dtype.clone()));
+    }
+    if probes.is_empty() {
+        return Ok(());
+    }
+
+    let may_match = futures::future::try_join_all(row_ranges.iter().map(|row_range| {
+        let rg = &metadata.row_groups[row_range.row_group_index];
+        let probes = &probes;
+        let io_client = io_client.clone();
+        let io_stats = io_stats.clone();
+        async move {
+            for (name, values, dtype) in probes {
+                let Some((_, column)) = flat_column(rg, name) else {
+                    continue;
+                };
+                let Some(hashes) = bloom_filter_hashes(
+                    values,
+                    dtype,
+                    &column.descriptor().descriptor.primitive_type.physical_type,
+                )?
+                else {
+                    continue;
+                };
+                if !bloom_filter_may_contain(
+                    uri,
+                    column,
+                    &hashes,
+                    file_size,
+                    io_client.clone(),
+                    io_stats.clone(),
+                )
+                .await?
+                {
+                    return DaftResult::Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }))
+    .await?;
+
+    let num_row_groups = row_ranges.len();
+    let mut may_match = may_match.into_iter();
+    row_ranges.retain(|_| may_match.next().unwrap());
+    if let Some(io_stats) = io_stats {
+        io_stats.mark_row_groups_skipped(num_row_groups - row_ranges.len());
+    }
+    Ok(())
+}
+
+/// Gets the byte range of the column index or offset index of a column chunk.
+fn index_range(column: &ColumnChunkMetaData, column_index: bool) -> Option<Range<usize>> {
+    let chunk = column.column_chunk();
+    let (offset, length) = if column_index {
+        (chunk.column_index_offset, chunk.column_index_length)
+    } else {
+        (chunk.offset_index_offset, chunk.offset_index_length)
+    };
+    let offset = usize::try_from(offset?).ok()?;
+    Some(offset..offset + usize::try_from(length?).ok()?)
+}
+
+/// Selects the window of rows of a row group that may satisfy `predicate`, by evaluating it on
+/// the statistics of the pages of the columns in `page_stats`, which map to the first row of each
+/// page along with its statistics. Returns `None` when no row may satisfy it.
+fn select_rows(
+    uri: &str,
+    rg_stats: &TableStatistics,
+    page_stats: &[(String, Vec<usize>, Vec<ColumnStatistics>)],
+    num_rows: usize,
+    predicate: &Expr,
+) -> super::Result<Option<Range<usize>>> {
+    let boundaries = page_stats
+        .iter()
+        .flat_map(|(_, first_rows, _)| first_rows.iter().copied())
+        .chain([0, num_rows])
+        .filter(|row| *row <= num_rows)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut selected: Option<Range<usize>> = None;
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let mut columns = rg_stats
+            .columns
+            .iter()
+            .map(|(name, stats)| (name.clone(), stats.subset(end - start)))
+            .collect::<IndexMap<_, _>>();
+        for (name, first_rows, stats) in page_stats {
+            let page = first_rows.partition_point(|row| *row <= start) - 1;
+            columns.insert(name.clone(), stats[page].subset(end - start));
+        }
+        let evaled = TableStatistics { columns }
+            .eval_expression(predicate)
+            .with_context(|_| UnableToRunExpressionOnStatsSnafu {
+                path: uri.to_string(),
+            })?;
+        if evaled.to_truth_value() != TruthValue::False {
+            selected = Some(selected.map_or(start, |s| s.start)..end);
+        }
+    }
+    Ok(selected)
+}
+
+/// Narrows the row groups of `row_ranges` to the window of rows whose pages may satisfy
+/// `predicate` according to the page index of the columns it references, dropping row groups
+/// without such rows. Returns the pages to read of each column chunk, keyed by the indices of its
+/// row group and column, for the column chunks that don't need all of their pages.
+///
+/// Only pages before and after the window are skipped, so that all columns decode the same rows.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn prune_pages(
+    uri: &str,
+    metadata: &FileMetaData,
+    row_ranges: &mut Vec<RowGroupRange>,
+    predicate: &ExprRef,
+    schema: &Schema,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<HashMap<(usize, usize), PageSelection>> {
+    let predicate_columns = get_required_columns(predicate)
+        .into_iter()
+        .filter(|name| schema.get_field(name).is_ok())
+        .collect::<Vec<_>>();
+
+    // Read the page indexes of all row groups at once, since they're usually stored together
+    let mut index_ranges = vec![];
+    for row_range in row_ranges.iter() {
+        let rg = &metadata.row_groups[row_range.row_group_index];
+        for name in predicate_columns.iter() {
+            if let Some((_, column)) = flat_column(rg, name) {
+                index_ranges.extend(index_range(column, true));
+            }
+        }
+        for name in schema.fields.keys() {
+            if let Some((_, column)) = flat_column(rg, name) {
+                index_ranges.extend(index_range(column, false));
+            }
+        }
+    }
+    let (Some(span_start), Some(span_end)) = (
+        index_ranges.iter().map(|r| r.start).min(),
+        index_ranges.iter().map(|r| r.end).max(),
+    ) else {
+        return Ok(HashMap::new());
+    };
+    if predicate_columns.is_empty() || span_end - span_start > MAX_PAGE_INDEX_SPAN {
+        return Ok(HashMap::new());
+    }
+    let mut reader =
+        SpanReader::fetch(uri, span_start..span_end, io_client, io_stats.clone()).await?;
+
+    let mut page_selections = HashMap::new();
+    let mut num_row_groups_skipped = 0;
+    let mut num_pages_skipped = 0;
+    let mut pruned_row_ranges = Vec::with_capacity(row_ranges.len());
+    for row_range in row_ranges.iter() {
+        let rg = &metadata.row_groups[row_range.row_group_index];
+        let mut locations = HashMap::new();
+        for name in schema.fields.keys() {
+            if let Some((idx, column)) = flat_column(rg, name)
+                && index_range(column, false).is_some()
+            {
+                match read_pages_locations(&mut reader, std::slice::from_ref(column)) {
+                    Ok(mut pages) if pages.len() == 1 && !pages[0].is_empty() => {
+                        locations.insert(idx, pages.pop().unwrap());
+                    }
+                    Ok(_) => {}
+                    Err(err) => {
+                        log::warn!("Unable to read offset index of Parquet file {uri}: {err}")
+                    }
+                }
+            }
+        }
+
+        let mut page_stats = vec![];
+        for name in predicate_columns.iter() {
+            let Some((idx, column)) = flat_column(rg, name) else {
+                continue;
+            };
+            let (Some(locations), Some(_)) = (locations.get(&idx), index_range(column, true))
+            else {
+                continue;
+            };
+            let index = match read_columns_indexes(&mut reader, std::slice::from_ref(column)) {
+                Ok(mut indexes) if indexes.len() == 1 => indexes.pop().unwrap(),
+                Ok(_) => continue,
+                Err(err) => {
+                    log::warn!("Unable to read column index of Parquet file {uri}: {err}");
+                    continue;
+                }
+            };
+            let Some(first_rows) = locations
+                .iter()
+                .map(|l| usize::try_from(l.first_row_index).ok())
+                .collect::<Option<Vec<_>>>()
+                .filter(|first_rows| first_rows[0] == 0)
+            else {
+                continue;
+            };
+            let page_row_counts = first_rows
+                .iter()
+                .zip(first_rows.iter().skip(1).chain([&rg.num_rows()]))
+                .map(|(start, end)| end.saturating_sub(*start))
+                .collect::<Vec<_>>();
+            let dtype = &schema.get_field(name)?.dtype;
+            if let Some(stats) =
+                statistics::column_index_to_page_stats(index.as_ref(), &page_row_counts, dtype)
+            {
+                page_stats.push((name.clone(), first_rows, stats));
+            }
+        }
+        if page_stats.is_empty() {
+            pruned_row_ranges.push(*row_range);
+            continue;
+        }
+
+        let rg_stats =
+            statistics::row_group_metadata_to_table_stats(rg, schema).with_context(|_| {
+                UnableToConvertRowGroupMetadataToStatsSnafu {
+                    path: uri.to_string(),
+                }
+            })?;
+        let selected = select_rows(uri, &rg_stats, &page_stats, rg.num_rows(), predicate)?;
+        let rows = selected
+            .map(|s| s.start.max(row_range.start)..s.end.min(row_range.start + row_range.num_rows))
+            .filter(|rows| rows.end > rows.start);
+        let Some(rows) = rows else {
+            num_row_groups_skipped += 1;
+            continue;
+        };
+
+        for (idx, locations) in locations.iter() {
+            let (chunk_start, chunk_len) = rg.columns()[*idx].byte_range();
+            let chunk_range = chunk_start as usize..(chunk_start + chunk_len) as usize;
+            if let Some((selection, skipped)) = PageSelection::new(chunk_range, locations, &rows) {
+                page_selections.insert((row_range.row_group_index, *idx), selection);
+                num_pages_skipped += skipped;
+            }
+        }
+        pruned_row_ranges.push(RowGroupRange {
+            row_group_index: row_range.row_group_index,
+            start: rows.start,
+            num_rows: rows.end - rows.start,
+        });
+    }
+
+    *row_ranges = pruned_row_ranges;
+    if let Some(io_stats) = io_stats {
+        io_stats.mark_row_groups_skipped(num_row_groups_skipped);
+        io_stats.mark_pages_skipped(num_pages_skipped);
+    }
+    Ok(page_selections)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Field, Float64Array, Int64Array, Utf8Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
+    use daft_dsl::{col, lit, Expr, ExprRef};
+    use daft_io::{IOClient, IOConfig, IOStatsContext};
+    use daft_stats::{ColumnRangeStatistics, ColumnStatistics, TableStatistics};
+    use daft_table::Table;
+    use indexmap::IndexMap;
+    use parquet2::{
+        bloom_filter::{hash_byte, hash_native},
+        indexes::PageLocation,
+        schema::types::PhysicalType,
+    };
+
+    use super::{bloom_filter_hashes, equality_predicates, select_rows, PageSelection};
+    use crate::{
+        file::ParquetReaderBuilder,
+        write::{write_parquet, ParquetWriteOptions},
+    };
+
+    fn page_location(offset: i64, compressed_page_size: i32, first_row_index: i64) -> PageLocation {
+        PageLocation {
+            offset,
+            compressed_page_size,
+            first_row_index,
+        }
+    }
+
+    /// A dictionary page at bytes 100..200, followed by three 100 byte data pages of 10 rows each.
+    fn locations() -> Vec<PageLocation> {
+        vec![
+            page_location(200, 100, 0),
+            page_location(300, 100, 10),
+            page_location(400, 100, 20),
+        ]
+    }
+
+    #[test]
+    fn test_page_selection_skips_pages_around_rows() {
+        let (selection, skipped) = PageSelection::new(100..500, &locations(), &(12..18)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![100..200, 300..400]);
+        assert_eq!(selection.first_row, 10);
+        assert_eq!(selection.num_data_pages, 1);
+        assert_eq!(skipped, 2);
+
+        // The first data page is read along with the dictionary page in a single range.
+        let (selection, skipped) = PageSelection::new(100..500, &locations(), &(0..5)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![100..300]);
+        assert_eq!(selection.first_row, 0);
+        assert_eq!(selection.num_data_pages, 1);
+        assert_eq!(skipped, 2);
+    }
+
+    #[test]
+    fn test_page_selection_page_boundaries() {
+        // Rows that start and end on page boundaries only need the pages that hold them.
+        let (selection, skipped) = PageSelection::new(100..500, &locations(), &(10..20)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![100..200, 300..400]);
+        assert_eq!(selection.first_row, 10);
+        assert_eq!((selection.num_data_pages, skipped), (1, 2));
+
+        // One row past a boundary pulls in the next page.
+        let (selection, skipped) = PageSelection::new(100..500, &locations(), &(10..21)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![100..200, 300..500]);
+        assert_eq!((selection.num_data_pages, skipped), (2, 1));
+    }
+
+    #[test]
+    fn test_page_selection_multiple_pages() {
+        let (selection, skipped) = PageSelection::new(100..500, &locations(), &(15..25)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![100..200, 300..500]);
+        assert_eq!(selection.first_row, 10);
+        assert_eq!((selection.num_data_pages, skipped), (2, 1));
+
+        // Without a dictionary page, only the data pages are read.
+        let (selection, skipped) = PageSelection::new(200..500, &locations(), &(5..15)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![200..400]);
+        assert_eq!(selection.first_row, 0);
+        assert_eq!((selection.num_data_pages, skipped), (2, 1));
+        let (selection, _) = PageSelection::new(200..500, &locations(), &(25..30)).unwrap();
+        assert_eq!(selection.byte_ranges, vec![400..500]);
+        assert_eq!(selection.first_row, 20);
+    }
+
+    #[test]
+    fn test_page_selection_needs_every_page_or_invalid() {
+        assert!(PageSelection::new(100..500, &locations(), &(0..30)).is_none());
+        assert!(PageSelection::new(100..500, &locations(), &(5..25)).is_none());
+        // Pages outside of the column chunk
+        assert!(PageSelection::new(100..450, &locations(), &(25..30)).is_none());
+        assert!(PageSelection::new(250..500, &locations(), &(25..30)).is_none());
+        // Negative first rows
+        let invalid = vec![page_location(200, 100, -1), page_location(300, 100, 10)];
+        assert!(PageSelection::new(100..400, &invalid, &(12..15)).is_none());
+    }
+
+    fn int64_stats(lower: i64, upper: i64, row_count: usize) -> ColumnStatistics {
+        let range = ColumnRangeStatistics::new(
+            Some(Int64Array::from(("lower", vec![lower])).into_series()),
+            Some(Int64Array::from(("upper", vec![upper])).into_series()),
+        )
+        .unwrap();
+        ColumnStatistics::new(range, Some(row_count), Some(0), None)
+    }
+
+    /// Statistics of columns "x", "y" and "z", which all hold the row index of each of 30 rows,
+    /// where "x" has pages of 10 rows, "y" pages of 15 rows and "z" has no page statistics.
+    fn row_stats() -> (
+        TableStatistics,
+        Vec<(String, Vec<usize>, Vec<ColumnStatistics>)>,
+    ) {
+        let rg_stats = TableStatistics {
+            columns: IndexMap::from([
+                ("x".to_string(), int64_stats(0, 29, 30)),
+                ("y".to_string(), int64_stats(0, 29, 30)),
+                ("z".to_string(), int64_stats(0, 29, 30)),
+            ]),
+        };
+        let page_stats = vec![
+            (
+                "x".to_string(),
+                vec![0, 10, 20],
+                vec![
+                    int64_stats(0, 9, 10),
+                    int64_stats(10, 19, 10),
+                    int64_stats(20, 29, 10),
+                ],
+            ),
+            (
+                "y".to_string(),
+                vec![0, 15],
+                vec![int64_stats(0, 14, 15), int64_stats(15, 29, 15)],
+            ),
+        ];
+        (rg_stats, page_stats)
+    }
+
+    #[test]
+    fn test_select_rows() -> DaftResult<()> {
+        let (rg_stats, page_stats) = row_stats();
+        let select = |predicate: ExprRef| {
+            select_rows("test.parquet", &rg_stats, &page_stats, 30, &predicate)
+        };
+
+        assert_eq!(select(col("x").eq(lit(15i64)))?, Some(10..20));
+        assert_eq!(select(col("x").gt(lit(12i64)))?, Some(10..30));
+        assert_eq!(select(col("x").lt(lit(0i64)))?, None);
+        // Windows are split at the page boundaries of all columns.
+        assert_eq!(
+            select(col("x").eq(lit(12i64)).and(col("y").eq(lit(12i64))))?,
+            Some(10..15)
+        );
+        // Only a single window of rows is selected, spanning all matching pages.
+        assert_eq!(
+            select(col("x").lt(lit(5i64)).or(col("x").gt(lit(25i64))))?,
+            Some(0..30)
+        );
+        // Columns without page statistics can't narrow the rows down.
+        assert_eq!(select(col("z").eq(lit(1i64)))?, Some(0..30));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bloom_filter_hashes() -> DaftResult<()> {
+        let ints = Int64Array::from(("ints", vec![1, 2])).into_series();
+        assert_eq!(
+            bloom_filter_hashes(&ints, &DataType::Int64, &PhysicalType::Int64)?,
+            Some(vec![hash_native(1i64), hash_native(2i64)])
+        );
+        // Integers narrower than 64 bits are hashed as the physical type they're stored as.
+        assert_eq!(
+            bloom_filter_hashes(&ints, &DataType::Int16, &PhysicalType::Int32)?,
+            Some(vec![hash_native(1i32), hash_native(2i32)])
+        );
+        assert_eq!(
+            bloom_filter_hashes(&ints, &DataType::UInt64, &PhysicalType::Int64)?,
+            Some(vec![hash_native(1i64), hash_native(2i64)])
+        );
+
+        let strings = Utf8Array::from(("strings", ["a", "bc"].as_slice())).into_series();
+        assert_eq!(
+            bloom_filter_hashes(&strings, &DataType::Utf8, &PhysicalType::ByteArray)?,
+            Some(vec![hash_byte("a".as_bytes()), hash_byte("bc".as_bytes())])
+        );
+
+        // Floats, nulls and values of other types can't be probed for.
+        let floats = Float64Array::from(("floats", vec![1.0])).into_series();
+        assert_eq!(
+            bloom_filter_hashes(&floats, &DataType::Float64, &PhysicalType::Double)?,
+            None
+        );
+        let nulls = Int64Array::from_iter("nulls", vec![Some(1), None].into_iter()).into_series();
+        assert_eq!(
+            bloom_filter_hashes(&nulls, &DataType::Int64, &PhysicalType::Int64)?,
+            None
+        );
+        assert_eq!(
+            bloom_filter_hashes(&strings, &DataType::Int64, &PhysicalType::Int64)?,
+            None
+        );
+        assert_eq!(
+            bloom_filter_hashes(&ints, &DataType::Utf8, &PhysicalType::ByteArray)?,
+            None
+        );
+        Ok(())
+    }
+
+    fn predicate_columns(expr: &Expr) -> Vec<String> {
+        let mut predicates = vec![];
+        equality_predicates(expr, &mut predicates);
+        predicates
+            .into_iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_equality_predicates() {
+        let items = Int64Array::from(("items", vec![1, 2, 3])).into_series();
+        let expr = col("a")
+            .eq(lit(1i64))
+            .and(lit("x").eq(col("b")))
+            .and(col("c").is_in(lit(items)))
+            .and(col("d").gt(lit(1i64)))
+            .alias("pred");
+        assert_eq!(predicate_columns(&expr), vec!["a", "b", "c"]);
+
+        let mut predicates = vec![];
+        equality_predicates(&col("a").eq(lit(7i64)), &mut predicates);
+        let (_, values) = &predicates[0];
+        assert_eq!(values.i64().unwrap().as_arrow().values().as_slice(), &[7]);
+
+        // Disjunctions and comparisons between columns don't require any values.
+        assert!(predicate_columns(&col("a").eq(lit(1i64)).or(col("b").eq(lit(2i64)))).is_empty());
+        assert!(predicate_columns(&col("a").eq(col("b"))).is_empty());
+    }
+
+    /// Reads a Parquet file with a predicate and checks that row groups and pages are skipped
+    /// without changing the rows read.
+    #[test]
+    fn test_pruning_skips_row_groups_and_pages() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = format!(
+            "file://{}/data.parquet",
+            dir.path().to_string_lossy().replace('\\', "/")
+        );
+        let xs = (0..10_000).collect::<Vec<i64>>();
+        let table = Table::new(
+            Schema::new(vec![Field::new("x", DataType::Int64)])?,
+            vec![Int64Array::from(("x", xs)).into_series()],
+        )?;
+        let options = ParquetWriteOptions {
+            row_group_size: table.size_bytes()?.div_ceil(4),
+            inflation_factor: 1.0,
+            dictionary_encoding: false,
+            data_page_size: Some(1024),
+            ..Default::default()
+        };
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        write_parquet(
+            &uri,
+            std::slice::from_ref(&table),
+            table.schema.clone(),
+            options,
+            io_client.clone(),
+            None,
+        )?;
+
+        let predicate = col("x").eq(lit(7i64));
+        let io_stats = IOStatsContext::new("test_pruning_skips_row_groups_and_pages");
+        let runtime_handle = daft_io::get_runtime(true)?;
+        let read = runtime_handle.block_on(async {
+            let reader = ParquetReaderBuilder::from_uri(
+                &uri,
+                io_client.clone(),
+                Some(io_stats.clone()),
+                None,
+            )
+            .await?
+            .set_filter(predicate.clone())
+            .build()?
+            .prune_with_indexes(io_client.clone(), Some(io_stats.clone()))
+            .await?;
+            let ranges = reader.prebuffer_ranges(io_client.clone(), Some(io_stats.clone()))?;
+            reader.read_from_ranges_into_table(ranges).await
+        })?;
+
+        assert_eq!(io_stats.load_row_groups_skipped(), 3);
+        assert!(io_stats.load_pages_skipped() > 0);
+        let filtered = read.filter(&[predicate])?;
+        assert_eq!(
+            filtered
+                .get_column("x")?
+                .i64()?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            &[7]
+        );
+        Ok(())
+    }
+}
@@ -134,7 +134,10 @@ async fn read_parquet_single(
             builder
         };
 
-        let parquet_reader = builder.build()?;
+        let parquet_reader = builder
+            .build()?
+            .prune_with_indexes(io_client.clone(), io_stats.clone())
+            .await?;
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             metadata,
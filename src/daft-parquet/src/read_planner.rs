@@ -1,4 +1,9 @@
-use std::{fmt::Display, ops::Range, sync::Arc};
+use std::{
+    fmt::Display,
+    io::{Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
 
 use bytes::Bytes;
 use common_error::DaftResult;
@@ -198,10 +203,42 @@ pub(crate) struct RangesContainer {
 }
 
 impl RangesContainer {
-    pub async fn get_range_reader(
+    /// Gets a reader of the bytes of `ranges` one after another, such as the dictionary page of a
+    /// column chunk followed by the data pages selected by its page index.
+    pub async fn get_ranges_reader(
         &self,
-        range: Range<usize>,
+        ranges: &[Range<usize>],
     ) -> DaftResult<impl futures::AsyncRead> {
+        let mut needed_entries = vec![];
+        let mut ranges_to_slice = vec![];
+        for range in ranges.iter().filter(|r| r.end > r.start) {
+            let (entries, slices) = self.entries_for_range(range.clone());
+            needed_entries.extend(entries);
+            ranges_to_slice.extend(slices);
+        }
+
+        // We block on the first entry so we can surface up the error. This shouldn't cause any performance issues since we have to wait for this to complete anyways
+        if let Some(entry) = needed_entries.first()
+            && let Some(range) = ranges_to_slice.first()
+        {
+            entry.get_or_wait(range.clone()).await?;
+        }
+
+        let bytes_iter = tokio_stream::iter(needed_entries.into_iter().zip(ranges_to_slice))
+            .then(|(e, r)| async move { e.get_or_wait(r).await })
+            .inspect_err(|e| log::warn!("Encountered error while streaming bytes into parquet reader. This may show up as a Thrift Error Downstream: {}", e));
+
+        let stream_reader = tokio_util::io::StreamReader::new(bytes_iter);
+        let convert = async_compat::Compat::new(stream_reader);
+
+        Ok(convert)
+    }
+
+    /// Gets the entries holding the bytes of `range`, along with the range to slice from each.
+    fn entries_for_range(
+        &self,
+        range: Range<usize>,
+    ) -> (Vec<Arc<RangeCacheEntry>>, Vec<Range<usize>>) {
         let mut current_pos = range.start;
         let mut curr_index;
         let start_point = self.ranges.binary_search_by_key(&current_pos, |e| e.start);
@@ -259,21 +296,65 @@ impl RangesContainer {
 
         assert_eq!(current_pos, range.end);
 
-        // We block on the first entry so we can surface up the error. This shouldn't cause any performance issues since we have to wait for this to complete anyways
-        if let Some(entry) = needed_entries.first()
-            && let Some(range) = ranges_to_slice.first()
-        {
-            entry.get_or_wait(range.clone()).await?;
-        }
+        (needed_entries, ranges_to_slice)
+    }
+}
 
-        let bytes_iter = tokio_stream::iter(needed_entries.into_iter().zip(ranges_to_slice))
-            .then(|(e, r)| async move { e.get_or_wait(r).await })
-            .inspect_err(|e| log::warn!("Encountered error while streaming bytes into parquet reader. This may show up as a Thrift Error Downstream: {}", e));
+/// A reader of bytes fetched from `start` onwards in a file, which seeks to positions within the
+/// file. This lets parquet2 read structures found at known file offsets, like page indexes, from a
+/// single range of the file.
+pub(crate) struct SpanReader {
+    start: u64,
+    cursor: std::io::Cursor<Bytes>,
+}
 
-        let stream_reader = tokio_util::io::StreamReader::new(bytes_iter);
-        let convert = async_compat::Compat::new(stream_reader);
+impl SpanReader {
+    pub async fn fetch(
+        uri: &str,
+        range: Range<usize>,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> crate::Result<Self> {
+        let start = range.start as u64;
+        let bytes = io_client
+            .single_url_get(uri.into(), Some(range), io_stats)
+            .await?
+            .bytes()
+            .await?;
+        Ok(Self {
+            start,
+            cursor: std::io::Cursor::new(bytes),
+        })
+    }
 
-        Ok(convert)
+    pub fn bytes(&self) -> &Bytes {
+        self.cursor.get_ref()
+    }
+}
+
+impl Read for SpanReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for SpanReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.start).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "Seeking to {pos} before the fetched range starting at {}",
+                            self.start
+                        ),
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.cursor.seek(pos)? + self.start)
     }
 }
 
@@ -4,8 +4,10 @@ use common_error::DaftError;
 use snafu::Snafu;
 
 mod column_range;
+mod page_index;
 mod table_stats;
 mod utils;
+pub(crate) use page_index::column_index_to_page_stats;
 pub use table_stats::row_group_metadata_to_table_stats;
 
 #[derive(Debug, Snafu)]
@@ -0,0 +1,97 @@
+use daft_core::DataType;
+use daft_stats::{ColumnRangeStatistics, ColumnStatistics};
+use parquet2::{
+    indexes::{ByteIndex, FixedLenByteIndex, Index, NativeIndex},
+    statistics::{BinaryStatistics, FixedLenStatistics, PrimitiveStatistics, Statistics},
+    types::NativeType,
+};
+
+use super::column_range::parquet_statistics_to_column_range_statistics;
+
+/// Converts the column index of a Parquet column chunk into statistics of each of its pages, whose
+/// numbers of rows are given by `page_row_counts`. Returns `None` for indexes of unsupported types.
+pub fn column_index_to_page_stats(
+    index: &dyn Index,
+    page_row_counts: &[usize],
+    dtype: &DataType,
+) -> Option<Vec<ColumnStatistics>> {
+    if !ColumnRangeStatistics::supports_dtype(dtype) {
+        return None;
+    }
+    let index = index.as_any();
+    let page_stats = if let Some(index) = index.downcast_ref::<NativeIndex<i32>>() {
+        native_page_stats(index)
+    } else if let Some(index) = index.downcast_ref::<NativeIndex<i64>>() {
+        native_page_stats(index)
+    } else if let Some(index) = index.downcast_ref::<NativeIndex<[u32; 3]>>() {
+        native_page_stats(index)
+    } else if let Some(index) = index.downcast_ref::<NativeIndex<f32>>() {
+        native_page_stats(index)
+    } else if let Some(index) = index.downcast_ref::<NativeIndex<f64>>() {
+        native_page_stats(index)
+    } else if let Some(index) = index.downcast_ref::<ByteIndex>() {
+        index
+            .indexes
+            .iter()
+            .map(|page| {
+                Box::new(BinaryStatistics {
+                    primitive_type: index.primitive_type.clone(),
+                    null_count: page.null_count,
+                    distinct_count: None,
+                    min_value: page.min.clone(),
+                    max_value: page.max.clone(),
+                }) as Box<dyn Statistics>
+            })
+            .collect()
+    } else if let Some(index) = index.downcast_ref::<FixedLenByteIndex>() {
+        index
+            .indexes
+            .iter()
+            .map(|page| {
+                Box::new(FixedLenStatistics {
+                    primitive_type: index.primitive_type.clone(),
+                    null_count: page.null_count,
+                    distinct_count: None,
+                    min_value: page.min.clone(),
+                    max_value: page.max.clone(),
+                }) as Box<dyn Statistics>
+            })
+            .collect()
+    } else {
+        return None;
+    };
+    if page_stats.len() != page_row_counts.len() {
+        return None;
+    }
+
+    let stats = page_stats
+        .iter()
+        .zip(page_row_counts)
+        .map(|(pq_stats, row_count)| {
+            ColumnStatistics::new(
+                parquet_statistics_to_column_range_statistics(pq_stats.as_ref(), dtype)
+                    .unwrap_or(ColumnRangeStatistics::Missing),
+                Some(*row_count),
+                pq_stats.null_count().and_then(|v| usize::try_from(v).ok()),
+                None,
+            )
+        })
+        .collect();
+    Some(stats)
+}
+
+fn native_page_stats<T: NativeType>(index: &NativeIndex<T>) -> Vec<Box<dyn Statistics>> {
+    index
+        .indexes
+        .iter()
+        .map(|page| {
+            Box::new(PrimitiveStatistics::<T> {
+                primitive_type: index.primitive_type.clone(),
+                null_count: page.null_count,
+                distinct_count: None,
+                min_value: page.min,
+                max_value: page.max,
+            }) as Box<dyn Statistics>
+        })
+        .collect()
+}
@@ -1,6 +1,12 @@
 use common_error::DaftResult;
 use daft_core::schema::Schema;
-use daft_stats::{ColumnRangeStatistics, TableStatistics};
+use daft_stats::{ColumnRangeStatistics, ColumnStatistics, TableStatistics};
+use parquet2::{
+    schema::types::PhysicalType,
+    statistics::{
+        BinaryStatistics, BooleanStatistics, FixedLenStatistics, PrimitiveStatistics, Statistics,
+    },
+};
 use snafu::ResultExt;
 
 use super::column_range::parquet_statistics_to_column_range_statistics;
@@ -25,27 +31,76 @@ pub fn row_group_metadata_to_table_stats(
         })
         .collect();
 
-    // Iterate through the schema and construct ColumnRangeStatistics per field
+    let row_count = Some(metadata.num_rows());
+
+    // Iterate through the schema and construct ColumnStatistics per field
     let columns = schema
         .fields
         .iter()
         .map(|(field_name, field)| {
             if ColumnRangeStatistics::supports_dtype(&field.dtype) {
-                let stats: ColumnRangeStatistics = parquet_column_metadata
+                let pq_stats = parquet_column_metadata
                     .remove(field_name)
                     .expect("Cannot find parsed Daft field in Parquet rowgroup metadata")
                     .transpose()
-                    .context(super::UnableToParseParquetColumnStatisticsSnafu)?
-                    .and_then(|v| {
-                        parquet_statistics_to_column_range_statistics(v.as_ref(), &field.dtype).ok()
-                    })
-                    .unwrap_or(ColumnRangeStatistics::Missing);
+                    .context(super::UnableToParseParquetColumnStatisticsSnafu)?;
+                let stats = match pq_stats {
+                    Some(pq_stats) => ColumnStatistics::new(
+                        parquet_statistics_to_column_range_statistics(
+                            pq_stats.as_ref(),
+                            &field.dtype,
+                        )
+                        .unwrap_or(ColumnRangeStatistics::Missing),
+                        row_count,
+                        pq_stats.null_count().and_then(|v| usize::try_from(v).ok()),
+                        parquet_distinct_count(pq_stats.as_ref())
+                            .and_then(|v| usize::try_from(v).ok()),
+                    ),
+                    None => {
+                        ColumnStatistics::new(ColumnRangeStatistics::Missing, row_count, None, None)
+                    }
+                };
                 Ok((field_name.clone(), stats))
             } else {
-                Ok((field_name.clone(), ColumnRangeStatistics::Missing))
+                // Nested fields span several Parquet columns, whose null counts are of their leaves
+                Ok((
+                    field_name.clone(),
+                    ColumnStatistics::new(ColumnRangeStatistics::Missing, row_count, None, None),
+                ))
             }
         })
         .collect::<DaftResult<IndexMap<_, _>>>()?;
 
     Ok(TableStatistics { columns })
 }
+
+/// Gets the number of distinct values of a Parquet column chunk, which most writers don't record.
+fn parquet_distinct_count(pq_stats: &dyn Statistics) -> Option<i64> {
+    let stats = pq_stats.as_any();
+    match pq_stats.physical_type() {
+        PhysicalType::Boolean => stats
+            .downcast_ref::<BooleanStatistics>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::Int32 => stats
+            .downcast_ref::<PrimitiveStatistics<i32>>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::Int64 => stats
+            .downcast_ref::<PrimitiveStatistics<i64>>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::Int96 => stats
+            .downcast_ref::<PrimitiveStatistics<[u32; 3]>>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::Float => stats
+            .downcast_ref::<PrimitiveStatistics<f32>>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::Double => stats
+            .downcast_ref::<PrimitiveStatistics<f64>>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::ByteArray => stats
+            .downcast_ref::<BinaryStatistics>()
+            .and_then(|s| s.distinct_count),
+        PhysicalType::FixedLenByteArray(_) => stats
+            .downcast_ref::<FixedLenStatistics>()
+            .and_then(|s| s.distinct_count),
+    }
+}
@@ -0,0 +1,496 @@
+use std::{
+    io::{self, BufWriter, Write},
+    sync::Arc,
+};
+
+use arrow2::{
+    array::{Array, DictionaryArray},
+    chunk::Chunk,
+    compute::{
+        aggregate::estimated_bytes_size,
+        cast::{cast, CastOptions},
+    },
+    datatypes::{DataType, Field as ArrowField, IntegerType, Schema as ArrowSchema},
+    io::{
+        ipc::write::{default_ipc_fields, schema_to_bytes},
+        parquet::write::{
+            row_group_iter, to_parquet_type, transverse, BrotliLevel, CompressionOptions, Encoding,
+            GzipLevel, KeyValue, ParquetType, SchemaDescriptor, Version, WriteOptions, ZstdLevel,
+        },
+    },
+};
+use base64::{engine::general_purpose, Engine as _};
+use common_daft_config::DaftExecutionConfig;
+use common_error::{DaftError, DaftResult};
+use daft_core::schema::SchemaRef;
+use daft_io::{IOClient, IOStatsRef, ObjectWriter};
+use daft_table::Table;
+use parquet2::write::{FileWriter, WriteOptions as FileWriteOptions};
+use snafu::ResultExt;
+use tokio::{io::AsyncWriteExt, runtime::Runtime};
+
+use crate::{InternalIOSnafu, UnableToWriteParquetSnafu};
+
+/// The key of the Arrow field metadata holding the Parquet field id of a field, as set by pyarrow.
+pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
+
+const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+
+/// Dictionaries larger than this are discarded in favor of plain encoding, as in pyarrow.
+const DICTIONARY_PAGE_SIZE_LIMIT: usize = 1024 * 1024;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParquetWriteOptions {
+    pub compression: CompressionOptions,
+    /// Target size in bytes of each row group once written, which is estimated from the in-memory
+    /// size of a table by dividing it by `inflation_factor`.
+    pub row_group_size: usize,
+    pub inflation_factor: f64,
+    /// Whether to dictionary encode top-level integer, string and binary columns.
+    pub dictionary_encoding: bool,
+    pub write_statistics: bool,
+    pub data_page_size: Option<usize>,
+}
+
+impl ParquetWriteOptions {
+    pub fn from_execution_config(config: &DaftExecutionConfig) -> Self {
+        Self {
+            compression: CompressionOptions::Snappy,
+            row_group_size: config.parquet_target_row_group_size,
+            inflation_factor: config.parquet_inflation_factor,
+            dictionary_encoding: true,
+            write_statistics: true,
+            data_page_size: None,
+        }
+    }
+
+    fn rows_per_row_group(&self, num_rows: usize, size_bytes: usize) -> usize {
+        let num_row_groups =
+            (size_bytes as f64 / self.inflation_factor / self.row_group_size as f64)
+                .ceil()
+                .max(1.0);
+        ((num_rows as f64 / num_row_groups).ceil() as usize).max(1)
+    }
+
+    fn to_arrow_options(self) -> WriteOptions {
+        WriteOptions {
+            write_statistics: self.write_statistics,
+            compression: self.compression,
+            version: Version::V1,
+            data_pagesize_limit: self.data_page_size,
+        }
+    }
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self::from_execution_config(&DaftExecutionConfig::default())
+    }
+}
+
+/// Parses a compression codec by its pyarrow name, along with its optional level.
+pub fn parse_compression(name: &str, level: Option<i32>) -> DaftResult<CompressionOptions> {
+    let invalid_level =
+        |level| DaftError::ValueError(format!("Invalid {name} compression level: {level}"));
+    let compression = match name.to_lowercase().as_str() {
+        "none" | "uncompressed" => CompressionOptions::Uncompressed,
+        "snappy" => CompressionOptions::Snappy,
+        "lz4" => CompressionOptions::Lz4Raw,
+        "gzip" => CompressionOptions::Gzip(
+            level
+                .map(|level| {
+                    u8::try_from(level)
+                        .ok()
+                        .and_then(|l| GzipLevel::try_new(l).ok())
+                        .ok_or_else(|| invalid_level(level))
+                })
+                .transpose()?,
+        ),
+        "brotli" => CompressionOptions::Brotli(
+            level
+                .map(|level| {
+                    u32::try_from(level)
+                        .ok()
+                        .and_then(|l| BrotliLevel::try_new(l).ok())
+                        .ok_or_else(|| invalid_level(level))
+                })
+                .transpose()?,
+        ),
+        "zstd" => CompressionOptions::Zstd(
+            level
+                .map(|level| ZstdLevel::try_new(level).map_err(|_| invalid_level(level)))
+                .transpose()?,
+        ),
+        _ => {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported Parquet compression: {name}"
+            )))
+        }
+    };
+    if let Some(level) = level
+        && !matches!(
+            compression,
+            CompressionOptions::Gzip(_)
+                | CompressionOptions::Brotli(_)
+                | CompressionOptions::Zstd(_)
+        )
+    {
+        return Err(invalid_level(level));
+    }
+    Ok(compression)
+}
+
+/// Writes tables of the same schema into a Parquet file, splitting them into row groups of
+/// about `ParquetWriteOptions::row_group_size` bytes.
+pub struct ParquetWriter<W: Write> {
+    uri: String,
+    schema: SchemaRef,
+    arrow_schema: ArrowSchema,
+    options: ParquetWriteOptions,
+    writer: FileWriter<W>,
+}
+
+impl<W: Write> ParquetWriter<W> {
+    /// Creates a writer into `sink`, where `uri` is the destination of the file for error messages.
+    pub fn try_new(
+        uri: &str,
+        sink: W,
+        schema: SchemaRef,
+        options: ParquetWriteOptions,
+    ) -> DaftResult<Self> {
+        let arrow_schema = schema.to_arrow()?;
+        let parquet_fields = arrow_schema
+            .fields
+            .iter()
+            .map(|field| {
+                let mut parquet_type = to_parquet_type(field)?;
+                set_field_ids(&mut parquet_type, field)?;
+                Ok(parquet_type)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let writer = FileWriter::new(
+            sink,
+            SchemaDescriptor::new("schema".to_string(), parquet_fields),
+            FileWriteOptions {
+                write_statistics: options.write_statistics,
+                version: Version::V1,
+            },
+            Some("Daft".to_string()),
+        );
+        Ok(Self {
+            uri: uri.to_string(),
+            schema,
+            arrow_schema,
+            options,
+            writer,
+        })
+    }
+
+    pub fn write_table(&mut self, table: &Table) -> DaftResult<()> {
+        if table.schema != self.schema {
+            return Err(DaftError::SchemaMismatch(format!(
+                "Unable to write table with schema {} into Parquet file {} with schema {}",
+                table.schema, self.uri, self.schema
+            )));
+        }
+        let num_rows = table.len();
+        let rows_per_row_group = self
+            .options
+            .rows_per_row_group(num_rows, table.size_bytes()?);
+        for start in (0..num_rows).step_by(rows_per_row_group) {
+            let end = (start + rows_per_row_group).min(num_rows);
+            self.write_row_group(&table.slice(start, end)?)?;
+        }
+        Ok(())
+    }
+
+    fn write_row_group(&mut self, table: &Table) -> DaftResult<()> {
+        let (arrays, encodings): (Vec<_>, Vec<_>) = (0..table.num_columns())
+            .map(|i| self.encode_column(table.get_column_by_index(i)?.to_arrow()))
+            .collect::<DaftResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let row_group = row_group_iter(
+            Chunk::try_new(arrays)?,
+            encodings,
+            self.writer.schema().fields().to_vec(),
+            self.options.to_arrow_options(),
+        );
+        self.writer
+            .write(row_group)
+            .context(UnableToWriteParquetSnafu {
+                path: self.uri.clone(),
+            })?;
+        Ok(())
+    }
+
+    /// Returns the array to write for a column along with the encodings of its leaves.
+    fn encode_column(&self, array: Box<dyn Array>) -> DaftResult<(Box<dyn Array>, Vec<Encoding>)> {
+        if self.options.dictionary_encoding && supports_dictionary(array.data_type()) {
+            let dict_type = DataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(array.data_type().clone()),
+                false,
+            );
+            let dict = cast(array.as_ref(), &dict_type, CastOptions::default())?;
+            let values = dict
+                .as_any()
+                .downcast_ref::<DictionaryArray<u32>>()
+                .unwrap()
+                .values();
+            if estimated_bytes_size(values.as_ref()) <= DICTIONARY_PAGE_SIZE_LIMIT {
+                return Ok((dict, vec![Encoding::RleDictionary]));
+            }
+        }
+        let encodings = transverse(array.data_type(), |_| Encoding::Plain);
+        Ok((array, encodings))
+    }
+
+    /// Writes the footer of the file, returning its total size along with the flushed sink.
+    pub fn finish(mut self) -> DaftResult<(u64, W)> {
+        let key_value_metadata = vec![arrow_schema_to_metadata_key(&self.arrow_schema)];
+        let size =
+            self.writer
+                .end(Some(key_value_metadata))
+                .context(UnableToWriteParquetSnafu {
+                    path: self.uri.clone(),
+                })?;
+        let mut sink = self.writer.into_inner();
+        sink.flush()?;
+        Ok((size, sink))
+    }
+
+    /// Abandons the file, returning the sink without writing the footer.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
+}
+
+fn supports_dictionary(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+    )
+}
+
+/// Sets the field ids of a Parquet type and its children from the metadata of its Arrow field.
+fn set_field_ids(parquet_type: &mut ParquetType, field: &ArrowField) -> DaftResult<()> {
+    let field_id = field
+        .metadata
+        .get(PARQUET_FIELD_ID_META_KEY)
+        .map(|id| {
+            id.parse::<i32>().map_err(|_| {
+                DaftError::ValueError(format!(
+                    "Invalid Parquet field id for field {}: {id}",
+                    field.name
+                ))
+            })
+        })
+        .transpose()?;
+    match parquet_type {
+        ParquetType::PrimitiveType(primitive_type) => primitive_type.field_info.id = field_id,
+        ParquetType::GroupType {
+            field_info, fields, ..
+        } => {
+            field_info.id = field_id;
+            match field.data_type().to_logical_type() {
+                DataType::Struct(children) => {
+                    for (child_type, child) in fields.iter_mut().zip(children) {
+                        set_field_ids(child_type, child)?;
+                    }
+                }
+                // Lists and maps nest their items under an intermediate repeated group.
+                DataType::List(child)
+                | DataType::LargeList(child)
+                | DataType::FixedSizeList(child, _)
+                | DataType::Map(child, _) => {
+                    if let Some(ParquetType::GroupType { fields: items, .. }) = fields.first_mut()
+                        && let Some(item) = items.first_mut()
+                    {
+                        set_field_ids(item, child)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Serializes the Arrow schema into the file metadata, so that readers recover the exact Arrow
+/// types of the columns.
+fn arrow_schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
+    let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
+    // Arrow expects the schema in the legacy IPC format, prefixed by a continuation marker and
+    // its length.
+    let mut len_prefix_schema = Vec::with_capacity(serialized_schema.len() + 8);
+    len_prefix_schema.extend_from_slice(&[255u8, 255, 255, 255]);
+    len_prefix_schema.extend_from_slice(&(serialized_schema.len() as u32).to_le_bytes());
+    len_prefix_schema.extend_from_slice(&serialized_schema);
+    KeyValue {
+        key: ARROW_SCHEMA_META_KEY.to_string(),
+        value: Some(general_purpose::STANDARD.encode(&len_prefix_schema)),
+    }
+}
+
+/// Writes `tables` into a single Parquet file at `uri` in any store supported by `io_client`,
+/// returning the size of the file.
+pub fn write_parquet(
+    uri: &str,
+    tables: &[Table],
+    schema: SchemaRef,
+    options: ParquetWriteOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<u64> {
+    let runtime_handle = daft_io::get_runtime(true)?;
+    let object_writer =
+        runtime_handle.block_on(io_client.open_writer(uri.to_string(), io_stats))?;
+    let sink = BufWriter::new(BlockingObjectWriter {
+        writer: object_writer,
+        runtime_handle: runtime_handle.clone(),
+    });
+
+    let mut writer = ParquetWriter::try_new(uri, sink, schema, options)?;
+    if let Err(err) = tables
+        .iter()
+        .try_for_each(|table| writer.write_table(table))
+    {
+        let (mut sink, _) = writer.into_inner().into_parts();
+        if let Err(abort_err) = runtime_handle.block_on(sink.writer.abort()) {
+            log::warn!("Unable to abort write of Parquet file {uri}: {abort_err}");
+        }
+        return Err(err);
+    }
+    // Writers that fail from here on are dropped, which aborts their upload in the background.
+    let (size, sink) = writer.finish()?;
+    let mut object_writer = sink
+        .into_inner()
+        .map_err(|err| err.into_error())
+        .context(InternalIOSnafu { path: uri })?
+        .writer;
+    runtime_handle
+        .block_on(object_writer.shutdown())
+        .context(InternalIOSnafu { path: uri })?;
+    Ok(size)
+}
+
+/// Adapts an [`ObjectWriter`] to the blocking [`Write`] expected by the Parquet file writer, which
+/// must therefore not be driven from within the runtime.
+struct BlockingObjectWriter {
+    writer: ObjectWriter,
+    runtime_handle: Arc<Runtime>,
+}
+
+impl Write for BlockingObjectWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.runtime_handle.block_on(self.writer.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.runtime_handle.block_on(self.writer.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use common_error::DaftResult;
+    use daft_core::{
+        datatypes::{Field, Int64Array, Utf8Array},
+        schema::Schema,
+        DataType, IntoSeries,
+    };
+    use daft_io::{IOClient, IOConfig};
+    use daft_table::Table;
+
+    use super::{write_parquet, ParquetWriteOptions, PARQUET_FIELD_ID_META_KEY};
+    use crate::read::{read_parquet, read_parquet_metadata};
+
+    #[test]
+    fn test_parquet_write_roundtrip() -> DaftResult<()> {
+        let dir = tempfile::tempdir()?;
+        let uri = format!(
+            "file://{}/nested/data.parquet",
+            dir.path().to_string_lossy().replace('\\', "/")
+        );
+
+        let ids = (0..1000).collect::<Vec<i64>>();
+        let names = ids
+            .iter()
+            .map(|i| format!("name_{}", i % 10))
+            .collect::<Vec<_>>();
+        let field_id = BTreeMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), "7".to_string())]);
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64).with_metadata(field_id),
+            Field::new("name", DataType::Utf8),
+        ])?;
+        let table = Table::new(
+            schema,
+            vec![
+                Int64Array::from(("id", ids)).into_series(),
+                Utf8Array::from(("name", names.as_slice())).into_series(),
+            ],
+        )?;
+        let options = ParquetWriteOptions {
+            row_group_size: table.size_bytes()?.div_ceil(4),
+            inflation_factor: 1.0,
+            ..Default::default()
+        };
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        write_parquet(
+            &uri,
+            std::slice::from_ref(&table),
+            table.schema.clone(),
+            options,
+            io_client.clone(),
+            None,
+        )?;
+
+        let runtime_handle = daft_io::get_runtime(true)?;
+        let metadata =
+            runtime_handle.block_on(read_parquet_metadata(&uri, io_client.clone(), None, None))?;
+        assert_eq!(metadata.row_groups.len(), 4);
+        let field_ids = metadata
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.get_field_info().id)
+            .collect::<Vec<_>>();
+        assert_eq!(field_ids, vec![Some(7), None]);
+
+        let read = read_parquet(
+            &uri,
+            None,
+            None,
+            None,
+            None,
+            None,
+            io_client,
+            None,
+            runtime_handle,
+            Default::default(),
+        )?;
+        assert_eq!(read.len(), table.len());
+        for name in ["id", "name"] {
+            assert_eq!(
+                read.get_column(name)?.to_arrow(),
+                table.get_column(name)?.to_arrow()
+            );
+        }
+
+        Ok(())
+    }
+}
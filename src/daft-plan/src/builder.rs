@@ -16,7 +16,8 @@ use common_error::{DaftError, DaftResult};
 use common_io_config::IOConfig;
 use daft_core::schema::Schema;
 use daft_core::schema::SchemaRef;
-use daft_dsl::{col, Expr, ExprRef};
+use daft_core::DataType;
+use daft_dsl::{col, functions::struct_, ApproxPercentileParams, Expr, ExprRef};
 use daft_scan::{file_format::FileFormat, Pushdowns, ScanExternalInfo, ScanOperatorRef};
 
 #[cfg(feature = "python")]
@@ -52,7 +53,7 @@ fn check_for_agg(expr: &Expr) -> bool {
 
     match expr {
         Agg(_) => true,
-        Column(_) | Literal(_) => false,
+        Column(_) | Literal(_) | Element => false,
         Alias(e, _) | Cast(e, _) | Not(e) | IsNull(e) | NotNull(e) => check_for_agg(e),
         BinaryOp { left, right, .. } => check_for_agg(left) || check_for_agg(right),
         Function { inputs, .. } => inputs.iter().map(|v| v.as_ref()).any(check_for_agg),
@@ -62,9 +63,14 @@ fn check_for_agg(expr: &Expr) -> bool {
             if_false,
             predicate,
         } => check_for_agg(if_true) || check_for_agg(if_false) || check_for_agg(predicate),
+        Window(..) => expr.children().iter().any(|e| check_for_agg(e)),
     }
 }
 
+fn check_for_window(expr: &Expr) -> bool {
+    matches!(expr, Expr::Window(..)) || expr.children().iter().any(|e| check_for_window(e))
+}
+
 fn err_if_agg(fn_name: &str, exprs: &Vec<ExprRef>) -> DaftResult<()> {
     for e in exprs {
         if check_for_agg(e) {
@@ -78,6 +84,31 @@ fn err_if_agg(fn_name: &str, exprs: &Vec<ExprRef>) -> DaftResult<()> {
     Ok(())
 }
 
+fn err_if_window(fn_name: &str, exprs: &Vec<ExprRef>) -> DaftResult<()> {
+    for e in exprs {
+        if check_for_window(e) {
+            return Err(DaftError::ValueError(format!(
+                "Window expressions are not currently supported in {fn_name}: {e}\nUse DataFrame.with_window_columns to evaluate them as new columns first",
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn check_window_expr(expr: &Expr) -> DaftResult<()> {
+    match expr {
+        Expr::Alias(e, _) => check_window_expr(e),
+        Expr::Window(..) => {
+            let children = expr.children();
+            err_if_agg("window", &children)?;
+            err_if_window("window", &children)
+        }
+        _ => Err(DaftError::ValueError(format!(
+            "Expected window expression, but got: {expr}"
+        ))),
+    }
+}
+
 fn extract_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
     use Expr::*;
 
@@ -95,9 +126,33 @@ fn extract_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
                 Count(e, count_mode) => Count(Alias(e, name.clone()).into(), count_mode),
                 Sum(e) => Sum(Alias(e, name.clone()).into()),
                 Mean(e) => Mean(Alias(e, name.clone()).into()),
+                Stddev(e, ddof) => Stddev(Alias(e, name.clone()).into(), ddof),
+                Variance(e, ddof) => Variance(Alias(e, name.clone()).into(), ddof),
+                Covariance(left, right, ddof) => {
+                    Covariance(Alias(left, name.clone()).into(), right, ddof)
+                }
+                Correlation(left, right) => Correlation(Alias(left, name.clone()).into(), right),
+                CoMoments(left, right) => CoMoments(Alias(left, name.clone()).into(), right),
+                MergeCoMoments(e) => MergeCoMoments(Alias(e, name.clone()).into()),
                 Min(e) => Min(Alias(e, name.clone()).into()),
                 Max(e) => Max(Alias(e, name.clone()).into()),
                 AnyValue(e, ignore_nulls) => AnyValue(Alias(e, name.clone()).into(), ignore_nulls),
+                BoolAnd(e) => BoolAnd(Alias(e, name.clone()).into()),
+                BoolOr(e) => BoolOr(Alias(e, name.clone()).into()),
+                BitAnd(e) => BitAnd(Alias(e, name.clone()).into()),
+                BitOr(e) => BitOr(Alias(e, name.clone()).into()),
+                BitXor(e) => BitXor(Alias(e, name.clone()).into()),
+                ApproxCountDistinct(e) => ApproxCountDistinct(Alias(e, name.clone()).into()),
+                ApproxPercentile(params) => ApproxPercentile(ApproxPercentileParams {
+                    child: Alias(params.child, name.clone()).into(),
+                    ..params
+                }),
+                ApproxSketch(e, sketch_type) => {
+                    ApproxSketch(Alias(e, name.clone()).into(), sketch_type)
+                }
+                MergeSketch(e, sketch_type) => {
+                    MergeSketch(Alias(e, name.clone()).into(), sketch_type)
+                }
                 List(e) => List(Alias(e, name.clone()).into()),
                 Concat(e) => Concat(Alias(e, name.clone()).into()),
                 MapGroups { func, inputs } => MapGroups {
@@ -122,8 +177,28 @@ fn extract_and_check_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
 
     let agg_expr = extract_agg_expr(expr)?;
     let has_nested_agg = match &agg_expr {
-        Count(e, _) | Sum(e) | Mean(e) | Min(e) | Max(e) | AnyValue(e, _) | List(e) | Concat(e) => {
-            check_for_agg(e)
+        Count(e, _)
+        | Sum(e)
+        | Mean(e)
+        | Stddev(e, _)
+        | Variance(e, _)
+        | MergeCoMoments(e)
+        | Min(e)
+        | Max(e)
+        | AnyValue(e, _)
+        | BoolAnd(e)
+        | BoolOr(e)
+        | BitAnd(e)
+        | BitOr(e)
+        | BitXor(e)
+        | ApproxCountDistinct(e)
+        | ApproxPercentile(ApproxPercentileParams { child: e, .. })
+        | ApproxSketch(e, _)
+        | MergeSketch(e, _)
+        | List(e)
+        | Concat(e) => check_for_agg(e),
+        Covariance(left, right, _) | Correlation(left, right) | CoMoments(left, right) => {
+            check_for_agg(left) || check_for_agg(right)
         }
         MapGroups { inputs, .. } => inputs.iter().map(|v| v.as_ref()).any(check_for_agg),
     };
@@ -194,6 +269,7 @@ impl LogicalPlanBuilder {
 
     pub fn select(&self, to_select: Vec<ExprRef>) -> DaftResult<Self> {
         err_if_agg("project", &to_select)?;
+        err_if_window("project", &to_select)?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Project::try_new(self.plan.clone(), to_select, Default::default())?.into();
@@ -206,6 +282,7 @@ impl LogicalPlanBuilder {
         resource_request: ResourceRequest,
     ) -> DaftResult<Self> {
         err_if_agg("with_columns", &columns)?;
+        err_if_window("with_columns", &columns)?;
 
         let new_col_names = columns
             .iter()
@@ -253,8 +330,48 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
+    pub fn unnest(&self, to_unnest: Vec<String>) -> DaftResult<Self> {
+        let schema = self.schema();
+        for name in &to_unnest {
+            if !schema.fields.contains_key(name) {
+                return Err(DaftError::FieldNotFound(format!(
+                    "Column {name} not found in schema: {:?}",
+                    schema.names()
+                )));
+            }
+        }
+        let to_unnest = HashSet::<_>::from_iter(to_unnest.iter());
+
+        // Each struct column is replaced in place by one column per struct field, so that
+        // projection pushdown can later prune the fields that aren't used downstream.
+        let mut exprs = vec![];
+        for (name, field) in schema.fields.iter() {
+            if !to_unnest.contains(name) {
+                exprs.push(col(name.clone()));
+                continue;
+            }
+            match &field.dtype {
+                DataType::Struct(fields) => exprs.extend(
+                    fields
+                        .iter()
+                        .map(|f| struct_::get(col(name.clone()), f.name.as_str())),
+                ),
+                dtype => {
+                    return Err(DaftError::TypeError(format!(
+                        "Can only unnest struct columns, but column {name} has type {dtype}"
+                    )))
+                }
+            }
+        }
+
+        let logical_plan: LogicalPlan =
+            logical_ops::Project::try_new(self.plan.clone(), exprs, Default::default())?.into();
+        Ok(logical_plan.into())
+    }
+
     pub fn filter(&self, predicate: ExprRef) -> DaftResult<Self> {
         err_if_agg("filter", &vec![predicate.to_owned()])?;
+        err_if_window("filter", &vec![predicate.to_owned()])?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Filter::try_new(self.plan.clone(), predicate)?.into();
@@ -269,6 +386,7 @@ impl LogicalPlanBuilder {
 
     pub fn explode(&self, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
         err_if_agg("explode", &to_explode)?;
+        err_if_window("explode", &to_explode)?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Explode::try_new(self.plan.clone(), to_explode)?.into();
@@ -277,6 +395,7 @@ impl LogicalPlanBuilder {
 
     pub fn sort(&self, sort_by: Vec<ExprRef>, descending: Vec<bool>) -> DaftResult<Self> {
         err_if_agg("sort", &sort_by)?;
+        err_if_window("sort", &sort_by)?;
 
         let logical_plan: LogicalPlan =
             logical_ops::Sort::try_new(self.plan.clone(), sort_by, descending)?.into();
@@ -337,6 +456,9 @@ impl LogicalPlanBuilder {
         agg_exprs: Vec<ExprRef>,
         groupby_exprs: Vec<ExprRef>,
     ) -> DaftResult<Self> {
+        err_if_window("aggregate", &agg_exprs)?;
+        err_if_window("groupby", &groupby_exprs)?;
+
         let agg_exprs = agg_exprs
             .iter()
             .map(|v| v.as_ref())
@@ -348,6 +470,16 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
+    pub fn window(&self, window_exprs: Vec<ExprRef>) -> DaftResult<Self> {
+        for e in window_exprs.iter() {
+            check_window_expr(e)?;
+        }
+
+        let logical_plan: LogicalPlan =
+            logical_ops::Window::try_new(self.plan.clone(), window_exprs)?.into();
+        Ok(logical_plan.into())
+    }
+
     pub fn join(
         &self,
         right: &Self,
@@ -355,15 +487,32 @@ impl LogicalPlanBuilder {
         right_on: Vec<ExprRef>,
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
+    ) -> DaftResult<Self> {
+        self.join_with_predicate(right, left_on, right_on, None, join_type, join_strategy)
+    }
+
+    pub fn join_with_predicate(
+        &self,
+        right: &Self,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        predicate: Option<ExprRef>,
+        join_type: JoinType,
+        join_strategy: Option<JoinStrategy>,
     ) -> DaftResult<Self> {
         err_if_agg("join", &left_on)?;
         err_if_agg("join", &right_on)?;
+        if let Some(predicate) = &predicate {
+            err_if_agg("join", &vec![predicate.to_owned()])?;
+            err_if_window("join", &vec![predicate.to_owned()])?;
+        }
 
         let logical_plan: LogicalPlan = logical_ops::Join::try_new(
             self.plan.clone(),
             right.plan.clone(),
             left_on,
             right_on,
+            predicate,
             join_type,
             join_strategy,
         )?
@@ -371,6 +520,10 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
+    pub fn cross_join(&self, right: &Self) -> DaftResult<Self> {
+        self.join(right, vec![], vec![], JoinType::Inner, None)
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             logical_ops::Concat::try_new(self.plan.clone(), other.plan.clone())?.into();
@@ -524,6 +677,10 @@ impl PyLogicalPlanBuilder {
         Ok(self.builder.exclude(to_exclude)?.into())
     }
 
+    pub fn unnest(&self, to_unnest: Vec<String>) -> PyResult<Self> {
+        Ok(self.builder.unnest(to_unnest)?.into())
+    }
+
     pub fn filter(&self, predicate: PyExpr) -> PyResult<Self> {
         Ok(self.builder.filter(predicate.expr)?.into())
     }
@@ -585,6 +742,10 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn window(&self, window_exprs: Vec<PyExpr>) -> PyResult<Self> {
+        Ok(self.builder.window(pyexprs_to_exprs(window_exprs))?.into())
+    }
+
     pub fn join(
         &self,
         right: &Self,
@@ -592,13 +753,15 @@ impl PyLogicalPlanBuilder {
         right_on: Vec<PyExpr>,
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
+        predicate: Option<PyExpr>,
     ) -> PyResult<Self> {
         Ok(self
             .builder
-            .join(
+            .join_with_predicate(
                 &right.builder,
                 pyexprs_to_exprs(left_on),
                 pyexprs_to_exprs(right_on),
+                predicate.map(|e| e.expr),
                 join_type,
                 join_strategy,
             )?
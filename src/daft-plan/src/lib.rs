@@ -4,7 +4,6 @@
 
 mod builder;
 mod display;
-mod join;
 mod logical_ops;
 mod logical_optimization;
 mod logical_plan;
@@ -20,8 +19,8 @@ mod test;
 mod treenode;
 
 pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
+pub use daft_core::join::{JoinStrategy, JoinType};
 use daft_scan::file_format::FileFormat;
-pub use join::{JoinStrategy, JoinType};
 pub use logical_plan::LogicalPlan;
 pub use partitioning::ClusteringSpec;
 pub use physical_plan::PhysicalPlanScheduler;
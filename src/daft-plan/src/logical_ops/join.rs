@@ -22,6 +22,8 @@ pub struct Join {
 
     pub left_on: Vec<ExprRef>,
     pub right_on: Vec<ExprRef>,
+    // Residual predicate over the joined rows, applied in addition to the join keys.
+    pub predicate: Option<ExprRef>,
     pub join_type: JoinType,
     pub join_strategy: Option<JoinStrategy>,
     pub output_schema: SchemaRef,
@@ -37,6 +39,7 @@ impl std::hash::Hash for Join {
         std::hash::Hash::hash(&self.right, state);
         std::hash::Hash::hash(&self.left_on, state);
         std::hash::Hash::hash(&self.right_on, state);
+        std::hash::Hash::hash(&self.predicate, state);
         std::hash::Hash::hash(&self.join_type, state);
         std::hash::Hash::hash(&self.join_strategy, state);
         std::hash::Hash::hash(&self.output_schema, state);
@@ -50,9 +53,34 @@ impl Join {
         right: Arc<LogicalPlan>,
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
+        predicate: Option<ExprRef>,
         join_type: JoinType,
         join_strategy: Option<JoinStrategy>,
     ) -> logical_plan::Result<Self> {
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::ValueError(format!(
+                "Length of left_on does not match length of right_on for Join {} vs {}",
+                left_on.len(),
+                right_on.len()
+            )))
+            .context(CreationSnafu);
+        }
+        if left_on.is_empty() {
+            // Joins without keys are always executed as nested-loop joins.
+            if let Some(join_strategy @ (JoinStrategy::Hash | JoinStrategy::SortMerge)) =
+                join_strategy
+            {
+                return Err(DaftError::ValueError(format!(
+                    "{join_strategy} join strategy requires join keys, but none were given"
+                )))
+                .context(CreationSnafu);
+            }
+        } else if predicate.is_some() && join_type != JoinType::Inner {
+            return Err(DaftError::ValueError(format!(
+                "Join predicates on keyed joins are only supported for inner joins, but got a {join_type} join"
+            )))
+            .context(CreationSnafu);
+        }
         for (on_exprs, schema) in [(&left_on, left.schema()), (&right_on, right.schema())] {
             let on_fields = on_exprs
                 .iter()
@@ -72,38 +100,56 @@ impl Join {
         let mut right_input_mapping = indexmap::IndexMap::new();
         // Schema inference ported from existing behaviour for parity,
         // but contains bug https://github.com/Eventual-Inc/Daft/issues/1294
-        let output_schema = {
-            let left_join_keys = left_on
-                .iter()
-                .map(|e| e.name())
-                .collect::<common_error::DaftResult<HashSet<_>>>()
+        let left_join_keys = left_on
+            .iter()
+            .map(|e| e.name())
+            .collect::<common_error::DaftResult<HashSet<_>>>()
+            .context(CreationSnafu)?;
+        let left_schema = &left.schema().fields;
+        let fields = left_schema
+            .iter()
+            .map(|(_, field)| field)
+            .cloned()
+            .chain(right.schema().fields.iter().filter_map(|(rname, rfield)| {
+                if left_join_keys.contains(rname.as_str()) {
+                    right_input_mapping.insert(rname.clone(), rname.clone());
+                    None
+                } else if left_schema.contains_key(rname) {
+                    let new_name = format!("right.{}", rname);
+                    right_input_mapping.insert(new_name.clone(), rname.clone());
+                    Some(rfield.rename(new_name))
+                } else {
+                    right_input_mapping.insert(rname.clone(), rname.clone());
+                    Some(rfield.clone())
+                }
+            }))
+            .collect::<Vec<_>>();
+        let joined_schema: SchemaRef = Schema::new(fields).context(CreationSnafu)?.into();
+        // The predicate is evaluated over the joined rows, even if the join only outputs the left side.
+        if let Some(predicate) = &predicate {
+            let predicate_field = predicate
+                .to_field(joined_schema.as_ref())
                 .context(CreationSnafu)?;
-            let left_schema = &left.schema().fields;
-            let fields = left_schema
-                .iter()
-                .map(|(_, field)| field)
-                .cloned()
-                .chain(right.schema().fields.iter().filter_map(|(rname, rfield)| {
-                    if left_join_keys.contains(rname.as_str()) {
-                        right_input_mapping.insert(rname.clone(), rname.clone());
-                        None
-                    } else if left_schema.contains_key(rname) {
-                        let new_name = format!("right.{}", rname);
-                        right_input_mapping.insert(new_name.clone(), rname.clone());
-                        Some(rfield.rename(new_name))
-                    } else {
-                        right_input_mapping.insert(rname.clone(), rname.clone());
-                        Some(rfield.clone())
-                    }
-                }))
-                .collect::<Vec<_>>();
-            Schema::new(fields).context(CreationSnafu)?.into()
+            if predicate_field.dtype != DataType::Boolean {
+                return Err(DaftError::ValueError(format!(
+                    "Expected join predicate to resolve to type Boolean, but received: {}",
+                    predicate_field.dtype
+                )))
+                .context(CreationSnafu);
+            }
+        }
+        let output_schema = if join_type.is_left_only() {
+            // Semi and anti joins only filter the left side, so none of the right columns are output.
+            left.schema()
+        } else {
+            joined_schema
         };
         Ok(Self {
             left,
             right,
             left_on,
             right_on,
+            predicate,
             join_type,
             join_strategy,
             output_schema,
@@ -138,6 +184,9 @@ impl Join {
                 ));
             }
         }
+        if let Some(predicate) = &self.predicate {
+            res.push(format!("Predicate = {predicate}"));
+        }
         res.push(format!(
             "Output schema = {}",
             self.output_schema.short_string()
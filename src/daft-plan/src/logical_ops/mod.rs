@@ -12,6 +12,7 @@ mod sample;
 mod sink;
 mod sort;
 mod source;
+mod window;
 
 pub use agg::Aggregate;
 pub use concat::Concat;
@@ -27,3 +28,4 @@ pub use sample::Sample;
 pub use sink::Sink;
 pub use sort::Sort;
 pub use source::Source;
+pub use window::Window;
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use daft_core::datatypes::FieldID;
 use daft_core::schema::{Schema, SchemaRef};
-use daft_dsl::{optimization, AggExpr, Expr, ExprRef};
+use daft_dsl::{optimization, AggExpr, ApproxPercentileParams, Expr, ExprRef};
 use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use snafu::ResultExt;
@@ -201,7 +201,7 @@ fn replace_column_with_semantic_id(
         Transformed::Yes(new_expr.into())
     } else {
         match e.as_ref() {
-            Expr::Column(_) | Expr::Literal(_) => Transformed::No(e),
+            Expr::Column(_) | Expr::Literal(_) | Expr::Element => Transformed::No(e),
             Expr::Agg(agg_expr) => replace_column_with_semantic_id_aggexpr(
                 agg_expr.clone(),
                 subexprs_to_replace,
@@ -336,6 +336,8 @@ fn replace_column_with_semantic_id(
                     )
                 }
             }
+            // Window expressions are only evaluated by the Window op, never within a projection.
+            Expr::Window(..) => Transformed::No(e),
         }
     }
 }
@@ -365,6 +367,59 @@ fn replace_column_with_semantic_id_aggexpr(
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Mean, |_| e.clone())
         }
+        AggExpr::Stddev(ref child, ddof) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::Stddev(transformed_child, ddof),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::Variance(ref child, ddof) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::Variance(transformed_child, ddof),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::Covariance(ref left, ref right, ddof) => {
+            let left = replace_column_with_semantic_id(left.clone(), subexprs_to_replace, schema);
+            let right = replace_column_with_semantic_id(right.clone(), subexprs_to_replace, schema);
+            if left.is_no() && right.is_no() {
+                Transformed::No(e)
+            } else {
+                Transformed::Yes(AggExpr::Covariance(
+                    left.unwrap().clone(),
+                    right.unwrap().clone(),
+                    ddof,
+                ))
+            }
+        }
+        AggExpr::Correlation(ref left, ref right) => {
+            let left = replace_column_with_semantic_id(left.clone(), subexprs_to_replace, schema);
+            let right = replace_column_with_semantic_id(right.clone(), subexprs_to_replace, schema);
+            if left.is_no() && right.is_no() {
+                Transformed::No(e)
+            } else {
+                Transformed::Yes(AggExpr::Correlation(
+                    left.unwrap().clone(),
+                    right.unwrap().clone(),
+                ))
+            }
+        }
+        AggExpr::CoMoments(ref left, ref right) => {
+            let left = replace_column_with_semantic_id(left.clone(), subexprs_to_replace, schema);
+            let right = replace_column_with_semantic_id(right.clone(), subexprs_to_replace, schema);
+            if left.is_no() && right.is_no() {
+                Transformed::No(e)
+            } else {
+                Transformed::Yes(AggExpr::CoMoments(
+                    left.unwrap().clone(),
+                    right.unwrap().clone(),
+                ))
+            }
+        }
+        AggExpr::MergeCoMoments(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::MergeCoMoments, |_| e.clone())
+        }
         AggExpr::Min(ref child) => {
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::Min, |_| e.clone())
@@ -379,6 +434,57 @@ fn replace_column_with_semantic_id_aggexpr(
                 |_| e.clone(),
             )
         }
+        AggExpr::BoolAnd(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::BoolAnd, |_| e.clone())
+        }
+        AggExpr::BoolOr(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::BoolOr, |_| e.clone())
+        }
+        AggExpr::BitAnd(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::BitAnd, |_| e.clone())
+        }
+        AggExpr::BitOr(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::BitOr, |_| e.clone())
+        }
+        AggExpr::BitXor(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::BitXor, |_| e.clone())
+        }
+        AggExpr::ApproxCountDistinct(ref child) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+                .map_yes_no(AggExpr::ApproxCountDistinct, |_| e.clone())
+        }
+        AggExpr::ApproxPercentile(ApproxPercentileParams {
+            ref child,
+            ref percentiles,
+            force_list_output,
+        }) => replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
+            .map_yes_no(
+                |transformed_child| {
+                    AggExpr::ApproxPercentile(ApproxPercentileParams {
+                        child: transformed_child,
+                        percentiles: percentiles.clone(),
+                        force_list_output,
+                    })
+                },
+                |_| e.clone(),
+            ),
+        AggExpr::ApproxSketch(ref child, sketch_type) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::ApproxSketch(transformed_child, sketch_type),
+                |_| e.clone(),
+            )
+        }
+        AggExpr::MergeSketch(ref child, sketch_type) => {
+            replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema).map_yes_no(
+                |transformed_child| AggExpr::MergeSketch(transformed_child, sketch_type),
+                |_| e.clone(),
+            )
+        }
         AggExpr::List(ref child) => {
             replace_column_with_semantic_id(child.clone(), subexprs_to_replace, schema)
                 .map_yes_no(AggExpr::List, |_| e.clone())
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::schema::{Schema, SchemaRef};
+use daft_dsl::{Expr, ExprRef, WindowSpec};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    LogicalPlan,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    /// Window expressions to evaluate, e.g. row_number().over(...).alias("rn").
+    pub window_exprs: Vec<ExprRef>,
+    /// Window specification shared by all of the window expressions.
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub output_schema: SchemaRef,
+}
+
+fn extract_window_spec(expr: &Expr) -> logical_plan::Result<&WindowSpec> {
+    match expr {
+        Expr::Window(_, spec) => Ok(spec),
+        Expr::Alias(e, _) => extract_window_spec(e),
+        _ => Err(DaftError::ValueError(format!(
+            "Expected window expression, but got: {expr}"
+        )))
+        .context(CreationSnafu),
+    }
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_exprs: Vec<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        let spec = match window_exprs.first() {
+            Some(first) => extract_window_spec(first)?.clone(),
+            None => {
+                return Err(DaftError::ValueError(
+                    "Window must be given at least one window expression".to_string(),
+                ))
+                .context(CreationSnafu)
+            }
+        };
+        for e in window_exprs.iter().skip(1) {
+            if extract_window_spec(e)? != &spec {
+                return Err(DaftError::ValueError(format!(
+                    "All window expressions in a single window must share the same window spec, but got: {spec} and {}",
+                    extract_window_spec(e)?
+                )))
+                .context(CreationSnafu);
+            }
+        }
+        if spec.order_by.len() != spec.descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "Window order_by and descending must have the same length, but got {} and {}",
+                spec.order_by.len(),
+                spec.descending.len()
+            )))
+            .context(CreationSnafu);
+        }
+
+        let output_schema = {
+            let upstream_schema = input.schema();
+            let window_schema = {
+                let window_fields = window_exprs
+                    .iter()
+                    .map(|e| e.to_field(&upstream_schema))
+                    .collect::<common_error::DaftResult<Vec<_>>>()
+                    .context(CreationSnafu)?;
+                Schema::new(window_fields).context(CreationSnafu)?
+            };
+            // Window columns replace any upstream columns with the same name, same as with_columns.
+            let fields = upstream_schema
+                .fields
+                .values()
+                .filter(|field| !window_schema.fields.contains_key(&field.name))
+                .chain(window_schema.fields.values())
+                .cloned()
+                .collect::<Vec<_>>();
+            Schema::new(fields).context(CreationSnafu)?.into()
+        };
+
+        Ok(Self {
+            input,
+            window_exprs,
+            partition_by: spec.partition_by,
+            order_by: spec.order_by,
+            descending: spec.descending,
+            output_schema,
+        })
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_exprs.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" },))
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res.push(format!("Output schema = {}", self.output_schema.short_string()));
+        res
+    }
+}
@@ -16,7 +16,7 @@ use daft_scan::{rewrite_predicate_for_partitioning, PredicateGroups};
 use crate::{
     logical_ops::{Concat, Filter, Project, Source},
     source_info::SourceInfo,
-    LogicalPlan,
+    JoinType, LogicalPlan,
 };
 
 use super::{ApplyOrder, OptimizerRule, Transformed};
@@ -252,6 +252,15 @@ impl OptimizerRule for PushDownFilter {
                 // TODO(Clark): Merge filter predicate with on predicate, if present.
                 // TODO(Clark): Duplicate filters for joined columns so filters can be pushed down to both sides.
 
+                // A filter can't be pushed into a side of the join whose unmatched rows are emitted with nulls for
+                // the other side, and semi/anti joins don't output any columns from the right side.
+                let (is_left_pushable, is_right_pushable) = match child_join.join_type {
+                    JoinType::Inner => (true, true),
+                    JoinType::Left | JoinType::Semi | JoinType::Anti => (true, false),
+                    JoinType::Right => (false, true),
+                    JoinType::Outer => (false, false),
+                };
+
                 // Get all input columns for predicate.
                 let predicate_cols: HashSet<_> = get_required_columns(&filter.predicate)
                     .iter()
@@ -261,20 +270,22 @@ impl OptimizerRule for PushDownFilter {
                 // required by the predicate.
                 let left_cols: HashSet<_> =
                     child_join.left.schema().names().iter().cloned().collect();
-                let can_push_left = left_cols
-                    .intersection(&predicate_cols)
-                    .collect::<HashSet<_>>()
-                    .len()
-                    == predicate_cols.len();
+                let can_push_left = is_left_pushable
+                    && left_cols
+                        .intersection(&predicate_cols)
+                        .collect::<HashSet<_>>()
+                        .len()
+                        == predicate_cols.len();
                 // Only push the filter into the right side of the join if the right side of the join has all columns
                 // required by the predicate.
                 let right_cols: HashSet<_> =
                     child_join.right.schema().names().iter().cloned().collect();
-                let can_push_right = right_cols
-                    .intersection(&predicate_cols)
-                    .collect::<HashSet<_>>()
-                    .len()
-                    == predicate_cols.len();
+                let can_push_right = is_right_pushable
+                    && right_cols
+                        .intersection(&predicate_cols)
+                        .collect::<HashSet<_>>()
+                        .len()
+                        == predicate_cols.len();
                 if !can_push_left && !can_push_right {
                     return Ok(Transformed::No(plan));
                 }
@@ -823,4 +834,35 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)?;
         Ok(())
     }
+
+    /// Tests that Filter isn't pushed into the null-producing side of a Join.
+    #[test]
+    fn filter_does_not_commute_with_null_producing_join_side() -> DaftResult<()> {
+        let left_scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let right_scan_op = dummy_scan_operator(vec![
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Float64),
+        ]);
+        let left_scan_plan = dummy_scan_node(left_scan_op);
+        let right_scan_plan = dummy_scan_node(right_scan_op);
+        let join_on = vec![col("b")];
+        let pred = col("c").lt(lit(2.0));
+        let plan = left_scan_plan
+            .join(
+                &right_scan_plan,
+                join_on.clone(),
+                join_on,
+                JoinType::Left,
+                None,
+            )?
+            .filter(pred)?
+            .build();
+        // Filter should not be pushed into the right side of a left join.
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
 }
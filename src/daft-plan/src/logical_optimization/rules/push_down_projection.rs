@@ -7,7 +7,7 @@ use daft_dsl::{col, optimization::replace_columns_with_expressions, Expr, ExprRe
 use indexmap::IndexSet;
 
 use crate::{
-    logical_ops::{Aggregate, Project, Source},
+    logical_ops::{Aggregate, Project, Source, Window},
     source_info::SourceInfo,
     LogicalPlan, ResourceRequest,
 };
@@ -233,6 +233,80 @@ impl PushDownProjection {
                     Ok(Transformed::No(plan))
                 }
             }
+            LogicalPlan::Window(window) => {
+                // Prune window expressions that are not used in this projection.
+                let required_columns = &plan.required_columns()[0];
+                let pruned_window_exprs = window
+                    .window_exprs
+                    .iter()
+                    .filter(|&e| required_columns.contains(e.name().unwrap()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if pruned_window_exprs.len() < window.window_exprs.len() {
+                    // If no window expressions are used, drop the window altogether.
+                    let new_upstream: Arc<LogicalPlan> = if pruned_window_exprs.is_empty() {
+                        window.input.clone()
+                    } else {
+                        let new_window: LogicalPlan =
+                            Window::try_new(window.input.clone(), pruned_window_exprs)?.into();
+                        new_window.into()
+                    };
+
+                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
+                    // Retry optimization now that the upstream node is different.
+                    let new_plan = self
+                        .try_optimize(new_plan.clone())?
+                        .or(Transformed::Yes(new_plan));
+                    return Ok(new_plan);
+                }
+
+                // Otherwise, prune the columns from the window's input that are neither
+                // passed through to this projection nor used by the window expressions.
+                let window_output_names = window
+                    .window_exprs
+                    .iter()
+                    .map(|e| e.name().unwrap())
+                    .collect::<IndexSet<_>>();
+                let grand_upstream_plan = &window.input;
+                let grand_upstream_schema = grand_upstream_plan.schema();
+                let combined_dependencies = required_columns
+                    .iter()
+                    .filter(|&name| {
+                        !window_output_names.contains(name.as_str())
+                            && grand_upstream_schema.fields.contains_key(name)
+                    })
+                    .chain(upstream_plan.required_columns().iter().flatten())
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+
+                // Skip optimization if no columns would be pruned.
+                if grand_upstream_schema.names().len() == combined_dependencies.len() {
+                    return Ok(Transformed::No(plan));
+                }
+
+                let new_subprojection: LogicalPlan = {
+                    let pushdown_column_exprs = combined_dependencies
+                        .into_iter()
+                        .map(col)
+                        .collect::<Vec<_>>();
+
+                    Project::try_new(
+                        grand_upstream_plan.clone(),
+                        pushdown_column_exprs,
+                        Default::default(),
+                    )?
+                    .into()
+                };
+
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
+                // Retry optimization now that the upstream node is different.
+                let new_plan = self
+                    .try_optimize(new_plan.clone())?
+                    .or(Transformed::Yes(new_plan));
+                Ok(new_plan)
+            }
             LogicalPlan::Sort(..)
             | LogicalPlan::Repartition(..)
             | LogicalPlan::Limit(..)
@@ -495,7 +569,7 @@ mod tests {
 
     use common_error::DaftResult;
     use daft_core::{datatypes::Field, DataType};
-    use daft_dsl::{col, lit};
+    use daft_dsl::{col, functions::struct_, lit, WindowExpr, WindowSpec};
     use daft_scan::Pushdowns;
 
     use crate::{
@@ -710,4 +784,104 @@ mod tests {
 
         Ok(())
     }
+
+    /// Projection<-Window prunes unused window expressions and upstream columns
+    #[test]
+    fn test_projection_window() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+            Field::new("d", DataType::Int64),
+        ]);
+        let spec = WindowSpec::new(vec![col("a")], vec![col("c")], vec![false]);
+        let cum_sum = col("b").cum_sum().over(spec.clone()).alias("s");
+        let window_exprs = vec![
+            cum_sum.clone(),
+            WindowExpr::RowNumber.over(spec).alias("rn"),
+        ];
+        let proj = vec![col("a"), col("s")];
+        let plan = dummy_scan_node(scan_op.clone())
+            .window(window_exprs)?
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .window(vec![cum_sum])?
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
+    /// Projection<-Window drops the window altogether if none of its expressions are used
+    #[test]
+    fn test_projection_drops_unused_window() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let spec = WindowSpec::new(vec![col("a")], vec![col("c")], vec![false]);
+        let window_exprs = vec![
+            col("b").lag(1, lit(0)).over(spec.clone()).alias("prev_b"),
+            WindowExpr::Rank.over(spec).alias("rank"),
+        ];
+        let proj = vec![col("b").add(lit(1))];
+        let plan = dummy_scan_node(scan_op.clone())
+            .window(window_exprs)?
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["b".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
+    /// Projection<-Unnest: Test that struct fields that aren't used downstream of an unnest
+    /// are pruned.
+    #[test]
+    fn test_projection_prunes_unnested_fields() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new(
+                "s",
+                DataType::Struct(vec![
+                    Field::new("x", DataType::Int64),
+                    Field::new("y", DataType::Utf8),
+                ]),
+            ),
+            Field::new("b", DataType::Int64),
+        ]);
+        let plan = dummy_scan_node(scan_op.clone())
+            .unnest(vec!["s".to_string()])?
+            .select(vec![col("a"), col("x").add(lit(1))])?
+            .build();
+
+        let proj_pushdown = vec!["a".to_string(), "s".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .select(vec![col("a"), struct_::get(col("s"), "x").add(lit(1))])?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
 }
@@ -25,6 +25,7 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
 }
 
 impl LogicalPlan {
@@ -53,6 +54,7 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { output_schema, .. }) => output_schema.clone(),
         }
     }
 
@@ -120,14 +122,33 @@ impl LogicalPlan {
                 vec![res]
             }
             Self::Join(join) => {
-                let left = join.left_on.iter().flat_map(get_required_columns).collect();
-                let right = join
+                let mut left: IndexSet<_> =
+                    join.left_on.iter().flat_map(get_required_columns).collect();
+                let mut right: IndexSet<_> = join
                     .right_on
                     .iter()
                     .flat_map(get_required_columns)
                     .collect();
+                if let Some(predicate) = &join.predicate {
+                    let left_schema = join.left.schema();
+                    for name in get_required_columns(predicate) {
+                        if left_schema.fields.contains_key(&name) {
+                            left.insert(name);
+                        } else if let Some(rname) = join.right_input_mapping.get(&name) {
+                            right.insert(rname.clone());
+                        }
+                    }
+                }
                 vec![left, right]
             }
+            Self::Window(window) => {
+                let res = window
+                    .window_exprs
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Source(_) => todo!(),
             Self::Sink(_) => todo!(),
         }
@@ -151,6 +172,7 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input.clone()]
             }
+            Self::Window(Window { input, .. }) => vec![input.clone()],
         }
     }
 
@@ -169,12 +191,13 @@ impl LogicalPlan {
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
+                Self::Window(Window { window_exprs, .. }) => Self::Window(Window::try_new(input.clone(), window_exprs.clone()).unwrap()),
                 _ => panic!("Logical op {} has two inputs, but got one", self),
             },
             [input1, input2] => match self {
                 Self::Source(_) => panic!("Source nodes don't have children, with_new_children() should never be called for Source ops"),
                 Self::Concat(_) => Self::Concat(Concat::try_new(input1.clone(), input2.clone()).unwrap()),
-                Self::Join(Join { left_on, right_on, join_type, join_strategy, .. }) => Self::Join(Join::try_new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), *join_type, *join_strategy).unwrap()),
+                Self::Join(Join { left_on, right_on, predicate, join_type, join_strategy, .. }) => Self::Join(Join::try_new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), predicate.clone(), *join_type, *join_strategy).unwrap()),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
             _ => panic!("Logical ops should never have more than 2 inputs, but got: {}", children.len())
@@ -212,6 +235,7 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
         };
         name.to_string()
     }
@@ -234,6 +258,7 @@ impl LogicalPlan {
                 vec![format!("Sample: {fraction}", fraction = sample.fraction)]
             }
             Self::MonotonicallyIncreasingId(_) => vec!["MonotonicallyIncreasingId".to_string()],
+            Self::Window(window) => window.multiline_display(),
         }
     }
 
@@ -296,3 +321,4 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
@@ -24,6 +24,12 @@ impl BroadcastJoin {
         join_type: JoinType,
         is_swapped: bool,
     ) -> Self {
+        // The broadcaster is the left side of the join unless the sides are swapped.
+        debug_assert!(
+            join_type.can_broadcast(!is_swapped),
+            "Can't broadcast the {} side of a {join_type} join",
+            if is_swapped { "right" } else { "left" }
+        );
         Self {
             broadcaster,
             receiver,
@@ -16,6 +16,7 @@ mod in_memory;
 mod json;
 mod limit;
 mod monotonically_increasing_id;
+mod nested_loop_join;
 mod parquet;
 mod project;
 mod reduce;
@@ -24,6 +25,7 @@ mod scan;
 mod sort;
 mod sort_merge_join;
 mod split;
+mod window;
 
 pub use agg::Aggregate;
 pub use broadcast_join::BroadcastJoin;
@@ -43,6 +45,7 @@ pub use in_memory::InMemoryScan;
 pub use json::TabularWriteJson;
 pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
+pub use nested_loop_join::NestedLoopJoin;
 pub use parquet::TabularWriteParquet;
 pub use project::Project;
 pub use reduce::ReduceMerge;
@@ -51,3 +54,4 @@ pub use scan::TabularScan;
 pub use sort::Sort;
 pub use sort_merge_join::SortMergeJoin;
 pub use split::Split;
+pub use window::Window;
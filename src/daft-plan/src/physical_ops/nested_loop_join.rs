@@ -0,0 +1,49 @@
+use daft_dsl::ExprRef;
+
+use crate::{physical_plan::PhysicalPlanRef, JoinType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NestedLoopJoin {
+    // Upstream node.
+    pub broadcaster: PhysicalPlanRef,
+    pub receiver: PhysicalPlanRef,
+    pub predicate: Option<ExprRef>,
+    pub join_type: JoinType,
+    pub is_swapped: bool,
+}
+
+impl NestedLoopJoin {
+    pub(crate) fn new(
+        broadcaster: PhysicalPlanRef,
+        receiver: PhysicalPlanRef,
+        predicate: Option<ExprRef>,
+        join_type: JoinType,
+        is_swapped: bool,
+    ) -> Self {
+        // Unmatched broadcaster rows can only be emitted if there is a single receiver partition.
+        debug_assert!(
+            join_type.can_broadcast(!is_swapped)
+                || receiver.clustering_spec().num_partitions() == 1,
+            "Can't broadcast the {} side of a {join_type} join to multiple partitions",
+            if is_swapped { "right" } else { "left" }
+        );
+        Self {
+            broadcaster,
+            receiver,
+            predicate,
+            join_type,
+            is_swapped,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("NestedLoopJoin: Type = {}", self.join_type));
+        if let Some(predicate) = &self.predicate {
+            res.push(format!("Predicate = {predicate}"));
+        }
+        res.push(format!("Is swapped = {}", self.is_swapped));
+        res
+    }
+}
@@ -184,8 +184,8 @@ impl Project {
 
                 Ok(newpred.if_else(newtrue, newfalse))
             }
-            // Cannot have agg exprs in partition specs.
-            Expr::Agg(_) => Err(()),
+            // Cannot have agg, window or list element exprs in partition specs.
+            Expr::Agg(_) | Expr::Window(..) | Expr::Element => Err(()),
         }
     }
 
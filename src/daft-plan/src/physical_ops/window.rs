@@ -0,0 +1,109 @@
+use std::{collections::HashSet, sync::Arc};
+
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use itertools::Itertools;
+
+use crate::{
+    partitioning::{HashClusteringConfig, RangeClusteringConfig, UnknownClusteringConfig},
+    physical_plan::PhysicalPlanRef,
+    ClusteringSpec,
+};
+use serde::{Deserialize, Serialize};
+
+/// Evaluates window expressions over each partition, assuming that every window partition
+/// (i.e. every distinct partition_by key) is wholly contained in a single input partition.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Window {
+    // Upstream node.
+    pub input: PhysicalPlanRef,
+    pub window_exprs: Vec<ExprRef>,
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    /// Estimated size of an output row relative to an input row, since window columns are
+    /// appended to every row.
+    pub row_size_ratio: f64,
+    pub clustering_spec: Arc<ClusteringSpec>,
+}
+
+impl Window {
+    pub(crate) fn new(
+        input: PhysicalPlanRef,
+        window_exprs: Vec<ExprRef>,
+        partition_by: Vec<ExprRef>,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        row_size_ratio: f64,
+    ) -> Self {
+        let clustering_spec =
+            Self::translate_clustering_spec(input.clustering_spec(), &window_exprs);
+        Self {
+            input,
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            row_size_ratio,
+            clustering_spec,
+        }
+    }
+
+    fn translate_clustering_spec(
+        input_clustering_spec: Arc<ClusteringSpec>,
+        window_exprs: &[ExprRef],
+    ) -> Arc<ClusteringSpec> {
+        use crate::ClusteringSpec::*;
+        match input_clustering_spec.as_ref() {
+            // If the scheme is vacuous, the result partition spec is the same.
+            Random(_) | Unknown(_) => input_clustering_spec,
+            // Rows never move across partitions, so the scheme holds unless a window column
+            // clobbers one of the columns it is defined on.
+            Range(RangeClusteringConfig { by, .. }) | Hash(HashClusteringConfig { by, .. }) => {
+                let required_cols_for_clustering_spec = by
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect::<HashSet<String>>();
+                for expr in window_exprs {
+                    let newname = expr.name().unwrap().to_string();
+                    if required_cols_for_clustering_spec.contains(&newname) {
+                        return ClusteringSpec::Unknown(UnknownClusteringConfig::new(
+                            input_clustering_spec.num_partitions(),
+                        ))
+                        .into();
+                    }
+                }
+                input_clustering_spec
+            }
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_exprs.iter().map(|e| e.to_string()).join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .map(
+                    |(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" },),
+                )
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res.push(format!(
+            "Clustering spec = {{ {} }}",
+            self.clustering_spec.multiline_display().join(", ")
+        ));
+        res
+    }
+}
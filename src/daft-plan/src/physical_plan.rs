@@ -56,10 +56,12 @@ pub enum PhysicalPlan {
     FanoutByRange(FanoutByRange),
     ReduceMerge(ReduceMerge),
     Aggregate(Aggregate),
+    Window(Window),
     Concat(Concat),
     HashJoin(HashJoin),
     SortMergeJoin(SortMergeJoin),
     BroadcastJoin(BroadcastJoin),
+    NestedLoopJoin(NestedLoopJoin),
     TabularWriteParquet(TabularWriteParquet),
     TabularWriteJson(TabularWriteJson),
     TabularWriteCsv(TabularWriteCsv),
@@ -156,6 +158,9 @@ impl PhysicalPlan {
                     .into()
                 }
             }
+            Self::Window(Window {
+                clustering_spec, ..
+            }) => clustering_spec.clone(),
             Self::Concat(Concat { input, other }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(
                     input.clustering_spec().num_partitions()
@@ -188,6 +193,9 @@ impl PhysicalPlan {
             }
             Self::BroadcastJoin(BroadcastJoin {
                 receiver: right, ..
+            })
+            | Self::NestedLoopJoin(NestedLoopJoin {
+                receiver: right, ..
             }) => right.clustering_spec(),
             Self::SortMergeJoin(SortMergeJoin {
                 left,
@@ -250,6 +258,14 @@ impl PhysicalPlan {
             | Self::ReduceMerge(ReduceMerge { input, .. })
             | Self::Sort(Sort { input, .. })
             | Self::Split(Split { input, .. }) => input.approximate_size_bytes(),
+            // Window columns are appended to every row.
+            Self::Window(Window {
+                input,
+                row_size_ratio,
+                ..
+            }) => input
+                .approximate_size_bytes()
+                .map(|size| (size as f64 * row_size_ratio) as usize),
             Self::Concat(Concat { input, other }) => {
                 input.approximate_size_bytes().and_then(|input_size| {
                     other
@@ -257,6 +273,25 @@ impl PhysicalPlan {
                         .map(|other_size| input_size + other_size)
                 })
             }
+            // Semi and anti joins only filter the left side, which is always the receiver for broadcast joins.
+            Self::BroadcastJoin(BroadcastJoin {
+                receiver: left,
+                join_type,
+                ..
+            })
+            | Self::HashJoin(HashJoin {
+                left, join_type, ..
+            })
+            | Self::SortMergeJoin(SortMergeJoin {
+                left, join_type, ..
+            })
+            | Self::NestedLoopJoin(NestedLoopJoin {
+                receiver: left,
+                join_type,
+                ..
+            }) if join_type.is_left_only() => left.approximate_size_bytes(),
+            // The output of a nested-loop join can be as large as the cartesian product of both sides.
+            Self::NestedLoopJoin(_) => None,
             // Assume a simple sum of the sizes of both sides of the join for the post-join size.
             // TODO(Clark): This will double-count join key columns, we should ensure that these are only counted once.
             Self::BroadcastJoin(BroadcastJoin {
@@ -303,6 +338,7 @@ impl PhysicalPlan {
             Self::FanoutByRange(FanoutByRange { input, .. }) => vec![input.clone()],
             Self::ReduceMerge(ReduceMerge { input }) => vec![input.clone()],
             Self::Aggregate(Aggregate { input, .. }) => vec![input.clone()],
+            Self::Window(Window { input, .. }) => vec![input.clone()],
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => vec![input.clone()],
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => vec![input.clone()],
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => vec![input.clone()],
@@ -313,6 +349,11 @@ impl PhysicalPlan {
                 broadcaster,
                 receiver,
                 ..
+            })
+            | Self::NestedLoopJoin(NestedLoopJoin {
+                broadcaster,
+                receiver,
+                ..
             }) => vec![broadcaster.clone(), receiver.clone()],
             Self::SortMergeJoin(SortMergeJoin { left, right, .. }) => {
                 vec![left.clone(), right.clone()]
@@ -347,6 +388,7 @@ impl PhysicalPlan {
                 Self::FanoutByRange(FanoutByRange { num_partitions, sort_by, descending, .. }) => Self::FanoutByRange(FanoutByRange::new(input.clone(), *num_partitions, sort_by.clone(), descending.clone())),
                 Self::ReduceMerge(..) => Self::ReduceMerge(ReduceMerge::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone())),
+                Self::Window(Window { window_exprs, partition_by, order_by, descending, row_size_ratio, .. }) => Self::Window(Window::new(input.clone(), window_exprs.clone(), partition_by.clone(), order_by.clone(), descending.clone(), *row_size_ratio)),
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
@@ -367,6 +409,12 @@ impl PhysicalPlan {
                     is_swapped,
                     ..
                 }) => Self::BroadcastJoin(BroadcastJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), *join_type, *is_swapped)),
+                Self::NestedLoopJoin(NestedLoopJoin {
+                    predicate,
+                    join_type,
+                    is_swapped,
+                    ..
+                }) => Self::NestedLoopJoin(NestedLoopJoin::new(input1.clone(), input2.clone(), predicate.clone(), *join_type, *is_swapped)),
                 Self::SortMergeJoin(SortMergeJoin { left_on, right_on, join_type, num_partitions, left_is_larger, needs_presort, .. }) => Self::SortMergeJoin(SortMergeJoin::new(input1.clone(), input2.clone(), left_on.clone(), right_on.clone(), *join_type, *num_partitions, *left_is_larger, *needs_presort)),
                 Self::Concat(_) => Self::Concat(Concat::new(input1.clone(), input2.clone())),
                 _ => panic!("Physical op {:?} has one input, but got two", self),
@@ -395,8 +443,10 @@ impl PhysicalPlan {
             Self::FanoutByRange(..) => "FanoutByRange",
             Self::ReduceMerge(..) => "ReduceMerge",
             Self::Aggregate(..) => "Aggregate",
+            Self::Window(..) => "Window",
             Self::HashJoin(..) => "HashJoin",
             Self::BroadcastJoin(..) => "BroadcastJoin",
+            Self::NestedLoopJoin(..) => "NestedLoopJoin",
             Self::SortMergeJoin(..) => "SortMergeJoin",
             Self::Concat(..) => "Concat",
             Self::TabularWriteParquet(..) => "TabularWriteParquet",
@@ -429,8 +479,10 @@ impl PhysicalPlan {
             Self::FanoutByRange(fanout_by_range) => fanout_by_range.multiline_display(),
             Self::ReduceMerge(reduce_merge) => reduce_merge.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
+            Self::Window(window) => window.multiline_display(),
             Self::HashJoin(hash_join) => hash_join.multiline_display(),
             Self::BroadcastJoin(broadcast_join) => broadcast_join.multiline_display(),
+            Self::NestedLoopJoin(nested_loop_join) => nested_loop_join.multiline_display(),
             Self::SortMergeJoin(sort_merge_join) => sort_merge_join.multiline_display(),
             Self::Concat(concat) => concat.multiline_display(),
             Self::TabularWriteParquet(tabular_write_parquet) => {
@@ -815,6 +867,39 @@ impl PhysicalPlan {
                     .call1((upstream_iter, aggs_as_pyexprs, groupbys_as_pyexprs))?;
                 Ok(py_iter.into())
             }
+            PhysicalPlan::Window(Window {
+                input,
+                window_exprs,
+                partition_by,
+                order_by,
+                descending,
+                ..
+            }) => {
+                let upstream_iter = input.to_partition_tasks(py, psets)?;
+                let window_pyexprs: Vec<PyExpr> = window_exprs
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let partition_by_pyexprs: Vec<PyExpr> = partition_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let order_by_pyexprs: Vec<PyExpr> = order_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let py_iter = py
+                    .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "window"))?
+                    .call1((
+                        upstream_iter,
+                        window_pyexprs,
+                        partition_by_pyexprs,
+                        order_by_pyexprs,
+                        descending.clone(),
+                    ))?;
+                Ok(py_iter.into())
+            }
             PhysicalPlan::Coalesce(Coalesce {
                 input,
                 num_from,
@@ -944,6 +1029,28 @@ impl PhysicalPlan {
                     ))?;
                 Ok(py_iter.into())
             }
+            PhysicalPlan::NestedLoopJoin(NestedLoopJoin {
+                broadcaster: left,
+                receiver: right,
+                predicate,
+                join_type,
+                is_swapped,
+            }) => {
+                let upstream_left_iter = left.to_partition_tasks(py, psets)?;
+                let upstream_right_iter = right.to_partition_tasks(py, psets)?;
+                let predicate_pyexpr = predicate.clone().map(PyExpr::from);
+                let py_iter = py
+                    .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "nested_loop_join"))?
+                    .call1((
+                        upstream_left_iter,
+                        upstream_right_iter,
+                        predicate_pyexpr,
+                        *join_type,
+                        *is_swapped,
+                    ))?;
+                Ok(py_iter.into())
+            }
             PhysicalPlan::TabularWriteParquet(TabularWriteParquet {
                 schema,
                 file_info:
@@ -9,9 +9,14 @@ use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
 
 use daft_core::count_mode::CountMode;
+use daft_core::schema::SchemaRef;
 use daft_core::DataType;
 use daft_dsl::col;
+use daft_dsl::functions::moments::{statistic, MomentsExpr};
+use daft_dsl::functions::sketch::sketch_percentile;
+use daft_dsl::ApproxPercentileParams;
 use daft_dsl::ExprRef;
+use daft_dsl::SketchType;
 
 use daft_scan::ScanExternalInfo;
 
@@ -20,7 +25,7 @@ use crate::logical_ops::{
     Filter as LogicalFilter, Join as LogicalJoin, Limit as LogicalLimit,
     MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Project as LogicalProject,
     Repartition as LogicalRepartition, Sample as LogicalSample, Sink as LogicalSink,
-    Sort as LogicalSort, Source,
+    Sort as LogicalSort, Source, Window as LogicalWindow,
 };
 use crate::logical_plan::LogicalPlan;
 use crate::partitioning::{
@@ -30,7 +35,7 @@ use crate::physical_plan::{PhysicalPlan, PhysicalPlanRef};
 use crate::sink_info::{OutputFileInfo, SinkInfo};
 use crate::source_info::SourceInfo;
 use crate::FileFormat;
-use crate::{physical_ops::*, JoinStrategy};
+use crate::{physical_ops::*, JoinStrategy, JoinType};
 
 pub(super) fn translate_single_logical_node(
     logical_plan: &LogicalPlan,
@@ -336,6 +341,45 @@ pub(super) fn translate_single_logical_node(
                                         .alias(output_name),
                                 );
                             }
+                            Stddev(..) | Variance(..) | Covariance(..) | Correlation(..) => {
+                                // Merge the co-moments of each partition, then compute the statistic from them.
+                                let (left, right, moments_expr) = match agg_expr {
+                                    Stddev(e, ddof) => (e, e, MomentsExpr::Stddev { ddof: *ddof }),
+                                    Variance(e, ddof) => {
+                                        (e, e, MomentsExpr::Variance { ddof: *ddof })
+                                    }
+                                    Covariance(left, right, ddof) => {
+                                        (left, right, MomentsExpr::Covariance { ddof: *ddof })
+                                    }
+                                    Correlation(left, right) => {
+                                        (left, right, MomentsExpr::Correlation)
+                                    }
+                                    _ => unreachable!(),
+                                };
+                                let comoments_id = CoMoments(left.clone(), right.clone())
+                                    .semantic_id(&schema)
+                                    .id;
+                                let merge_of_comoments_id =
+                                    MergeCoMoments(col(comoments_id.clone()))
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(comoments_id.clone())
+                                    .or_insert(CoMoments(
+                                        left.alias(comoments_id.clone()).clone(),
+                                        right.clone(),
+                                    ));
+                                second_stage_aggs
+                                    .entry(merge_of_comoments_id.clone())
+                                    .or_insert(MergeCoMoments(
+                                        col(comoments_id.clone())
+                                            .alias(merge_of_comoments_id.clone()),
+                                    ));
+                                final_exprs.push(
+                                    statistic(col(merge_of_comoments_id.clone()), moments_expr)
+                                        .alias(output_name),
+                                );
+                            }
                             Min(e) => {
                                 let min_id = agg_expr.semantic_id(&schema).id;
                                 let min_of_min_id =
@@ -380,6 +424,96 @@ pub(super) fn translate_single_logical_node(
                                         *ignore_nulls,
                                     ));
                             }
+                            BoolAnd(e) | BoolOr(e) | BitAnd(e) | BitOr(e) | BitXor(e) => {
+                                // These reduce the partial results of each partition with the same aggregation.
+                                let agg_id = agg_expr.semantic_id(&schema).id;
+                                let first_stage_agg =
+                                    agg_expr.with_new_children(vec![e.alias(agg_id.clone())]);
+                                let second_stage_agg =
+                                    agg_expr.with_new_children(vec![col(agg_id.clone())]);
+                                let agg_of_agg_id = second_stage_agg.semantic_id(&schema).id;
+                                first_stage_aggs
+                                    .entry(agg_id.clone())
+                                    .or_insert(first_stage_agg);
+                                second_stage_aggs.entry(agg_of_agg_id.clone()).or_insert(
+                                    agg_expr.with_new_children(vec![
+                                        col(agg_id.clone()).alias(agg_of_agg_id.clone())
+                                    ]),
+                                );
+                                final_exprs.push(col(agg_of_agg_id.clone()).alias(output_name));
+                            }
+                            ApproxCountDistinct(e) => {
+                                // Merge the HyperLogLog sketches of each partition, which yields the estimate.
+                                let sketch_id = ApproxSketch(e.clone(), SketchType::HyperLogLog)
+                                    .semantic_id(&schema)
+                                    .id;
+                                let merge_of_sketch_id =
+                                    MergeSketch(col(sketch_id.clone()), SketchType::HyperLogLog)
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone(),
+                                        SketchType::HyperLogLog,
+                                    ));
+                                second_stage_aggs
+                                    .entry(merge_of_sketch_id.clone())
+                                    .or_insert(MergeSketch(
+                                        col(sketch_id.clone()).alias(merge_of_sketch_id.clone()),
+                                        SketchType::HyperLogLog,
+                                    ));
+                                final_exprs
+                                    .push(col(merge_of_sketch_id.clone()).alias(output_name));
+                            }
+                            ApproxPercentile(ApproxPercentileParams {
+                                child: e,
+                                percentiles,
+                                force_list_output,
+                            }) => {
+                                // Merge the DDSketches of each partition, then compute the percentiles of the merged sketch.
+                                let sketch_id = ApproxSketch(e.clone(), SketchType::DDSketch)
+                                    .semantic_id(&schema)
+                                    .id;
+                                let merge_of_sketch_id =
+                                    MergeSketch(col(sketch_id.clone()), SketchType::DDSketch)
+                                        .semantic_id(&schema)
+                                        .id;
+                                first_stage_aggs
+                                    .entry(sketch_id.clone())
+                                    .or_insert(ApproxSketch(
+                                        e.alias(sketch_id.clone()).clone(),
+                                        SketchType::DDSketch,
+                                    ));
+                                second_stage_aggs
+                                    .entry(merge_of_sketch_id.clone())
+                                    .or_insert(MergeSketch(
+                                        col(sketch_id.clone()).alias(merge_of_sketch_id.clone()),
+                                        SketchType::DDSketch,
+                                    ));
+                                let percentiles =
+                                    percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+                                final_exprs.push(
+                                    sketch_percentile(
+                                        col(merge_of_sketch_id.clone()),
+                                        &percentiles,
+                                        *force_list_output,
+                                    )
+                                    .alias(output_name),
+                                );
+                            }
+                            ApproxSketch(..) | MergeSketch(..) => {
+                                // Sketches only exist as the partial states of the two aggregation stages.
+                                return Err(common_error::DaftError::ValueError(format!(
+                                    "Sketch aggregations can't be split across partitions: {agg_expr}"
+                                )));
+                            }
+                            CoMoments(..) | MergeCoMoments(..) => {
+                                // Co-moments only exist as the partial states of the two aggregation stages.
+                                return Err(common_error::DaftError::ValueError(format!(
+                                    "Co-moments aggregations can't be split across partitions: {agg_expr}"
+                                )));
+                            }
                             List(e) => {
                                 let list_id = agg_expr.semantic_id(&schema).id;
                                 let concat_of_list_id =
@@ -471,17 +605,74 @@ pub(super) fn translate_single_logical_node(
 
             Ok(result_plan.arced())
         }
+        LogicalPlan::Window(LogicalWindow {
+            input,
+            window_exprs,
+            partition_by,
+            order_by,
+            descending,
+            output_schema,
+        }) => {
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            let input_clustering_spec = input_physical.clustering_spec();
+            let num_input_partitions = input_clustering_spec.num_partitions();
+
+            // Every window partition must be wholly contained in a single physical partition.
+            let gather_plan = if num_input_partitions == 1 {
+                input_physical
+            } else if partition_by.is_empty() {
+                PhysicalPlan::Coalesce(Coalesce::new(input_physical, num_input_partitions, 1))
+                    .arced()
+            } else {
+                let is_hash_partitioned = input_clustering_spec.as_ref()
+                    == &ClusteringSpec::Hash(HashClusteringConfig::new(
+                        num_input_partitions,
+                        partition_by.clone(),
+                    ));
+                if is_hash_partitioned {
+                    input_physical
+                } else {
+                    let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                        input_physical,
+                        num_input_partitions,
+                        partition_by.clone(),
+                    ))
+                    .arced();
+                    PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op)).arced()
+                }
+            };
+
+            // Fall back to the input size if the row sizes can't be estimated.
+            let input_row_size = input.schema().estimate_row_size_bytes();
+            let row_size_ratio = if input_row_size > 0. {
+                output_schema.estimate_row_size_bytes() / input_row_size
+            } else {
+                1.
+            };
+
+            Ok(PhysicalPlan::Window(Window::new(
+                gather_plan,
+                window_exprs.clone(),
+                partition_by.clone(),
+                order_by.clone(),
+                descending.clone(),
+                row_size_ratio,
+            ))
+            .arced())
+        }
         LogicalPlan::Concat(..) => {
             let other_physical = physical_children.pop().expect("requires 1 inputs");
             let input_physical = physical_children.pop().expect("requires 2 inputs");
             Ok(PhysicalPlan::Concat(Concat::new(input_physical, other_physical)).arced())
         }
         LogicalPlan::Join(LogicalJoin {
+            left,
+            right,
             left_on,
             right_on,
+            predicate,
             join_type,
             join_strategy,
-            output_schema,
             ..
         }) => {
             let mut right_physical = physical_children.pop().expect("requires 1 inputs");
@@ -546,15 +737,51 @@ pub(super) fn translate_single_logical_node(
                 (None, Some(right_size_bytes)) => (Some(right_size_bytes), true),
                 (None, None) => (None, false),
             };
+            if left_on.is_empty() {
+                // Without join keys, every pair of rows has to be compared, so all partitions of one side are
+                // broadcast to each partition of the other side, preferring to broadcast the smaller side.
+                let is_swapped = if join_type.can_broadcast(!left_is_larger) {
+                    left_is_larger
+                } else if join_type.can_broadcast(left_is_larger) {
+                    !left_is_larger
+                } else {
+                    // Unmatched rows of both sides are emitted, so the receiving side must be a single partition.
+                    let num_left_partitions = left_clustering_spec.num_partitions();
+                    if num_left_partitions > 1 {
+                        left_physical = PhysicalPlan::Coalesce(Coalesce::new(
+                            left_physical,
+                            num_left_partitions,
+                            1,
+                        ))
+                        .arced();
+                    }
+                    true
+                };
+                if is_swapped {
+                    // These will get swapped back when doing the actual local joins.
+                    (left_physical, right_physical) = (right_physical, left_physical);
+                }
+                return Ok(PhysicalPlan::NestedLoopJoin(NestedLoopJoin::new(
+                    left_physical,
+                    right_physical,
+                    predicate.clone(),
+                    *join_type,
+                    is_swapped,
+                ))
+                .arced());
+            }
             let is_larger_partitioned = if left_is_larger {
                 is_left_hash_partitioned || is_left_sort_partitioned
             } else {
                 is_right_hash_partitioned || is_right_sort_partitioned
             };
+            // Only the side of the join that doesn't need its unmatched rows emitted can be broadcast, since each
+            // receiving partition only sees a subset of the matches for a broadcast row.
+            let can_broadcast_smaller = join_type.can_broadcast(!left_is_larger);
             let join_strategy = join_strategy.unwrap_or_else(|| {
-                let is_primitive = |exprs: &Vec<ExprRef>| {
+                let is_primitive = |exprs: &Vec<ExprRef>, schema: SchemaRef| {
                     exprs.iter().map(|e| e.name().unwrap()).all(|col| {
-                        let dtype = &output_schema.get_field(col).unwrap().dtype;
+                        let dtype = &schema.get_field(col).unwrap().dtype;
                         dtype.is_integer()
                             || dtype.is_floating()
                             || matches!(
@@ -565,6 +792,7 @@ pub(super) fn translate_single_logical_node(
                 };
                 // If larger table is not already partitioned on the join key AND the smaller table is under broadcast size threshold, use broadcast join.
                 if !is_larger_partitioned
+                    && can_broadcast_smaller
                     && let Some(smaller_size_bytes) = smaller_size_bytes
                     && smaller_size_bytes <= cfg.broadcast_join_size_bytes_threshold
                 {
@@ -573,8 +801,11 @@ pub(super) fn translate_single_logical_node(
                 // TODO(Clark): Support non-primitive dtypes for sort-merge join (e.g. temporal types).
                 // TODO(Clark): Also do a sort-merge join if a downstream op needs the table to be sorted on the join key.
                 // TODO(Clark): Look into defaulting to sort-merge join over hash join under more input partitioning setups.
-                } else if is_primitive(left_on)
-                    && is_primitive(right_on)
+                // TODO: Support non-inner sort-merge joins, which need each partition to be merged with exactly one
+                // partition of the other side so that unmatched rows are only emitted once.
+                } else if *join_type == JoinType::Inner
+                    && is_primitive(left_on, left.schema())
+                    && is_primitive(right_on, right.schema())
                     && (is_left_sort_partitioned || is_right_sort_partitioned)
                     && (!is_larger_partitioned
                         || (left_is_larger && is_left_sort_partitioned
@@ -586,10 +817,20 @@ pub(super) fn translate_single_logical_node(
                     JoinStrategy::Hash
                 }
             });
-            match join_strategy {
+            let join_physical = match join_strategy {
                 JoinStrategy::Broadcast => {
                     // If either the left or right side of the join are very small tables, perform a broadcast join with the
                     // entire smaller table broadcast to each of the partitions of the larger table.
+                    // If the smaller side can't be broadcast for this join type, broadcast the larger side instead.
+                    let left_is_larger = if can_broadcast_smaller {
+                        left_is_larger
+                    } else if join_type.can_broadcast(left_is_larger) {
+                        !left_is_larger
+                    } else {
+                        return Err(common_error::DaftError::ValueError(format!(
+                            "Broadcast join does not support {join_type} joins"
+                        )));
+                    };
                     if left_is_larger {
                         // These will get swapped back when doing the actual local joins.
                         (left_physical, right_physical) = (right_physical, left_physical);
@@ -605,7 +846,11 @@ pub(super) fn translate_single_logical_node(
                     .arced())
                 }
                 JoinStrategy::SortMerge => {
-                    let needs_presort = if cfg.sort_merge_join_sort_with_aligned_boundaries {
+                    let needs_presort = if *join_type != JoinType::Inner {
+                        // Non-inner joins must emit unmatched rows exactly once, so always sort both sides with
+                        // aligned boundaries, allowing each pair of co-partitioned inputs to be joined on its own.
+                        true
+                    } else if cfg.sort_merge_join_sort_with_aligned_boundaries {
                         // Use the special-purpose presorting that ensures join inputs are sorted with aligned
                         // boundaries, allowing for a more efficient downstream merge-join (~one-to-one zip).
                         !is_left_sort_partitioned || !is_right_sort_partitioned
@@ -679,6 +924,13 @@ pub(super) fn translate_single_logical_node(
                     ))
                     .arced())
                 }
+            }?;
+            // Residual predicates are only allowed on keyed inner joins, so they can be applied after the join.
+            match predicate {
+                Some(predicate) => {
+                    Ok(PhysicalPlan::Filter(Filter::new(join_physical, predicate.clone())).arced())
+                }
+                None => Ok(join_physical),
             }
         }
         LogicalPlan::Sink(LogicalSink {
@@ -749,13 +1001,14 @@ mod tests {
     use common_daft_config::DaftExecutionConfig;
     use common_error::DaftResult;
     use daft_core::{datatypes::Field, DataType};
-    use daft_dsl::{col, lit};
+    use daft_dsl::{col, lit, WindowExpr, WindowSpec};
     use std::assert_matches::assert_matches;
     use std::sync::Arc;
 
     use crate::physical_plan::PhysicalPlan;
     use crate::physical_planner::plan;
     use crate::test::{dummy_scan_node, dummy_scan_operator};
+    use crate::{JoinStrategy, JoinType};
 
     /// Tests that planner drops a simple Repartition (e.g. df.into_partitions()) the child already has the desired number of partitions.
     ///
@@ -845,4 +1098,222 @@ mod tests {
         assert_matches!(physical_plan.as_ref(), PhysicalPlan::Project(_));
         Ok(())
     }
+
+    /// Tests that planner shuffles the input of a Window by its partition keys, unless the input
+    /// is already hash-partitioned by them.
+    ///
+    /// Window-upstream_op -> Window-ReduceMerge-FanoutByHash-upstream_op
+    /// Window-ReduceMerge-FanoutByHash(a)-upstream_op -> (unchanged)
+    #[test]
+    fn window_shuffles_by_partition_keys() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let spec = WindowSpec::new(vec![col("a")], vec![col("b")], vec![false]);
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]));
+
+        let logical_plan = builder
+            .into_partitions(10)?
+            .window(vec![WindowExpr::RowNumber.over(spec.clone())])?
+            .build();
+        let physical_plan = plan(logical_plan, cfg.clone())?;
+        let PhysicalPlan::Window(window) = physical_plan.as_ref() else {
+            panic!("Expected Window, got {}", physical_plan.name());
+        };
+        assert_matches!(window.input.as_ref(), PhysicalPlan::ReduceMerge(_));
+        // The UInt64 row number grows each row of two Int64 columns by half.
+        assert_eq!(window.row_size_ratio, 1.5);
+
+        let logical_plan = builder
+            .hash_repartition(Some(10), vec![col("a")])?
+            .window(vec![WindowExpr::RowNumber.over(spec)])?
+            .build();
+        let physical_plan = plan(logical_plan, cfg)?;
+        let PhysicalPlan::Window(window) = physical_plan.as_ref() else {
+            panic!("Expected Window, got {}", physical_plan.name());
+        };
+        // Only the shuffle from the explicit repartition remains.
+        assert_matches!(window.input.as_ref(), PhysicalPlan::ReduceMerge(_));
+        assert_matches!(
+            window.input.children()[0].children()[0].as_ref(),
+            PhysicalPlan::TabularScan(_)
+        );
+        Ok(())
+    }
+
+    /// Tests that broadcast joins only broadcast the side of the join whose unmatched rows are never emitted.
+    #[test]
+    fn broadcast_join_respects_join_type() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]));
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Float64),
+        ]));
+        for (join_type, should_swap) in [
+            (JoinType::Left, true),
+            (JoinType::Semi, true),
+            (JoinType::Anti, true),
+            (JoinType::Right, false),
+        ] {
+            let logical_plan = left
+                .join(
+                    &right,
+                    vec![col("a")],
+                    vec![col("a")],
+                    join_type,
+                    Some(JoinStrategy::Broadcast),
+                )?
+                .build();
+            let physical_plan = plan(logical_plan, cfg.clone())?;
+            let PhysicalPlan::BroadcastJoin(broadcast_join) = physical_plan.as_ref() else {
+                panic!("Expected BroadcastJoin, got {}", physical_plan.name());
+            };
+            // The right side is broadcast iff the sides are swapped.
+            assert_eq!(broadcast_join.is_swapped, should_swap);
+        }
+
+        let logical_plan = left
+            .join(
+                &right,
+                vec![col("a")],
+                vec![col("a")],
+                JoinType::Outer,
+                Some(JoinStrategy::Broadcast),
+            )?
+            .build();
+        assert!(plan(logical_plan, cfg).is_err());
+        Ok(())
+    }
+
+    /// Tests that non-inner sort-merge joins always sort both sides with aligned boundaries.
+    #[test]
+    fn sort_merge_join_presorts_non_inner_joins() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]));
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Float64),
+        ]));
+        for (join_type, needs_presort) in [
+            (JoinType::Inner, false),
+            (JoinType::Left, true),
+            (JoinType::Right, true),
+            (JoinType::Outer, true),
+            (JoinType::Semi, true),
+            (JoinType::Anti, true),
+        ] {
+            let logical_plan = left
+                .join(
+                    &right,
+                    vec![col("a")],
+                    vec![col("a")],
+                    join_type,
+                    Some(JoinStrategy::SortMerge),
+                )?
+                .build();
+            let physical_plan = plan(logical_plan, cfg.clone())?;
+            let PhysicalPlan::SortMergeJoin(sort_merge_join) = physical_plan.as_ref() else {
+                panic!("Expected SortMergeJoin, got {}", physical_plan.name());
+            };
+            assert_eq!(sort_merge_join.join_type, join_type);
+            assert_eq!(sort_merge_join.needs_presort, needs_presort);
+        }
+        Ok(())
+    }
+
+    /// Tests that joins without keys are planned as nested-loop joins, and that residual predicates on keyed joins
+    /// are applied after the join.
+    #[test]
+    fn join_predicates_planned() -> DaftResult<()> {
+        let cfg: Arc<DaftExecutionConfig> = DaftExecutionConfig::default().into();
+        let left = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]))
+        .into_partitions(4)?;
+        let right = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Float64),
+        ]));
+
+        let physical_plan = plan(left.cross_join(&right)?.build(), cfg.clone())?;
+        assert_matches!(physical_plan.as_ref(), PhysicalPlan::NestedLoopJoin(_));
+
+        // Unmatched left rows are emitted, so the right side must be broadcast.
+        let predicate = col("a").lt_eq(col("c"));
+        let logical_plan = left
+            .join_with_predicate(
+                &right,
+                vec![],
+                vec![],
+                Some(predicate.clone()),
+                JoinType::Left,
+                None,
+            )?
+            .build();
+        let physical_plan = plan(logical_plan, cfg.clone())?;
+        let PhysicalPlan::NestedLoopJoin(nested_loop_join) = physical_plan.as_ref() else {
+            panic!("Expected NestedLoopJoin, got {}", physical_plan.name());
+        };
+        assert!(nested_loop_join.is_swapped);
+        assert_eq!(nested_loop_join.predicate, Some(predicate.clone()));
+
+        // Unmatched rows of both sides are emitted, so the left side is coalesced into a single receiving partition.
+        let logical_plan = left
+            .join_with_predicate(
+                &right,
+                vec![],
+                vec![],
+                Some(predicate.clone()),
+                JoinType::Outer,
+                None,
+            )?
+            .build();
+        let physical_plan = plan(logical_plan, cfg.clone())?;
+        let PhysicalPlan::NestedLoopJoin(nested_loop_join) = physical_plan.as_ref() else {
+            panic!("Expected NestedLoopJoin, got {}", physical_plan.name());
+        };
+        assert!(nested_loop_join.is_swapped);
+        assert_eq!(
+            nested_loop_join.receiver.clustering_spec().num_partitions(),
+            1
+        );
+
+        let logical_plan = left
+            .join_with_predicate(
+                &right,
+                vec![col("a")],
+                vec![col("a")],
+                Some(predicate.clone()),
+                JoinType::Inner,
+                Some(JoinStrategy::Hash),
+            )?
+            .build();
+        let physical_plan = plan(logical_plan, cfg)?;
+        let PhysicalPlan::Filter(filter) = physical_plan.as_ref() else {
+            panic!("Expected Filter, got {}", physical_plan.name());
+        };
+        assert_matches!(filter.input.as_ref(), PhysicalPlan::HashJoin(_));
+
+        // Predicates on keyed joins that emit unmatched rows can't be applied after the join.
+        assert!(left
+            .join_with_predicate(
+                &right,
+                vec![col("a")],
+                vec![col("a")],
+                Some(predicate),
+                JoinType::Left,
+                None,
+            )
+            .is_err());
+        Ok(())
+    }
 }
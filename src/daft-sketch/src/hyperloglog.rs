@@ -0,0 +1,321 @@
+/// Number of bits of the hash that select a register.
+const PRECISION: u32 = 14;
+
+/// Number of registers in a sketch, which is also the size of its dense serialized form in bytes.
+pub const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// Largest number of registers that a sketch keeps sparse, at which the sparse form takes a quarter
+/// of the memory of the dense form.
+const MAX_SPARSE_REGISTERS: usize = NUM_REGISTERS / 4;
+
+/// Size of each non-zero register in the sparse serialized form: its index as a big-endian `u16`,
+/// followed by its value.
+const SPARSE_ENTRY_SIZE: usize = 3;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Registers {
+    /// The non-zero registers as `(index, value)` pairs, sorted by index.
+    Sparse(Vec<(u16, u8)>),
+    Dense(Vec<u8>),
+}
+
+/// A HyperLogLog sketch for estimating the number of distinct values in a set.
+///
+/// Values are added to the sketch as 64-bit hashes. Sketches of disjoint sets can be merged to
+/// obtain a sketch of their union, which makes them suitable as partial aggregation states.
+/// The relative standard error of the estimate is about `1.04 / sqrt(NUM_REGISTERS)`, i.e. ~0.8%.
+///
+/// A dense sketch takes `NUM_REGISTERS` bytes (16KiB), which adds up when sketching many small
+/// groups. Sketches therefore start out sparse, storing only their non-zero registers, and become
+/// dense once more than `NUM_REGISTERS / 4` registers are set. A sketch of `n` distinct values
+/// takes at most `4 * n` bytes in memory and `3 * n` bytes serialized until then.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch.
+    pub fn new() -> Self {
+        Self {
+            registers: Registers::Sparse(vec![]),
+        }
+    }
+
+    /// Reconstructs a sketch from its serialized form, as returned by [`HyperLogLog::to_bytes`].
+    ///
+    /// Dense sketches are serialized as their `NUM_REGISTERS` registers, and sparse ones as
+    /// entries of `SPARSE_ENTRY_SIZE` bytes, which always take fewer bytes in total.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() == NUM_REGISTERS {
+            return Ok(Self {
+                registers: Registers::Dense(bytes.to_vec()),
+            });
+        }
+        if bytes.len() % SPARSE_ENTRY_SIZE != 0
+            || bytes.len() / SPARSE_ENTRY_SIZE > MAX_SPARSE_REGISTERS
+        {
+            return Err(format!(
+                "Expected a serialized HyperLogLog sketch of {NUM_REGISTERS} bytes or a sparse sketch of at most {} entries of {SPARSE_ENTRY_SIZE} bytes, but got {} bytes",
+                MAX_SPARSE_REGISTERS,
+                bytes.len()
+            ));
+        }
+        let entries = bytes
+            .chunks_exact(SPARSE_ENTRY_SIZE)
+            .map(|entry| (u16::from_be_bytes([entry[0], entry[1]]), entry[2]))
+            .collect::<Vec<_>>();
+        let is_valid = entries
+            .iter()
+            .all(|(index, _)| (*index as usize) < NUM_REGISTERS)
+            && entries.windows(2).all(|w| w[0].0 < w[1].0);
+        if !is_valid {
+            return Err(
+                "Expected the registers of a sparse HyperLogLog sketch to be sorted and in range"
+                    .to_string(),
+            );
+        }
+        Ok(Self {
+            registers: Registers::Sparse(entries),
+        })
+    }
+
+    /// Returns the serialized form of this sketch.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match &self.registers {
+            Registers::Sparse(entries) => entries
+                .iter()
+                .flat_map(|(index, value)| {
+                    let [high, low] = index.to_be_bytes();
+                    [high, low, *value]
+                })
+                .collect(),
+            Registers::Dense(registers) => registers.clone(),
+        }
+    }
+
+    /// Sets a register to the maximum of its value and `value`.
+    fn update(&mut self, index: usize, value: u8) {
+        match &mut self.registers {
+            Registers::Sparse(entries) => {
+                match entries.binary_search_by_key(&(index as u16), |(i, _)| *i) {
+                    Ok(pos) => entries[pos].1 = entries[pos].1.max(value),
+                    Err(pos) => {
+                        entries.insert(pos, (index as u16, value));
+                        if entries.len() > MAX_SPARSE_REGISTERS {
+                            self.densify();
+                        }
+                    }
+                }
+            }
+            Registers::Dense(registers) => {
+                let register = &mut registers[index];
+                *register = (*register).max(value);
+            }
+        }
+    }
+
+    fn densify(&mut self) {
+        if let Registers::Sparse(entries) = &self.registers {
+            let mut registers = vec![0; NUM_REGISTERS];
+            for (index, value) in entries {
+                registers[*index as usize] = *value;
+            }
+            self.registers = Registers::Dense(registers);
+        }
+    }
+
+    /// Adds a value to the sketch, given its 64-bit hash.
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // Set a sentinel bit so that the rank is bounded even if the remaining bits are all zero.
+        let rest = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.update(index, rank);
+    }
+
+    /// Merges another sketch into this one, so that this sketch represents the union of both sets.
+    pub fn merge(&mut self, other: &Self) {
+        match &other.registers {
+            Registers::Sparse(entries) => {
+                for (index, value) in entries {
+                    self.update(*index as usize, *value);
+                }
+            }
+            Registers::Dense(other_registers) => {
+                self.densify();
+                if let Registers::Dense(registers) = &mut self.registers {
+                    for (register, other_register) in registers.iter_mut().zip(other_registers) {
+                        *register = (*register).max(*other_register);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the estimated number of distinct values added to the sketch.
+    pub fn count(&self) -> u64 {
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, num_zeros) = match &self.registers {
+            Registers::Sparse(entries) => {
+                let num_zeros = NUM_REGISTERS - entries.len();
+                let sum = entries
+                    .iter()
+                    .map(|(_, value)| 2f64.powi(-(*value as i32)))
+                    .sum::<f64>();
+                (sum + num_zeros as f64, num_zeros)
+            }
+            Registers::Dense(registers) => {
+                registers
+                    .iter()
+                    .fold((0.0, 0usize), |(sum, num_zeros), register| {
+                        (
+                            sum + 2f64.powi(-(*register as i32)),
+                            num_zeros + (*register == 0) as usize,
+                        )
+                    })
+            }
+        };
+        let estimate = alpha * m * m / sum;
+        // Use linear counting for small cardinalities, where the raw estimate is biased.
+        let estimate = if estimate <= 2.5 * m && num_zeros > 0 {
+            m * (m / num_zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HyperLogLog, Registers, MAX_SPARSE_REGISTERS, NUM_REGISTERS, SPARSE_ENTRY_SIZE};
+
+    /// A simple 64-bit mixing function (splitmix64) to generate well-distributed hashes.
+    fn hash(value: u64) -> u64 {
+        let mut z = value.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn assert_close(estimate: u64, expected: u64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(
+            error < 0.05,
+            "estimate {estimate} is not within 5% of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(HyperLogLog::new().count(), 0);
+    }
+
+    #[test]
+    fn test_count_with_duplicates() {
+        let mut sketch = HyperLogLog::new();
+        for i in 0..100_000 {
+            sketch.add_hash(hash(i % 10_000));
+        }
+        assert_close(sketch.count(), 10_000);
+    }
+
+    #[test]
+    fn test_small_counts_are_exact() {
+        let mut sketch = HyperLogLog::new();
+        for i in 0..10 {
+            sketch.add_hash(hash(i));
+            sketch.add_hash(hash(i));
+        }
+        assert_eq!(sketch.count(), 10);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut left = HyperLogLog::new();
+        let mut right = HyperLogLog::new();
+        for i in 0..50_000 {
+            left.add_hash(hash(i));
+            right.add_hash(hash(i + 25_000));
+        }
+        left.merge(&right);
+        assert_close(left.count(), 75_000);
+    }
+
+    #[test]
+    fn test_roundtrip_bytes() {
+        let mut sketch = HyperLogLog::new();
+        for i in 0..1000 {
+            sketch.add_hash(hash(i));
+        }
+        let bytes = sketch.to_bytes();
+        assert!(bytes.len() < NUM_REGISTERS && bytes.len() % SPARSE_ENTRY_SIZE == 0);
+        assert_eq!(HyperLogLog::from_bytes(&bytes).unwrap(), sketch);
+
+        for i in 1000..100_000 {
+            sketch.add_hash(hash(i));
+        }
+        let bytes = sketch.to_bytes();
+        assert_eq!(bytes.len(), NUM_REGISTERS);
+        assert_eq!(HyperLogLog::from_bytes(&bytes).unwrap(), sketch);
+
+        assert!(HyperLogLog::from_bytes(&[0; NUM_REGISTERS - 1]).is_err());
+        assert!(HyperLogLog::from_bytes(&[0; NUM_REGISTERS - 4]).is_err());
+        // Registers out of order
+        assert!(HyperLogLog::from_bytes(&[0, 2, 1, 0, 1, 1]).is_err());
+    }
+
+    #[test]
+    fn test_sparse_until_full() {
+        let mut sketch = HyperLogLog::new();
+        assert!(sketch.to_bytes().is_empty());
+        let mut i = 0;
+        while matches!(sketch.registers, Registers::Sparse(_)) {
+            sketch.add_hash(hash(i));
+            i += 1;
+        }
+        // Registers collide, so more values than registers were added.
+        assert!(i > MAX_SPARSE_REGISTERS as u64);
+        let Registers::Dense(registers) = &sketch.registers else {
+            unreachable!()
+        };
+        assert_eq!(
+            registers.iter().filter(|r| **r > 0).count(),
+            MAX_SPARSE_REGISTERS + 1
+        );
+        assert_close(sketch.count(), i);
+    }
+
+    #[test]
+    fn test_merge_sparse_and_dense() {
+        let mut sparse = HyperLogLog::new();
+        let mut dense = HyperLogLog::new();
+        for i in 0..100 {
+            sparse.add_hash(hash(i));
+        }
+        for i in 0..50_000 {
+            dense.add_hash(hash(i + 50));
+        }
+        let mut merged = sparse.clone();
+        merged.merge(&dense);
+        assert_close(merged.count(), 50_050);
+        dense.merge(&sparse);
+        assert_eq!(merged, dense);
+
+        let mut other_sparse = HyperLogLog::new();
+        for i in 50..150 {
+            other_sparse.add_hash(hash(i));
+        }
+        sparse.merge(&other_sparse);
+        assert!(matches!(sparse.registers, Registers::Sparse(_)));
+        assert_close(sparse.count(), 150);
+    }
+}
@@ -1,2 +1,4 @@
 mod arrow2_serde;
+mod hyperloglog;
 pub use arrow2_serde::{from_arrow2, into_arrow2, ARROW2_DDSKETCH_DTYPE};
+pub use hyperloglog::{HyperLogLog, NUM_REGISTERS as HLL_NUM_REGISTERS};
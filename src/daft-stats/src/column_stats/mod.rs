@@ -1,6 +1,7 @@
 mod arithmetic;
 mod comparison;
 mod logical;
+mod statistics;
 
 use std::string::FromUtf8Error;
 
@@ -11,6 +12,8 @@ use daft_core::{
 };
 use snafu::{ResultExt, Snafu};
 
+pub use statistics::ColumnStatistics;
+
 use crate::DaftCoreComputeSnafu;
 #[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ColumnRangeStatistics {
@@ -132,21 +135,6 @@ impl ColumnRangeStatistics {
     pub fn from_series(series: &Series) -> Self {
         let lower = series.min(None).unwrap();
         let upper = series.max(None).unwrap();
-        let _count = series
-            .count(None, daft_core::CountMode::All)
-            .unwrap()
-            .u64()
-            .unwrap()
-            .get(0)
-            .unwrap() as usize;
-        let _null_count = series
-            .count(None, daft_core::CountMode::Null)
-            .unwrap()
-            .u64()
-            .unwrap()
-            .get(0)
-            .unwrap() as usize;
-        let _num_bytes = series.size_bytes().unwrap();
         Self::Loaded(lower, upper)
     }
 
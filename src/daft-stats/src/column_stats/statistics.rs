@@ -0,0 +1,330 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, DaftCompare, DaftLogical},
+    DataType, Series,
+};
+use snafu::ResultExt;
+
+use super::{ColumnRangeStatistics, TruthValue};
+use crate::DaftCoreComputeSnafu;
+
+/// Statistics of a column: the range of its values along with the number of rows, nulls and
+/// distinct values it holds, any of which may be unknown.
+///
+/// Counts stay valid for deciding whether a column has no nulls or only nulls when rows are
+/// later removed from it, e.g. by a filter or a slice.
+#[derive(Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ColumnStatistics {
+    pub range: ColumnRangeStatistics,
+    pub row_count: Option<usize>,
+    pub null_count: Option<usize>,
+    pub distinct_count: Option<usize>,
+}
+
+impl From<ColumnRangeStatistics> for ColumnStatistics {
+    fn from(range: ColumnRangeStatistics) -> Self {
+        Self {
+            range,
+            row_count: None,
+            null_count: None,
+            distinct_count: None,
+        }
+    }
+}
+
+impl ColumnStatistics {
+    pub fn new(
+        range: ColumnRangeStatistics,
+        row_count: Option<usize>,
+        null_count: Option<usize>,
+        distinct_count: Option<usize>,
+    ) -> Self {
+        Self {
+            range,
+            row_count,
+            null_count,
+            distinct_count,
+        }
+    }
+
+    pub fn missing() -> Self {
+        ColumnRangeStatistics::Missing.into()
+    }
+
+    /// Statistics of a column that is entirely null, such as a column missing from a file.
+    pub fn all_null(row_count: Option<usize>) -> Self {
+        Self::new(ColumnRangeStatistics::Missing, row_count, row_count, None)
+    }
+
+    pub fn from_series(series: &Series) -> Self {
+        let count = |mode| {
+            series
+                .count(None, mode)
+                .unwrap()
+                .u64()
+                .unwrap()
+                .get(0)
+                .unwrap() as usize
+        };
+        Self::new(
+            ColumnRangeStatistics::from_series(series),
+            Some(count(daft_core::CountMode::All)),
+            Some(count(daft_core::CountMode::Null)),
+            None,
+        )
+    }
+
+    /// Drops the counts, for when rows may be duplicated or nulls introduced.
+    pub fn without_counts(&self) -> Self {
+        self.range.clone().into()
+    }
+
+    /// Statistics of `row_count` of these rows, such as the rows of a page of a column chunk.
+    pub fn subset(&self, row_count: usize) -> Self {
+        let null_count = if self.has_no_nulls() {
+            Some(0)
+        } else if self.is_all_null() {
+            Some(row_count)
+        } else {
+            None
+        };
+        Self::new(self.range.clone(), Some(row_count), null_count, None)
+    }
+
+    pub fn has_no_nulls(&self) -> bool {
+        self.null_count == Some(0)
+    }
+
+    pub fn is_all_null(&self) -> bool {
+        matches!((self.null_count, self.row_count), (Some(nulls), Some(rows)) if nulls == rows)
+    }
+
+    pub fn to_truth_value(&self) -> TruthValue {
+        // Null predicates filter out their rows, so a predicate that is always null is never true.
+        if self.is_all_null() {
+            return TruthValue::False;
+        }
+        self.range.to_truth_value()
+    }
+
+    pub fn cast(&self, dtype: &DataType) -> crate::Result<Self> {
+        let range = self.range.cast(dtype)?;
+        // Casts that keep the range are lossless, so they neither introduce nulls nor merge values.
+        let lossless = matches!(range, ColumnRangeStatistics::Loaded(..));
+        Ok(Self::new(
+            range,
+            self.row_count,
+            if lossless || self.is_all_null() {
+                self.null_count
+            } else {
+                None
+            },
+            if lossless { self.distinct_count } else { None },
+        ))
+    }
+
+    /// Unions the statistics of two sets of rows of the same column.
+    pub fn union(&self, rhs: &Self) -> crate::Result<Self> {
+        let sum = |l: Option<usize>, r: Option<usize>| l.zip(r).map(|(l, r)| l + r);
+        Ok(Self::new(
+            self.range.union(&rhs.range)?,
+            sum(self.row_count, rhs.row_count),
+            sum(self.null_count, rhs.null_count),
+            None,
+        ))
+    }
+
+    /// Statistics of a value computed from the values of each row of `inputs`, which is null
+    /// whenever any input is null.
+    pub(crate) fn elementwise(range: ColumnRangeStatistics, inputs: &[&Self]) -> Self {
+        let row_count = inputs.iter().find_map(|s| s.row_count);
+        let null_count = if inputs.iter().all(|s| s.has_no_nulls()) {
+            Some(0)
+        } else if inputs.iter().any(|s| s.is_all_null()) {
+            row_count
+        } else {
+            None
+        };
+        Self::new(range, row_count, null_count, None)
+    }
+
+    fn from_predicate(tv: TruthValue, row_count: Option<usize>, null_count: Option<usize>) -> Self {
+        Self::new(
+            ColumnRangeStatistics::from_truth_value(tv),
+            row_count,
+            null_count,
+            None,
+        )
+    }
+
+    /// Statistics of a Kleene logical operation, which isn't null whenever one side decides it.
+    fn logical(range: ColumnRangeStatistics, lhs: &Self, rhs: &Self) -> Self {
+        let row_count = lhs.row_count.or(rhs.row_count);
+        let null_count = (lhs.has_no_nulls() && rhs.has_no_nulls()).then_some(0);
+        Self::new(range, row_count, null_count, None)
+    }
+
+    /// The null count of these values when broadcasted to `row_count` rows.
+    fn null_count_over(&self, row_count: Option<usize>) -> Option<usize> {
+        if self.has_no_nulls() {
+            Some(0)
+        } else if self.is_all_null() || self.row_count == row_count {
+            row_count.and(self.null_count)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_null(&self) -> Self {
+        let tv = if self.has_no_nulls() {
+            TruthValue::False
+        } else if self.is_all_null() {
+            TruthValue::True
+        } else {
+            TruthValue::Maybe
+        };
+        Self::from_predicate(tv, self.row_count, Some(0))
+    }
+
+    pub fn not_null(&self) -> Self {
+        let tv = if self.has_no_nulls() {
+            TruthValue::True
+        } else if self.is_all_null() {
+            TruthValue::False
+        } else {
+            TruthValue::Maybe
+        };
+        Self::from_predicate(tv, self.row_count, Some(0))
+    }
+
+    pub fn fill_null(&self, fill_value: &Self) -> crate::Result<Self> {
+        let row_count = self.row_count.or(fill_value.row_count);
+        if self.has_no_nulls() {
+            return Ok(self.clone());
+        }
+        if self.is_all_null() {
+            return Ok(Self::new(
+                fill_value.range.clone(),
+                row_count,
+                fill_value.null_count_over(row_count),
+                None,
+            ));
+        }
+        let null_count = fill_value.has_no_nulls().then_some(0);
+        Ok(Self::new(
+            union_ranges(&self.range, &fill_value.range)?,
+            row_count,
+            null_count,
+            None,
+        ))
+    }
+
+    pub fn if_else(&self, if_true: &Self, if_false: &Self) -> crate::Result<Self> {
+        let row_count = self.row_count.or(if_true.row_count).or(if_false.row_count);
+        let branch = match self.range.to_truth_value() {
+            TruthValue::True if self.has_no_nulls() => Some(if_true),
+            TruthValue::False if self.has_no_nulls() => Some(if_false),
+            _ => None,
+        };
+        if let Some(branch) = branch {
+            return Ok(Self::new(
+                branch.range.clone(),
+                row_count,
+                branch.null_count_over(row_count),
+                None,
+            ));
+        }
+        let null_count =
+            (self.has_no_nulls() && if_true.has_no_nulls() && if_false.has_no_nulls()).then_some(0);
+        Ok(Self::new(
+            union_ranges(&if_true.range, &if_false.range)?,
+            row_count,
+            null_count,
+            None,
+        ))
+    }
+
+    /// Evaluates whether the values of this column are in `items`, which is false when no item is
+    /// within the range of the column and true when the column only holds a single listed value.
+    pub fn is_in(&self, items: &Series) -> Self {
+        let tv = if self.is_all_null() {
+            TruthValue::False
+        } else {
+            match &self.range {
+                ColumnRangeStatistics::Loaded(lower, upper) => {
+                    // Items that can't be compared with the column can't be used for pruning.
+                    range_is_in(lower, upper, items).unwrap_or(TruthValue::Maybe)
+                }
+                ColumnRangeStatistics::Missing => TruthValue::Maybe,
+            }
+        };
+        let tv = match tv {
+            TruthValue::True if !self.has_no_nulls() => TruthValue::Maybe,
+            tv => tv,
+        };
+        Self::from_predicate(tv, self.row_count, self.null_count)
+    }
+}
+
+fn range_is_in(lower: &Series, upper: &Series, items: &Series) -> crate::Result<TruthValue> {
+    let within_range = items
+        .gte(lower)
+        .context(DaftCoreComputeSnafu)?
+        .and(&items.lte(upper).context(DaftCoreComputeSnafu)?)
+        .context(DaftCoreComputeSnafu)?;
+    if !within_range.as_arrow().iter().any(|v| v == Some(true)) {
+        return Ok(TruthValue::False);
+    }
+    let is_single_value = lower.equal(upper).context(DaftCoreComputeSnafu)?.get(0);
+    if is_single_value == Some(true) {
+        Ok(TruthValue::True)
+    } else {
+        Ok(TruthValue::Maybe)
+    }
+}
+
+/// Unions two ranges that may be of different types, when the second can be losslessly cast to the
+/// type of the first.
+fn union_ranges(
+    lhs: &ColumnRangeStatistics,
+    rhs: &ColumnRangeStatistics,
+) -> crate::Result<ColumnRangeStatistics> {
+    match (lhs, rhs) {
+        (ColumnRangeStatistics::Loaded(lower, _), ColumnRangeStatistics::Loaded(..)) => {
+            match rhs.cast(lower.data_type())? {
+                ColumnRangeStatistics::Missing => Ok(ColumnRangeStatistics::Missing),
+                rhs => lhs.union(&rhs),
+            }
+        }
+        _ => Ok(ColumnRangeStatistics::Missing),
+    }
+}
+
+impl Not for &ColumnStatistics {
+    type Output = crate::Result<ColumnStatistics>;
+    fn not(self) -> Self::Output {
+        Ok(ColumnStatistics::new(
+            self.range.not()?,
+            self.row_count,
+            self.null_count,
+            self.distinct_count,
+        ))
+    }
+}
+
+impl BitAnd for &ColumnStatistics {
+    type Output = crate::Result<ColumnStatistics>;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let range = self.range.bitand(&rhs.range)?;
+        Ok(ColumnStatistics::logical(range, self, rhs))
+    }
+}
+
+impl BitOr for &ColumnStatistics {
+    type Output = crate::Result<ColumnStatistics>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let range = self.range.bitor(&rhs.range)?;
+        Ok(ColumnStatistics::logical(range, self, rhs))
+    }
+}
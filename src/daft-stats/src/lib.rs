@@ -7,7 +7,7 @@ mod partition_spec;
 mod table_metadata;
 mod table_stats;
 
-pub use column_stats::{ColumnRangeStatistics, TruthValue};
+pub use column_stats::{ColumnRangeStatistics, ColumnStatistics, TruthValue};
 pub use partition_spec::PartitionSpec;
 pub use table_metadata::TableMetadata;
 pub use table_stats::TableStatistics;
@@ -9,7 +9,7 @@ use daft_dsl::{Expr, ExprRef};
 use daft_table::Table;
 use indexmap::{IndexMap, IndexSet};
 
-use crate::column_stats::ColumnRangeStatistics;
+use crate::column_stats::{ColumnRangeStatistics, ColumnStatistics};
 
 use daft_core::{
     array::ops::DaftCompare,
@@ -18,7 +18,7 @@ use daft_core::{
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TableStatistics {
-    pub columns: IndexMap<String, ColumnRangeStatistics>,
+    pub columns: IndexMap<String, ColumnStatistics>,
 }
 
 impl TableStatistics {
@@ -33,7 +33,7 @@ impl TableStatistics {
         for name in table.column_names() {
             let col = table.get_column(&name).unwrap();
             let stats = ColumnRangeStatistics::new(Some(col.slice(0, 1)?), Some(col.slice(1, 2)?))?;
-            columns.insert(name, stats);
+            columns.insert(name, stats.into());
         }
         Ok(TableStatistics { columns })
     }
@@ -42,7 +42,7 @@ impl TableStatistics {
         let mut columns = IndexMap::with_capacity(table.num_columns());
         for name in table.column_names() {
             let col = table.get_column(&name).unwrap();
-            let stats = ColumnRangeStatistics::from_series(col);
+            let stats = ColumnStatistics::from_series(col);
             columns.insert(name, stats);
         }
         TableStatistics { columns }
@@ -59,8 +59,8 @@ impl TableStatistics {
         for col in unioned_columns {
             let res_col = match (self.columns.get(col), other.columns.get(col)) {
                 (None, None) => panic!("Key missing from both tables; invalid state"),
-                (Some(_l), None) => Ok(ColumnRangeStatistics::Missing),
-                (None, Some(_r)) => Ok(ColumnRangeStatistics::Missing),
+                (Some(_l), None) => Ok(ColumnStatistics::missing()),
+                (None, Some(_r)) => Ok(ColumnStatistics::missing()),
                 (Some(l), Some(r)) => l.union(r),
             }?;
             columns.insert(col.clone(), res_col);
@@ -98,7 +98,7 @@ impl TableStatistics {
                 let name = field.name.as_str();
                 let elem_size = if let Some(stats) = self.columns.get(name) {
                     // first try to use column stats
-                    stats.element_size()?
+                    stats.range.element_size()?
                 } else {
                     None
                 }
@@ -110,7 +110,7 @@ impl TableStatistics {
                 sum_so_far += elem_size;
             }
         } else {
-            for elem_size in self.columns.values().map(|c| c.element_size()) {
+            for elem_size in self.columns.values().map(|c| c.range.element_size()) {
                 sum_so_far += elem_size?.unwrap_or(0.);
             }
         }
@@ -118,7 +118,22 @@ impl TableStatistics {
         Ok(sum_so_far)
     }
 
-    pub fn eval_expression(&self, expr: &Expr) -> crate::Result<ColumnRangeStatistics> {
+    /// Gets the number of rows, which is the same for all columns that know it.
+    pub fn row_count(&self) -> Option<usize> {
+        self.columns.values().find_map(|c| c.row_count)
+    }
+
+    /// Drops the counts of all columns, for when rows may be duplicated or nulls introduced.
+    pub fn without_counts(&self) -> Self {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(name, c)| (name.clone(), c.without_counts()))
+            .collect();
+        TableStatistics { columns }
+    }
+
+    pub fn eval_expression(&self, expr: &Expr) -> crate::Result<ColumnStatistics> {
         match expr {
             Expr::Alias(col, _) => self.eval_expression(col.as_ref()),
             Expr::Column(col_name) => {
@@ -131,27 +146,56 @@ impl TableStatistics {
                     })
                 }
             }
-            Expr::Literal(lit_value) => lit_value.try_into(),
+            Expr::Literal(lit_value) => {
+                let range: ColumnRangeStatistics = lit_value.try_into()?;
+                let null_count = (!matches!(lit_value, daft_dsl::LiteralValue::Null)).then_some(0);
+                Ok(ColumnStatistics::new(range, None, null_count, None))
+            }
             Expr::Not(col) => self.eval_expression(col)?.not(),
             Expr::BinaryOp { op, left, right } => {
                 let lhs = self.eval_expression(left)?;
                 let rhs = self.eval_expression(right)?;
                 use daft_dsl::Operator::*;
-                match op {
-                    Lt => lhs.lt(&rhs),
-                    LtEq => lhs.lte(&rhs),
-                    Eq => lhs.equal(&rhs),
-                    NotEq => lhs.not_equal(&rhs),
-                    GtEq => lhs.gte(&rhs),
-                    Gt => lhs.gt(&rhs),
-                    Plus => &lhs + &rhs,
-                    Minus => &lhs - &rhs,
-                    And => lhs.bitand(&rhs),
-                    Or => lhs.bitor(&rhs),
+                let range = match op {
+                    Lt => lhs.range.lt(&rhs.range),
+                    LtEq => lhs.range.lte(&rhs.range),
+                    Eq => lhs.range.equal(&rhs.range),
+                    NotEq => lhs.range.not_equal(&rhs.range),
+                    GtEq => lhs.range.gte(&rhs.range),
+                    Gt => lhs.range.gt(&rhs.range),
+                    Plus => &lhs.range + &rhs.range,
+                    Minus => &lhs.range - &rhs.range,
+                    And => return lhs.bitand(&rhs),
+                    Or => return lhs.bitor(&rhs),
                     _ => Ok(ColumnRangeStatistics::Missing),
+                }?;
+                Ok(ColumnStatistics::elementwise(range, &[&lhs, &rhs]))
+            }
+            Expr::IsNull(col) => Ok(self.eval_expression(col)?.is_null()),
+            Expr::NotNull(col) => Ok(self.eval_expression(col)?.not_null()),
+            Expr::FillNull(col, fill_value) => self
+                .eval_expression(col)?
+                .fill_null(&self.eval_expression(fill_value)?),
+            Expr::IsIn(col, items) => {
+                let stats = self.eval_expression(col)?;
+                match items.as_ref() {
+                    Expr::Literal(items) => Ok(stats.is_in(&items.to_series())),
+                    _ => Ok(ColumnStatistics::elementwise(
+                        ColumnRangeStatistics::Missing,
+                        &[&stats],
+                    )),
                 }
             }
-            _ => Ok(ColumnRangeStatistics::Missing),
+            Expr::IfElse {
+                if_true,
+                if_false,
+                predicate,
+            } => self.eval_expression(predicate)?.if_else(
+                &self.eval_expression(if_true)?,
+                &self.eval_expression(if_false)?,
+            ),
+            Expr::Cast(col, dtype) => self.eval_expression(col)?.cast(dtype),
+            _ => Ok(ColumnStatistics::missing()),
         }
     }
 
@@ -169,13 +213,18 @@ impl TableStatistics {
             let crs = match self.columns.get(field_name) {
                 Some(column_stat) => column_stat
                     .cast(&field.dtype)
-                    .unwrap_or(ColumnRangeStatistics::Missing),
+                    .unwrap_or_else(|_| ColumnStatistics::missing()),
+                // Columns that are missing are filled with nulls
                 None => fill_map
                     .as_ref()
                     .and_then(|m| m.get(field_name.as_str()))
                     .map(|e| self.eval_expression(e))
                     .transpose()?
-                    .unwrap_or(ColumnRangeStatistics::Missing),
+                    .map(|stats| ColumnStatistics {
+                        row_count: stats.row_count.or(self.row_count()),
+                        ..stats
+                    })
+                    .unwrap_or_else(|| ColumnStatistics::all_null(self.row_count())),
             };
             columns.insert(field_name.clone(), crs);
         }
@@ -188,7 +237,7 @@ impl Display for TableStatistics {
         let columns = self
             .columns
             .iter()
-            .map(|(s, c)| c.combined_series().unwrap().rename(s))
+            .map(|(s, c)| c.range.combined_series().unwrap().rename(s))
             .collect::<Vec<_>>();
 
         let tab = Table::from_columns(columns).unwrap();
@@ -199,8 +248,8 @@ impl Display for TableStatistics {
 #[cfg(test)]
 mod test {
 
-    use daft_core::{datatypes::Int64Array, IntoSeries};
-    use daft_dsl::{col, lit};
+    use daft_core::{datatypes::Int64Array, DataType, IntoSeries};
+    use daft_dsl::{col, lit, null_lit};
     use daft_table::Table;
 
     use crate::column_stats::TruthValue;
@@ -235,4 +284,86 @@ mod test {
 
         Ok(())
     }
+
+    fn table_stats_with_nulls(values: Vec<Option<i64>>) -> TableStatistics {
+        let series = Int64Array::from_iter("a", values.into_iter()).into_series();
+        let table = Table::from_columns(vec![series]).unwrap();
+        TableStatistics::from_table(&table)
+    }
+
+    #[test]
+    fn test_is_null() -> crate::Result<()> {
+        let no_nulls = table_stats_with_nulls(vec![Some(1), Some(2)]);
+        let some_nulls = table_stats_with_nulls(vec![Some(1), None]);
+        let all_nulls = table_stats_with_nulls(vec![None, None]);
+
+        let expr = col("a").is_null();
+        let result = no_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+        let result = some_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+        let result = all_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::True);
+
+        let expr = col("a").not_null();
+        let result = no_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::True);
+        let result = all_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        // Comparisons on a column of only nulls are never true
+        let expr = col("a").eq(lit(1));
+        let result = all_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in() -> crate::Result<()> {
+        let table_stats = table_stats_with_nulls(vec![Some(1), Some(4)]);
+        let items = |values: Vec<i64>| lit(Int64Array::from(("items", values)).into_series());
+
+        let expr = col("a").is_in(items(vec![0, 5, 10]));
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let expr = col("a").is_in(items(vec![0, 3]));
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+
+        let table_stats = table_stats_with_nulls(vec![Some(3), Some(3)]);
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::True);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_null_if_else_and_cast() -> crate::Result<()> {
+        let table_stats = table_stats_with_nulls(vec![Some(1), None, Some(4)]);
+
+        let expr = col("a").fill_null(lit(0)).is_null();
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let expr = col("a").fill_null(lit(10)).gt(lit(20));
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let expr = col("a").not_null().if_else(col("a"), lit(0)).lt(lit(0));
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::False);
+
+        let expr = col("a").cast(&DataType::Int64).is_null();
+        let result = table_stats.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+
+        let all_nulls = table_stats_with_nulls(vec![None]);
+        let expr = col("a").fill_null(null_lit()).not_null();
+        let result = all_nulls.eval_expression(&expr)?;
+        assert_eq!(result.to_truth_value(), TruthValue::Maybe);
+
+        Ok(())
+    }
 }
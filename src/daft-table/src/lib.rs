@@ -16,8 +16,10 @@ use daft_core::datatypes::{BooleanArray, DataType, Field, UInt64Array};
 use daft_core::schema::{Schema, SchemaRef};
 use daft_core::series::{IntoSeries, Series};
 
-use daft_dsl::functions::FunctionEvaluator;
-use daft_dsl::{col, null_lit, AggExpr, Expr, ExprRef};
+use daft_dsl::functions::{list::ListExpr, FunctionEvaluator, FunctionExpr};
+use daft_dsl::{
+    col, null_lit, AggExpr, ApproxPercentileParams, Expr, ExprRef, SketchType, ELEMENT_NAME,
+};
 #[cfg(feature = "python")]
 pub mod ffi;
 mod ops;
@@ -323,11 +325,70 @@ impl Table {
             Count(expr, mode) => Series::count(&self.eval_expression(expr)?, groups, *mode),
             Sum(expr) => Series::sum(&self.eval_expression(expr)?, groups),
             Mean(expr) => Series::mean(&self.eval_expression(expr)?, groups),
+            Stddev(expr, ddof) => {
+                let evaled = self.eval_expression(expr)?;
+                evaled.comoments(&evaled, groups)?.comoments_stddev(*ddof)
+            }
+            Variance(expr, ddof) => {
+                let evaled = self.eval_expression(expr)?;
+                evaled
+                    .comoments(&evaled, groups)?
+                    .comoments_covariance(*ddof)
+            }
+            Covariance(left, right, ddof) => Series::comoments(
+                &self.eval_expression(left)?,
+                &self.eval_expression(right)?,
+                groups,
+            )?
+            .comoments_covariance(*ddof),
+            Correlation(left, right) => Series::comoments(
+                &self.eval_expression(left)?,
+                &self.eval_expression(right)?,
+                groups,
+            )?
+            .comoments_correlation(),
+            CoMoments(left, right) => Series::comoments(
+                &self.eval_expression(left)?,
+                &self.eval_expression(right)?,
+                groups,
+            ),
+            MergeCoMoments(expr) => Series::merge_comoments(&self.eval_expression(expr)?, groups),
             Min(expr) => Series::min(&self.eval_expression(expr)?, groups),
             Max(expr) => Series::max(&self.eval_expression(expr)?, groups),
             AnyValue(expr, ignore_nulls) => {
                 Series::any_value(&self.eval_expression(expr)?, groups, *ignore_nulls)
             }
+            BoolAnd(expr) => Series::bool_and(&self.eval_expression(expr)?, groups),
+            BoolOr(expr) => Series::bool_or(&self.eval_expression(expr)?, groups),
+            BitAnd(expr) => Series::bit_and(&self.eval_expression(expr)?, groups),
+            BitOr(expr) => Series::bit_or(&self.eval_expression(expr)?, groups),
+            BitXor(expr) => Series::bit_xor(&self.eval_expression(expr)?, groups),
+            ApproxCountDistinct(expr) => {
+                Series::approx_count_distinct(&self.eval_expression(expr)?, groups)
+            }
+            ApproxPercentile(ApproxPercentileParams {
+                child: expr,
+                percentiles,
+                force_list_output,
+            }) => {
+                let percentiles = percentiles.iter().map(|p| p.0).collect::<Vec<_>>();
+                Series::ddsketch(&self.eval_expression(expr)?, groups)?
+                    .sketch_percentile(&percentiles, *force_list_output)
+            }
+            ApproxSketch(expr, sketch_type) => {
+                let evaled = self.eval_expression(expr)?;
+                match sketch_type {
+                    SketchType::HyperLogLog => evaled.hll_sketch(groups),
+                    SketchType::DDSketch => evaled.ddsketch(groups),
+                }
+            }
+            MergeSketch(expr, sketch_type) => {
+                let evaled = self.eval_expression(expr)?;
+                match sketch_type {
+                    SketchType::HyperLogLog => evaled.hll_merge(groups),
+                    SketchType::DDSketch => evaled.ddsketch_merge(groups),
+                }
+            }
             List(expr) => Series::agg_list(&self.eval_expression(expr)?, groups),
             Concat(expr) => Series::agg_concat(&self.eval_expression(expr)?, groups),
             MapGroups { .. } => Err(DaftError::ValueError(
@@ -377,6 +438,10 @@ impl Table {
                     _ => panic!("{op:?} not supported"),
                 }
             }
+            Function {
+                func: FunctionExpr::List(list_expr @ (ListExpr::Eval(_) | ListExpr::Filter(_))),
+                inputs,
+            } => self.eval_list_element_function(list_expr, inputs),
             Function { func, inputs } => {
                 let evaluated_inputs = inputs
                     .iter()
@@ -395,6 +460,10 @@ impl Table {
                 let predicate_series = self.eval_expression(predicate)?;
                 Ok(if_true_series.if_else(&if_false_series, &predicate_series)?)
             }
+            Window(..) => Err(DaftError::ValueError(format!(
+                "Window expressions must be evaluated with a window operation, but got: {expr}"
+            ))),
+            Element => self.get_column(ELEMENT_NAME).cloned(),
         }?;
         if expected_field.name != series.field().name {
             return Err(DaftError::ComputeError(format!(
@@ -584,10 +653,12 @@ mod test {
 
     use crate::Table;
     use common_error::DaftResult;
+    use daft_core::array::ops::as_arrow::AsArrow;
     use daft_core::datatypes::{DataType, Float64Array, Int64Array};
+    use daft_core::join::JoinType;
     use daft_core::schema::Schema;
     use daft_core::series::IntoSeries;
-    use daft_dsl::col;
+    use daft_dsl::{col, lit, WindowExpr, WindowSpec};
     #[test]
     fn add_int_and_float_expression() -> DaftResult<()> {
         let a = Int64Array::from(("a", vec![1, 2, 3])).into_series();
@@ -609,4 +680,154 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn window_rank_and_cum_sum() -> DaftResult<()> {
+        let a = Int64Array::from(("a", vec![1, 2, 1, 1, 2])).into_series();
+        let b = Int64Array::from(("b", vec![10, 5, 10, 20, 7])).into_series();
+        let table = Table::from_columns(vec![a, b])?;
+        let spec = WindowSpec::new(vec![col("a")], vec![col("b")], vec![false]);
+        let window_exprs = vec![
+            WindowExpr::RowNumber.over(spec.clone()),
+            WindowExpr::Rank.over(spec.clone()),
+            WindowExpr::DenseRank.over(spec.clone()),
+            col("b").cum_sum().over(spec.clone()).alias("s"),
+            col("b").lag(1, lit(0)).over(spec).alias("prev"),
+        ];
+        let result = table.window(window_exprs.as_slice(), &[col("a")], &[col("b")], &[false])?;
+
+        let values = |name: &str| -> DaftResult<Vec<Option<i64>>> {
+            let s = result.get_column(name)?.cast(&DataType::Int64)?;
+            Ok(s.i64()?.as_arrow().iter().map(|v| v.copied()).collect())
+        };
+        assert_eq!(values("a")?, [1, 1, 1, 2, 2].map(Some));
+        assert_eq!(values("b")?, [10, 10, 20, 5, 7].map(Some));
+        assert_eq!(values("row_number")?, [1, 2, 3, 1, 2].map(Some));
+        assert_eq!(values("rank")?, [1, 1, 3, 1, 2].map(Some));
+        assert_eq!(values("dense_rank")?, [1, 1, 2, 1, 2].map(Some));
+        assert_eq!(values("s")?, [10, 20, 40, 5, 12].map(Some));
+        assert_eq!(values("prev")?, [0, 10, 10, 0, 5].map(Some));
+
+        Ok(())
+    }
+
+    #[test]
+    fn join_types() -> DaftResult<()> {
+        let left = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Int64Array::from(("b", vec![10, 20, 30])).into_series(),
+        ])?;
+        let right = Table::from_columns(vec![
+            Int64Array::from(("a", vec![2, 3, 3, 4])).into_series(),
+            Int64Array::from(("c", vec![200, 300, 301, 400])).into_series(),
+        ])?;
+        let values = |table: &Table, name: &str| -> DaftResult<Vec<Option<i64>>> {
+            let s = table.get_column(name)?;
+            Ok(s.i64()?.as_arrow().iter().map(|v| v.copied()).collect())
+        };
+        let on = [col("a")];
+
+        let result = left.hash_join(&right, &on, &on, JoinType::Left)?;
+        assert_eq!(values(&result, "a")?, [2, 3, 3, 1].map(Some));
+        assert_eq!(values(&result, "b")?, [20, 30, 30, 10].map(Some));
+        assert_eq!(
+            values(&result, "c")?,
+            [Some(200), Some(300), Some(301), None]
+        );
+
+        let result = left.hash_join(&right, &on, &on, JoinType::Right)?;
+        assert_eq!(values(&result, "a")?, [2, 3, 3, 4].map(Some));
+        assert_eq!(values(&result, "b")?, [Some(20), Some(30), Some(30), None]);
+
+        let result = left.hash_join(&right, &on, &on, JoinType::Outer)?;
+        assert_eq!(values(&result, "a")?, [2, 3, 3, 1, 4].map(Some));
+        assert_eq!(
+            values(&result, "c")?,
+            [Some(200), Some(300), Some(301), None, Some(400)]
+        );
+
+        for is_sort_merge in [false, true] {
+            let semi_anti_join = |how| {
+                if is_sort_merge {
+                    left.sort_merge_join(&right, &on, &on, how, false)
+                } else {
+                    left.hash_join(&right, &on, &on, how)
+                }
+            };
+            let result = semi_anti_join(JoinType::Semi)?;
+            assert_eq!(result.column_names(), ["a", "b"]);
+            assert_eq!(values(&result, "a")?, [2, 3].map(Some));
+
+            let result = semi_anti_join(JoinType::Anti)?;
+            assert_eq!(values(&result, "a")?, [Some(1)]);
+        }
+
+        let result = left.sort_merge_join(&right, &on, &on, JoinType::Outer, false)?;
+        let mut keys = values(&result, "a")?;
+        keys.sort();
+        assert_eq!(keys, [1, 2, 3, 3, 4].map(Some));
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_loop_join() -> DaftResult<()> {
+        let left = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1, 2, 3])).into_series(),
+            Int64Array::from(("b", vec![10, 20, 30])).into_series(),
+        ])?;
+        let right = Table::from_columns(vec![
+            Int64Array::from(("a", vec![4, 3, 2, 3])).into_series(),
+            Int64Array::from(("c", vec![400, 300, 200, 301])).into_series(),
+        ])?;
+        let values = |table: &Table, name: &str| -> DaftResult<Vec<Option<i64>>> {
+            let s = table.get_column(name)?;
+            Ok(s.i64()?.as_arrow().iter().map(|v| v.copied()).collect())
+        };
+
+        let result = left.nested_loop_join(&right, None, JoinType::Inner)?;
+        assert_eq!(result.column_names(), ["a", "b", "right.a", "c"]);
+        assert_eq!(result.len(), 12);
+
+        // Range join on an inequality between the two sides.
+        let predicate = col("a").lt(col("right.a"));
+        let result = left.nested_loop_join(&right, Some(&predicate), JoinType::Inner)?;
+        assert_eq!(values(&result, "a")?, [1, 1, 1, 1, 2, 2, 2, 3].map(Some));
+        let mut pairs = values(&result, "a")?
+            .into_iter()
+            .zip(values(&result, "right.a")?)
+            .collect::<Vec<_>>();
+        pairs.sort();
+        assert!(pairs.iter().all(|(l, r)| l < r));
+
+        // Range join with a residual predicate.
+        let predicate = col("a").lt(col("right.a")).and(col("c").lt(lit(400)));
+        let result = left.nested_loop_join(&right, Some(&predicate), JoinType::Left)?;
+        assert_eq!(values(&result, "a")?, [1, 1, 1, 2, 2, 3].map(Some));
+        assert_eq!(values(&result, "c")?.last(), Some(&None));
+
+        // The inequality is flipped if the right side is on the left of the comparison.
+        let predicate = col("right.a").lt_eq(col("a"));
+        let result = left.nested_loop_join(&right, Some(&predicate), JoinType::Semi)?;
+        assert_eq!(result.column_names(), ["a", "b"]);
+        assert_eq!(values(&result, "a")?, [2, 3].map(Some));
+        let result = left.nested_loop_join(&right, Some(&predicate), JoinType::Anti)?;
+        assert_eq!(values(&result, "a")?, [Some(1)]);
+
+        // Predicates without an inequality are evaluated over the cartesian product.
+        let predicate = col("a").eq(col("right.a"));
+        let result = left.nested_loop_join(&right, Some(&predicate), JoinType::Outer)?;
+        assert_eq!(result.len(), 5);
+
+        // Conflicting right columns are prefixed once, so a conflict with a prefixed name fails.
+        let prefixed_left = Table::from_columns(vec![
+            Int64Array::from(("a", vec![1])).into_series(),
+            Int64Array::from(("right.a", vec![2])).into_series(),
+        ])?;
+        assert!(prefixed_left
+            .nested_loop_join(&right, None, JoinType::Inner)
+            .is_err());
+
+        Ok(())
+    }
 }
@@ -72,3 +72,72 @@ pub(super) fn hash_inner_join(left: &Table, right: &Table) -> DaftResult<(Series
     let right_series = UInt64Array::from(("right_indices", right_idx));
     Ok((left_series.into_series(), right_series.into_series()))
 }
+
+pub(super) fn hash_semi_anti_join(
+    left: &Table,
+    right: &Table,
+    is_anti: bool,
+) -> DaftResult<Series> {
+    if left.num_columns() != right.num_columns() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+            left.num_columns(),
+            right.num_columns()
+        )));
+    }
+    if left.num_columns() == 0 {
+        return Err(DaftError::ValueError(
+            "No columns were passed in to join on".to_string(),
+        ));
+    }
+
+    // Null keys never match, so every left row is unmatched.
+    let has_null_type = left.columns.iter().any(|s| s.data_type().is_null())
+        || right.columns.iter().any(|s| s.data_type().is_null());
+    if has_null_type {
+        let left_idx = if is_anti {
+            (0..left.len() as u64).collect()
+        } else {
+            vec![]
+        };
+        return Ok(UInt64Array::from(("left_indices", left_idx)).into_series());
+    }
+    let types_not_match = left
+        .columns
+        .iter()
+        .zip(right.columns.iter())
+        .any(|(l, r)| l.data_type() != r.data_type());
+    if types_not_match {
+        return Err(DaftError::SchemaMismatch(
+            "Types between left and right do not match".to_string(),
+        ));
+    }
+
+    // Only the existence of a match matters, so build the probe table on the right table and emit
+    // each left row at most once.
+    let probe_table = right.to_probe_hash_table()?;
+
+    let l_hashes = left.hash_rows()?;
+    let mut left_idx = vec![];
+    let is_equal = build_multi_array_is_equal(
+        left.columns.as_slice(),
+        right.columns.as_slice(),
+        false,
+        false,
+    )?;
+    for (l_idx, h) in l_hashes.as_arrow().values_iter().enumerate() {
+        let is_match = probe_table
+            .raw_entry()
+            .from_hash(*h, |other| {
+                *h == other.hash && {
+                    let r_idx = other.idx;
+                    is_equal(l_idx, r_idx as usize)
+                }
+            })
+            .is_some();
+        if is_match != is_anti {
+            left_idx.push(l_idx as u64);
+        }
+    }
+    Ok(UInt64Array::from(("left_indices", left_idx)).into_series())
+}
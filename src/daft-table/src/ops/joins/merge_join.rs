@@ -241,3 +241,8 @@ pub fn merge_inner_join(left: &Table, right: &Table) -> DaftResult<(Series, Seri
     let right_series = UInt64Array::from(("right_indices", right_indices));
     Ok((left_series.into_series(), right_series.into_series()))
 }
+
+pub fn merge_semi_anti_join(left: &Table, right: &Table, is_anti: bool) -> DaftResult<Series> {
+    let (left_indices, _) = merge_inner_join(left, right)?;
+    super::semi_anti_indices(&left_indices, left.len(), is_anti)
+}
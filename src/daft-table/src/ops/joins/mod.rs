@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use daft_core::{schema::Schema, utils::supertype::try_get_supertype, Series};
+use daft_core::{
+    array::ops::as_arrow::AsArrow, datatypes::UInt64Array, join::JoinType, schema::Schema,
+    utils::supertype::try_get_supertype, DataType, IntoSeries, Series,
+};
 
 use common_error::{DaftError, DaftResult};
 use daft_dsl::ExprRef;
@@ -9,6 +12,7 @@ use crate::Table;
 
 mod hash_join;
 mod merge_join;
+mod range_join;
 
 fn match_types_for_tables(left: &Table, right: &Table) -> DaftResult<(Table, Table)> {
     let mut lseries = vec![];
@@ -30,11 +34,170 @@ fn match_types_for_tables(left: &Table, right: &Table) -> DaftResult<(Table, Tab
     Ok((Table::from_columns(lseries)?, Table::from_columns(rseries)?))
 }
 
+/// Returns the indices in `0..len` that don't appear in `indices`.
+fn unmatched_indices(indices: &[Option<u64>], len: usize) -> Vec<u64> {
+    let mut matched = vec![false; len];
+    indices
+        .iter()
+        .flatten()
+        .for_each(|idx| matched[*idx as usize] = true);
+    (0..len as u64)
+        .filter(|idx| !matched[*idx as usize])
+        .collect()
+}
+
+/// Extends the matched (left, right) index pairs of an inner join with the unmatched rows that the
+/// join type must also emit, using a null index for the side that has no matching row.
+fn add_unmatched_indices(
+    lidx: Series,
+    ridx: Series,
+    left_len: usize,
+    right_len: usize,
+    how: JoinType,
+) -> DaftResult<(Series, Series)> {
+    let (emit_left, emit_right) = match how {
+        JoinType::Inner => return Ok((lidx, ridx)),
+        JoinType::Left => (true, false),
+        JoinType::Right => (false, true),
+        JoinType::Outer => (true, true),
+        JoinType::Semi | JoinType::Anti => {
+            return Err(DaftError::ValueError(format!(
+                "{how} join does not produce right-side indices"
+            )))
+        }
+    };
+    let mut left_indices = lidx
+        .u64()?
+        .as_arrow()
+        .iter()
+        .map(|i| i.copied())
+        .collect::<Vec<_>>();
+    let mut right_indices = ridx
+        .u64()?
+        .as_arrow()
+        .iter()
+        .map(|i| i.copied())
+        .collect::<Vec<_>>();
+    if emit_left {
+        for idx in unmatched_indices(&left_indices, left_len) {
+            left_indices.push(Some(idx));
+            right_indices.push(None);
+        }
+    }
+    if emit_right {
+        for idx in unmatched_indices(&right_indices, right_len) {
+            left_indices.push(None);
+            right_indices.push(Some(idx));
+        }
+    }
+    let to_series = |name: &str, indices: Vec<Option<u64>>| {
+        UInt64Array::from((name, Box::new(arrow2::array::PrimitiveArray::from(indices))))
+            .into_series()
+    };
+    Ok((
+        to_series("left_indices", left_indices),
+        to_series("right_indices", right_indices),
+    ))
+}
+
+/// Returns the left indices of a semi join (or an anti join) given the matched left indices of the
+/// corresponding inner join, in order and without duplicates.
+fn semi_anti_indices(lidx: &Series, left_len: usize, is_anti: bool) -> DaftResult<Series> {
+    let mut is_matched = vec![false; left_len];
+    for l_idx in lidx.u64()?.as_arrow().values_iter() {
+        is_matched[*l_idx as usize] = true;
+    }
+    let left_idx = (0..left_len as u64)
+        .filter(|l_idx| is_matched[*l_idx as usize] != is_anti)
+        .collect::<Vec<_>>();
+    Ok(UInt64Array::from(("left_indices", left_idx)).into_series())
+}
+
+/// Returns the index pairs of the cartesian product of the left and right rows.
+fn cross_join_indices(left_len: usize, right_len: usize) -> (Series, Series) {
+    let left_idx = (0..left_len as u64)
+        .flat_map(|l_idx| std::iter::repeat(l_idx).take(right_len))
+        .collect::<Vec<_>>();
+    let right_idx = (0..left_len)
+        .flat_map(|_| 0..right_len as u64)
+        .collect::<Vec<_>>();
+    (
+        UInt64Array::from(("left_indices", left_idx)).into_series(),
+        UInt64Array::from(("right_indices", right_idx)).into_series(),
+    )
+}
+
+/// Keeps the index pairs whose joined rows satisfy the predicate.
+fn filter_index_pairs(
+    left: &Table,
+    right: &Table,
+    lidx: Series,
+    ridx: Series,
+    predicate: &ExprRef,
+) -> DaftResult<(Series, Series)> {
+    let pairs = Table::from_columns(
+        left.take(&lidx)?
+            .columns
+            .into_iter()
+            .chain(right.take(&ridx)?.columns)
+            .collect(),
+    )?;
+    let mask = pairs.eval_expression(predicate)?;
+    if *mask.data_type() != DataType::Boolean {
+        return Err(DaftError::ValueError(format!(
+            "Join predicate must evaluate to a Boolean, but got {}: {predicate}",
+            mask.data_type()
+        )));
+    }
+    let mask = mask.bool()?;
+    Ok((lidx.filter(mask)?, ridx.filter(mask)?))
+}
+
+/// Renames the columns of the right table to their names in the output of a join without join
+/// keys, where names that conflict with the left table are prefixed with "right.", as in the
+/// logical join. Fails if a renamed column still conflicts with another column.
+fn rename_right_columns(left: &Schema, right: &Table) -> DaftResult<Table> {
+    let columns = right
+        .columns
+        .iter()
+        .map(|s| {
+            if left.fields.contains_key(s.name()) {
+                s.rename(format!("right.{}", s.name()))
+            } else {
+                s.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    Schema::new(
+        left.fields
+            .values()
+            .chain(columns.iter().map(|s| s.field()))
+            .cloned()
+            .collect(),
+    )?;
+    Table::from_columns(columns)
+}
+
+/// Takes the rows at `idx` from `series`, where a null index yields a null row.
+fn take_with_nulls(series: &Series, idx: &Series) -> DaftResult<Series> {
+    if series.is_empty() {
+        // Every index must be null, so avoid indexing into the empty array altogether.
+        Ok(Series::full_null(
+            series.name(),
+            series.data_type(),
+            idx.len(),
+        ))
+    } else {
+        series.take(idx)
+    }
+}
+
 pub fn infer_join_schema(
     left: &Schema,
     right: &Schema,
     left_on: &[ExprRef],
     right_on: &[ExprRef],
+    how: JoinType,
 ) -> DaftResult<Schema> {
     if left_on.len() != right_on.len() {
         return Err(DaftError::ValueError(format!(
@@ -53,6 +216,11 @@ pub fn infer_join_schema(
         .map(|e| e.to_field(right))
         .collect::<DaftResult<Vec<_>>>()?;
 
+    // Semi and anti joins only filter the left table.
+    if how.is_left_only() {
+        return Schema::new(left.fields.values().cloned().collect());
+    }
+
     // Left Join Keys are first
     let mut join_fields = lfields
         .iter()
@@ -109,8 +277,19 @@ impl Table {
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        how: JoinType,
     ) -> DaftResult<Self> {
-        self.join(right, left_on, right_on, hash_join::hash_inner_join)
+        if how.is_left_only() {
+            self.semi_anti_join(
+                right,
+                left_on,
+                right_on,
+                how,
+                hash_join::hash_semi_anti_join,
+            )
+        } else {
+            self.join(right, left_on, right_on, how, hash_join::hash_inner_join)
+        }
     }
 
     pub fn sort_merge_join(
@@ -118,10 +297,11 @@ impl Table {
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        how: JoinType,
         is_sorted: bool,
     ) -> DaftResult<Self> {
         if is_sorted {
-            self.join(right, left_on, right_on, merge_join::merge_inner_join)
+            self.sorted_merge_join(right, left_on, right_on, how)
         } else {
             if left_on.is_empty() {
                 return Err(DaftError::ValueError(
@@ -147,19 +327,131 @@ impl Table {
                     .collect::<Vec<_>>()
                     .as_slice(),
             )?;
-            left.join(&right, left_on, right_on, merge_join::merge_inner_join)
+            left.sorted_merge_join(&right, left_on, right_on, how)
         }
     }
 
+    fn sorted_merge_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        if how.is_left_only() {
+            self.semi_anti_join(
+                right,
+                left_on,
+                right_on,
+                how,
+                merge_join::merge_semi_anti_join,
+            )
+        } else {
+            self.join(right, left_on, right_on, how, merge_join::merge_inner_join)
+        }
+    }
+
+    /// Joins with the right table on an arbitrary predicate over the joined rows, or without any
+    /// predicate for a cross join.
+    ///
+    /// If the predicate contains an inequality between the two tables (e.g. `left.ts >= right.start`),
+    /// matches are found with a sort-based range join instead of testing every pair of rows.
+    pub fn nested_loop_join(
+        &self,
+        right: &Self,
+        predicate: Option<&ExprRef>,
+        how: JoinType,
+    ) -> DaftResult<Self> {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, &[], &[], how)?;
+        let is_output_empty = match how {
+            JoinType::Inner | JoinType::Semi => self.is_empty() || right.is_empty(),
+            JoinType::Left | JoinType::Anti => self.is_empty(),
+            JoinType::Right => right.is_empty(),
+            JoinType::Outer => self.is_empty() && right.is_empty(),
+        };
+        if is_output_empty {
+            return Self::empty(Some(join_schema.into()));
+        }
+        // The predicate is expressed over the joined rows, so the right columns are named as in the output.
+        let right = rename_right_columns(&self.schema, right)?;
+
+        let (lidx, ridx) = match predicate {
+            None => cross_join_indices(self.len(), right.len()),
+            Some(predicate) => {
+                let (lidx, ridx, residual) =
+                    match range_join::split_range_predicate(predicate, &self.schema, &right.schema)
+                    {
+                        Some((condition, residual)) => {
+                            let (lidx, ridx) =
+                                range_join::range_join_indices(self, &right, &condition)?;
+                            (lidx, ridx, residual)
+                        }
+                        None => {
+                            let (lidx, ridx) = cross_join_indices(self.len(), right.len());
+                            (lidx, ridx, Some(predicate.clone()))
+                        }
+                    };
+                match residual {
+                    Some(residual) => filter_index_pairs(self, &right, lidx, ridx, &residual)?,
+                    None => (lidx, ridx),
+                }
+            }
+        };
+
+        if how.is_left_only() {
+            let lidx = semi_anti_indices(&lidx, self.len(), how == JoinType::Anti)?;
+            return self.take(&lidx);
+        }
+        let (lidx, ridx) = add_unmatched_indices(lidx, ridx, self.len(), right.len(), how)?;
+        let join_series = self
+            .columns
+            .iter()
+            .map(|s| take_with_nulls(s, &lidx))
+            .chain(right.columns.iter().map(|s| take_with_nulls(s, &ridx)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        Table::new(join_schema, join_series)
+    }
+
+    fn semi_anti_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        how: JoinType,
+        semi_anti_join: impl Fn(&Table, &Table, bool) -> DaftResult<Series>,
+    ) -> DaftResult<Self> {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
+        let is_anti = how == JoinType::Anti;
+        if self.is_empty() || (right.is_empty() && !is_anti) {
+            return Self::empty(Some(join_schema.into()));
+        }
+        if right.is_empty() {
+            return Ok(self.clone());
+        }
+        let ltable = self.eval_expression_list(left_on)?;
+        let rtable = right.eval_expression_list(right_on)?;
+
+        let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
+        let lidx = semi_anti_join(&ltable, &rtable, is_anti)?;
+        self.take(&lidx)
+    }
+
     fn join(
         &self,
         right: &Self,
         left_on: &[ExprRef],
         right_on: &[ExprRef],
+        how: JoinType,
         inner_join: impl Fn(&Table, &Table) -> DaftResult<(Series, Series)>,
     ) -> DaftResult<Self> {
-        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on)?;
-        if self.is_empty() || right.is_empty() {
+        let join_schema = infer_join_schema(&self.schema, &right.schema, left_on, right_on, how)?;
+        let is_output_empty = match how {
+            JoinType::Left => self.is_empty(),
+            JoinType::Right => right.is_empty(),
+            JoinType::Outer => self.is_empty() && right.is_empty(),
+            _ => self.is_empty() || right.is_empty(),
+        };
+        if is_output_empty {
             return Self::empty(Some(join_schema.into()));
         }
         let ltable = self.eval_expression_list(left_on)?;
@@ -167,6 +459,7 @@ impl Table {
 
         let (ltable, rtable) = match_types_for_tables(&ltable, &rtable)?;
         let (lidx, ridx) = inner_join(&ltable, &rtable)?;
+        let (lidx, ridx) = add_unmatched_indices(lidx, ridx, self.len(), right.len(), how)?;
         let mut join_fields = ltable
             .column_names()
             .iter()
@@ -175,8 +468,24 @@ impl Table {
 
         let mut join_series = self
             .get_columns(ltable.column_names().as_slice())?
-            .take(&lidx)?
-            .columns;
+            .columns
+            .iter()
+            .map(|s| take_with_nulls(s, &lidx))
+            .collect::<DaftResult<Vec<_>>>()?;
+        // Rows without a left-side match take their join key values from the right side.
+        if matches!(how, JoinType::Right | JoinType::Outer) {
+            let has_left = lidx.not_null()?;
+            join_series = join_series
+                .into_iter()
+                .zip(rtable.columns.iter())
+                .map(|(lkey, rkey)| {
+                    let rkey = take_with_nulls(rkey, &ridx)?
+                        .cast(lkey.data_type())?
+                        .rename(lkey.name());
+                    lkey.if_else(&rkey, &has_left)
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+        }
         drop(ltable);
         drop(rtable);
 
@@ -192,7 +501,7 @@ impl Table {
                 continue;
             } else {
                 join_fields.push(field.clone());
-                join_series.push(self.get_column(&field.name)?.take(&lidx)?);
+                join_series.push(take_with_nulls(self.get_column(&field.name)?, &lidx)?);
                 names_so_far.insert(field.name.clone());
             }
         }
@@ -225,12 +534,10 @@ impl Table {
                 curr_name = "right.".to_string() + curr_name.as_str();
             }
             join_fields.push(field.rename(curr_name.clone()));
-            join_series.push(
-                right
-                    .get_column(&field.name)?
-                    .rename(curr_name.clone())
-                    .take(&ridx)?,
-            );
+            join_series.push(take_with_nulls(
+                &right.get_column(&field.name)?.rename(curr_name.clone()),
+                &ridx,
+            )?);
             names_so_far.insert(curr_name.clone());
         }
 
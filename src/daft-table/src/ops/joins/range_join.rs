@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+
+use daft_core::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::UInt64Array,
+    kernels::search_sorted::build_partial_compare_with_nulls,
+    schema::Schema,
+    series::{IntoSeries, Series},
+    utils::supertype::try_get_supertype,
+};
+use daft_dsl::{
+    optimization::{conjuct, get_required_columns, split_conjuction},
+    Expr, ExprRef, Operator,
+};
+
+use crate::Table;
+use common_error::DaftResult;
+
+/// An inequality `left_expr op right_expr`, where `left_expr` only references columns of the left
+/// table and `right_expr` only references columns of the right table.
+pub(super) struct RangeCondition {
+    left_expr: ExprRef,
+    op: Operator,
+    right_expr: ExprRef,
+}
+
+fn flip_inequality(op: Operator) -> Operator {
+    match op {
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        _ => unreachable!("{op} is not an inequality"),
+    }
+}
+
+/// Finds the first conjunct of `predicate` that is an inequality between the left and right tables,
+/// returning it along with the conjunction of the remaining (residual) conjuncts.
+pub(super) fn split_range_predicate(
+    predicate: &ExprRef,
+    left: &Schema,
+    right: &Schema,
+) -> Option<(RangeCondition, Option<ExprRef>)> {
+    let is_over = |expr: &ExprRef, schema: &Schema| {
+        let required_columns = get_required_columns(expr);
+        !required_columns.is_empty()
+            && required_columns
+                .iter()
+                .all(|name| schema.fields.contains_key(name))
+    };
+    let conjuncts = split_conjuction(predicate);
+    let (condition_idx, condition) =
+        conjuncts
+            .iter()
+            .enumerate()
+            .find_map(|(idx, conjunct)| match conjunct.as_ref() {
+                Expr::BinaryOp {
+                    op: op @ (Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq),
+                    left: lhs,
+                    right: rhs,
+                } => {
+                    if is_over(lhs, left) && is_over(rhs, right) {
+                        Some((
+                            idx,
+                            RangeCondition {
+                                left_expr: lhs.clone(),
+                                op: *op,
+                                right_expr: rhs.clone(),
+                            },
+                        ))
+                    } else if is_over(rhs, left) && is_over(lhs, right) {
+                        Some((
+                            idx,
+                            RangeCondition {
+                                left_expr: rhs.clone(),
+                                op: flip_inequality(*op),
+                                right_expr: lhs.clone(),
+                            },
+                        ))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })?;
+    let residual = conjuct(
+        conjuncts
+            .into_iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx != condition_idx)
+            .map(|(_, conjunct)| conjunct.clone()),
+    );
+    Some((condition, residual))
+}
+
+/// Returns the first index in `0..len` for which `pred` is false, assuming that `pred` is true for
+/// a (possibly empty) prefix of `0..len` and false for the rest.
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Returns the (left, right) index pairs that satisfy the range condition.
+///
+/// The right keys are sorted once, after which the matching right rows for each left row form a
+/// contiguous run of the sorted keys that is found by binary search.
+pub(super) fn range_join_indices(
+    left: &Table,
+    right: &Table,
+    condition: &RangeCondition,
+) -> DaftResult<(Series, Series)> {
+    let lkeys = left.eval_expression(&condition.left_expr)?;
+    let rkeys = right.eval_expression(&condition.right_expr)?;
+    let supertype = try_get_supertype(lkeys.data_type(), rkeys.data_type())?;
+    let lkeys = lkeys.cast(&supertype)?.as_physical()?;
+    let rkeys = rkeys.cast(&supertype)?.as_physical()?;
+
+    // Null keys never satisfy the condition, so only the non-null right keys are sorted.
+    let rvalid_idx =
+        UInt64Array::from(("right_indices", (0..right.len() as u64).collect::<Vec<_>>()))
+            .into_series()
+            .filter(rkeys.not_null()?.bool()?)?;
+    let rkeys = rkeys.take(&rvalid_idx)?;
+    let sort_idx = rkeys.argsort(false)?;
+    let rkeys = rkeys.take(&sort_idx)?;
+    let rsorted_idx = rvalid_idx.take(&sort_idx)?;
+    let rsorted_idx = rsorted_idx.u64()?.as_arrow();
+
+    let lkeys = lkeys.to_arrow();
+    let rkeys = rkeys.to_arrow();
+    let cmp = build_partial_compare_with_nulls(lkeys.as_ref(), rkeys.as_ref(), false)?;
+    let num_right = rkeys.len();
+
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    for l_idx in 0..lkeys.len() {
+        if lkeys.is_null(l_idx) {
+            continue;
+        }
+        // Sorted positions of the first right key that is >= and > the left key, respectively.
+        let lower = partition_point(num_right, |r| {
+            matches!(cmp(l_idx, r), Some(Ordering::Greater))
+        });
+        let upper = partition_point(num_right, |r| {
+            !matches!(cmp(l_idx, r), Some(Ordering::Less))
+        });
+        let matches = match condition.op {
+            Operator::Lt => upper..num_right,
+            Operator::LtEq => lower..num_right,
+            Operator::Gt => 0..lower,
+            Operator::GtEq => 0..upper,
+            _ => unreachable!("{} is not an inequality", condition.op),
+        };
+        for r in matches {
+            left_idx.push(l_idx as u64);
+            right_idx.push(rsorted_idx.value(r));
+        }
+    }
+    let left_series = UInt64Array::from(("left_indices", left_idx));
+    let right_series = UInt64Array::from(("right_indices", right_idx));
+    Ok((left_series.into_series(), right_series.into_series()))
+}
@@ -0,0 +1,42 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::series::Series;
+use daft_dsl::{functions::list::ListExpr, Expr, ExprRef, ELEMENT_NAME};
+
+use crate::Table;
+
+/// Evaluates `expr` over `elements`, which `expr` refers to with [`Expr::Element`].
+fn eval_element_expression(elements: &Series, expr: &Expr) -> DaftResult<Series> {
+    let table = Table::from_columns(vec![elements.rename(ELEMENT_NAME)])?;
+    table.eval_expression(expr)
+}
+
+impl Table {
+    /// Evaluates a higher-order list function, whose element expression has to be evaluated
+    /// over the elements of the lists rather than over this table.
+    pub(crate) fn eval_list_element_function(
+        &self,
+        list_expr: &ListExpr,
+        inputs: &[ExprRef],
+    ) -> DaftResult<Series> {
+        let input = match inputs {
+            [input] => self.eval_expression(input)?,
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected 1 input arg, got {}",
+                    inputs.len()
+                )))
+            }
+        };
+        match list_expr {
+            ListExpr::Eval(element_expr) => {
+                input.list_map_elements(|elements| eval_element_expression(elements, element_expr))
+            }
+            ListExpr::Filter(predicate) => {
+                input.list_filter_elements(|elements| eval_element_expression(elements, predicate))
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a higher-order list function, but got: {list_expr:?}"
+            ))),
+        }
+    }
+}
@@ -3,8 +3,10 @@ mod explode;
 mod groups;
 mod hash;
 mod joins;
+mod list;
 mod partition;
 mod search_sorted;
 mod sort;
+mod window;
 
 pub use joins::infer_join_schema;
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{arrow2::comparison::build_multi_array_is_equal, GroupIndices},
+    datatypes::{BooleanArray, UInt64Array},
+    series::{IntoSeries, Series},
+};
+use daft_dsl::{Expr, ExprRef, WindowExpr};
+
+use crate::Table;
+
+fn extract_window_expr(expr: &Expr) -> DaftResult<&WindowExpr> {
+    match expr {
+        Expr::Window(window_expr, _) => Ok(window_expr),
+        Expr::Alias(e, _) => extract_window_expr(e),
+        _ => Err(DaftError::ValueError(format!(
+            "Expected window expression, but got: {expr}"
+        ))),
+    }
+}
+
+/// Splits the (already sorted) rows of `keys` into runs of consecutive equal keys.
+fn contiguous_groups(keys: &[Series], num_rows: usize) -> DaftResult<GroupIndices> {
+    if num_rows == 0 {
+        return Ok(vec![]);
+    }
+    if keys.is_empty() {
+        return Ok(vec![(0..num_rows as u64).collect()]);
+    }
+    let comparator = build_multi_array_is_equal(keys, keys, true, true)?;
+    let mut groups = vec![];
+    let mut current = vec![0u64];
+    for i in 1..num_rows {
+        if !comparator(i - 1, i) {
+            groups.push(std::mem::take(&mut current));
+        }
+        current.push(i as u64);
+    }
+    groups.push(current);
+    Ok(groups)
+}
+
+impl Table {
+    /// Evaluates the window expressions over the partitions given by `partition_by`, with rows in
+    /// each partition ordered by `order_by`.
+    ///
+    /// The output is sorted by the partition and order keys, and contains all columns of this
+    /// table followed by the window columns, which replace any existing columns of the same name.
+    pub fn window(
+        &self,
+        window_exprs: &[ExprRef],
+        partition_by: &[ExprRef],
+        order_by: &[ExprRef],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        if order_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "order_by and descending length must match, got {} vs {}",
+                order_by.len(),
+                descending.len()
+            )));
+        }
+
+        let sort_keys = partition_by
+            .iter()
+            .chain(order_by.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let sorted = if sort_keys.is_empty() {
+            self.clone()
+        } else {
+            let sort_descending = std::iter::repeat(false)
+                .take(partition_by.len())
+                .chain(descending.iter().copied())
+                .collect::<Vec<_>>();
+            self.sort(&sort_keys, &sort_descending)?
+        };
+        let num_rows = sorted.len();
+
+        let partition_keys = sorted
+            .eval_expression_list(partition_by)?
+            .as_physical()?
+            .columns;
+        let partitions = contiguous_groups(&partition_keys, num_rows)?;
+
+        // Rows are peers if they share both the partition and the order keys.
+        let peer_keys = if order_by.is_empty() {
+            partition_keys
+        } else {
+            partition_keys
+                .into_iter()
+                .chain(sorted.eval_expression_list(order_by)?.as_physical()?.columns)
+                .collect()
+        };
+        let is_peer = build_multi_array_is_equal(&peer_keys, &peer_keys, true, true)?;
+
+        let mut window_columns = Vec::with_capacity(window_exprs.len());
+        for expr in window_exprs {
+            let name = expr.name()?;
+            let window_expr = extract_window_expr(expr)?;
+            let column = match window_expr {
+                WindowExpr::RowNumber | WindowExpr::Rank | WindowExpr::DenseRank => {
+                    let mut values = vec![0u64; num_rows];
+                    for group in partitions.iter() {
+                        let start = group[0] as usize;
+                        let mut rank = 1u64;
+                        let mut dense_rank = 1u64;
+                        for (offset, i) in group.iter().enumerate() {
+                            let i = *i as usize;
+                            if i > start && !is_peer(i - 1, i) {
+                                rank = offset as u64 + 1;
+                                dense_rank += 1;
+                            }
+                            values[i] = match window_expr {
+                                WindowExpr::RowNumber => offset as u64 + 1,
+                                WindowExpr::Rank => rank,
+                                _ => dense_rank,
+                            };
+                        }
+                    }
+                    UInt64Array::from((name, values)).into_series()
+                }
+                WindowExpr::Lag {
+                    expr: child,
+                    offset,
+                    default,
+                }
+                | WindowExpr::Lead {
+                    expr: child,
+                    offset,
+                    default,
+                } => {
+                    let is_lag = matches!(window_expr, WindowExpr::Lag { .. });
+                    let mut indices: Vec<Option<u64>> = vec![None; num_rows];
+                    for group in partitions.iter() {
+                        for (pos, i) in group.iter().enumerate() {
+                            let source_pos = if is_lag {
+                                pos.checked_sub(*offset)
+                            } else {
+                                pos.checked_add(*offset).filter(|p| *p < group.len())
+                            };
+                            indices[*i as usize] = source_pos.map(|p| group[p]);
+                        }
+                    }
+                    let in_range = indices.iter().map(|i| i.is_some()).collect::<Vec<_>>();
+                    let values = sorted.eval_expression(child)?;
+                    let indices = UInt64Array::from((
+                        "indices",
+                        Box::new(arrow2::array::PrimitiveArray::from(indices)),
+                    ));
+                    let shifted = values.take(&indices.into_series())?;
+                    let default = sorted.eval_expression(default)?;
+                    shifted.if_else(
+                        &default,
+                        &BooleanArray::from(("in_range", in_range.as_slice())).into_series(),
+                    )?
+                }
+                WindowExpr::CumSum(child) => {
+                    sorted.eval_expression(child)?.cumulative_sum(&partitions)?
+                }
+                WindowExpr::CumMin(child) => {
+                    sorted.eval_expression(child)?.cumulative_min(&partitions)?
+                }
+                WindowExpr::CumMax(child) => {
+                    sorted.eval_expression(child)?.cumulative_max(&partitions)?
+                }
+            };
+            window_columns.push(column.rename(name));
+        }
+
+        let window_names = window_columns
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect::<HashSet<_>>();
+        let columns = sorted
+            .columns
+            .into_iter()
+            .filter(|s| !window_names.contains(s.name()))
+            .chain(window_columns)
+            .collect::<Vec<_>>();
+        Self::from_columns(columns)
+    }
+}
@@ -6,6 +6,7 @@ use crate::ffi;
 use crate::Table;
 use common_error::DaftError;
 use daft_core::datatypes::Field;
+use daft_core::join::JoinType;
 use daft_core::schema::Schema;
 use daft_core::series::Series;
 
@@ -102,13 +103,19 @@ impl PyTable {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::ExprRef> = left_on.into_iter().map(|e| e.into()).collect();
         let right_exprs: Vec<daft_dsl::ExprRef> = right_on.into_iter().map(|e| e.into()).collect();
         py.allow_threads(|| {
             Ok(self
                 .table
-                .hash_join(&right.table, left_exprs.as_slice(), right_exprs.as_slice())?
+                .hash_join(
+                    &right.table,
+                    left_exprs.as_slice(),
+                    right_exprs.as_slice(),
+                    how,
+                )?
                 .into())
         })
     }
@@ -119,6 +126,7 @@ impl PyTable {
         right: &Self,
         left_on: Vec<PyExpr>,
         right_on: Vec<PyExpr>,
+        how: JoinType,
         is_sorted: bool,
     ) -> PyResult<Self> {
         let left_exprs: Vec<daft_dsl::ExprRef> = left_on.into_iter().map(|e| e.into()).collect();
@@ -130,12 +138,29 @@ impl PyTable {
                     &right.table,
                     left_exprs.as_slice(),
                     right_exprs.as_slice(),
+                    how,
                     is_sorted,
                 )?
                 .into())
         })
     }
 
+    pub fn nested_loop_join(
+        &self,
+        py: Python,
+        right: &Self,
+        predicate: Option<PyExpr>,
+        how: JoinType,
+    ) -> PyResult<Self> {
+        let predicate: Option<daft_dsl::ExprRef> = predicate.map(|e| e.into());
+        py.allow_threads(|| {
+            Ok(self
+                .table
+                .nested_loop_join(&right.table, predicate.as_ref(), how)?
+                .into())
+        })
+    }
+
     pub fn explode(&self, py: Python, to_explode: Vec<PyExpr>) -> PyResult<Self> {
         let converted_to_explode: Vec<daft_dsl::ExprRef> =
             to_explode.into_iter().map(|e| e.expr).collect();
@@ -5,6 +5,7 @@ from datetime import date, datetime
 import pyarrow as pa
 import pytest
 from pyarrow import dataset as pads
+from pyarrow import parquet as papq
 
 import daft
 from tests.conftest import assert_df_equals
@@ -194,6 +195,27 @@ def test_parquet_write_multifile_with_partitioning(tmp_path, smaller_parquet_tar
     assert readback["y"] == [y % 2 for y in data["x"]]
 
 
+@pytest.fixture()
+def smaller_parquet_target_row_group_size():
+    old_execution_config = daft.context.get_context().daft_execution_config
+    try:
+        daft.set_execution_config(parquet_target_row_group_size=1024)
+        yield
+    finally:
+        daft.set_execution_config(old_execution_config)
+
+
+def test_parquet_write_row_groups(tmp_path, smaller_parquet_target_row_group_size):
+    data = {"x": list(range(1_000)), "y": [str(i) for i in range(1_000)]}
+    df = daft.from_pydict(data)
+    [path] = df.write_parquet(tmp_path).to_pydict()["path"]
+
+    metadata = papq.ParquetFile(path).metadata
+    assert metadata.created_by == "Daft"
+    assert metadata.num_row_groups > 1
+    assert daft.read_parquet(path).to_pydict() == data
+
+
 def test_csv_write(tmp_path):
     df = daft.read_csv(COOKBOOK_DATA_CSV)
 
@@ -221,3 +243,30 @@ def test_csv_write_with_partitioning(tmp_path):
     assert_df_equals(df.to_pandas().fillna(""), read_back_pd_df.fillna(""))
 
     assert len(pd_df) == 5
+
+
+def test_csv_write_nulls_and_empty_strings(tmp_path):
+    df = daft.from_pydict({"x": ["a", "", None], "y": [1, 2, 3]})
+    [path] = df.write_csv(tmp_path).to_pydict()["path"]
+
+    with open(path) as f:
+        assert f.read() == 'x,y\na,1\n"",2\n,3\n'
+
+
+def test_json_write(tmp_path):
+    data = {"x": [1, 2, None], "y": ["a", "", None], "z": [[1], [], None]}
+    df = daft.from_pydict(data)
+    output_files = df.write_json(tmp_path)
+    assert len(output_files) == 1
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/*.json").to_pydict()
+    assert read_back == data
+
+
+def test_json_write_with_partitioning(tmp_path):
+    df = daft.from_pydict({"x": list(range(10)), "y": [i % 2 for i in range(10)]})
+    output_files = df.write_json(tmp_path, partition_cols=["y"])
+    assert sorted(output_files.to_pydict()["y"]) == [0, 1]
+
+    read_back = daft.read_json(tmp_path.as_posix() + "/**/*.json").sort("x").to_pydict()
+    assert read_back["x"] == list(range(10))
@@ -399,3 +399,163 @@ def test_agg_deprecation():
         df.collect()
 
         assert df.to_pydict() == {"b": [True, False], "a": [4, 2]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_count_distinct(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 3],
+            "values": ["1", "2", "2", None, "a", "a", None],
+        },
+        repartition=repartition_nparts,
+    )
+
+    global_df = daft_df.agg([col("values").approx_count_distinct().alias("distinct")])
+    assert global_df.to_pydict() == {"distinct": [3]}
+
+    grouped_df = daft_df.groupby("group").agg([col("values").approx_count_distinct().alias("distinct")]).sort("group")
+    assert grouped_df.to_pydict() == {"group": [1, 2, 3], "distinct": [2, 1, 0]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_approx_count_distinct_large(make_df, repartition_nparts):
+    daft_df = make_df({"values": [i % 5000 for i in range(20000)]}, repartition=repartition_nparts)
+
+    result = daft_df.agg([col("values").approx_count_distinct()]).to_pydict()["values"][0]
+    assert abs(result - 5000) / 5000 < 0.05
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_approx_percentiles(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1] * 100 + [2] * 100 + [3],
+            "values": [float(i) for i in range(1, 101)] + [float(i) * 10 for i in range(1, 101)] + [None],
+        },
+        repartition=repartition_nparts,
+    )
+
+    grouped_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("values").approx_percentiles(0.5).alias("p50"),
+                col("values").approx_percentiles([0.5, 0.99]).alias("p50_p99"),
+            ]
+        )
+        .sort("group")
+    )
+    result = grouped_df.to_pydict()
+
+    assert result["group"] == [1, 2, 3]
+    assert result["p50"][0] == pytest.approx(50, rel=0.02)
+    assert result["p50"][1] == pytest.approx(500, rel=0.02)
+    assert result["p50"][2] is None
+    assert result["p50_p99"][0] == pytest.approx([50, 99], rel=0.02)
+    assert result["p50_p99"][1] == pytest.approx([500, 990], rel=0.02)
+    assert result["p50_p99"][2] is None
+
+    global_df = daft_df.agg([col("values").approx_percentiles([0.0, 1.0]).alias("min_max")])
+    assert global_df.to_pydict()["min_max"][0] == pytest.approx([1, 1000], rel=0.02)
+
+
+def test_approx_percentiles_invalid(make_df):
+    daft_df = make_df({"values": [1, 2, 3]})
+
+    with pytest.raises(ValueError, match="between 0.0 and 1.0"):
+        daft_df.agg([col("values").approx_percentiles(1.5)]).collect()
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_stddev_variance_covariance_correlation(make_df, repartition_nparts):
+    xs = [1e9 + v for v in [4.0, 7.0, 13.0, 16.0, 2.0, 5.0, 9.0]]
+    ys = [2, 3, 7, 6, 1, 8, 4]
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 3],
+            "x": xs + [None],
+            "y": ys + [5],
+        },
+        repartition=repartition_nparts,
+    )
+
+    grouped_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("x").stddev().alias("stddev"),
+                col("x").variance(ddof=0).alias("variance"),
+                col("x").covariance(col("y")).alias("covariance"),
+                col("x").correlation(col("y")).alias("correlation"),
+            ]
+        )
+        .sort("group")
+    )
+    result = grouped_df.to_pydict()
+
+    assert result["group"] == [1, 2, 3]
+    for i, (lo, hi) in enumerate([(0, 4), (4, 7)]):
+        x, y = np.array(xs[lo:hi]), np.array(ys[lo:hi], dtype=np.float64)
+        assert result["stddev"][i] == pytest.approx(np.std(x, ddof=1))
+        assert result["variance"][i] == pytest.approx(np.var(x, ddof=0))
+        assert result["covariance"][i] == pytest.approx(np.cov(x, y, ddof=1)[0][1])
+        assert result["correlation"][i] == pytest.approx(np.corrcoef(x, y)[0][1])
+    assert result["stddev"][2] is None
+    assert result["variance"][2] is None
+    assert result["covariance"][2] is None
+    assert result["correlation"][2] is None
+
+    global_df = daft_df.agg([col("x").variance().alias("variance")])
+    assert global_df.to_pydict()["variance"][0] == pytest.approx(np.var(np.array(xs), ddof=1))
+
+
+def test_covariance_non_numeric(make_df):
+    daft_df = make_df({"x": [1, 2, 3], "y": ["a", "b", "c"]})
+
+    with pytest.raises(ValueError, match="covariance"):
+        daft_df.agg([col("x").covariance(col("y"))]).collect()
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_bool_and_bit_aggs(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 3],
+            "flag": [True, None, True, False, True, None],
+            "bits": [0b1100, 0b1010, None, 0b0110, 0b0011, None],
+        },
+        repartition=repartition_nparts,
+    )
+
+    grouped_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                col("flag").bool_and().alias("bool_and"),
+                col("flag").bool_or().alias("bool_or"),
+                col("bits").bit_and().alias("bit_and"),
+                col("bits").bit_or().alias("bit_or"),
+                col("bits").bit_xor().alias("bit_xor"),
+            ]
+        )
+        .sort("group")
+    )
+    assert grouped_df.to_pydict() == {
+        "group": [1, 2, 3],
+        "bool_and": [True, False, None],
+        "bool_or": [True, True, None],
+        "bit_and": [0b1000, 0b0010, None],
+        "bit_or": [0b1110, 0b0111, None],
+        "bit_xor": [0b0110, 0b0101, None],
+    }
+
+    global_df = daft_df.agg([col("flag").bool_and(), col("bits").bit_xor()])
+    assert global_df.to_pydict() == {"flag": [False], "bits": [0b1100 ^ 0b1010 ^ 0b0110 ^ 0b0011]}
+
+
+def test_bit_agg_non_integer(make_df):
+    daft_df = make_df({"x": [1.0, 2.0]})
+
+    with pytest.raises(ValueError, match="integer"):
+        daft_df.agg([col("x").bit_and()]).collect()
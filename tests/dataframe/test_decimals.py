@@ -4,8 +4,10 @@ import decimal
 import tempfile
 
 import pyarrow as pa
+import pytest
 
 import daft
+from daft import DataType, col
 
 PYARROW_GE_7_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (7, 0, 0)
 
@@ -45,3 +47,108 @@ def test_python_decimal() -> None:
 
     res = df.to_pydict()["decimal128"]
     assert str(res) == str(python_decimals)
+
+
+def _decimal_df(**columns) -> daft.DataFrame:
+    return daft.from_pydict(
+        {
+            name: pa.array([decimal.Decimal(v) if v is not None else None for v in values], type=pa.decimal128(5, 2))
+            for name, values in columns.items()
+        }
+    )
+
+
+def test_decimal_arithmetic() -> None:
+    df = _decimal_df(a=["1.10", "2.25", None], b=["0.50", "-1.00", "3.00"])
+    df = df.select(
+        (col("a") + col("b")).alias("add"),
+        (col("a") - col("b")).alias("sub"),
+        (col("a") * col("b")).alias("mul"),
+        (col("a") / col("b")).alias("div"),
+        (col("a") + 1).alias("add_int"),
+    )
+
+    assert df.schema()["add"].dtype == DataType.decimal128(6, 2)
+    assert df.schema()["sub"].dtype == DataType.decimal128(6, 2)
+    assert df.schema()["mul"].dtype == DataType.decimal128(11, 4)
+    assert df.schema()["div"].dtype == DataType.decimal128(13, 8)
+
+    res = df.to_pydict()
+    assert res["add"] == [decimal.Decimal("1.60"), decimal.Decimal("1.25"), None]
+    assert res["sub"] == [decimal.Decimal("0.60"), decimal.Decimal("3.25"), None]
+    assert res["mul"] == [decimal.Decimal("0.55"), decimal.Decimal("-2.25"), None]
+    assert res["div"] == [decimal.Decimal("2.2"), decimal.Decimal("-2.25"), None]
+    assert res["add_int"] == [decimal.Decimal("2.10"), decimal.Decimal("3.25"), None]
+
+
+def test_decimal_division_by_zero_is_null() -> None:
+    df = _decimal_df(a=["1.00", "2.00"], b=["0.00", "3.00"])
+    res = df.select(col("a") / col("b")).to_pydict()["a"]
+    assert res == [None, decimal.Decimal("0.66666667")]
+
+
+def test_decimal_comparisons() -> None:
+    df = _decimal_df(a=["1.10", "2.25", None], b=["1.1", "-1.00", "3.00"])
+    df = df.with_column("c", daft.lit(2))
+    res = df.select(
+        (col("a") == col("b")).alias("eq"),
+        (col("a") > col("b")).alias("gt"),
+        (col("a") < col("c")).alias("lt_int"),
+        (col("a") < col("c").cast(DataType.float64())).alias("lt_float"),
+    ).to_pydict()
+    assert res == {
+        "eq": [True, False, None],
+        "gt": [False, True, None],
+        "lt_int": [True, False, None],
+        "lt_float": [True, False, None],
+    }
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_decimal_aggregations(repartition_nparts) -> None:
+    df = _decimal_df(a=["1.10", "2.25", None, "3.00", "-0.50"])
+    df = df.with_column("group", (col("a") > 0).if_else(1, 2)).repartition(repartition_nparts)
+
+    aggs = [
+        col("a").sum().alias("sum"),
+        col("a").mean().alias("mean"),
+        col("a").min().alias("min"),
+        col("a").max().alias("max"),
+    ]
+    res = df.agg(*aggs)
+    assert res.schema()["sum"].dtype == DataType.decimal128(38, 2)
+    assert res.schema()["mean"].dtype == DataType.decimal128(38, 6)
+    assert res.to_pydict() == {
+        "sum": [decimal.Decimal("5.85")],
+        "mean": [decimal.Decimal("1.4625")],
+        "min": [decimal.Decimal("-0.50")],
+        "max": [decimal.Decimal("3.00")],
+    }
+
+    grouped = df.where(col("a").not_null()).groupby("group").agg(*aggs).sort("group").to_pydict()
+    assert grouped == {
+        "group": [1, 2],
+        "sum": [decimal.Decimal("6.35"), decimal.Decimal("-0.50")],
+        "mean": [decimal.Decimal("2.116667"), decimal.Decimal("-0.5")],
+        "min": [decimal.Decimal("1.10"), decimal.Decimal("-0.50")],
+        "max": [decimal.Decimal("3.00"), decimal.Decimal("-0.50")],
+    }
+
+
+def test_decimal_casts() -> None:
+    df = _decimal_df(a=["1.10", "-2.25", None])
+    df = df.with_column("s", daft.lit("1.255"))
+    res = df.select(
+        col("a").cast(DataType.string()).alias("to_str"),
+        col("a").cast(DataType.float64()).alias("to_float"),
+        col("a").cast(DataType.decimal128(10, 3)).alias("rescaled"),
+        col("s").cast(DataType.decimal128(5, 2)).alias("from_str"),
+        daft.lit("abc").cast(DataType.decimal128(5, 2)).alias("from_bad_str"),
+        daft.lit(7).cast(DataType.decimal128(5, 2)).alias("from_int"),
+    ).to_pydict()
+    assert res["to_str"] == ["1.10", "-2.25", None]
+    assert res["to_float"] == [1.1, -2.25, None]
+    assert [str(v) if v is not None else v for v in res["rescaled"]] == ["1.100", "-2.250", None]
+    assert res["from_str"] == [decimal.Decimal("1.26")] * 3
+    assert res["from_bad_str"] == [None] * 3
+    assert res["from_int"] == [decimal.Decimal("7.00")] * 3
@@ -5,6 +5,7 @@ import pytest
 
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
+from daft.expressions import col
 from tests.utils import sort_arrow_table
 
 
@@ -414,3 +415,111 @@ def test_inner_join_null_type_column(join_strategy, make_df):
 
     with pytest.raises((ExpressionTypeError, ValueError)):
         daft_df.join(daft_df2, on="id", how="inner", strategy=join_strategy)
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+@pytest.mark.parametrize("join_strategy", [None, "hash", "sort_merge", "sort_merge_aligned_boundaries"], indirect=True)
+@pytest.mark.parametrize(
+    "how,expected",
+    [
+        (
+            "left",
+            {
+                "values_left": ["a1", "b1", "c1", "d1", "d1", "e1"],
+                "values_right": [None, "b2", "b2", "c2", "d2", None],
+            },
+        ),
+        (
+            "right",
+            {
+                "values_left": ["b1", "c1", "d1", "d1", None, None],
+                "values_right": ["b2", "b2", "c2", "d2", "e2", "f2"],
+            },
+        ),
+        (
+            "outer",
+            {
+                "values_left": ["a1", "b1", "c1", "d1", "d1", "e1", None, None],
+                "values_right": [None, "b2", "b2", "c2", "d2", None, "e2", "f2"],
+            },
+        ),
+        ("semi", {"values_left": ["b1", "c1", "d1"]}),
+        ("anti", {"values_left": ["a1", "e1"]}),
+    ],
+)
+def test_non_inner_join(join_strategy, make_df, repartition_nparts, how, expected):
+    daft_df = make_df(
+        {
+            "id": [1, 2, 2, 3, None],
+            "values_left": ["a1", "b1", "c1", "d1", "e1"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df2 = make_df(
+        {
+            "id": [2, 3, 3, 4, None],
+            "values_right": ["b2", "c2", "d2", "e2", "f2"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.join(daft_df2, on="id", how=how, strategy=join_strategy).select(*expected.keys())
+
+    def rows(data):
+        return sorted(zip(*data.values()), key=str)
+
+    result = daft_df.to_pydict()
+    assert list(result.keys()) == list(expected.keys())
+    assert rows(result) == rows(expected)
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2])
+def test_cross_join(make_df, n_partitions: int):
+    df1 = make_df({"A": [1, 2, 3]}, repartition=n_partitions)
+    df2 = make_df({"A": [10, 20], "B": ["x", "y"]}, repartition=n_partitions)
+
+    daft_df = df1.cross_join(df2).sort(["A", "right.A"])
+
+    assert daft_df.to_pydict() == {
+        "A": [1, 1, 2, 2, 3, 3],
+        "right.A": [10, 20, 10, 20, 10, 20],
+        "B": ["x", "y", "x", "y", "x", "y"],
+    }
+
+
+@pytest.mark.parametrize("n_partitions", [1, 2])
+@pytest.mark.parametrize(
+    "how,expected",
+    [
+        ("inner", {"ts": [2, 5, 5], "start": [0, 0, 4]}),
+        ("left", {"ts": [2, 5, 5, 9], "start": [0, 0, 4, None]}),
+        ("right", {"ts": [2, 5, 5, None], "start": [0, 0, 4, 6]}),
+        ("outer", {"ts": [2, 5, 5, 9, None], "start": [0, 0, 4, None, 6]}),
+        ("semi", {"ts": [2, 5]}),
+        ("anti", {"ts": [9]}),
+    ],
+)
+def test_range_join(make_df, n_partitions: int, how, expected):
+    events = make_df({"ts": [2, 5, 9]}, repartition=n_partitions)
+    intervals = make_df({"start": [0, 4, 6], "end": [6, 8, 7]}, repartition=n_partitions)
+
+    predicate = (col("ts") >= col("start")) & (col("ts") < col("end"))
+    daft_df = events.join(intervals, how=how, predicate=predicate).select(*expected.keys())
+
+    def rows(data):
+        return sorted(zip(*data.values()), key=str)
+
+    result = daft_df.to_pydict()
+    assert list(result.keys()) == list(expected.keys())
+    assert rows(result) == rows(expected)
+
+
+def test_keyed_join_with_predicate(make_df):
+    df1 = make_df({"id": [1, 1, 2], "a": [1, 5, 3]})
+    df2 = make_df({"id": [1, 2, 2], "b": [2, 1, 4]})
+
+    daft_df = df1.join(df2, on="id", predicate=col("a") < col("b")).sort("a")
+
+    assert daft_df.to_pydict() == {"id": [1, 2], "a": [1, 3], "b": [2, 4]}
+
+    with pytest.raises(ValueError):
+        df1.join(df2, on="id", how="left", predicate=col("a") < col("b"))
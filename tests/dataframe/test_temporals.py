@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import tempfile
-from datetime import datetime, timedelta, timezone
+from datetime import date, datetime, timedelta, timezone
 
 import pyarrow as pa
 import pytest
@@ -265,3 +265,166 @@ def test_temporal_arithmetic_mismatch_granularity(t_timeunit, d_timeunit, timezo
     ]:
         with pytest.raises(ValueError):
             df.select(expression).collect()
+
+
+def test_temporal_arithmetic_date_difference() -> None:
+    df = daft.from_pydict(
+        {
+            "end": [date(2021, 1, 3), date(2020, 12, 31), None],
+            "start": [date(2021, 1, 1), date(2021, 1, 1), date(2021, 1, 1)],
+        }
+    )
+    result = df.select((df["end"] - df["start"]).alias("diff")).to_pydict()
+    assert result["diff"] == [timedelta(days=2), timedelta(days=-1), None]
+
+
+def test_temporal_arithmetic_duration_with_integer() -> None:
+    df = daft.from_pydict(
+        {
+            "duration": [timedelta(seconds=10), timedelta(seconds=-9), None],
+            "factor": [3, 0, 2],
+        }
+    )
+    df = df.select(
+        (df["duration"] * df["factor"]).alias("mul"),
+        (df["factor"] * df["duration"]).alias("rmul"),
+        (df["duration"] / df["factor"]).alias("div"),
+    ).collect()
+
+    assert df.schema()["mul"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    assert df.schema()["rmul"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    assert df.schema()["div"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    result = df.to_pydict()
+    assert result["mul"] == [timedelta(seconds=30), timedelta(0), None]
+    assert result["rmul"] == [timedelta(seconds=30), timedelta(0), None]
+    assert result["div"] == [timedelta(seconds=10) / 3, None, None]
+
+
+def test_temporal_arithmetic_integer_times_duration_keeps_lhs_name() -> None:
+    df = daft.from_pydict({"factor": [3, 0], "duration": [timedelta(seconds=10), timedelta(seconds=-9)]})
+    df = df.select(df["factor"] * df["duration"]).collect()
+
+    assert df.to_pydict() == {"factor": [timedelta(seconds=30), timedelta(0)]}
+
+
+def test_duration_comparisons_with_mismatched_units() -> None:
+    pa_table = pa.Table.from_pydict(
+        {
+            "seconds": pa.array([1, 2, None], pa.duration("s")),
+            "millis": pa.array([1000, 1000, 1000], pa.duration("ms")),
+        }
+    )
+    df = daft.from_arrow(pa_table)
+    result = df.select(
+        (df["seconds"] == df["millis"]).alias("eq"),
+        (df["seconds"] > df["millis"]).alias("gt"),
+        (df["seconds"] <= df["millis"]).alias("le"),
+    ).to_pydict()
+    assert result == {"eq": [True, False, None], "gt": [False, True, None], "le": [True, False, None]}
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_duration_sum_and_mean(repartition_nparts) -> None:
+    df = daft.from_pydict(
+        {
+            "group": [1, 1, 1, 2, 2],
+            "duration": [timedelta(days=1), timedelta(days=2), None, timedelta(hours=1), timedelta(hours=2)],
+        }
+    ).repartition(repartition_nparts)
+
+    aggs = [df["duration"].sum().alias("sum"), df["duration"].mean().alias("mean")]
+    result = df.agg(*aggs)
+    assert result.schema()["sum"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    assert result.schema()["mean"].dtype == daft.DataType.duration(daft.TimeUnit.us())
+    assert result.to_pydict() == {"sum": [timedelta(days=3, hours=3)], "mean": [timedelta(hours=18, minutes=45)]}
+
+    grouped = df.groupby("group").agg(*aggs).sort("group").to_pydict()
+    assert grouped == {
+        "group": [1, 2],
+        "sum": [timedelta(days=3), timedelta(hours=3)],
+        "mean": [timedelta(days=1, hours=12), timedelta(hours=1, minutes=30)],
+    }
+
+
+def _wall_clock_us(dt: datetime) -> int:
+    return int((dt - datetime(1970, 1, 1)).total_seconds() * 1_000_000)
+
+
+def test_hour_honors_named_timezone() -> None:
+    utc = datetime(2021, 7, 1, 12, 0)
+    df = daft.from_arrow(
+        pa.table({"ts": pa.array([_wall_clock_us(utc), None], pa.timestamp("us", tz="America/New_York"))})
+    )
+    result = df.select(df["ts"].dt.hour(), df["ts"].dt.date().alias("date")).to_pydict()
+    assert result == {"ts": [8, None], "date": [date(2021, 7, 1), None]}
+
+
+def test_convert_time_zone() -> None:
+    df = daft.from_arrow(
+        pa.table({"ts": pa.array([_wall_clock_us(datetime(2021, 1, 1, 3, 0)), None], pa.timestamp("us", tz="UTC"))})
+    )
+    df = df.select(df["ts"].dt.convert_time_zone("America/New_York"))
+    assert df.schema()["ts"].dtype == daft.DataType.timestamp("us", "America/New_York")
+
+    result = df.select(df["ts"].dt.hour().alias("hour"), df["ts"].cast(daft.DataType.int64()).alias("raw")).to_pydict()
+    assert result == {"hour": [22, None], "raw": [_wall_clock_us(datetime(2021, 1, 1, 3, 0)), None]}
+
+
+def test_convert_time_zone_requires_timezone_aware_input() -> None:
+    df = daft.from_pydict({"ts": [datetime(2021, 1, 1)]})
+    with pytest.raises(ValueError):
+        df.select(df["ts"].dt.convert_time_zone("UTC")).collect()
+
+
+def test_replace_time_zone() -> None:
+    wall_clock = datetime(2021, 1, 1, 3, 0)
+    df = daft.from_pydict({"ts": [wall_clock, None]})
+    df = df.select(df["ts"].dt.replace_time_zone("America/New_York"))
+    assert df.schema()["ts"].dtype == daft.DataType.timestamp("us", "America/New_York")
+
+    df = df.select(
+        df["ts"].cast(daft.DataType.int64()).alias("raw"),
+        df["ts"].dt.replace_time_zone(None).cast(daft.DataType.int64()).alias("naive"),
+    )
+    assert df.to_pydict() == {
+        "raw": [_wall_clock_us(wall_clock + timedelta(hours=5)), None],
+        "naive": [_wall_clock_us(wall_clock), None],
+    }
+
+
+@pytest.mark.parametrize(
+    "ambiguous, expected_utc",
+    [
+        ("earliest", datetime(2021, 11, 7, 5, 30)),
+        ("latest", datetime(2021, 11, 7, 6, 30)),
+        ("null", None),
+    ],
+)
+def test_replace_time_zone_ambiguous(ambiguous, expected_utc) -> None:
+    df = daft.from_pydict({"ts": [datetime(2021, 11, 7, 1, 30)]})
+    df = df.select(
+        df["ts"].dt.replace_time_zone("America/New_York", ambiguous=ambiguous).cast(daft.DataType.int64()).alias("raw")
+    )
+    expected = None if expected_utc is None else _wall_clock_us(expected_utc)
+    assert df.to_pydict() == {"raw": [expected]}
+
+
+def test_replace_time_zone_ambiguous_raises() -> None:
+    df = daft.from_pydict({"ts": [datetime(2021, 11, 7, 1, 30)]})
+    with pytest.raises(ValueError):
+        df.select(df["ts"].dt.replace_time_zone("America/New_York")).collect()
+
+
+def test_replace_time_zone_nonexistent() -> None:
+    df = daft.from_pydict({"ts": [datetime(2021, 3, 14, 2, 30)]})
+    nulled = df.select(df["ts"].dt.replace_time_zone("America/New_York", ambiguous="null").cast(daft.DataType.int64()))
+    assert nulled.to_pydict() == {"ts": [None]}
+
+    with pytest.raises(ValueError):
+        df.select(df["ts"].dt.replace_time_zone("America/New_York", ambiguous="earliest")).collect()
+
+
+def test_replace_time_zone_invalid_ambiguous() -> None:
+    df = daft.from_pydict({"ts": [datetime(2021, 1, 1)]})
+    with pytest.raises(ValueError):
+        df.select(df["ts"].dt.replace_time_zone("UTC", ambiguous="bogus")).collect()
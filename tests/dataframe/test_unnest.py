@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+
+
+def test_unnest(make_df):
+    df = make_df({"s": [{"x": 1, "y": "a"}, None, {"x": 3, "y": None}], "z": [1.0, 2.0, 3.0]})
+    df = df.unnest("s")
+    assert df.column_names == ["x", "y", "z"]
+    assert df.to_pydict() == {"x": [1, None, 3], "y": ["a", None, None], "z": [1.0, 2.0, 3.0]}
+
+
+def test_unnest_then_select(make_df):
+    df = make_df({"a": [1, 2], "s": [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}]})
+    df = df.unnest("s").select(col("a"), col("x") + 1)
+    assert df.to_pydict() == {"a": [1, 2], "x": [2, 3]}
+
+
+def test_unnest_multiple_cols(make_df):
+    df = make_df({"s": [{"x": 1}, {"x": 2}], "t": [{"y": "a"}, {"y": "b"}]})
+    df = df.unnest("s", "t")
+    assert df.to_pydict() == {"x": [1, 2], "y": ["a", "b"]}
+
+
+def test_unnest_bad_col_type(make_df):
+    df = make_df({"a": [1, 2]})
+    with pytest.raises(ValueError):
+        df.unnest("a")
+
+
+def test_unnest_name_clash(make_df):
+    df = make_df({"x": [1, 2], "s": [{"x": 1}, {"x": 2}]})
+    with pytest.raises(ValueError):
+        df.unnest("s")
+
+
+def test_unnest_missing_col(make_df):
+    df = make_df({"s": [{"x": 1}, {"x": 2}]})
+    with pytest.raises(ValueError):
+        df.unnest("t")
@@ -0,0 +1,172 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col, dense_rank, rank, row_number
+
+
+def test_row_number_rank_dense_rank_peers(make_df) -> None:
+    df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4, 5],
+            "group": ["a", "a", "a", "a", "b", "b"],
+            "x": [1, 2, 2, 3, 5, 5],
+        }
+    )
+    df = df.with_window_columns(
+        {
+            "rank": rank(partition_by="group", order_by="x"),
+            "dense_rank": dense_rank(partition_by="group", order_by="x"),
+        }
+    )
+    df = df.with_window_columns({"rn": row_number(partition_by="group", order_by=["x", "id"])})
+
+    assert df.sort("id").to_pydict() == {
+        "id": [0, 1, 2, 3, 4, 5],
+        "group": ["a", "a", "a", "a", "b", "b"],
+        "x": [1, 2, 2, 3, 5, 5],
+        "rank": [1, 2, 2, 4, 1, 1],
+        "dense_rank": [1, 2, 2, 3, 1, 1],
+        "rn": [1, 2, 3, 4, 1, 2],
+    }
+
+
+def test_row_number_descending(make_df) -> None:
+    df = make_df({"id": [0, 1, 2, 3, 4, 5], "group": ["a", "a", "a", "a", "b", "b"]})
+    df = df.with_window_columns({"rn": row_number(partition_by="group", order_by="id", desc=True)})
+
+    assert df.sort("id").to_pydict()["rn"] == [4, 3, 2, 1, 2, 1]
+
+
+def test_lag_lead_defaults(make_df) -> None:
+    df = make_df(
+        {
+            "id": [0, 1, 2, 3, 4, 5],
+            "group": ["a", "a", "a", "a", "b", "b"],
+            "x": [1, 2, 2, 3, 5, 5],
+        }
+    )
+    spec = dict(partition_by="group", order_by="id")
+    df = df.with_window_columns(
+        {
+            "lag": col("x").lag(1, **spec),
+            "lag_default": col("x").lag(1, default=0, **spec),
+            "lead": col("x").lead(1, **spec),
+            "lead_default": col("x").lead(2, default=-1, **spec),
+        }
+    )
+
+    result = df.sort("id").to_pydict()
+    assert result["lag"] == [None, 1, 2, 2, None, 5]
+    assert result["lag_default"] == [0, 1, 2, 2, 0, 5]
+    assert result["lead"] == [2, 2, 3, None, 5, None]
+    assert result["lead_default"] == [2, 3, -1, -1, -1, -1]
+
+
+@pytest.mark.parametrize(
+    "desc, expected",
+    [
+        # Nulls sort last in ascending order and first in descending order, and are peers of each other.
+        (False, [1, 3, 3, 2]),
+        (True, [4, 1, 1, 3]),
+    ],
+)
+def test_rank_null_ordering(make_df, desc, expected) -> None:
+    df = make_df({"id": [0, 1, 2, 3], "x": [1, None, None, 2]})
+    df = df.with_window_columns({"rank": rank(order_by="x", desc=desc)})
+
+    assert df.sort("id").to_pydict()["rank"] == expected
+
+
+def test_cumulative_skips_nulls(make_df) -> None:
+    df = make_df(
+        {
+            "group": ["a", "a", "a", "b", "b"],
+            "t": [0, 1, 2, 0, 1],
+            "v": [2, None, 1, None, 4],
+        }
+    )
+    spec = dict(partition_by="group", order_by="t")
+    df = df.with_window_columns(
+        {
+            "cum_sum": col("v").cum_sum(**spec),
+            "cum_min": col("v").cum_min(**spec),
+            "cum_max": col("v").cum_max(**spec),
+        }
+    )
+
+    result = df.sort(["group", "t"]).to_pydict()
+    assert result["cum_sum"] == [2, 2, 3, None, 4]
+    assert result["cum_min"] == [2, 2, 1, None, 4]
+    assert result["cum_max"] == [2, 2, 2, None, 4]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3, 7])
+@pytest.mark.parametrize("repartition_columns", [[], ["group"]])
+def test_window_multiple_partitions(make_df, repartition_nparts, repartition_columns) -> None:
+    items = list(range(100))
+    df = make_df(
+        {"group": [i % 7 for i in items], "x": items},
+        repartition=repartition_nparts,
+        repartition_columns=repartition_columns,
+    )
+    df = df.with_window_columns(
+        {
+            "rn": row_number(partition_by="group", order_by="x"),
+            "cum_sum": col("x").cum_sum(partition_by="group", order_by="x"),
+        }
+    )
+
+    result = df.sort("x").to_pydict()
+    assert result["rn"] == [i // 7 + 1 for i in items]
+    assert result["cum_sum"] == [sum(range(i % 7, i + 1, 7)) for i in items]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_window_without_partition_by(make_df, repartition_nparts) -> None:
+    items = list(range(20))
+    df = make_df({"x": items[::-1]}, repartition=repartition_nparts)
+    df = df.with_window_columns({"rn": row_number(order_by="x")})
+
+    assert df.sort("x").to_pydict() == {"x": items, "rn": [i + 1 for i in items]}
+
+
+def test_window_columns_pruned_by_projection(make_df) -> None:
+    df = make_df({"group": ["a", "a", "b"], "x": [1, 2, 3], "y": [4, 5, 6]})
+    df = df.with_window_columns(
+        {
+            "rn": row_number(partition_by="group", order_by="x"),
+            "prev_y": col("y").lag(1, partition_by="group", order_by="x"),
+        }
+    )
+
+    assert df.select("x", "rn").sort("x").to_pydict() == {"x": [1, 2, 3], "rn": [1, 2, 1]}
+
+
+def test_window_columns_replace_existing(make_df) -> None:
+    df = make_df({"x": [3, 1, 2]})
+    df = df.with_window_columns({"x": col("x").cum_sum(order_by="x")})
+
+    assert sorted(df.to_pydict()["x"]) == [1, 3, 6]
+
+
+def test_window_specs_must_match(make_df) -> None:
+    df = make_df({"group": ["a", "a", "b"], "x": [1, 2, 3]})
+
+    with pytest.raises(ValueError, match="same window spec"):
+        df.with_window_columns(
+            {
+                "rn": row_number(partition_by="group", order_by="x"),
+                "rank": rank(order_by="x"),
+            }
+        )
+
+
+def test_window_expressions_rejected_outside_window(make_df) -> None:
+    df = make_df({"x": [1, 2, 3]})
+
+    with pytest.raises(ValueError, match="with_window_columns"):
+        df.with_column("rn", row_number(order_by="x"))
+
+    with pytest.raises(ValueError, match="with_window_columns"):
+        df.where(col("x").lag(1, order_by="x") > 1)
@@ -5,6 +5,7 @@ import pytest
 from pyarrow import parquet as pq
 
 import daft
+from daft.table import MicroPartition
 
 from ..conftest import minio_create_bucket
 
@@ -60,3 +61,28 @@ def test_minio_parquet_ignore_marker_files(minio_io_config):
 
         read = daft.read_parquet(f"s3://{bucket_name}/**", io_config=minio_io_config)
         assert read.to_pydict() == {"x": [1, 2, 3, 4] * 3}
+
+
+@pytest.mark.integration()
+@pytest.mark.parametrize(
+    "pred",
+    [
+        daft.col("x") == 7,
+        daft.col("x") == 9_999,
+        daft.col("x").is_in([3, 5_000, 100_000]),
+        (daft.col("x") > 2_500) & (daft.col("x") < 2_600),
+        daft.col("x") < 0,
+    ],
+)
+def test_minio_parquet_page_index_pushdowns(minio_io_config, pred):
+    bucket_name = "data-engineering-prod"
+    with minio_create_bucket(minio_io_config, bucket_name=bucket_name) as fs:
+        path = "s3://data-engineering-prod/page_index.parquet"
+        pa_table = pa.Table.from_pydict({"x": list(range(10_000)), "y": [str(i) for i in range(10_000)]})
+        pq.write_table(
+            pa_table, path, filesystem=fs, row_group_size=4_000, data_page_size=1_024, write_page_index=True
+        )
+
+        with_pushdown = MicroPartition.read_parquet(path, predicate=pred, io_config=minio_io_config)
+        after = MicroPartition.read_parquet(path, io_config=minio_io_config).filter([pred])
+        assert with_pushdown.to_arrow() == after.to_arrow()
@@ -1,6 +1,8 @@
 from __future__ import annotations
 
 import decimal
+import hashlib
+import struct
 from datetime import date, datetime, time
 
 import numpy as np
@@ -232,3 +234,83 @@ def test_murmur3_32_hash_decimal_full_scaled():
     arr = Series.from_pylist([decimal.Decimal(".00001420"), None])
     hashes = arr.murmur3_32()
     assert hashes.to_pylist() == [-500754589, None]
+
+
+@pytest.mark.parametrize(
+    "hash_function, reference",
+    [
+        ("sha1", lambda b: hashlib.sha1(b).hexdigest()),
+        ("sha256", lambda b: hashlib.sha256(b).hexdigest()),
+        ("md5", lambda b: hashlib.md5(b).hexdigest()),
+        ("xxhash64", lambda b: xxhash.xxh64_intdigest(b)),
+    ],
+)
+def test_hash_function_str_with_reference(hash_function, reference):
+    arr = Series.from_pylist(["foo", "bar", None, ""])
+    hashed = arr.hash(hash_function=hash_function)
+    assert hashed.to_pylist() == [reference(b"foo"), reference(b"bar"), None, reference(b"")]
+
+
+def test_hash_function_binary_with_reference():
+    arr = Series.from_pylist([b"foo", None])
+    assert arr.hash(hash_function="sha256").to_pylist() == [hashlib.sha256(b"foo").hexdigest(), None]
+
+
+def test_hash_function_xxhash64_seed():
+    arr = Series.from_pylist([1, 2, 3])
+    hashed = arr.hash(seed=42, hash_function="xxhash64")
+    assert hashed.datatype() == DataType.uint64()
+    assert hashed.to_pylist() == [xxhash.xxh64_intdigest(v.to_bytes(8, "little", signed=True), 42) for v in [1, 2, 3]]
+
+
+@pytest.mark.parametrize(
+    "data",
+    [
+        [34, -1, None],
+        ["iceberg", None],
+        [date(2017, 11, 16), None],
+        [decimal.Decimal("14.20"), None],
+    ],
+)
+def test_hash_function_murmur3_32_matches_murmur3_32(data):
+    arr = Series.from_pylist(data)
+    assert arr.hash(hash_function="murmur3_32").to_pylist() == arr.murmur3_32().to_pylist()
+
+
+def test_hash_function_integer_widths_agree():
+    arr = Series.from_pylist([1, 2, None])
+    expected = arr.cast(DataType.int64()).hash(hash_function="sha1").to_pylist()
+    for dtype in [DataType.int8(), DataType.uint16(), DataType.int32(), DataType.uint64()]:
+        assert arr.cast(dtype).hash(hash_function="sha1").to_pylist() == expected
+
+
+def test_hash_function_float_widths_agree():
+    arr = Series.from_pylist([1.5, -2.0, None])
+    expected = [
+        hashlib.sha1(struct.pack("<d", 1.5)).hexdigest(),
+        hashlib.sha1(struct.pack("<d", -2.0)).hexdigest(),
+        None,
+    ]
+    for dtype in [DataType.float32(), DataType.float64()]:
+        assert arr.cast(dtype).hash(hash_function="sha1").to_pylist() == expected
+
+
+def test_hash_function_nested():
+    arr = Series.from_pylist([[1, 2], [1, 2], [12], [1, None], [], None])
+    hashed = arr.hash(hash_function="xxhash64").to_pylist()
+    assert hashed[0] == hashed[1]
+    assert len(set(hashed[1:5])) == 4
+    assert hashed[5] is None
+
+    structs = Series.from_pylist([{"a": 1, "b": "x"}, {"a": 1, "b": "x"}, {"a": 1, "b": None}])
+    hashed = structs.hash(hash_function="md5").to_pylist()
+    assert hashed[0] == hashed[1]
+    assert hashed[0] != hashed[2]
+
+
+def test_hash_function_invalid():
+    arr = Series.from_pylist(["foo"])
+    with pytest.raises(ValueError):
+        arr.hash(hash_function="crc32")
+    with pytest.raises(ValueError):
+        arr.hash(seed=1, hash_function="sha256")
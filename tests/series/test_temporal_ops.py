@@ -357,3 +357,115 @@ def test_series_timestamp_truncate_operation_invalid_relative_to() -> None:
     with pytest.raises(ValueError):
         # Start time must be a series of timestamps
         input_series.dt.truncate("1 second", Series.from_pylist([1]))
+
+
+def test_series_date_strftime() -> None:
+    from datetime import date
+
+    input_series = Series.from_pylist([date(2024, 2, 29), None, date(1969, 12, 31)])
+    formatted = input_series.dt.strftime("%d/%m/%Y")
+
+    assert formatted.datatype() == DataType.string()
+    assert formatted.to_pylist() == ["29/02/2024", None, "31/12/1969"]
+
+
+@pytest.mark.parametrize(
+    ["tz", "expected"],
+    [
+        (None, "2024-01-01 12:30:00"),
+        ("+08:00", "2024-01-01 20:30:00 +0800"),
+        ("Asia/Singapore", "2024-01-01 20:30:00 +0800"),
+    ],
+)
+def test_series_timestamp_strftime(tz, expected) -> None:
+    from datetime import datetime
+
+    fmt = "%Y-%m-%d %H:%M:%S" if tz is None else "%Y-%m-%d %H:%M:%S %z"
+    input_series = Series.from_pylist([datetime(2024, 1, 1, 12, 30), None]).cast(
+        DataType.timestamp(TimeUnit.us(), None)
+    )
+    if tz is not None:
+        input_series = input_series.dt.replace_time_zone("UTC").dt.convert_time_zone(tz)
+
+    assert input_series.dt.strftime(fmt).to_pylist() == [expected, None]
+
+
+def test_series_strftime_invalid_format() -> None:
+    from datetime import date, datetime
+
+    with pytest.raises(ValueError):
+        Series.from_pylist([date(2024, 1, 1)]).dt.strftime("%H:%M")
+    with pytest.raises(ValueError):
+        Series.from_pylist([datetime(2024, 1, 1)]).dt.strftime("%Y-%m-%d %z")
+    with pytest.raises(ValueError):
+        Series.from_pylist([datetime(2024, 1, 1)]).dt.strftime("%Q")
+
+
+@pytest.mark.parametrize("tz", [None, "UTC", "+08:00", "Asia/Singapore"])
+def test_series_timestamp_time_components(tz) -> None:
+    from datetime import datetime
+
+    input_series = Series.from_pylist([datetime(2024, 1, 1, 13, 45, 30, 123456), None]).cast(
+        DataType.timestamp(TimeUnit.us(), None)
+    )
+    hour_offset = 0
+    if tz is not None:
+        input_series = input_series.dt.replace_time_zone("UTC").dt.convert_time_zone(tz)
+        hour_offset = 8 if tz != "UTC" else 0
+
+    assert input_series.dt.hour().to_pylist() == [(13 + hour_offset) % 24, None]
+    assert input_series.dt.minute().to_pylist() == [45, None]
+    assert input_series.dt.second().to_pylist() == [30, None]
+    assert input_series.dt.millisecond().to_pylist() == [123, None]
+
+    times = input_series.dt.time()
+    assert times.datatype() == DataType.time(TimeUnit.us())
+    assert times.dt.hour().to_pylist() == [(13 + hour_offset) % 24, None]
+
+
+def test_series_time_components() -> None:
+    from datetime import time
+
+    input_series = Series.from_pylist([time(1, 2, 3, 4000), None, time(23, 59, 59, 999999)])
+
+    assert input_series.dt.hour().to_pylist() == [1, None, 23]
+    assert input_series.dt.minute().to_pylist() == [2, None, 59]
+    assert input_series.dt.second().to_pylist() == [3, None, 59]
+    assert input_series.dt.millisecond().to_pylist() == [4, None, 999]
+    assert input_series.dt.time().to_pylist() == input_series.to_pylist()
+
+
+def test_series_timestamp_nanosecond_time() -> None:
+    from datetime import datetime
+
+    input_series = Series.from_pylist([datetime(2024, 1, 1, 13, 45, 30)]).cast(DataType.timestamp(TimeUnit.ns()))
+    assert input_series.dt.time().datatype() == DataType.time(TimeUnit.ns())
+
+
+@pytest.mark.parametrize(
+    ["input", "day_of_year", "week_of_year", "quarter"],
+    [
+        ((2024, 1, 1), 1, 1, 1),
+        ((2023, 1, 1), 1, 52, 1),
+        ((2020, 12, 31), 366, 53, 4),
+        ((2024, 4, 1), 92, 14, 2),
+        ((2024, 9, 30), 274, 40, 3),
+    ],
+)
+def test_series_date_calendar_components(input, day_of_year, week_of_year, quarter) -> None:
+    from datetime import date, datetime
+
+    dates = Series.from_pylist([date(*input), None])
+    timestamps = Series.from_pylist([datetime(*input, 12), None])
+
+    for s in (dates, timestamps):
+        assert s.dt.day_of_year().to_pylist() == [day_of_year, None]
+        assert s.dt.week_of_year().to_pylist() == [week_of_year, None]
+        assert s.dt.quarter().to_pylist() == [quarter, None]
+
+
+def test_series_date_has_no_time_components() -> None:
+    from datetime import date
+
+    with pytest.raises(ValueError):
+        Series.from_pylist([date(2024, 1, 1)]).dt.minute()
@@ -3,7 +3,7 @@ from __future__ import annotations
 import pyarrow as pa
 import pytest
 
-from daft import Series
+from daft import DataType, Series, TimeUnit
 
 
 @pytest.mark.parametrize(
@@ -807,3 +807,109 @@ def test_series_utf8_replace_bad_regex_pattern() -> None:
     replacement = Series.from_arrow(pa.array([" "]))
     with pytest.raises(ValueError):
         s.str.replace(pattern, replacement, regex=True)
+
+
+def test_series_utf8_to_date() -> None:
+    from datetime import date
+
+    s = Series.from_arrow(pa.array(["29/02/2024", None, "31/12/1969"]))
+    result = s.str.to_date("%d/%m/%Y")
+
+    assert result.datatype() == DataType.date()
+    assert result.to_pylist() == [date(2024, 2, 29), None, date(1969, 12, 31)]
+
+
+def test_series_utf8_to_date_on_error() -> None:
+    from datetime import date
+
+    s = Series.from_arrow(pa.array(["2024-01-01", "not a date", "2023-02-30"]))
+    assert s.str.to_date("%Y-%m-%d", on_error="null").to_pylist() == [date(2024, 1, 1), None, None]
+
+    with pytest.raises(ValueError):
+        s.str.to_date("%Y-%m-%d")
+
+
+def test_series_utf8_to_datetime() -> None:
+    from datetime import datetime
+
+    s = Series.from_arrow(pa.array(["2024-01-01 12:30:00", None, "2024-01-01"]))
+    result = s.str.to_datetime("%Y-%m-%d %H:%M:%S", on_error="null")
+
+    assert result.datatype() == DataType.timestamp(TimeUnit.us(), None)
+    assert result.to_pylist() == [datetime(2024, 1, 1, 12, 30), None, None]
+
+
+def test_series_utf8_to_datetime_with_timezone() -> None:
+    s = Series.from_arrow(pa.array(["2024-01-01 12:30", "2024-01-01 12:30 +0100"]))
+
+    localized = s.str.to_datetime("%Y-%m-%d %H:%M", timezone="Asia/Singapore", on_error="null")
+    assert localized.datatype() == DataType.timestamp(TimeUnit.us(), "Asia/Singapore")
+    assert localized.dt.strftime("%H:%M %z").to_pylist() == ["12:30 +0800", None]
+
+    with_offset = s.str.to_datetime("%Y-%m-%d %H:%M %z", timezone="Asia/Singapore", on_error="null")
+    assert with_offset.dt.strftime("%H:%M %z").to_pylist() == [None, "19:30 +0800"]
+
+    naive = s.str.to_datetime("%Y-%m-%d %H:%M %z", on_error="null")
+    assert naive.dt.strftime("%H:%M").to_pylist() == [None, "11:30"]
+
+
+def test_series_utf8_to_datetime_roundtrip_with_strftime() -> None:
+    fmt = "%Y-%m-%dT%H:%M:%S%.6f"
+    s = Series.from_arrow(pa.array(["2024-03-10T02:30:00.123456", "1969-07-20T20:17:40.000000"]))
+    assert s.str.to_datetime(fmt).dt.strftime(fmt).to_pylist() == s.to_pylist()
+
+
+def test_series_utf8_pad_and_repeat() -> None:
+    s = Series.from_arrow(pa.array(["a", None, "abcd"]))
+    length = Series.from_arrow(pa.array([3], type=pa.int64()))
+    pad = Series.from_arrow(pa.array(["-"]))
+    assert s.str.lpad(length, pad).to_pylist() == ["--a", None, "abc"]
+    assert s.str.rpad(length, pad).to_pylist() == ["a--", None, "abc"]
+    n = Series.from_arrow(pa.array([2, 1, 0], type=pa.uint8()))
+    assert s.str.repeat(n).to_pylist() == ["aa", None, ""]
+
+
+def test_series_utf8_substr() -> None:
+    s = Series.from_arrow(pa.array(["hello", None, "ab"]))
+    start = Series.from_arrow(pa.array([1], type=pa.int64()))
+    assert s.str.substr(start).to_pylist() == ["ello", None, "b"]
+    length = Series.from_arrow(pa.array([2], type=pa.int64()))
+    assert s.str.substr(start, length).to_pylist() == ["el", None, "b"]
+
+
+def test_series_utf8_count_matches_and_like() -> None:
+    s = Series.from_arrow(pa.array(["abab", None, "ABA"]))
+    pattern = Series.from_arrow(pa.array(["ab"]))
+    assert s.str.count_matches(pattern).to_pylist() == [2, None, 0]
+    like_pattern = Series.from_arrow(pa.array(["ab%"]))
+    assert s.str.like(like_pattern).to_pylist() == [True, None, False]
+    assert s.str.ilike(like_pattern).to_pylist() == [True, None, True]
+
+
+def test_series_utf8_strip_chars_and_normalize() -> None:
+    s = Series.from_arrow(pa.array(["xxaxx", None, "ﬁ"]))
+    chars = Series.from_arrow(pa.array(["x"]))
+    assert s.str.strip(chars).to_pylist() == ["a", None, "ﬁ"]
+    assert s.str.lstrip(chars).to_pylist() == ["axx", None, "ﬁ"]
+    assert s.str.normalize("NFKC").to_pylist() == ["xxaxx", None, "fi"]
+
+
+def test_series_utf8_tokenize_and_ngrams() -> None:
+    s = Series.from_arrow(pa.array(["a b  c", None]))
+    assert s.str.tokenize().to_pylist() == [["a", "b", "c"], None]
+    assert s.str.ngrams(2).to_pylist() == [["a b", "b c"], None]
+    assert s.str.ngrams(2, chars=True).to_pylist() == [["a ", " b", "b ", "  ", " c"], None]
+    assert s.str.word_count().to_pylist() == [3, None]
+
+
+def test_series_utf8_minhash_and_lsh_bands() -> None:
+    s = Series.from_pylist(["a b c d", "a b c d", None])
+    signatures = s.minhash(num_hashes=8, ngram_size=2)
+    assert signatures.datatype() == DataType.fixed_size_list(DataType.uint32(), 8)
+    result = signatures.to_pylist()
+    assert result[0] == result[1]
+    assert result[2] is None
+
+    bands = signatures.lsh_bands(2).to_pylist()
+    assert bands[0] == bands[1]
+    assert len(bands[0]) == 2
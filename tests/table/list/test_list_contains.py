@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_contains():
+    table = MicroPartition.from_pydict(
+        {
+            "col": [["a", "b"], [], None, ["c", None], ["b"]],
+            "item": ["b", "a", "a", None, "c"],
+        }
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.contains("b").alias("lit"),
+            col("col").list.contains(col("item")).alias("per_row"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "lit": [True, False, None, False, True],
+        "per_row": [True, False, None, None, False],
+    }
+
+
+def test_fixed_size_list_contains():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3, 4], None]})
+    table = table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.int64(), 2))])
+
+    result = table.eval_expression_list([col("col").list.contains(3)])
+
+    assert result.to_pydict() == {"col": [False, True, None]}
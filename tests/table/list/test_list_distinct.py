@@ -0,0 +1,22 @@
+from __future__ import annotations
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_distinct():
+    table = MicroPartition.from_pydict({"col": [["b", "a", "b", "c", "a"], [], None, ["x", None, None]]})
+
+    result = table.eval_expression_list([col("col").list.distinct()])
+
+    assert result.to_pydict() == {"col": [["b", "a", "c"], [], None, ["x", None]]}
+
+
+def test_fixed_size_list_distinct():
+    table = MicroPartition.from_pydict({"col": [[1, 1, 2], [3, 4, 3]]})
+    table = table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.int64(), 3))])
+
+    result = table.eval_expression_list([col("col").list.distinct()])
+
+    assert result.to_pydict() == {"col": [[1, 2], [3, 4]]}
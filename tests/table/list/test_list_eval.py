@@ -0,0 +1,110 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col, element, lit
+from daft.table import MicroPartition
+
+
+def test_list_eval():
+    table = MicroPartition.from_pydict({"col": [[1, 2, None], [], None, [4]]})
+
+    result = table.eval_expression_list(
+        [
+            (col("col").list.eval(element() * 2)).alias("doubled"),
+            (col("col").list.eval(element().cast(DataType.string()))).alias("strings"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "doubled": [[2, 4, None], [], None, [8]],
+        "strings": [["1", "2", None], [], None, ["4"]],
+    }
+
+
+def test_list_eval_literal():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [], None]})
+
+    result = table.eval_expression_list([col("col").list.eval(lit("a"))])
+
+    assert result.to_pydict() == {"col": [["a", "a"], [], None]}
+
+
+def test_list_filter():
+    table = MicroPartition.from_pydict({"col": [[-1, 2, None, 3], [], None, [-4]]})
+
+    result = table.eval_expression_list([col("col").list.filter(element() > 0)])
+
+    assert result.to_pydict() == {"col": [[2, 3], [], None, []]}
+
+
+def test_fixed_size_list_eval_and_filter():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3, 4], None]})
+    fixed_dtype = DataType.fixed_size_list(DataType.int64(), 2)
+    table = table.eval_expression_list([col("col").cast(fixed_dtype)])
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.eval(element() + 1).alias("plus_one"),
+            col("col").list.filter(element() % 2 == 0).alias("even"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "plus_one": [[2, 3], [4, 5], None],
+        "even": [[2], [4], None],
+    }
+
+
+def test_list_eval_on_sliced_table():
+    table = MicroPartition.from_pydict({"col": [[1], [2, 3], [4]]}).slice(1, 3)
+
+    result = table.eval_expression_list([col("col").list.eval(element() * 10)])
+
+    assert result.to_pydict() == {"col": [[20, 30], [40]]}
+
+
+def test_element_outside_list_eval():
+    table = MicroPartition.from_pydict({"col": [1, 2]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([element() + 1])
+
+
+def test_list_filter_non_boolean_predicate():
+    table = MicroPartition.from_pydict({"col": [[1, 2]]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").list.filter(element() + 1)])
+
+
+def test_element_does_not_resolve_to_empty_column_name():
+    table = MicroPartition.from_pydict({"": [1, 2]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([element() + 1])
+
+
+@pytest.mark.parametrize(
+    "element_expr",
+    [
+        element().sum(),
+        element() - element().mean(),
+    ],
+)
+def test_list_eval_rejects_aggregations(element_expr):
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3]]})
+
+    with pytest.raises(ValueError, match="not supported over list elements"):
+        table.eval_expression_list([col("col").list.eval(element_expr)])
+
+    with pytest.raises(ValueError, match="not supported over list elements"):
+        table.eval_expression_list([col("col").list.filter(element_expr > 0)])
+
+
+def test_list_eval_rejects_window_functions():
+    table = MicroPartition.from_pydict({"col": [[1, 2], [3]]})
+
+    with pytest.raises(ValueError, match="not supported over list elements"):
+        table.eval_expression_list([col("col").list.eval(element().cum_sum())])
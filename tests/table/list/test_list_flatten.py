@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_flatten():
+    table = MicroPartition.from_pydict({"col": [[[1, 2], [3]], [[], None, [4]], [], None]})
+
+    result = table.eval_expression_list([col("col").list.flatten()])
+
+    assert result.to_pydict() == {"col": [[1, 2, 3], [4], [], None]}
+
+
+def test_list_of_fixed_size_list_flatten():
+    table = MicroPartition.from_pydict({"col": [[[1, 2], [3, 4]], [None, [5, 6]]]})
+    dtype = DataType.list(DataType.fixed_size_list(DataType.int64(), 2))
+    table = table.eval_expression_list([col("col").cast(dtype)])
+
+    result = table.eval_expression_list([col("col").list.flatten()])
+
+    assert result.to_pydict() == {"col": [[1, 2, 3, 4], [5, 6]]}
+
+
+def test_list_flatten_not_nested():
+    table = MicroPartition.from_pydict({"col": [[1, 2]]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").list.flatten()])
@@ -0,0 +1,49 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_slice():
+    table = MicroPartition.from_pydict(
+        {
+            "col": [[1, 2, 3, 4], [5], [], None, [6, 7, 8]],
+            "start": [1, 0, 0, 0, -2],
+        }
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.slice(1).alias("from_1"),
+            col("col").list.slice(0, 2).alias("to_2"),
+            col("col").list.slice(-2, 10).alias("last_2"),
+            col("col").list.slice(2, 1).alias("empty"),
+            col("col").list.slice(col("start")).alias("per_row"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "from_1": [[2, 3, 4], [], [], None, [7, 8]],
+        "to_2": [[1, 2], [5], [], None, [6, 7]],
+        "last_2": [[3, 4], [5], [], None, [7, 8]],
+        "empty": [[], [], [], None, []],
+        "per_row": [[2, 3, 4], [5], [], None, [7, 8]],
+    }
+
+
+def test_fixed_size_list_slice():
+    table = MicroPartition.from_pydict({"col": [["a", "b", "c"], None, ["d", "e", "f"]]})
+    table = table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.string(), 3))])
+
+    result = table.eval_expression_list([col("col").list.slice(1, 2)])
+
+    assert result.to_pydict() == {"col": [["b"], None, ["e"]]}
+
+
+def test_list_slice_bad_bound_type():
+    table = MicroPartition.from_pydict({"col": [[1, 2]]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").list.slice("a")])
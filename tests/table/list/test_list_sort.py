@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_sort():
+    table = MicroPartition.from_pydict({"col": [[3, 1, 2], [], None, [5, 4]]})
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.sort().alias("asc"),
+            col("col").list.sort(desc=True).alias("desc"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "asc": [[1, 2, 3], [], None, [4, 5]],
+        "desc": [[3, 2, 1], [], None, [5, 4]],
+    }
+
+
+def test_list_sort_strings():
+    table = MicroPartition.from_pydict({"col": [["b", "c", "a"], ["z", "y"]]})
+
+    result = table.eval_expression_list([col("col").list.sort()])
+
+    assert result.to_pydict() == {"col": [["a", "b", "c"], ["y", "z"]]}
+
+
+def test_fixed_size_list_sort():
+    dtype = DataType.fixed_size_list(DataType.int64(), 2)
+    table = MicroPartition.from_pydict({"col": [[2, 1], None, [3, 4]]})
+    table = table.eval_expression_list([col("col").cast(dtype)])
+
+    result = table.eval_expression_list([col("col").list.sort(desc=True)])
+
+    assert result.schema()["col"].dtype == dtype
+    assert result.to_pydict() == {"col": [[2, 1], None, [4, 3]]}
+
+
+def test_list_sort_structs():
+    table = MicroPartition.from_pydict(
+        {
+            "col": [
+                [{"a": 2, "b": "x"}, None, {"a": 1, "b": "y"}, {"a": 1, "b": "a"}, {"a": None, "b": "z"}],
+                [{"a": 3, "b": "c"}],
+                None,
+            ]
+        }
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col").list.sort().alias("asc"),
+            col("col").list.sort(desc=True).alias("desc"),
+        ]
+    )
+
+    # Structs are ordered by their fields in order, and null structs and fields sort last, or first when descending.
+    assert result.to_pydict() == {
+        "asc": [
+            [{"a": 1, "b": "a"}, {"a": 1, "b": "y"}, {"a": 2, "b": "x"}, {"a": None, "b": "z"}, None],
+            [{"a": 3, "b": "c"}],
+            None,
+        ],
+        "desc": [
+            [None, {"a": None, "b": "z"}, {"a": 2, "b": "x"}, {"a": 1, "b": "y"}, {"a": 1, "b": "a"}],
+            [{"a": 3, "b": "c"}],
+            None,
+        ],
+    }
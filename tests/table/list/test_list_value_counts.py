@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_value_counts():
+    table = MicroPartition.from_pydict({"col": [["a", "b", "a", None], [], None, ["c"]]})
+
+    result = table.eval_expression_list([col("col").list.value_counts().alias("counts")])
+    result = result.eval_expression_list(
+        [
+            col("counts").map.keys().alias("keys"),
+            col("counts").map.values().alias("values"),
+            col("counts").map.get("a").alias("a"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "keys": [["a", "b"], [], None, ["c"]],
+        "values": [[2, 1], [], None, [1]],
+        "a": [2, None, None, None],
+    }
@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_list_zip():
+    table = MicroPartition.from_pydict(
+        {
+            "x": [[1, 2], [3], [], None, [4, 5]],
+            "y": [["a", "b", "c"], ["d"], ["e"], ["f"], None],
+        }
+    )
+
+    result = table.eval_expression_list([col("x").list.zip(col("y"))])
+
+    assert result.schema()["x"].dtype == DataType.list(
+        DataType.struct({"x": DataType.int64(), "y": DataType.string()})
+    )
+    assert result.to_pydict() == {
+        "x": [
+            [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}],
+            [{"x": 3, "y": "d"}],
+            [],
+            None,
+            None,
+        ]
+    }
+
+
+def test_list_zip_fixed_size_lists():
+    table = MicroPartition.from_pydict({"x": [[1, 2], [3, 4]], "y": [[5, 6, 7], [8]]})
+    table = table.eval_expression_list([col("x").cast(DataType.fixed_size_list(DataType.int64(), 2)), col("y")])
+
+    result = table.eval_expression_list([col("x").list.zip(col("y"), col("x").alias("z"))])
+
+    assert result.to_pydict() == {
+        "x": [
+            [{"x": 1, "y": 5, "z": 1}, {"x": 2, "y": 6, "z": 2}],
+            [{"x": 3, "y": 8, "z": 3}],
+        ]
+    }
+
+
+def test_list_zip_duplicate_names():
+    table = MicroPartition.from_pydict({"x": [[1, 2]]})
+
+    with pytest.raises(ValueError, match="duplicate name"):
+        table.eval_expression_list([col("x").list.zip(col("x"))])
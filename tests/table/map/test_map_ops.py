@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+from daft.expressions import col, lit
+from daft.table import MicroPartition
+
+
+def _map_table() -> MicroPartition:
+    data = pa.array(
+        [[("a", 1), ("b", 2)], [("b", 3)], None, [], [("c", None), ("a", 5)]],
+        type=pa.map_(pa.string(), pa.int64()),
+    )
+    return MicroPartition.from_arrow(pa.table({"map": data, "key": ["b", "b", "a", "a", "c"]}))
+
+
+def test_map_get():
+    table = _map_table()
+    result = table.eval_expression_list([col("map").map.get("a")])
+    assert result.to_pydict() == {"map": [1, None, None, None, 5]}
+
+
+def test_map_get_with_key_per_row():
+    table = _map_table()
+    result = table.eval_expression_list([col("map").map.get(col("key"))])
+    assert result.to_pydict() == {"map": [2, 3, None, None, None]}
+
+
+def test_map_get_missing_key():
+    table = _map_table()
+    result = table.eval_expression_list([col("map").map.get(lit("z"))])
+    assert result.to_pydict() == {"map": [None] * 5}
+
+
+def test_map_keys_and_values():
+    table = _map_table()
+    result = table.eval_expression_list([col("map").map.keys().alias("keys"), col("map").map.values().alias("values")])
+    assert result.to_pydict() == {
+        "keys": [["a", "b"], ["b"], None, [], ["c", "a"]],
+        "values": [[1, 2], [3], None, [], [None, 5]],
+    }
+
+
+def test_map_get_on_sliced_table():
+    table = _map_table().slice(1, 5)
+    result = table.eval_expression_list([col("map").map.get("a")])
+    assert result.to_pydict() == {"map": [None, None, None, 5]}
+
+
+def test_list_to_map():
+    table = MicroPartition.from_pydict(
+        {
+            "keys": [["a", "b"], None, ["c"], []],
+            "values": [[1.0, 2.0], [3.0], None, []],
+        }
+    )
+    result = table.eval_expression_list([col("keys").list.to_map(col("values")).alias("map")])
+    roundtrip = result.eval_expression_list(
+        [
+            col("map").map.get("b").alias("b"),
+            col("map").map.keys().alias("keys"),
+            col("map").map.values().alias("values"),
+        ]
+    )
+    assert roundtrip.to_pydict() == {
+        "b": [2.0, None, None, None],
+        "keys": [["a", "b"], None, None, []],
+        "values": [[1.0, 2.0], None, None, []],
+    }
+
+
+def test_list_to_map_mismatched_lengths():
+    table = MicroPartition.from_pydict({"keys": [["a", "b"]], "values": [[1]]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("keys").list.to_map(col("values"))])
+
+
+def test_list_to_map_null_key():
+    table = MicroPartition.from_pydict({"keys": [["a", None]], "values": [[1, 2]]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("keys").list.to_map(col("values"))])
+
+
+def test_map_get_on_non_map():
+    table = MicroPartition.from_pydict({"col": [1, 2]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").map.get("a")])
@@ -0,0 +1,75 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col, lit, struct
+from daft.table import MicroPartition
+
+
+def test_struct_pack():
+    table = MicroPartition.from_pydict({"a": [1, None, 3], "b": ["x", "y", None]})
+
+    result = table.eval_expression_list([struct(col("a"), col("b"), lit(True).alias("c")).alias("s")])
+
+    assert result.to_pydict() == {
+        "s": [
+            {"a": 1, "b": "x", "c": True},
+            {"a": None, "b": "y", "c": True},
+            {"a": 3, "b": None, "c": True},
+        ]
+    }
+
+
+def test_struct_pack_column_names():
+    table = MicroPartition.from_pydict({"a": [1, 2], "b": [3, 4]})
+
+    result = table.eval_expression_list([struct("a", "b")])
+
+    assert result.to_pydict() == {"a": [{"a": 1, "b": 3}, {"a": 2, "b": 4}]}
+
+
+def test_struct_pack_duplicate_names():
+    table = MicroPartition.from_pydict({"a": [1, 2]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([struct(col("a"), col("a"))])
+
+
+def test_struct_rename_fields():
+    table = MicroPartition.from_pydict({"col": [{"foo": 1, "bar": "a"}, None, {"foo": 3, "bar": None}]})
+
+    result = table.eval_expression_list([col("col").struct.rename_fields(["x", "y"])])
+
+    assert result.to_pydict() == {"col": [{"x": 1, "y": "a"}, None, {"x": 3, "y": None}]}
+
+
+def test_struct_rename_fields_wrong_count():
+    table = MicroPartition.from_pydict({"col": [{"foo": 1, "bar": "a"}]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").struct.rename_fields(["x"])])
+
+
+def test_struct_with_field():
+    table = MicroPartition.from_pydict(
+        {"col": [{"foo": 1, "bar": "a"}, None, {"foo": 3, "bar": None}], "other": [10, 20, 30]}
+    )
+
+    result = table.eval_expression_list(
+        [
+            col("col").struct.with_field("foo", col("other")).alias("replaced"),
+            col("col").struct.with_field("baz", 1.5).alias("added"),
+        ]
+    )
+
+    assert result.to_pydict() == {
+        "replaced": [{"foo": 10, "bar": "a"}, None, {"foo": 30, "bar": None}],
+        "added": [{"foo": 1, "bar": "a", "baz": 1.5}, None, {"foo": 3, "bar": None, "baz": 1.5}],
+    }
+
+
+def test_struct_with_field_not_struct():
+    table = MicroPartition.from_pydict({"col": [1, 2]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").struct.with_field("foo", 1)])
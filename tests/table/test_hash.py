@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import hashlib
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_hash_expression():
+    table = MicroPartition.from_pydict({"col": ["foo", None]})
+
+    result = table.eval_expression_list(
+        [
+            col("col").hash().alias("xxhash64"),
+            col("col").hash(seed=1, hash_function="murmur3_32").alias("murmur3_32"),
+            col("col").hash(hash_function="sha256").alias("sha256"),
+        ]
+    )
+
+    assert result.schema()["xxhash64"].dtype == DataType.uint64()
+    assert result.schema()["murmur3_32"].dtype == DataType.int32()
+    assert result.schema()["sha256"].dtype == DataType.string()
+    assert result.to_pydict()["sha256"] == [hashlib.sha256(b"foo").hexdigest(), None]
+    assert result.to_pydict()["xxhash64"][1] is None
+
+
+def test_hash_expression_seed_changes_hashes():
+    table = MicroPartition.from_pydict({"col": [1, 2, 3]})
+
+    result = table.eval_expression_list(
+        [
+            col("col").hash(seed=1).alias("a"),
+            col("col").hash(seed=2).alias("b"),
+        ]
+    ).to_pydict()
+
+    assert result["a"] != result["b"]
+
+
+def test_hash_expression_seed_unsupported():
+    table = MicroPartition.from_pydict({"col": ["foo"]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").hash(seed=1, hash_function="md5")])
@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.expressions import col
+from daft.table import MicroPartition
+
+DOCS = [
+    "the quick brown fox jumps over the lazy dog",
+    "the quick brown fox jumps over the lazy dog",
+    "the quick brown fox jumps over the lazy cat",
+    "lorem ipsum dolor sit amet consectetur adipiscing elit",
+    None,
+]
+
+
+def _jaccard_estimate(left: list[int], right: list[int]) -> float:
+    return sum(a == b for a, b in zip(left, right)) / len(left)
+
+
+def test_minhash():
+    table = MicroPartition.from_pydict({"text": DOCS})
+
+    result = table.eval_expression_list([col("text").minhash(num_hashes=128, ngram_size=2)])
+
+    assert result.schema()["text"].dtype == DataType.fixed_size_list(DataType.uint32(), 128)
+    signatures = result.to_pydict()["text"]
+    assert signatures[0] == signatures[1]
+    assert _jaccard_estimate(signatures[0], signatures[2]) > _jaccard_estimate(signatures[0], signatures[3])
+    assert signatures[4] is None
+
+
+def test_minhash_seed():
+    table = MicroPartition.from_pydict({"text": DOCS[:1]})
+
+    result = table.eval_expression_list(
+        [
+            col("text").minhash(num_hashes=16, ngram_size=1, seed=1).alias("a"),
+            col("text").minhash(num_hashes=16, ngram_size=1, seed=1).alias("b"),
+            col("text").minhash(num_hashes=16, ngram_size=1, seed=2).alias("c"),
+        ]
+    ).to_pydict()
+
+    assert result["a"] == result["b"]
+    assert result["a"] != result["c"]
+
+
+def test_minhash_invalid_dtype():
+    table = MicroPartition.from_pydict({"col": [1, 2]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").minhash(num_hashes=16, ngram_size=1)])
+
+
+def test_lsh_bands():
+    table = MicroPartition.from_pydict({"text": DOCS})
+
+    result = table.eval_expression_list([col("text").minhash(num_hashes=16, ngram_size=2).lsh_bands(4)])
+
+    assert result.schema()["text"].dtype == DataType.fixed_size_list(DataType.uint64(), 4)
+    bands = result.to_pydict()["text"]
+    assert bands[0] == bands[1]
+    assert not set(bands[0]) & set(bands[3])
+    assert bands[4] is None
+
+
+def test_lsh_bands_invalid_num_bands():
+    table = MicroPartition.from_pydict({"text": DOCS})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("text").minhash(num_hashes=16, ngram_size=2).lsh_bands(3)])
+
+
+def test_lsh_bands_invalid_dtype():
+    table = MicroPartition.from_pydict({"col": [[1, 2, 3, 4]]})
+
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.int64(), 4)).lsh_bands(2)])
+
+
+def test_lsh_bands_null_values():
+    table = MicroPartition.from_pydict({"col": [[0, None], [None, 0], [0, 0]]})
+
+    result = table.eval_expression_list([col("col").cast(DataType.fixed_size_list(DataType.uint32(), 2)).lsh_bands(1)])
+
+    bands = [band for [band] in result.to_pydict()["col"]]
+    assert len(set(bands)) == 3
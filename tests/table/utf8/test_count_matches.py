@@ -0,0 +1,22 @@
+from __future__ import annotations
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_count_matches():
+    table = MicroPartition.from_pydict({"col": ["abcab", None, "aaaa", "xyz"]})
+    result = table.eval_expression_list([col("col").str.count_matches("aa")])
+    assert result.to_pydict() == {"col": [0, None, 2, 0]}
+
+
+def test_utf8_count_matches_regex():
+    table = MicroPartition.from_pydict({"col": ["a1b22c333", None, "none"]})
+    result = table.eval_expression_list([col("col").str.count_matches(r"\d+", regex=True)])
+    assert result.to_pydict() == {"col": [3, None, 0]}
+
+
+def test_utf8_count_matches_per_row_pattern():
+    table = MicroPartition.from_pydict({"col": ["abab", "abab", "abab"], "pattern": ["a", "ab", None]})
+    result = table.eval_expression_list([col("col").str.count_matches(col("pattern"))])
+    assert result.to_pydict() == {"col": [2, 2, None]}
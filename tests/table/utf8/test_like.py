@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_like():
+    table = MicroPartition.from_pydict({"col": ["foobar", "Foobar", "fo", None, "foo%"]})
+    result = table.eval_expression_list(
+        [
+            col("col").str.like("foo%").alias("prefix"),
+            col("col").str.like("f_").alias("two_chars"),
+            col("col").str.like("foo\\%").alias("escaped"),
+            col("col").str.ilike("FOO%").alias("ilike"),
+        ]
+    )
+    assert result.to_pydict() == {
+        "prefix": [True, False, False, None, True],
+        "two_chars": [False, False, True, None, False],
+        "escaped": [False, False, False, None, True],
+        "ilike": [True, True, False, None, True],
+    }
+
+
+def test_utf8_like_regex_characters_are_literal():
+    table = MicroPartition.from_pydict({"col": ["a.c", "abc"]})
+    result = table.eval_expression_list([col("col").str.like("a.c")])
+    assert result.to_pydict() == {"col": [True, False]}
+
+
+def test_utf8_like_per_row_pattern():
+    table = MicroPartition.from_pydict({"col": ["abc", "abc"], "pattern": ["a%", "%b"]})
+    result = table.eval_expression_list([col("col").str.like(col("pattern"))])
+    assert result.to_pydict() == {"col": [True, False]}
+
+
+def test_utf8_like_trailing_escape():
+    table = MicroPartition.from_pydict({"col": ["abc"]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").str.like("abc\\")])
@@ -0,0 +1,29 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_word_ngrams():
+    table = MicroPartition.from_pydict({"col": ["the quick  brown fox", None, "one", ""]})
+    result = table.eval_expression_list([col("col").str.ngrams(2)])
+    assert result.to_pydict() == {"col": [["the quick", "quick brown", "brown fox"], None, [], []]}
+
+
+def test_utf8_char_ngrams():
+    table = MicroPartition.from_pydict({"col": ["abcd", None, "ab", "héé"]})
+    result = table.eval_expression_list([col("col").str.ngrams(3, chars=True)])
+    assert result.to_pydict() == {"col": [["abc", "bcd"], None, [], ["héé"]]}
+
+
+def test_utf8_unigrams():
+    table = MicroPartition.from_pydict({"col": ["a b"]})
+    result = table.eval_expression_list([col("col").str.ngrams(1)])
+    assert result.to_pydict() == {"col": [["a", "b"]]}
+
+
+def test_utf8_ngrams_non_positive_n():
+    with pytest.raises(ValueError):
+        col("col").str.ngrams(0)
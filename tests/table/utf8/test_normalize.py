@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+import unicodedata
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+DATA = ["é", "ﬁ", None, "abc", "①"]
+
+
+@pytest.mark.parametrize("form", ["NFC", "NFD", "NFKC", "NFKD"])
+def test_utf8_normalize(form):
+    table = MicroPartition.from_pydict({"col": DATA})
+    result = table.eval_expression_list([col("col").str.normalize(form)])
+    expected = [unicodedata.normalize(form, s) if s is not None else None for s in DATA]
+    assert result.to_pydict() == {"col": expected}
+
+
+def test_utf8_normalize_unknown_form():
+    with pytest.raises(ValueError):
+        col("col").str.normalize("NFX")
@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_lpad_and_rpad():
+    table = MicroPartition.from_pydict({"col": ["42", None, "hello world", "", "ü"]})
+    result = table.eval_expression_list(
+        [col("col").str.lpad(5, "0").alias("lpad"), col("col").str.rpad(5, "ab").alias("rpad")]
+    )
+    assert result.to_pydict() == {
+        "lpad": ["00042", None, "hello", "00000", "0000ü"],
+        "rpad": ["42aba", None, "hello", "ababa", "üabab"],
+    }
+
+
+def test_utf8_lpad_default_pad():
+    table = MicroPartition.from_pydict({"col": ["a"]})
+    result = table.eval_expression_list([col("col").str.lpad(3)])
+    assert result.to_pydict() == {"col": ["  a"]}
+
+
+def test_utf8_lpad_per_row_length():
+    table = MicroPartition.from_pydict({"col": ["a", "b", "c"], "length": [1, 2, None]})
+    result = table.eval_expression_list([col("col").str.lpad(col("length"), "-")])
+    assert result.to_pydict() == {"col": ["a", "-b", None]}
+
+
+def test_utf8_lpad_negative_length():
+    table = MicroPartition.from_pydict({"col": ["a"]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").str.lpad(-1)])
@@ -0,0 +1,18 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_repeat():
+    table = MicroPartition.from_pydict({"col": ["ab", None, "", "x"], "n": [3, 2, 5, 0]})
+    result = table.eval_expression_list([col("col").str.repeat(col("n"))])
+    assert result.to_pydict() == {"col": ["ababab", None, "", ""]}
+
+
+def test_utf8_repeat_negative():
+    table = MicroPartition.from_pydict({"col": ["ab"]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").str.repeat(-1)])
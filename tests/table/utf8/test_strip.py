@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_strip():
+    table = MicroPartition.from_pydict({"col": ["  a b  ", None, "\tc\n"]})
+    result = table.eval_expression_list([col("col").str.strip()])
+    assert result.to_pydict() == {"col": ["a b", None, "c"]}
+
+
+def test_utf8_strip_chars():
+    table = MicroPartition.from_pydict({"col": ["__-a_b-_", None, "ab", "--"]})
+    result = table.eval_expression_list(
+        [
+            col("col").str.lstrip("_-").alias("lstrip"),
+            col("col").str.rstrip("_-").alias("rstrip"),
+            col("col").str.strip("_-").alias("strip"),
+        ]
+    )
+    assert result.to_pydict() == {
+        "lstrip": ["a_b-_", None, "ab", ""],
+        "rstrip": ["__-a_b", None, "ab", ""],
+        "strip": ["a_b", None, "ab", ""],
+    }
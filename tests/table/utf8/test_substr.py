@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_substr():
+    table = MicroPartition.from_pydict({"col": ["hello", None, "héllo", "ab"]})
+    result = table.eval_expression_list(
+        [
+            col("col").str.substr(1, 3).alias("middle"),
+            col("col").str.substr(-2).alias("last_two"),
+            col("col").str.substr(10, 2).alias("out_of_bounds"),
+        ]
+    )
+    assert result.to_pydict() == {
+        "middle": ["ell", None, "éll", "b"],
+        "last_two": ["lo", None, "lo", "ab"],
+        "out_of_bounds": ["", None, "", ""],
+    }
+
+
+def test_utf8_substr_per_row_bounds():
+    table = MicroPartition.from_pydict({"col": ["abcdef", "abcdef", "abcdef"], "start": [0, 2, 4], "len": [1, None, 5]})
+    result = table.eval_expression_list([col("col").str.substr(col("start"), col("len"))])
+    assert result.to_pydict() == {"col": ["a", "cdef", "ef"]}
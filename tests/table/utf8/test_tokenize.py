@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.expressions import col
+from daft.table import MicroPartition
+
+
+def test_utf8_tokenize():
+    table = MicroPartition.from_pydict({"col": [" hello  world ", None, "", "one\ttwo\nthree"]})
+    result = table.eval_expression_list([col("col").str.tokenize()])
+    assert result.to_pydict() == {"col": [["hello", "world"], None, [], ["one", "two", "three"]]}
+
+
+def test_utf8_tokenize_regex():
+    table = MicroPartition.from_pydict({"col": ["a,b;;c", "...x..."]})
+    result = table.eval_expression_list([col("col").str.tokenize(r"\W+")])
+    assert result.to_pydict() == {"col": [["a", "b", "c"], ["x"]]}
+
+
+def test_utf8_tokenize_invalid_regex():
+    table = MicroPartition.from_pydict({"col": ["a"]})
+    with pytest.raises(ValueError):
+        table.eval_expression_list([col("col").str.tokenize("(")])
+
+
+def test_utf8_word_count():
+    table = MicroPartition.from_pydict({"col": ["the quick  fox", None, "", "   "]})
+    result = table.eval_expression_list([col("col").str.word_count()])
+    assert result.to_pydict() == {"col": [3, None, 0, 0]}